@@ -0,0 +1,189 @@
+//! Symbol sets shared between days, their generators and fuzzers. Each has a
+//! 256-entry table built at compile time, so parsing a byte is one lookup.
+
+use std::fmt;
+
+/// Marks bytes outside an alphabet in its lookup table.
+const NOT_IN_ALPHABET: u8 = u8::MAX;
+
+/// Index of every byte in `symbols`, or `NOT_IN_ALPHABET`.
+const fn lookup_table(symbols: &[u8]) -> [u8; 256] {
+    let mut table = [NOT_IN_ALPHABET; 256];
+    let mut i = 0;
+
+    while i < symbols.len() {
+        table[symbols[i] as usize] = i as u8;
+        i += 1;
+    }
+
+    table
+}
+
+/// Camel Cards ranks (day 7), lowest first, so the derived ordering is the
+/// part 1 ordering.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum CardRank {
+    Two,
+    Three,
+    Four,
+    Five,
+    Six,
+    Seven,
+    Eight,
+    Nine,
+    Ten,
+    Jack,
+    Queen,
+    King,
+    Ace,
+}
+
+impl CardRank {
+    pub const ALL: [CardRank; 13] = [
+        CardRank::Two,
+        CardRank::Three,
+        CardRank::Four,
+        CardRank::Five,
+        CardRank::Six,
+        CardRank::Seven,
+        CardRank::Eight,
+        CardRank::Nine,
+        CardRank::Ten,
+        CardRank::Jack,
+        CardRank::Queen,
+        CardRank::King,
+        CardRank::Ace,
+    ];
+
+    /// How each rank is written in the puzzle input, in `ALL` order.
+    pub const SYMBOLS: &'static [u8; 13] = b"23456789TJQKA";
+
+    const TABLE: [u8; 256] = lookup_table(Self::SYMBOLS);
+
+    #[tracing::instrument]
+    pub fn from_byte(byte: u8) -> Option<Self> {
+        Self::ALL.get(Self::TABLE[byte as usize] as usize).copied()
+    }
+
+    #[tracing::instrument]
+    pub fn from_char(c: char) -> Option<Self> {
+        u8::try_from(c).ok().and_then(Self::from_byte)
+    }
+
+    /// Position in `ALL`, from 0 for `Two` up to 12 for `Ace`.
+    #[tracing::instrument]
+    pub fn index(&self) -> usize {
+        *self as usize
+    }
+
+    #[tracing::instrument]
+    pub fn symbol(&self) -> char {
+        Self::SYMBOLS[self.index()] as char
+    }
+}
+
+impl fmt::Display for CardRank {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.symbol())
+    }
+}
+
+/// One character of a day 8 node name. The puzzle input only uses capitals,
+/// but the part 2 example also uses digits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NodeLetter(u8);
+
+impl NodeLetter {
+    pub const SYMBOLS: &'static [u8; 36] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789";
+
+    const TABLE: [u8; 256] = lookup_table(Self::SYMBOLS);
+
+    #[tracing::instrument]
+    pub fn from_byte(byte: u8) -> Option<Self> {
+        (Self::TABLE[byte as usize] != NOT_IN_ALPHABET).then_some(Self(byte))
+    }
+
+    #[tracing::instrument]
+    pub fn from_char(c: char) -> Option<Self> {
+        u8::try_from(c).ok().and_then(Self::from_byte)
+    }
+
+    /// Position in `SYMBOLS`, for dense per-letter tables.
+    #[tracing::instrument]
+    pub fn index(&self) -> usize {
+        Self::TABLE[self.0 as usize] as usize
+    }
+
+    /// The ASCII byte, which is how node ids are packed.
+    #[tracing::instrument]
+    pub fn byte(&self) -> u8 {
+        self.0
+    }
+
+    #[tracing::instrument]
+    pub fn symbol(&self) -> char {
+        self.0 as char
+    }
+}
+
+impl fmt::Display for NodeLetter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.symbol())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn it_should_round_trip_card_ranks() -> miette::Result<()> {
+        for (i, rank) in CardRank::ALL.iter().enumerate() {
+            assert_eq!(i, rank.index());
+            assert_eq!(Some(*rank), CardRank::from_char(rank.symbol()));
+        }
+
+        assert_eq!(Some(CardRank::Ten), CardRank::from_byte(b'T'));
+        assert_eq!("K", CardRank::King.to_string());
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_order_card_ranks_for_part_1() -> miette::Result<()> {
+        assert!(CardRank::Ace > CardRank::King);
+        assert!(CardRank::Jack > CardRank::Ten);
+        assert!(CardRank::Two < CardRank::Three);
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_reject_symbols_outside_alphabets() -> miette::Result<()> {
+        for c in ['1', 't', 'X', ' ', 'é'] {
+            assert_eq!(None, CardRank::from_char(c));
+        }
+
+        for c in ['a', '-', ' ', 'é'] {
+            assert_eq!(None, NodeLetter::from_char(c));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_round_trip_node_letters() -> miette::Result<()> {
+        for (i, byte) in NodeLetter::SYMBOLS.iter().enumerate() {
+            let letter = NodeLetter::from_byte(*byte).expect("symbol is in the alphabet");
+
+            assert_eq!(i, letter.index());
+            assert_eq!(*byte, letter.byte());
+        }
+
+        assert_eq!(
+            "Z",
+            NodeLetter::from_char('Z')
+                .map(|l| l.to_string())
+                .unwrap_or_default()
+        );
+        Ok(())
+    }
+}
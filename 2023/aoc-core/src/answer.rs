@@ -0,0 +1,200 @@
+use std::{
+    fmt,
+    hash::{Hash, Hasher},
+};
+
+/// A part's answer, whether a number or text like a row of letters read off a
+/// grid. Answers are equal when they'd be submitted the same, so a `u32` part
+/// matches an `i64` example or the text of an answers.toml entry.
+#[derive(Debug, Clone)]
+pub enum Answer {
+    Int(i64),
+    UInt(u64),
+    Text(String),
+}
+
+impl Answer {
+    /// The answer `text` stands for: a number when it reads as one, otherwise
+    /// the text itself, as answers.toml and `--expected` give them.
+    #[tracing::instrument]
+    pub fn parse(text: &str) -> Answer {
+        let text = text.trim();
+
+        text.parse::<u64>()
+            .map(Answer::UInt)
+            .or_else(|_| text.parse::<i64>().map(Answer::Int))
+            .unwrap_or_else(|_| Answer::Text(text.to_string()))
+    }
+
+    /// The exact string AoC expects: no separators, no labels, no trailing newline.
+    #[tracing::instrument]
+    pub fn to_submit_string(&self) -> String {
+        match self {
+            Answer::Int(value) => value.to_string(),
+            Answer::UInt(value) => value.to_string(),
+            Answer::Text(text) => text.trim_end().to_string(),
+        }
+    }
+
+    /// Human friendly version with thousands separators, only meant for display.
+    #[tracing::instrument]
+    pub fn to_pretty_string(&self) -> String {
+        match self {
+            Answer::Int(value) if *value < 0 => {
+                format!("-{}", group_digits(&value.unsigned_abs().to_string()))
+            }
+            Answer::Int(value) => group_digits(&value.to_string()),
+            Answer::UInt(value) => group_digits(&value.to_string()),
+            Answer::Text(text) => text.trim_end().to_string(),
+        }
+    }
+}
+
+#[tracing::instrument]
+fn group_digits(digits: &str) -> String {
+    let mut grouped = String::with_capacity(digits.len() + digits.len() / 3);
+
+    for (i, c) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i).is_multiple_of(3) {
+            grouped.push(',');
+        }
+        grouped.push(c);
+    }
+
+    grouped
+}
+
+impl PartialEq for Answer {
+    fn eq(&self, other: &Self) -> bool {
+        self.to_submit_string() == other.to_submit_string()
+    }
+}
+
+impl Eq for Answer {}
+
+impl Hash for Answer {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.to_submit_string().hash(state);
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_submit_string())
+    }
+}
+
+impl From<i32> for Answer {
+    fn from(value: i32) -> Self {
+        Answer::Int(value as i64)
+    }
+}
+
+impl From<i64> for Answer {
+    fn from(value: i64) -> Self {
+        Answer::Int(value)
+    }
+}
+
+/// Kept as an `Int` when it fits, otherwise as its digits.
+impl From<i128> for Answer {
+    fn from(value: i128) -> Self {
+        i64::try_from(value)
+            .map(Answer::Int)
+            .unwrap_or_else(|_| Answer::Text(value.to_string()))
+    }
+}
+
+impl From<u32> for Answer {
+    fn from(value: u32) -> Self {
+        Answer::UInt(value as u64)
+    }
+}
+
+impl From<u64> for Answer {
+    fn from(value: u64) -> Self {
+        Answer::UInt(value)
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(value: usize) -> Self {
+        Answer::UInt(value as u64)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn it_should_format_submit_string_without_decoration() -> miette::Result<()> {
+        assert_eq!("9565386", Answer::from(9565386u32).to_submit_string());
+        assert_eq!("-42", Answer::from(-42i32).to_submit_string());
+        assert_eq!("0", Answer::from(0u64).to_submit_string());
+        assert_eq!(Answer::Int(-42), Answer::from(-42i128));
+        assert_eq!(
+            "170141183460469231731687303715884105727",
+            Answer::from(i128::MAX).to_submit_string()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_trim_trailing_newlines_from_text() -> miette::Result<()> {
+        assert_eq!("EHPZPJGL", Answer::from("EHPZPJGL\n").to_submit_string());
+        assert_eq!("EHPZPJGL", Answer::from("EHPZPJGL\r\n").to_submit_string());
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_group_digits_for_display() -> miette::Result<()> {
+        assert_eq!("9,565,386", Answer::from(9565386u32).to_pretty_string());
+        assert_eq!("-1,000", Answer::from(-1000i64).to_pretty_string());
+        assert_eq!("999", Answer::from(999u32).to_pretty_string());
+        assert_eq!("100,000", Answer::from(100000u64).to_pretty_string());
+        assert_eq!(
+            "-9,223,372,036,854,775,808",
+            Answer::from(i64::MIN).to_pretty_string()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_parse_numbers_and_keep_other_text() -> miette::Result<()> {
+        assert!(matches!(Answer::parse("288"), Answer::UInt(288)));
+        assert!(matches!(Answer::parse(" -42\n"), Answer::Int(-42)));
+        assert!(matches!(Answer::parse("EHPZPJGL"), Answer::Text(text) if text == "EHPZPJGL"));
+        assert!(matches!(Answer::parse("1,000"), Answer::Text(_)));
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_equal_answers_that_submit_the_same() -> miette::Result<()> {
+        assert_eq!(Answer::from(288u32), Answer::from(288i64));
+        assert_eq!(Answer::from(288u32), Answer::parse("288"));
+        assert_eq!(Answer::from("EHPZPJGL\n"), Answer::parse("EHPZPJGL"));
+        assert_ne!(Answer::from(288u32), Answer::from(-288i64));
+        assert_ne!(Answer::from(288u32), Answer::from("0288"));
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_display_as_submit_string() -> miette::Result<()> {
+        assert_eq!("1234567", format!("{}", Answer::from(1234567u64)));
+        Ok(())
+    }
+}
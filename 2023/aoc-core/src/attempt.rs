@@ -0,0 +1,67 @@
+use std::{fmt, time::Duration};
+
+/// What became of an attempt at a day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    /// Still one of the day's variants.
+    Kept,
+    /// Superseded by the variant `by`.
+    Replaced { by: &'static str, why: &'static str },
+    /// Dropped without anything taking its place.
+    Abandoned { why: &'static str },
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Outcome::Kept => write!(f, "kept"),
+            Outcome::Replaced { by, why } => write!(f, "replaced by {}: {}", by, why),
+            Outcome::Abandoned { why } => write!(f, "abandoned: {}", why),
+        }
+    }
+}
+
+/// One entry in a day's changelog of ways it was solved, so what was tried
+/// and why it was dropped outlives the modules themselves. Days list theirs
+/// as `attempts::ATTEMPTS`, oldest first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Attempt {
+    /// The variant module, like `part2_opt`.
+    pub name: &'static str,
+    /// When it was written, as `YYYY-MM-DD`.
+    pub date: &'static str,
+    /// Its median on the real input, if it was ever benchmarked.
+    pub timing: Option<Duration>,
+    pub outcome: Outcome,
+}
+
+impl Attempt {
+    #[tracing::instrument]
+    pub fn is_kept(&self) -> bool {
+        self.outcome == Outcome::Kept
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn it_should_describe_outcomes() -> miette::Result<()> {
+        assert_eq!("kept", Outcome::Kept.to_string());
+        assert_eq!(
+            "replaced by part1: shared the parser",
+            Outcome::Replaced {
+                by: "part1",
+                why: "shared the parser"
+            }
+            .to_string()
+        );
+        assert_eq!(
+            "abandoned: no faster",
+            Outcome::Abandoned { why: "no faster" }.to_string()
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,409 @@
+use std::{
+    fmt,
+    ops::{Index, IndexMut},
+    str::FromStr,
+};
+
+use aoc_geometry::Point2;
+use miette::Diagnostic;
+use thiserror::Error;
+
+use crate::Footprint;
+
+#[derive(Error, Diagnostic, Debug, PartialEq, Eq)]
+pub enum Error {
+    #[error("Grid has no rows")]
+    Empty,
+    #[error("Row {row} is {width} cells wide but the first row is {expected}")]
+    RaggedRow {
+        row: usize,
+        width: usize,
+        expected: usize,
+    },
+    #[error("Unexpected cell {cell:?} at ({x}, {y})")]
+    UnexpectedCell { x: usize, y: usize, cell: char },
+}
+
+/// How `Grid::parse_with` turns input lines into rows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Layout {
+    /// Each line trimmed and blank ones skipped, so indented test input parses.
+    #[default]
+    Trimmed,
+    /// Lines exactly as they are, for grids where whitespace is a cell.
+    Exact,
+    /// Trimmed like `Trimmed`, with short rows filled out to the widest one.
+    Padded(char),
+}
+
+/// A rectangular grid stored row by row, with `(0, 0)` its top left cell.
+/// Indexing with a point outside it panics; `get` returns `None` instead.
+#[derive(Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+// Grids are usually whole puzzle inputs, so spans only get their size.
+impl<T> fmt::Debug for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Grid")
+            .field("width", &self.width)
+            .field("height", &self.height)
+            .finish()
+    }
+}
+
+impl<T> Footprint for Grid<T> {
+    fn footprint(&self) -> usize {
+        self.cells.len()
+    }
+}
+
+impl<T> Grid<T> {
+    /// Builds a grid from its rows, which must all be as wide as the first.
+    #[tracing::instrument(skip(rows))]
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self, Error> {
+        let width = rows.first().ok_or(Error::Empty)?.len();
+        let height = rows.len();
+
+        if let Some((row, cells)) = rows.iter().enumerate().find(|(_, r)| r.len() != width) {
+            return Err(Error::RaggedRow {
+                row,
+                width: cells.len(),
+                expected: width,
+            });
+        }
+
+        Ok(Self {
+            cells: rows.into_iter().flatten().collect(),
+            width,
+            height,
+        })
+    }
+
+    /// Parses one row per line as `policy` lays them out, turning every
+    /// character into a cell with `classify`. It sees each cell's position, so
+    /// it can note where the special ones are in the same pass.
+    #[tracing::instrument(skip(input, classify))]
+    pub fn parse_with(
+        input: &str,
+        policy: Layout,
+        mut classify: impl FnMut(Point2, char) -> Option<T>,
+    ) -> Result<Self, Error> {
+        let lines = match policy {
+            Layout::Exact => input.lines().collect::<Vec<_>>(),
+            Layout::Trimmed | Layout::Padded(_) => input
+                .lines()
+                .map(|line| line.trim())
+                .filter(|line| !line.is_empty())
+                .collect(),
+        };
+
+        // Only padding needs the widest line; elsewhere no row is filled out.
+        let (width, fill) = match policy {
+            Layout::Padded(fill) => {
+                let widest = lines.iter().map(|line| line.chars().count()).max();
+                (widest.unwrap_or(0), fill)
+            }
+            Layout::Exact | Layout::Trimmed => (0, ' '),
+        };
+
+        let rows = lines
+            .iter()
+            .enumerate()
+            .map(|(y, line)| {
+                let padding = std::iter::repeat_n(fill, width.saturating_sub(line.chars().count()));
+
+                line.chars()
+                    .chain(padding)
+                    .enumerate()
+                    .map(|(x, cell)| {
+                        classify(Point2::new(x as i64, y as i64), cell)
+                            .ok_or(Error::UnexpectedCell { x, y, cell })
+                    })
+                    .collect::<Result<Vec<_>, Error>>()
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+
+        Self::from_rows(rows)
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Where `point` is in `cells`, or `None` off the grid.
+    #[tracing::instrument(skip(self))]
+    fn offset(&self, point: Point2) -> Option<usize> {
+        let x = usize::try_from(point.x).ok().filter(|x| *x < self.width)?;
+        let y = usize::try_from(point.y).ok().filter(|y| *y < self.height)?;
+        Some(y * self.width + x)
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub fn contains(&self, point: Point2) -> bool {
+        self.offset(point).is_some()
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub fn get(&self, point: Point2) -> Option<&T> {
+        self.offset(point).map(|i| &self.cells[i])
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub fn get_mut(&mut self, point: Point2) -> Option<&mut T> {
+        self.offset(point).map(|i| &mut self.cells[i])
+    }
+
+    /// Every cell with its point, in reading order.
+    #[tracing::instrument(skip(self))]
+    pub fn cells(&self) -> impl Iterator<Item = (Point2, &T)> + '_ {
+        self.cells.iter().enumerate().map(|(i, cell)| {
+            let point = Point2::new((i % self.width) as i64, (i / self.width) as i64);
+            (point, cell)
+        })
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> + '_ {
+        self.cells.chunks(self.width)
+    }
+
+    /// The cells of column `x`, top to bottom.
+    #[tracing::instrument(skip(self))]
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> + '_ {
+        self.cells
+            .iter()
+            .skip(x)
+            .step_by(self.width)
+            .take(self.height)
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T> + '_> + '_ {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// Up, left, right and down from `point`, leaving out any off the grid.
+    #[tracing::instrument(skip(self))]
+    pub fn neighbours4(&self, point: Point2) -> impl Iterator<Item = Point2> + '_ {
+        point.neighbours4().filter(|p| self.contains(*p))
+    }
+
+    /// The eight surrounding cells in reading order, leaving out any off the grid.
+    #[tracing::instrument(skip(self))]
+    pub fn neighbours8(&self, point: Point2) -> impl Iterator<Item = Point2> + '_ {
+        point.neighbours8().filter(|p| self.contains(*p))
+    }
+}
+
+impl<T> Index<Point2> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point2) -> &T {
+        self.get(point).unwrap_or_else(|| {
+            panic!(
+                "{:?} is outside the {}x{} grid",
+                point, self.width, self.height
+            )
+        })
+    }
+}
+
+impl<T> IndexMut<Point2> for Grid<T> {
+    fn index_mut(&mut self, point: Point2) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(point)
+            .unwrap_or_else(|| panic!("{:?} is outside the {}x{} grid", point, width, height))
+    }
+}
+
+/// Any cell type that can be made from a single character, `char` included.
+impl<T: TryFrom<char>> FromStr for Grid<T> {
+    type Err = Error;
+
+    fn from_str(input: &str) -> Result<Self, Error> {
+        Self::parse_with(input, Layout::Trimmed, |_, cell| T::try_from(cell).ok())
+    }
+}
+
+/// One line per row with no separators, so a `Grid<char>` prints as it parsed.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    const IMAGE: &str = "...#
+    #...
+    ..#.";
+
+    #[test]
+    fn it_should_parse_and_print_chars() -> miette::Result<()> {
+        let grid = IMAGE.parse::<Grid<char>>()?;
+
+        assert_eq!(4, grid.width());
+        assert_eq!(3, grid.height());
+        assert_eq!('#', grid[Point2::new(3, 0)]);
+        assert_eq!(Some(&'#'), grid.get(Point2::new(2, 2)));
+        assert_eq!(None, grid.get(Point2::new(4, 0)));
+        assert_eq!("...#\n#...\n..#.\n", grid.to_string());
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_iterate_cells_rows_and_columns() -> miette::Result<()> {
+        let grid = IMAGE.parse::<Grid<char>>()?;
+
+        let galaxies = grid
+            .cells()
+            .filter(|(_, c)| **c == '#')
+            .map(|(p, _)| p)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![Point2::new(3, 0), Point2::new(0, 1), Point2::new(2, 2)],
+            galaxies
+        );
+
+        assert_eq!(
+            vec!['#', '.', '.', '.'],
+            grid.rows().nth(1).unwrap().to_vec()
+        );
+        assert_eq!(
+            vec!['.', '.', '#'],
+            grid.column(2).copied().collect::<Vec<_>>()
+        );
+        assert_eq!(4, grid.columns().count());
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_find_neighbours_inside_the_grid() -> miette::Result<()> {
+        let grid = Grid::from_rows(vec![vec![0; 3]; 3])?;
+
+        assert_eq!(
+            vec![Point2::new(1, 0), Point2::new(0, 1)],
+            grid.neighbours4(Point2::new(0, 0)).collect::<Vec<_>>()
+        );
+        assert_eq!(4, grid.neighbours4(Point2::new(1, 1)).count());
+        assert_eq!(8, grid.neighbours8(Point2::new(1, 1)).count());
+        assert_eq!(
+            vec![Point2::new(1, 1), Point2::new(2, 1), Point2::new(1, 2)],
+            grid.neighbours8(Point2::new(2, 2)).collect::<Vec<_>>()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_miss_points_off_the_grid() -> miette::Result<()> {
+        let grid = IMAGE.parse::<Grid<char>>()?;
+
+        assert!(grid.contains(Point2::new(3, 2)));
+        assert!(!grid.contains(Point2::new(-1, 0)));
+        assert!(!grid.contains(Point2::new(0, 3)));
+        assert_eq!(None, grid.get(Point2::new(0, -1)));
+        assert_eq!(None, grid.get(Point2::new(4, 0)));
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_update_cells() -> miette::Result<()> {
+        let mut grid = Grid::from_rows(vec![vec![0; 2]; 2])?;
+
+        grid[Point2::new(1, 0)] = 5;
+        *grid.get_mut(Point2::new(0, 1)).unwrap() += 2;
+
+        assert_eq!(
+            vec![0, 5, 2, 0],
+            grid.cells().map(|(_, n)| *n).collect::<Vec<_>>()
+        );
+        assert_eq!(None, grid.get_mut(Point2::new(2, 0)));
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_reject_ragged_empty_and_unknown_input() -> miette::Result<()> {
+        assert_eq!(Err(Error::Empty), "\n\n".parse::<Grid<char>>());
+        assert_eq!(
+            Err(Error::RaggedRow {
+                row: 1,
+                width: 2,
+                expected: 3
+            }),
+            "...\n..".parse::<Grid<char>>()
+        );
+        assert_eq!(
+            Err(Error::UnexpectedCell {
+                x: 1,
+                y: 0,
+                cell: 'x'
+            }),
+            Grid::parse_with(".x", Layout::Trimmed, |_, c| (c == '.').then_some(()))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_lay_out_rows_by_policy() -> miette::Result<()> {
+        let input = "  #.\n\n.";
+
+        assert_eq!(
+            Err(Error::RaggedRow {
+                row: 1,
+                width: 0,
+                expected: 4
+            }),
+            Grid::<char>::parse_with(input, Layout::Exact, |_, c| Some(c))
+        );
+        assert_eq!(
+            Err(Error::RaggedRow {
+                row: 1,
+                width: 1,
+                expected: 2
+            }),
+            Grid::<char>::parse_with(input, Layout::Trimmed, |_, c| Some(c))
+        );
+        assert_eq!(
+            "#.\n..\n",
+            Grid::<char>::parse_with(input, Layout::Padded('.'), |_, c| Some(c))?.to_string()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_classify_cells_with_their_position() -> miette::Result<()> {
+        let mut galaxies = vec![];
+        let grid = Grid::parse_with(IMAGE, Layout::Trimmed, |position, c| {
+            if c == '#' {
+                galaxies.push(position);
+            }
+            Some(c == '#')
+        })?;
+
+        assert_eq!(
+            vec![Point2::new(3, 0), Point2::new(0, 1), Point2::new(2, 2)],
+            galaxies
+        );
+        assert!(grid[Point2::new(0, 1)]);
+        Ok(())
+    }
+}
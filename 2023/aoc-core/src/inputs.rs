@@ -0,0 +1,119 @@
+//! Real puzzle inputs, read at runtime. Worked examples are part of the
+//! puzzle text and ship with the repo, so days embed them with `include_str!`
+//! and a missing one fails the build. Real inputs are personal and may not be
+//! in a fresh clone, so a missing one is only an error when something reads it.
+
+use std::path::Path;
+
+use miette::Diagnostic;
+use thiserror::Error;
+
+/// The year of the days named plain `day-NN`, as the runner's scaffold does.
+const FIRST_YEAR: u16 = 2023;
+
+#[derive(Error, Diagnostic, Debug)]
+pub enum Error {
+    #[error("No puzzle input at {path}")]
+    #[diagnostic(
+        code(aoc::missing_input),
+        help("Real inputs aren't always in the repo. Save yours there, or download it with `{fetch}`")
+    )]
+    MissingInput { path: String, fetch: String },
+    #[error("Could not read puzzle input {path}: {reason}")]
+    UnreadableInput { path: String, reason: String },
+}
+
+/// `file` in the crate at `dir`, read now rather than at compile time.
+#[tracing::instrument]
+pub fn read(dir: &str, file: &str) -> Result<String, Error> {
+    let path = Path::new(dir).join(file);
+
+    std::fs::read_to_string(&path).map_err(|e| match e.kind() {
+        std::io::ErrorKind::NotFound => Error::MissingInput {
+            fetch: fetch_command(dir, &path),
+            path: path.display().to_string(),
+        },
+        _ => Error::UnreadableInput {
+            path: path.display().to_string(),
+            reason: e.to_string(),
+        },
+    })
+}
+
+/// The runner command that downloads the input for the crate at `dir`,
+/// named `day-NN` or `day-YYYY-NN`.
+#[tracing::instrument]
+fn fetch_command(dir: &str, path: &Path) -> String {
+    let name = Path::new(dir)
+        .file_name()
+        .and_then(|name| name.to_str())
+        .unwrap_or_default();
+
+    let numbers = name
+        .split('-')
+        .skip(1)
+        .map(|n| n.parse::<u16>().ok())
+        .collect::<Option<Vec<_>>>()
+        .unwrap_or_default();
+
+    let (year, day) = match numbers[..] {
+        [day] => (FIRST_YEAR.to_string(), day.to_string()),
+        [year, day] => (year.to_string(), day.to_string()),
+        _ => ("<year>".to_string(), "<day>".to_string()),
+    };
+
+    format!(
+        "cargo run -p runner -- fetch {} {} --output {}",
+        year,
+        day,
+        path.display()
+    )
+}
+
+/// A real puzzle input beside the calling crate's `Cargo.toml`, read at
+/// runtime: `aoc_core::puzzle_input!("input1.txt")?`.
+#[macro_export]
+macro_rules! puzzle_input {
+    ($file:literal) => {
+        $crate::inputs::read(env!("CARGO_MANIFEST_DIR"), $file)
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn it_should_read_files_beside_the_manifest() -> miette::Result<()> {
+        assert_eq!(
+            include_str!("../Cargo.toml"),
+            crate::puzzle_input!("Cargo.toml")?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_say_how_to_fetch_a_missing_input() -> miette::Result<()> {
+        let Err(Error::MissingInput { path, fetch }) = read("/nowhere/day-08", "input1.txt") else {
+            panic!("expected a missing input");
+        };
+
+        assert_eq!("/nowhere/day-08/input1.txt", path);
+        assert_eq!(
+            "cargo run -p runner -- fetch 2023 8 --output /nowhere/day-08/input1.txt",
+            fetch
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_find_the_year_and_day_in_any_crate_name() -> miette::Result<()> {
+        let fetch = |dir| fetch_command(dir, Path::new("input1.txt"));
+
+        assert!(fetch("/repo/day-11").contains(" fetch 2023 11 "));
+        assert!(fetch("/repo/day-2024-03").contains(" fetch 2024 3 "));
+        assert!(fetch("/repo/aoc-core").contains(" fetch <year> <day> "));
+        Ok(())
+    }
+}
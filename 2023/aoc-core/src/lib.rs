@@ -0,0 +1,24 @@
+pub mod alphabet;
+pub mod answer;
+pub mod attempt;
+pub mod grid;
+pub mod inputs;
+pub mod lines;
+pub mod manifest;
+pub mod point_set;
+pub mod skip_debug;
+pub mod solution;
+pub mod sparse_grid;
+pub mod streaming_min;
+pub mod warm;
+
+pub use answer::Answer;
+pub use attempt::{Attempt, Outcome};
+pub use grid::{Grid, Layout};
+pub use lines::{numbered_lines, AtLine};
+pub use point_set::PointSet;
+pub use skip_debug::{Footprint, SkipDebug};
+pub use solution::Solution;
+pub use sparse_grid::SparseGrid;
+pub use streaming_min::StreamingMin;
+pub use warm::{prefault, preload};
@@ -0,0 +1,60 @@
+/// Errors that can say which line of the puzzle input they came from.
+/// Parsers that only see one line build them with line 0, and whichever loop
+/// knows the position fills in the real number with `at_line`.
+pub trait AtLine {
+    fn at_line(self, line: usize) -> Self;
+}
+
+impl<T, E: AtLine> AtLine for Result<T, E> {
+    fn at_line(self, line: usize) -> Self {
+        self.map_err(|error| error.at_line(line))
+    }
+}
+
+/// Each line trimmed, paired with its 1-based line number in the input.
+#[tracing::instrument(skip(input))]
+pub fn numbered_lines(input: &str) -> impl Iterator<Item = (usize, &str)> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| (i + 1, line.trim()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[derive(Debug, PartialEq, Eq)]
+    enum Error {
+        Bad { line: usize },
+        Other,
+    }
+
+    impl AtLine for Error {
+        fn at_line(self, line: usize) -> Self {
+            match self {
+                Error::Bad { .. } => Error::Bad { line },
+                other => other,
+            }
+        }
+    }
+
+    #[test]
+    fn it_should_number_lines_from_one() -> miette::Result<()> {
+        assert_eq!(
+            vec![(1, "a"), (2, ""), (3, "b")],
+            numbered_lines("a\n\n    b").collect::<Vec<_>>()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_fill_in_line_on_errors() -> miette::Result<()> {
+        let result: Result<(), Error> = Err(Error::Bad { line: 0 });
+        assert_eq!(Err(Error::Bad { line: 3 }), result.at_line(3));
+        assert_eq!(Error::Other, Error::Other.at_line(3));
+        assert_eq!(Ok::<u8, Error>(1), Ok(1).at_line(3));
+        Ok(())
+    }
+}
@@ -0,0 +1,125 @@
+use miette::Diagnostic;
+use thiserror::Error;
+
+/// The workspace's answers.toml, baked in so day tests can check real inputs
+/// against it without knowing where the workspace root is.
+pub const ANSWERS: &str = include_str!("../../answers.toml");
+
+/// Label preferred when a part accepts more than one answer.
+const SUBMITTED: &str = "submitted";
+
+#[derive(Error, Diagnostic, Debug)]
+pub enum Error {
+    #[error("Could not parse answers.toml: {reason}")]
+    InvalidAnswers { reason: String },
+    #[error("No accepted answer for {year} day {day} part {part} in answers.toml")]
+    NoAnswer { year: u16, day: u8, part: u8 },
+    #[error("{year} day {day} part {part} accepts several answers and none is labelled submitted")]
+    AmbiguousAnswer { year: u16, day: u8, part: u8 },
+}
+
+/// The accepted answer for one part in `manifest`: the `submitted` one, or the
+/// only one there is.
+#[tracing::instrument(skip(manifest))]
+pub fn answer_in(manifest: &str, year: u16, day: u8, part: u8) -> Result<String, Error> {
+    let manifest = manifest
+        .parse::<toml::Table>()
+        .map_err(|e| Error::InvalidAnswers {
+            reason: e.message().to_string(),
+        })?;
+
+    let is_part = |entry: &&toml::Table| {
+        let number = |key: &str| entry.get(key).and_then(|v| v.as_integer());
+        number("year") == Some(year.into())
+            && number("day") == Some(day.into())
+            && number("part") == Some(part.into())
+    };
+
+    let accepted = manifest
+        .get("answers")
+        .and_then(|answers| answers.as_array())
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| entry.as_table())
+        .find(is_part)
+        .and_then(|entry| entry.get("accepted"))
+        .and_then(|accepted| accepted.as_table())
+        .ok_or(Error::NoAnswer { year, day, part })?;
+
+    let answers = accepted
+        .iter()
+        .filter_map(|(label, answer)| Some((label.as_str(), answer.as_str()?)))
+        .collect::<Vec<_>>();
+
+    match answers[..] {
+        [] => Err(Error::NoAnswer { year, day, part }),
+        [(_, answer)] => Ok(answer.to_string()),
+        _ => answers
+            .iter()
+            .find(|(label, _)| *label == SUBMITTED)
+            .map(|(_, answer)| answer.to_string())
+            .ok_or(Error::AmbiguousAnswer { year, day, part }),
+    }
+}
+
+/// The accepted answer for one part in the workspace's answers.toml.
+#[tracing::instrument]
+pub fn answer(year: u16, day: u8, part: u8) -> Result<String, Error> {
+    answer_in(ANSWERS, year, day, part)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    const MANIFEST: &str = r#"
+[[answers]]
+year = 2023
+day = 6
+part = 1
+accepted = { inclusive = "4", exclusive = "2" }
+
+[[answers]]
+year = 2023
+day = 6
+part = 2
+accepted = { exclusive = "7", submitted = "9" }
+
+[[answers]]
+year = 2023
+day = 9
+part = 1
+accepted = { submitted = "114" }
+"#;
+
+    #[test]
+    fn it_should_find_answers() -> miette::Result<()> {
+        assert_eq!("114", answer_in(MANIFEST, 2023, 9, 1)?);
+        assert_eq!("9", answer_in(MANIFEST, 2023, 6, 2)?);
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_reject_missing_and_ambiguous_answers() -> miette::Result<()> {
+        assert!(matches!(
+            answer_in(MANIFEST, 2023, 9, 2),
+            Err(Error::NoAnswer { part: 2, .. })
+        ));
+        assert!(matches!(
+            answer_in(MANIFEST, 2023, 6, 1),
+            Err(Error::AmbiguousAnswer { .. })
+        ));
+        assert!(matches!(
+            answer_in("[[answers", 2023, 6, 1),
+            Err(Error::InvalidAnswers { .. })
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_read_workspace_manifest() -> miette::Result<()> {
+        assert_eq!("55017", answer(2023, 1, 1)?);
+        Ok(())
+    }
+}
@@ -0,0 +1,284 @@
+use std::{
+    collections::{BTreeMap, HashSet},
+    fmt,
+};
+
+use aoc_geometry::Point2;
+
+use crate::Footprint;
+
+/// Occupied cells of a grid with nothing else to say about them, like day
+/// 11's galaxies or day 3's symbols. Alongside the set it keeps how many
+/// points share each row and column, so row and column counts and the
+/// bounding box don't need a scan.
+#[derive(Clone, PartialEq, Eq, Default)]
+pub struct PointSet {
+    points: HashSet<Point2>,
+    rows: BTreeMap<i64, usize>,
+    columns: BTreeMap<i64, usize>,
+}
+
+impl fmt::Debug for PointSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PointSet")
+            .field("len", &self.points.len())
+            .field("bounds", &self.bounds())
+            .finish()
+    }
+}
+
+impl Footprint for PointSet {
+    fn footprint(&self) -> usize {
+        self.points.len() + self.rows.len() + self.columns.len()
+    }
+}
+
+impl PointSet {
+    #[tracing::instrument]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds `point`, returning whether it wasn't already there.
+    #[tracing::instrument(skip(self))]
+    pub fn insert(&mut self, point: Point2) -> bool {
+        if !self.points.insert(point) {
+            return false;
+        }
+
+        *self.rows.entry(point.y).or_default() += 1;
+        *self.columns.entry(point.x).or_default() += 1;
+        true
+    }
+
+    /// Takes `point` out, returning whether it was there.
+    #[tracing::instrument(skip(self))]
+    pub fn remove(&mut self, point: Point2) -> bool {
+        if !self.points.remove(&point) {
+            return false;
+        }
+
+        decrement(&mut self.rows, point.y);
+        decrement(&mut self.columns, point.x);
+        true
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub fn contains(&self, point: Point2) -> bool {
+        self.points.contains(&point)
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    /// How many points are on row `y`.
+    #[tracing::instrument(skip(self))]
+    pub fn count_in_row(&self, y: i64) -> usize {
+        self.rows.get(&y).copied().unwrap_or(0)
+    }
+
+    /// How many points are in column `x`.
+    #[tracing::instrument(skip(self))]
+    pub fn count_in_col(&self, x: i64) -> usize {
+        self.columns.get(&x).copied().unwrap_or(0)
+    }
+
+    /// The top left and bottom right corners of the smallest box holding
+    /// every point, or `None` when there aren't any.
+    #[tracing::instrument(skip(self))]
+    pub fn bounds(&self) -> Option<(Point2, Point2)> {
+        let (left, _) = self.columns.first_key_value()?;
+        let (right, _) = self.columns.last_key_value()?;
+        let (top, _) = self.rows.first_key_value()?;
+        let (bottom, _) = self.rows.last_key_value()?;
+
+        Some((Point2::new(*left, *top), Point2::new(*right, *bottom)))
+    }
+
+    /// Every point, in no particular order.
+    #[tracing::instrument(skip(self))]
+    pub fn iter(&self) -> impl Iterator<Item = Point2> + '_ {
+        self.points.iter().copied()
+    }
+
+    /// Every point in reading order, row by row and left to right.
+    #[tracing::instrument(skip(self))]
+    pub fn iter_sorted(&self) -> impl Iterator<Item = Point2> {
+        let mut points = self.points.iter().copied().collect::<Vec<_>>();
+        points.sort_unstable_by_key(|point| (point.y, point.x));
+        points.into_iter()
+    }
+
+    /// The points among the eight around `point`, in reading order.
+    #[tracing::instrument(skip(self))]
+    pub fn neighbors8_of(&self, point: Point2) -> impl Iterator<Item = Point2> + '_ {
+        point
+            .neighbours8()
+            .filter(|neighbour| self.contains(*neighbour))
+    }
+}
+
+#[tracing::instrument(skip(counts))]
+fn decrement(counts: &mut BTreeMap<i64, usize>, key: i64) {
+    if let Some(count) = counts.get_mut(&key) {
+        *count -= 1;
+        if *count == 0 {
+            counts.remove(&key);
+        }
+    }
+}
+
+impl FromIterator<Point2> for PointSet {
+    fn from_iter<I: IntoIterator<Item = Point2>>(points: I) -> Self {
+        let mut set = Self::new();
+        set.extend(points);
+        set
+    }
+}
+
+impl Extend<Point2> for PointSet {
+    fn extend<I: IntoIterator<Item = Point2>>(&mut self, points: I) {
+        for point in points {
+            self.insert(point);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use proptest::prelude::*;
+
+    fn points(cells: &[(i64, i64)]) -> PointSet {
+        cells.iter().copied().map(Point2::from).collect()
+    }
+
+    #[test]
+    fn it_should_count_rows_and_columns() -> miette::Result<()> {
+        let mut set = points(&[(3, 0), (7, 1), (0, 2), (3, 2)]);
+
+        assert_eq!(4, set.len());
+        assert!(!set.insert(Point2::new(3, 0)));
+        assert_eq!(2, set.count_in_row(2));
+        assert_eq!(2, set.count_in_col(3));
+        assert_eq!(0, set.count_in_col(1));
+        assert_eq!(Some((Point2::new(0, 0), Point2::new(7, 2))), set.bounds());
+
+        assert!(set.remove(Point2::new(7, 1)));
+        assert!(!set.remove(Point2::new(7, 1)));
+        assert_eq!(0, set.count_in_row(1));
+        assert_eq!(Some((Point2::new(0, 0), Point2::new(3, 2))), set.bounds());
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_iterate_in_reading_order() -> miette::Result<()> {
+        let set = points(&[(4, 2), (0, 9), (9, 0), (1, 2)]);
+
+        assert_eq!(
+            vec![
+                Point2::new(9, 0),
+                Point2::new(1, 2),
+                Point2::new(4, 2),
+                Point2::new(0, 9)
+            ],
+            set.iter_sorted().collect::<Vec<_>>()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_find_neighbours_in_reading_order() -> miette::Result<()> {
+        let set = points(&[(0, 0), (1, 0), (2, 2), (5, 5)]);
+
+        assert_eq!(
+            vec![Point2::new(0, 0), Point2::new(1, 0), Point2::new(2, 2)],
+            set.neighbors8_of(Point2::new(1, 1)).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![Point2::new(1, 0)],
+            set.neighbors8_of(Point2::new(0, 0)).collect::<Vec<_>>()
+        );
+        assert_eq!(0, set.neighbors8_of(Point2::new(5, 5)).count());
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_start_empty() -> miette::Result<()> {
+        let set = PointSet::default();
+
+        assert!(set.is_empty());
+        assert_eq!(None, set.bounds());
+        assert_eq!("PointSet { len: 0, bounds: None }", format!("{:?}", set));
+        Ok(())
+    }
+
+    /// Inserts when true, removes when false, on a small grid so both hit.
+    fn edits() -> impl Strategy<Value = Vec<(bool, (i64, i64))>> {
+        prop::collection::vec((any::<bool>(), (0i64..8, 0i64..8)), 0..64)
+    }
+
+    proptest! {
+        #[test]
+        fn it_should_agree_with_a_hash_set(edits in edits()) {
+            let mut set = PointSet::new();
+            let mut reference = HashSet::new();
+
+            for (insert, cell) in edits {
+                let point = Point2::from(cell);
+                if insert {
+                    prop_assert_eq!(reference.insert(point), set.insert(point));
+                } else {
+                    prop_assert_eq!(reference.remove(&point), set.remove(point));
+                }
+            }
+
+            prop_assert_eq!(reference.len(), set.len());
+            for i in 0..8 {
+                prop_assert_eq!(reference.iter().filter(|p| p.y == i).count(), set.count_in_row(i));
+                prop_assert_eq!(reference.iter().filter(|p| p.x == i).count(), set.count_in_col(i));
+            }
+
+            let mut sorted = reference.iter().copied().collect::<Vec<_>>();
+            sorted.sort_by_key(|point| (point.y, point.x));
+            prop_assert_eq!(sorted, set.iter_sorted().collect::<Vec<_>>());
+
+            let bounds = reference.iter().map(|p| p.x).min().map(|left| {
+                (
+                    Point2::new(left, reference.iter().map(|p| p.y).min().unwrap_or(0)),
+                    Point2::new(
+                        reference.iter().map(|p| p.x).max().unwrap_or(0),
+                        reference.iter().map(|p| p.y).max().unwrap_or(0),
+                    ),
+                )
+            });
+            prop_assert_eq!(bounds, set.bounds());
+        }
+
+        #[test]
+        fn it_should_find_the_same_neighbours_as_a_hash_set(
+            cells in prop::collection::hash_set((0i64..6, 0i64..6), 0..24),
+            x in 0i64..6,
+            y in 0i64..6,
+        ) {
+            let reference = cells.into_iter().map(Point2::from).collect::<HashSet<_>>();
+            let set = reference.iter().copied().collect::<PointSet>();
+            let centre = Point2::new(x, y);
+
+            let expected = (y - 1..=y + 1)
+                .flat_map(|y| (x - 1..=x + 1).map(move |x| Point2::new(x, y)))
+                .filter(|point| *point != centre && reference.contains(point))
+                .collect::<Vec<_>>();
+
+            prop_assert_eq!(expected, set.neighbors8_of(centre).collect::<Vec<_>>());
+        }
+    }
+}
@@ -0,0 +1,121 @@
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque},
+    fmt,
+};
+
+/// How many items a value holds, which is all a span needs to know about it.
+pub trait Footprint {
+    fn footprint(&self) -> usize;
+}
+
+impl<T> Footprint for [T] {
+    fn footprint(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<T> Footprint for Vec<T> {
+    fn footprint(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<T> Footprint for VecDeque<T> {
+    fn footprint(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<K, V, S> Footprint for HashMap<K, V, S> {
+    fn footprint(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<T, S> Footprint for HashSet<T, S> {
+    fn footprint(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<K, V> Footprint for BTreeMap<K, V> {
+    fn footprint(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<T> Footprint for BTreeSet<T> {
+    fn footprint(&self) -> usize {
+        self.len()
+    }
+}
+
+impl Footprint for str {
+    fn footprint(&self) -> usize {
+        self.len()
+    }
+}
+
+impl Footprint for String {
+    fn footprint(&self) -> usize {
+        self.len()
+    }
+}
+
+/// Records a large value in a span as its type name and footprint, e.g.
+/// `Map(7)`, instead of its derived `Debug`:
+///
+/// ```text
+/// #[tracing::instrument(skip(self), fields(map = ?SkipDebug(self)))]
+/// fn get_mapped_value(&self, value: u64) -> u64 { .. }
+/// ```
+pub struct SkipDebug<'a, T: ?Sized>(pub &'a T);
+
+impl<T: Footprint + ?Sized> fmt::Debug for SkipDebug<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = std::any::type_name::<T>();
+        let short = name.split('<').next().unwrap_or(name);
+        let short = short.rsplit("::").next().unwrap_or(short);
+
+        write!(f, "{}({})", short, self.0.footprint())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    struct Grid {
+        cells: Vec<Vec<char>>,
+    }
+
+    impl Footprint for Grid {
+        fn footprint(&self) -> usize {
+            self.cells.iter().map(|row| row.len()).sum()
+        }
+    }
+
+    #[test]
+    fn it_should_print_name_and_footprint() -> miette::Result<()> {
+        let grid = Grid {
+            cells: vec![vec!['.'; 1000]; 1000],
+        };
+
+        assert_eq!("Grid(1000000)", format!("{:?}", SkipDebug(&grid)));
+        assert_eq!("Vec(3)", format!("{:?}", SkipDebug(&vec![1, 2, 3])));
+        assert_eq!("str(5)", format!("{:?}", SkipDebug("hello")));
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_record_in_spans() -> miette::Result<()> {
+        #[tracing::instrument(skip(values), fields(values = ?SkipDebug(values)))]
+        fn total(values: &[u64]) -> u64 {
+            values.iter().sum()
+        }
+
+        assert_eq!(6, total(&[1, 2, 3]));
+        Ok(())
+    }
+}
@@ -0,0 +1,147 @@
+use miette::Diagnostic;
+use thiserror::Error;
+
+use crate::Answer;
+
+/// What every `Solution` method returns. Day errors convert into it with `?`.
+pub type Result<T> = miette::Result<T>;
+
+#[derive(Error, Diagnostic, Debug)]
+pub enum Error {
+    #[error("{year} day {day} has no part {part}")]
+    NoSuchPart { year: u16, day: u8, part: u8 },
+}
+
+/// One puzzle day, parsed once and solved for either part, so days can be
+/// iterated, benchmarked and tested the same way.
+pub trait Solution {
+    const YEAR: u16;
+    const DAY: u8;
+
+    /// The parsed input shared by both parts. Days whose parts parse the text
+    /// differently just borrow it.
+    type Parsed<'a>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>>;
+
+    fn part1(parsed: &Self::Parsed<'_>) -> Result<Answer>;
+
+    fn part2(parsed: &Self::Parsed<'_>) -> Result<Answer>;
+
+    /// Parses `input` and solves `part` of it.
+    fn solve(input: &str, part: u8) -> Result<Answer> {
+        let parsed = Self::parse(input)?;
+
+        match part {
+            1 => Self::part1(&parsed),
+            2 => Self::part2(&parsed),
+            part => Err(Error::NoSuchPart {
+                year: Self::YEAR,
+                day: Self::DAY,
+                part,
+            }
+            .into()),
+        }
+    }
+}
+
+/// Implements `Solution` for a day whose parts each take the raw input text,
+/// from the `process` functions of its reference parts:
+///
+/// ```text
+/// aoc_core::solution!(Day01, 2023, 1, part1::process, part2::process);
+/// ```
+#[macro_export]
+macro_rules! solution {
+    ($name:ident, $year:expr, $day:expr, $part1:path, $part2:path) => {
+        #[doc = concat!("Day ", stringify!($day), " behind the shared `aoc_core::Solution` interface.")]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        pub struct $name;
+
+        impl $crate::Solution for $name {
+            const YEAR: u16 = $year;
+            const DAY: u8 = $day;
+
+            type Parsed<'a> = &'a str;
+
+            fn parse(input: &str) -> $crate::solution::Result<&str> {
+                Ok(input)
+            }
+
+            fn part1(input: &&str) -> $crate::solution::Result<$crate::Answer> {
+                $part1(input).map_err(Into::into)
+            }
+
+            fn part2(input: &&str) -> $crate::solution::Result<$crate::Answer> {
+                $part2(input).map_err(Into::into)
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    /// Sums and multiplies a line of numbers.
+    struct Numbers;
+
+    impl Solution for Numbers {
+        const YEAR: u16 = 2023;
+        const DAY: u8 = 0;
+
+        type Parsed<'a> = Vec<i64>;
+
+        fn parse(input: &str) -> Result<Vec<i64>> {
+            input
+                .split_whitespace()
+                .map(|n| n.parse::<i64>().map_err(|e| miette::miette!("{}", e)))
+                .collect()
+        }
+
+        fn part1(numbers: &Vec<i64>) -> Result<Answer> {
+            Ok(numbers.iter().sum::<i64>().into())
+        }
+
+        fn part2(numbers: &Vec<i64>) -> Result<Answer> {
+            Ok(numbers.iter().product::<i64>().into())
+        }
+    }
+
+    fn double(input: &str) -> Result<Answer> {
+        let number = input
+            .trim()
+            .parse::<u32>()
+            .map_err(|e| miette::miette!("{}", e))?;
+
+        Ok(Answer::from(number * 2))
+    }
+
+    crate::solution!(Doubled, 2023, 25, double, double);
+
+    fn shout(input: &str) -> Result<Answer> {
+        Ok(Answer::from(input.trim().to_uppercase()))
+    }
+
+    crate::solution!(Shouted, 2023, 24, shout, double);
+
+    #[test]
+    fn it_should_solve_both_parts() -> miette::Result<()> {
+        assert_eq!(Answer::Int(10), Numbers::solve("1 2 3 4", 1)?);
+        assert_eq!(Answer::Int(24), Numbers::solve("1 2 3 4", 2)?);
+        assert_eq!(Answer::UInt(42), Doubled::solve("21", 2)?);
+        assert_eq!(Answer::from("EHPZPJGL"), Shouted::solve("ehpzpjgl\n", 1)?);
+        assert_eq!(Answer::parse("42"), Shouted::solve("21", 2)?);
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_reject_other_parts() -> miette::Result<()> {
+        let report = Numbers::solve("1", 3).unwrap_err();
+
+        assert_eq!("2023 day 0 has no part 3", report.to_string());
+        assert!(Numbers::solve("x", 1).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,144 @@
+use std::{collections::HashMap, fmt};
+
+use aoc_geometry::Point2;
+
+use crate::Footprint;
+
+/// Values at a few points of a grid too big or too empty to store whole,
+/// so looking up a cell or its neighbours is a hash lookup rather than a scan
+/// (e.g. day 3's part numbers, keyed by every cell their digits cover).
+#[derive(Clone, PartialEq, Eq)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Point2, T>,
+}
+
+impl<T> fmt::Debug for SparseGrid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SparseGrid")
+            .field("len", &self.cells.len())
+            .finish()
+    }
+}
+
+impl<T> Footprint for SparseGrid<T> {
+    fn footprint(&self) -> usize {
+        self.cells.len()
+    }
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        Self {
+            cells: HashMap::new(),
+        }
+    }
+}
+
+impl<T> SparseGrid<T> {
+    #[tracing::instrument]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Puts `value` at `point`, returning what was there before.
+    #[tracing::instrument(skip(self, value))]
+    pub fn insert(&mut self, point: Point2, value: T) -> Option<T> {
+        self.cells.insert(point, value)
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub fn get(&self, point: Point2) -> Option<&T> {
+        self.cells.get(&point)
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub fn contains(&self, point: Point2) -> bool {
+        self.cells.contains_key(&point)
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// Every occupied cell with its value, in no particular order.
+    #[tracing::instrument(skip(self))]
+    pub fn iter(&self) -> impl Iterator<Item = (Point2, &T)> + '_ {
+        self.cells.iter().map(|(point, value)| (*point, value))
+    }
+
+    /// The occupied cells of the eight around `point`, in reading order.
+    #[tracing::instrument(skip(self))]
+    pub fn neighbours8(&self, point: Point2) -> impl Iterator<Item = (Point2, &T)> + '_ {
+        point
+            .neighbours8()
+            .filter_map(|neighbour| self.get(neighbour).map(|value| (neighbour, value)))
+    }
+}
+
+impl<T> FromIterator<(Point2, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (Point2, T)>>(cells: I) -> Self {
+        Self {
+            cells: cells.into_iter().collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn it_should_store_and_replace_values() -> miette::Result<()> {
+        let mut grid = SparseGrid::new();
+
+        assert_eq!(None, grid.insert(Point2::new(2, 0), 'a'));
+        assert_eq!(Some('a'), grid.insert(Point2::new(2, 0), 'b'));
+        grid.insert(Point2::new(1_000_000, 7), 'c');
+
+        assert_eq!(2, grid.len());
+        assert_eq!(Some(&'b'), grid.get(Point2::new(2, 0)));
+        assert!(grid.contains(Point2::new(1_000_000, 7)));
+        assert_eq!(None, grid.get(Point2::new(0, 0)));
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_find_occupied_neighbours_in_reading_order() -> miette::Result<()> {
+        let grid = [((0, 0), 1), ((1, 0), 2), ((2, 2), 3), ((5, 5), 4)]
+            .into_iter()
+            .map(|((x, y), value)| (Point2::new(x, y), value))
+            .collect::<SparseGrid<_>>();
+
+        assert_eq!(
+            vec![
+                (Point2::new(0, 0), &1),
+                (Point2::new(1, 0), &2),
+                (Point2::new(2, 2), &3)
+            ],
+            grid.neighbours8(Point2::new(1, 1)).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![(Point2::new(1, 0), &2)],
+            grid.neighbours8(Point2::new(0, 0)).collect::<Vec<_>>()
+        );
+        assert_eq!(0, grid.neighbours8(Point2::new(5, 5)).count());
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_start_empty() -> miette::Result<()> {
+        let grid = SparseGrid::<u8>::default();
+
+        assert!(grid.is_empty());
+        assert_eq!(0, grid.iter().count());
+        assert_eq!("SparseGrid { len: 0 }", format!("{:?}", grid));
+        Ok(())
+    }
+}
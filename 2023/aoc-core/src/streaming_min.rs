@@ -0,0 +1,107 @@
+/// Keeps the smallest value seen so far, so producers can ask whether a batch
+/// of candidates could still beat it before doing the work to generate them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StreamingMin<T> {
+    best: Option<T>,
+}
+
+impl<T: Ord + Copy + std::fmt::Debug> StreamingMin<T> {
+    #[tracing::instrument]
+    pub fn new() -> Self {
+        Self { best: None }
+    }
+
+    /// Records `value`, returning true if it is the new best.
+    #[tracing::instrument]
+    pub fn offer(&mut self, value: T) -> bool {
+        match self.best {
+            Some(best) if best <= value => false,
+            _ => {
+                self.best = Some(value);
+                true
+            }
+        }
+    }
+
+    #[tracing::instrument]
+    pub fn best(&self) -> Option<T> {
+        self.best
+    }
+
+    /// False when every candidate is known to be at least `lower_bound` and that
+    /// can't beat the current best, so the producer can skip them.
+    #[tracing::instrument]
+    pub fn worth_processing(&self, lower_bound: T) -> bool {
+        self.best.is_none_or(|best| lower_bound < best)
+    }
+
+    /// Combines two partial results, e.g. from different threads.
+    #[tracing::instrument]
+    pub fn merge(mut self, other: Self) -> Self {
+        if let Some(value) = other.best {
+            self.offer(value);
+        }
+        self
+    }
+}
+
+impl<T: Ord + Copy + std::fmt::Debug> Default for StreamingMin<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Ord + Copy + std::fmt::Debug> FromIterator<T> for StreamingMin<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut min = Self::new();
+        for value in iter {
+            min.offer(value);
+        }
+        min
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn it_should_track_smallest_value() -> miette::Result<()> {
+        let mut min = StreamingMin::new();
+        assert_eq!(None, min.best());
+        assert!(min.offer(10));
+        assert!(!min.offer(12));
+        assert!(!min.offer(10));
+        assert!(min.offer(3));
+        assert_eq!(Some(3), min.best());
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_always_be_worth_processing_when_empty() -> miette::Result<()> {
+        let min = StreamingMin::<u64>::new();
+        assert!(min.worth_processing(u64::MAX));
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_prune_candidates_that_cannot_win() -> miette::Result<()> {
+        let min = [35u64, 82, 43].into_iter().collect::<StreamingMin<_>>();
+        assert!(min.worth_processing(0));
+        assert!(min.worth_processing(34));
+        assert!(!min.worth_processing(35));
+        assert!(!min.worth_processing(86));
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_merge_partial_results() -> miette::Result<()> {
+        let a = [7u64, 9].into_iter().collect::<StreamingMin<_>>();
+        let b = [4u64, 11].into_iter().collect::<StreamingMin<_>>();
+        assert_eq!(Some(4), a.merge(b).best());
+        assert_eq!(Some(7), a.merge(StreamingMin::new()).best());
+        assert_eq!(Some(4), StreamingMin::new().merge(b).best());
+        Ok(())
+    }
+}
@@ -0,0 +1,43 @@
+use std::hint::black_box;
+
+/// Smallest page size on the platforms this is run on; touching one byte per
+/// page is enough to fault each of them in.
+pub const PAGE_SIZE: usize = 4096;
+
+/// Reads one byte from every page of `input`, so an `include_str!` input that
+/// hasn't been read yet is faulted in before timing starts rather than during
+/// whichever variant happens to run first.
+#[tracing::instrument(skip(input))]
+pub fn prefault(input: &str) -> &str {
+    let touched = input
+        .as_bytes()
+        .iter()
+        .step_by(PAGE_SIZE)
+        .fold(0u8, |sum, byte| sum.wrapping_add(black_box(*byte)));
+    black_box(touched);
+
+    input
+}
+
+/// `prefault`s `input` and parses it before anything is timed, for benchmarks
+/// that only time solving.
+#[tracing::instrument(skip(input, parse))]
+pub fn preload<T>(input: &str, parse: impl FnOnce(&str) -> T) -> T {
+    parse(prefault(input))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn it_should_hand_back_the_same_input() -> miette::Result<()> {
+        let input = "x".repeat(PAGE_SIZE * 3 + 1);
+
+        assert_eq!(input.as_ptr(), prefault(&input).as_ptr());
+        assert_eq!("", prefault(""));
+        assert_eq!(12_289, preload(&input, |input| input.len()));
+        Ok(())
+    }
+}
@@ -0,0 +1,199 @@
+/// A step to one of the four cells sharing an edge, with y growing downwards
+/// as it does down a puzzle input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Direction4 {
+    Up,
+    Left,
+    Right,
+    Down,
+}
+
+impl Direction4 {
+    /// Every direction in reading order of the cells they lead to.
+    pub const ALL: [Self; 4] = [Self::Up, Self::Left, Self::Right, Self::Down];
+
+    /// The step as `(dx, dy)`.
+    #[tracing::instrument]
+    pub fn offset(self) -> (isize, isize) {
+        match self {
+            Self::Up => (0, -1),
+            Self::Left => (-1, 0),
+            Self::Right => (1, 0),
+            Self::Down => (0, 1),
+        }
+    }
+
+    /// A quarter turn anticlockwise.
+    #[tracing::instrument]
+    pub fn turn_left(self) -> Self {
+        match self {
+            Self::Up => Self::Left,
+            Self::Left => Self::Down,
+            Self::Down => Self::Right,
+            Self::Right => Self::Up,
+        }
+    }
+
+    /// A quarter turn clockwise.
+    #[tracing::instrument]
+    pub fn turn_right(self) -> Self {
+        self.turn_left().opposite()
+    }
+
+    #[tracing::instrument]
+    pub fn opposite(self) -> Self {
+        match self {
+            Self::Up => Self::Down,
+            Self::Left => Self::Right,
+            Self::Right => Self::Left,
+            Self::Down => Self::Up,
+        }
+    }
+}
+
+/// A step to one of the eight cells around, diagonals included.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Direction8 {
+    UpLeft,
+    Up,
+    UpRight,
+    Left,
+    Right,
+    DownLeft,
+    Down,
+    DownRight,
+}
+
+impl Direction8 {
+    /// Every direction in reading order of the cells they lead to.
+    pub const ALL: [Self; 8] = [
+        Self::UpLeft,
+        Self::Up,
+        Self::UpRight,
+        Self::Left,
+        Self::Right,
+        Self::DownLeft,
+        Self::Down,
+        Self::DownRight,
+    ];
+
+    /// Clockwise from up, so rotating is moving along it.
+    const CLOCKWISE: [Self; 8] = [
+        Self::Up,
+        Self::UpRight,
+        Self::Right,
+        Self::DownRight,
+        Self::Down,
+        Self::DownLeft,
+        Self::Left,
+        Self::UpLeft,
+    ];
+
+    /// The step as `(dx, dy)`.
+    #[tracing::instrument]
+    pub fn offset(self) -> (isize, isize) {
+        match self {
+            Self::UpLeft => (-1, -1),
+            Self::Up => (0, -1),
+            Self::UpRight => (1, -1),
+            Self::Left => (-1, 0),
+            Self::Right => (1, 0),
+            Self::DownLeft => (-1, 1),
+            Self::Down => (0, 1),
+            Self::DownRight => (1, 1),
+        }
+    }
+
+    /// `eighths` of a turn clockwise, or anticlockwise when negative.
+    #[tracing::instrument]
+    pub fn rotate(self, eighths: i32) -> Self {
+        let index = Self::CLOCKWISE
+            .iter()
+            .position(|direction| *direction == self)
+            .unwrap_or(0);
+
+        Self::CLOCKWISE[(index as i32 + eighths).rem_euclid(8) as usize]
+    }
+
+    #[tracing::instrument]
+    pub fn opposite(self) -> Self {
+        self.rotate(4)
+    }
+
+    /// Whether the step is along a row or column rather than a diagonal.
+    #[tracing::instrument]
+    pub fn is_orthogonal(self) -> bool {
+        let (dx, dy) = self.offset();
+        dx == 0 || dy == 0
+    }
+}
+
+impl From<Direction4> for Direction8 {
+    fn from(direction: Direction4) -> Self {
+        match direction {
+            Direction4::Up => Self::Up,
+            Direction4::Left => Self::Left,
+            Direction4::Right => Self::Right,
+            Direction4::Down => Self::Down,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn it_should_turn_a_quarter_at_a_time() -> miette::Result<()> {
+        assert_eq!(Direction4::Left, Direction4::Up.turn_left());
+        assert_eq!(Direction4::Right, Direction4::Up.turn_right());
+        assert_eq!(Direction4::Up, Direction4::Down.opposite());
+
+        for direction in Direction4::ALL {
+            assert_eq!(direction, direction.turn_left().turn_right());
+            assert_eq!(direction.opposite(), direction.turn_right().turn_right());
+
+            let (dx, dy) = direction.offset();
+            assert_eq!((-dx, -dy), direction.opposite().offset());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_rotate_an_eighth_at_a_time() -> miette::Result<()> {
+        assert_eq!(Direction8::UpRight, Direction8::Up.rotate(1));
+        assert_eq!(Direction8::UpLeft, Direction8::Up.rotate(-1));
+        assert_eq!(Direction8::Left, Direction8::Down.rotate(10));
+
+        for direction in Direction8::ALL {
+            let (dx, dy) = direction.offset();
+            assert_eq!((-dx, -dy), direction.opposite().offset());
+            assert_eq!(
+                direction.is_orthogonal(),
+                direction.rotate(2).is_orthogonal()
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_list_directions_in_reading_order() -> miette::Result<()> {
+        let reading = |offsets: Vec<(isize, isize)>| {
+            let mut sorted = offsets.clone();
+            sorted.sort_by_key(|(dx, dy)| (*dy, *dx));
+            sorted == offsets
+        };
+
+        assert!(reading(Direction4::ALL.map(Direction4::offset).to_vec()));
+        assert!(reading(Direction8::ALL.map(Direction8::offset).to_vec()));
+        assert_eq!(
+            Direction4::ALL.map(Direction4::offset).to_vec(),
+            Direction4::ALL
+                .map(Direction8::from)
+                .map(Direction8::offset)
+                .to_vec()
+        );
+        Ok(())
+    }
+}
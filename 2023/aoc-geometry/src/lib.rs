@@ -0,0 +1,5 @@
+pub mod direction;
+pub mod point;
+
+pub use direction::{Direction4, Direction8};
+pub use point::Point2;
@@ -0,0 +1,142 @@
+use std::ops::{Add, AddAssign, Sub};
+
+use crate::{Direction4, Direction8};
+
+/// A point on an unbounded plane, with y growing downwards. Unlike a grid
+/// index it can go negative, so walks can step off the edge and back.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Point2 {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Point2 {
+    #[tracing::instrument]
+    pub fn new(x: i64, y: i64) -> Self {
+        Self { x, y }
+    }
+
+    /// Steps along rows and columns only, like walking a grid.
+    #[tracing::instrument]
+    pub fn manhattan(self, other: Self) -> u64 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    /// Steps with diagonals allowed, like a king on a chess board.
+    #[tracing::instrument]
+    pub fn chebyshev(self, other: Self) -> u64 {
+        self.x.abs_diff(other.x).max(self.y.abs_diff(other.y))
+    }
+
+    /// The four points sharing an edge, in `Direction4::ALL` order.
+    #[tracing::instrument]
+    pub fn neighbours4(self) -> impl Iterator<Item = Self> {
+        Direction4::ALL
+            .into_iter()
+            .map(move |direction| self + direction)
+    }
+
+    /// The eight points around, in `Direction8::ALL` order.
+    #[tracing::instrument]
+    pub fn neighbours8(self) -> impl Iterator<Item = Self> {
+        Direction8::ALL
+            .into_iter()
+            .map(move |direction| self + direction)
+    }
+}
+
+impl From<(i64, i64)> for Point2 {
+    fn from((x, y): (i64, i64)) -> Self {
+        Self { x, y }
+    }
+}
+
+impl From<(isize, isize)> for Point2 {
+    fn from((x, y): (isize, isize)) -> Self {
+        Self::new(x as i64, y as i64)
+    }
+}
+
+impl Add for Point2 {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl Sub for Point2 {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl Add<Direction4> for Point2 {
+    type Output = Self;
+
+    fn add(self, direction: Direction4) -> Self {
+        self + Self::from(direction.offset())
+    }
+}
+
+impl Add<Direction8> for Point2 {
+    type Output = Self;
+
+    fn add(self, direction: Direction8) -> Self {
+        self + Self::from(direction.offset())
+    }
+}
+
+impl AddAssign<Direction4> for Point2 {
+    fn add_assign(&mut self, direction: Direction4) {
+        *self = *self + direction;
+    }
+}
+
+impl AddAssign<Direction8> for Point2 {
+    fn add_assign(&mut self, direction: Direction8) {
+        *self = *self + direction;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn it_should_measure_distances() -> miette::Result<()> {
+        let a = Point2::new(1, 6);
+        let b = Point2::new(5, 11);
+
+        assert_eq!(9, a.manhattan(b));
+        assert_eq!(5, a.chebyshev(b));
+        assert_eq!(a.manhattan(b), b.manhattan(a));
+        assert_eq!(2, Point2::new(-1, 0).chebyshev(Point2::new(1, 1)));
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_step_in_directions() -> miette::Result<()> {
+        let mut point = Point2::default();
+
+        point += Direction4::Up;
+        point += Direction8::UpLeft;
+        assert_eq!(Point2::new(-1, -2), point);
+        assert_eq!(Point2::new(-1, -1), point + Direction4::Down);
+        assert_eq!(Point2::new(1, 2), Point2::default() - point);
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_surround_a_point() -> miette::Result<()> {
+        let centre = Point2::new(3, 3);
+
+        assert!(centre.neighbours4().all(|p| p.manhattan(centre) == 1));
+        assert!(centre.neighbours8().all(|p| p.chebyshev(centre) == 1));
+        assert_eq!(8, centre.neighbours8().count());
+        Ok(())
+    }
+}
@@ -0,0 +1,100 @@
+use crate::error::Error;
+
+/// `line` as exactly two whitespace-separated columns, like day 7's
+/// `32T3K 765` or day 2's `3 blue`. Any run of spaces or tabs separates them,
+/// and whitespace around the line is ignored.
+#[tracing::instrument]
+pub fn split_once_ws(line: &str) -> Result<(&str, &str), Error> {
+    let mut columns = line
+        .split(char::is_whitespace)
+        .filter(|column| !column.is_empty());
+
+    match (columns.next(), columns.next(), columns.next()) {
+        (Some(left), Some(right), None) => Ok((left, right)),
+        (_, _, Some(extra)) => Err(Error::UnexpectedColumn {
+            input: line.to_string(),
+            span: (offset_in(line, extra), extra.len()).into(),
+        }),
+        _ => Err(Error::MissingColumn {
+            input: line.to_string(),
+            span: (line.trim_end().len(), 0).into(),
+        }),
+    }
+}
+
+/// `line` either side of the first `sep`, both trimmed, like day 2's
+/// `Game 1: 3 blue`. Either side may be empty; what they should hold is up to
+/// each day.
+#[tracing::instrument]
+pub fn key_value(line: &str, sep: char) -> Result<(&str, &str), Error> {
+    let (key, value) = line
+        .split_once(sep)
+        .ok_or_else(|| Error::MissingDelimiter {
+            sep,
+            input: line.to_string(),
+            span: (0, line.len()).into(),
+        })?;
+
+    Ok((key.trim(), value.trim()))
+}
+
+/// Where `part`, a slice of `line`, starts within it.
+#[tracing::instrument]
+pub(crate) fn offset_in(line: &str, part: &str) -> usize {
+    part.as_ptr() as usize - line.as_ptr() as usize
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use miette::SourceSpan;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn it_should_split_on_any_whitespace() -> miette::Result<()> {
+        assert_eq!(("32T3K", "765"), split_once_ws("32T3K 765")?);
+        assert_eq!(("32T3K", "765"), split_once_ws("32T3K\t765")?);
+        assert_eq!(("32T3K", "765"), split_once_ws("32T3K  \t  765")?);
+        assert_eq!(("32T3K", "765"), split_once_ws("  32T3K 765 \t")?);
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_point_at_a_missing_column() -> miette::Result<()> {
+        for (line, at) in [("32T3K", 5), ("32T3K  ", 5), ("", 0), ("\t", 0)] {
+            let Err(Error::MissingColumn { span, .. }) = split_once_ws(line) else {
+                panic!("expected a missing column in {:?}", line);
+            };
+            assert_eq!(SourceSpan::from((at, 0)), span, "{:?}", line);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_point_at_an_extra_column() -> miette::Result<()> {
+        let Err(Error::UnexpectedColumn { span, .. }) = split_once_ws("KK677 28\t 1 2") else {
+            panic!("expected an extra column");
+        };
+        assert_eq!(SourceSpan::from((10, 1)), span);
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_split_at_the_first_separator() -> miette::Result<()> {
+        assert_eq!(
+            ("Game 1", "3 blue; 4 red"),
+            key_value("Game 1: 3 blue; 4 red", ':')?
+        );
+        assert_eq!(("Game 1", "a: b"), key_value("Game 1\t:\ta: b  ", ':')?);
+        assert_eq!(("", ""), key_value(":", ':')?);
+        assert_eq!(
+            Err(Error::MissingDelimiter {
+                sep: ':',
+                input: "Game 1 3 blue".to_string(),
+                span: (0, 13).into(),
+            }),
+            key_value("Game 1 3 blue", ':')
+        );
+        Ok(())
+    }
+}
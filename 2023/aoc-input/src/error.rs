@@ -0,0 +1,40 @@
+use miette::{Diagnostic, SourceSpan};
+use thiserror::Error;
+
+#[derive(Error, Diagnostic, Debug, PartialEq, Eq)]
+pub enum Error {
+    #[error("Input has no header line")]
+    MissingHeader,
+    #[error("Expected a blank line after the header, found line {line}")]
+    MissingSeparator { line: usize },
+    #[error("Expected two columns but found one")]
+    MissingColumn {
+        #[source_code]
+        input: String,
+        #[label("expected a second column here")]
+        span: SourceSpan,
+    },
+    #[error("Expected two columns but found more")]
+    UnexpectedColumn {
+        #[source_code]
+        input: String,
+        #[label("unexpected column")]
+        span: SourceSpan,
+    },
+    #[error("Expected {sep:?} to separate a key from its value")]
+    MissingDelimiter {
+        sep: char,
+        #[source_code]
+        input: String,
+        #[label("no {sep} in here")]
+        span: SourceSpan,
+    },
+    #[error("Could not parse this: {reason}")]
+    Unparsed {
+        reason: String,
+        #[source_code]
+        input: String,
+        #[label("here")]
+        span: SourceSpan,
+    },
+}
@@ -0,0 +1,122 @@
+use crate::error::Error;
+
+/// An input that opens with a single header line (day 5's seeds, day 8's
+/// instructions), then a blank line, then a body of blank-separated blocks.
+/// Every line is trimmed, and runs of blank lines never produce empty blocks.
+/// The header and blocks borrow from the input, so `normalize` it first and
+/// keep that alive for as long as the parsed input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HeaderAndBody<'a> {
+    pub header: &'a str,
+    pub blocks: Vec<Vec<&'a str>>,
+}
+
+impl<'a> HeaderAndBody<'a> {
+    /// The body may be empty; what it should contain is up to each day.
+    #[tracing::instrument(skip(input))]
+    pub fn parse(input: &'a str) -> Result<Self, Error> {
+        let mut lines = input.lines().map(|line| line.trim());
+
+        let header = lines
+            .next()
+            .filter(|header| !header.is_empty())
+            .ok_or(Error::MissingHeader)?;
+
+        if lines.next().is_some_and(|line| !line.is_empty()) {
+            return Err(Error::MissingSeparator { line: 2 });
+        }
+
+        let mut blocks = vec![];
+        let mut block = vec![];
+
+        for line in lines {
+            if line.is_empty() {
+                if !block.is_empty() {
+                    blocks.push(std::mem::take(&mut block));
+                }
+            } else {
+                block.push(line);
+            }
+        }
+
+        if !block.is_empty() {
+            blocks.push(block);
+        }
+
+        Ok(Self { header, blocks })
+    }
+
+    /// Every body line in order, for days whose body is one flat list.
+    #[tracing::instrument(skip(self))]
+    pub fn body_lines(&self) -> impl Iterator<Item = &'a str> + '_ {
+        self.blocks.iter().flatten().copied()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn it_should_split_header_from_blocks() -> miette::Result<()> {
+        let input = "seeds: 79 14
+
+        seed-to-soil map:
+        50 98 2
+
+
+        soil-to-fertilizer map:
+        0 15 37
+        ";
+
+        assert_eq!(
+            HeaderAndBody {
+                header: "seeds: 79 14",
+                blocks: vec![
+                    vec!["seed-to-soil map:", "50 98 2"],
+                    vec!["soil-to-fertilizer map:", "0 15 37"],
+                ],
+            },
+            HeaderAndBody::parse(input)?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_flatten_body_lines() -> miette::Result<()> {
+        let input = HeaderAndBody::parse("LR\n\nAAA = (BBB, BBB)\nBBB = (AAA, ZZZ)")?;
+
+        assert_eq!("LR", input.header);
+        assert_eq!(
+            vec!["AAA = (BBB, BBB)", "BBB = (AAA, ZZZ)"],
+            input.body_lines().collect::<Vec<_>>()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_allow_header_without_body() -> miette::Result<()> {
+        let input = HeaderAndBody::parse("seeds: 1 2")?;
+
+        assert_eq!("seeds: 1 2", input.header);
+        assert!(input.blocks.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_reject_missing_header() -> miette::Result<()> {
+        assert_eq!(Err(Error::MissingHeader), HeaderAndBody::parse(""));
+        assert_eq!(Err(Error::MissingHeader), HeaderAndBody::parse("\nAAA"));
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_reject_body_directly_after_header() -> miette::Result<()> {
+        assert_eq!(
+            Err(Error::MissingSeparator { line: 2 }),
+            HeaderAndBody::parse("LR\nAAA = (BBB, CCC)")
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,10 @@
+pub mod columns;
+pub mod error;
+pub mod header_and_body;
+pub mod normalize;
+pub mod parse;
+
+pub use columns::{key_value, split_once_ws};
+pub use error::Error;
+pub use header_and_body::HeaderAndBody;
+pub use normalize::normalize;
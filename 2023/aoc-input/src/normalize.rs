@@ -0,0 +1,40 @@
+use std::borrow::Cow;
+
+const BOM: char = '\u{feff}';
+
+/// Strips a leading byte order mark and turns `\r\n` and lone `\r` line
+/// endings into `\n`, as hand-edited inputs tend to need. Clean input and a
+/// stripped BOM are borrowed from `input`; only rewriting line endings
+/// allocates.
+#[tracing::instrument(skip(input))]
+pub fn normalize(input: &str) -> Cow<'_, str> {
+    let input = input.strip_prefix(BOM).unwrap_or(input);
+
+    if !input.contains('\r') {
+        return Cow::Borrowed(input);
+    }
+
+    Cow::Owned(input.replace("\r\n", "\n").replace('\r', "\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn it_should_borrow_clean_input() -> miette::Result<()> {
+        assert!(matches!(normalize("LR\n\nAAA"), Cow::Borrowed("LR\n\nAAA")));
+        assert!(matches!(normalize("\u{feff}LR\n"), Cow::Borrowed("LR\n")));
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_rewrite_carriage_returns() -> miette::Result<()> {
+        let normalized = normalize("\u{feff}LR\r\n\r\nAAA\rBBB\r\n");
+
+        assert!(matches!(normalized, Cow::Owned(_)));
+        assert_eq!("LR\n\nAAA\nBBB\n", normalized);
+        Ok(())
+    }
+}
@@ -0,0 +1,236 @@
+//! nom combinators for the shapes inputs keep coming back to: labelled lists
+//! of numbers, blocks of lines between blank lines and rectangular grids. They
+//! all fail with `Failure`, which keeps the `ParseIntError` when a number is
+//! what went wrong, so days can still say which of their errors it was.
+
+use std::{num::ParseIntError, str::FromStr};
+
+use nom::{
+    bytes::complete::{tag, take_while1},
+    character::complete::{char, line_ending, space0, space1},
+    combinator::{all_consuming, value, verify},
+    error::{ErrorKind, FromExternalError, ParseError},
+    multi::{many1, separated_list0, separated_list1},
+    sequence::{delimited, pair, preceded},
+    Finish, IResult, Parser,
+};
+
+use crate::{columns::offset_in, error::Error};
+
+/// Why a parser gave up.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Reason {
+    /// A token sat where a number should be but didn't parse as one.
+    Number(ParseIntError),
+    /// The input didn't have the shape the parser expected.
+    Expected(ErrorKind),
+}
+
+/// Where a parser gave up, as a slice of its input, and why. For a bad number
+/// `at` is just that token.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Failure<'a> {
+    pub at: &'a str,
+    pub reason: Reason,
+}
+
+pub type Parsed<'a, O> = IResult<&'a str, O, Failure<'a>>;
+
+impl<'a> ParseError<&'a str> for Failure<'a> {
+    fn from_error_kind(input: &'a str, kind: ErrorKind) -> Self {
+        Failure {
+            at: input,
+            reason: Reason::Expected(kind),
+        }
+    }
+
+    fn append(_: &'a str, _: ErrorKind, other: Self) -> Self {
+        other
+    }
+}
+
+impl<'a> FromExternalError<&'a str, ParseIntError> for Failure<'a> {
+    fn from_external_error(input: &'a str, _: ErrorKind, e: ParseIntError) -> Self {
+        Failure {
+            at: input,
+            reason: Reason::Number(e),
+        }
+    }
+}
+
+impl Failure<'_> {
+    /// A diagnostic pointing into `input`, which `at` must be a slice of.
+    #[tracing::instrument(skip(input))]
+    pub fn to_error(&self, input: &str) -> Error {
+        let (reason, len) = match &self.reason {
+            Reason::Number(e) => (e.to_string(), self.at.len()),
+            Reason::Expected(kind) => (kind.description().to_lowercase(), 0),
+        };
+
+        Error::Unparsed {
+            reason,
+            input: input.to_string(),
+            span: (offset_in(input, self.at), len).into(),
+        }
+    }
+}
+
+/// `parser` over the whole of `input`, failing if anything is left over.
+#[tracing::instrument(skip(parser))]
+pub fn finish<'a, O>(
+    parser: impl Parser<&'a str, O, Failure<'a>>,
+    input: &'a str,
+) -> Result<O, Failure<'a>> {
+    all_consuming(parser)(input)
+        .finish()
+        .map(|(_, output)| output)
+}
+
+/// `inner` with any spaces or tabs either side of it.
+#[tracing::instrument(skip(inner))]
+pub fn ws<'a, O>(
+    inner: impl Parser<&'a str, O, Failure<'a>>,
+) -> impl FnMut(&'a str) -> Parsed<'a, O> {
+    delimited(space0, inner, space0)
+}
+
+/// A run of letters, digits and signs read as a `T`. A run that isn't a `T`
+/// fails outright rather than letting a list end early, so `1 x` blames the
+/// `x` instead of whatever was expected after the `1`.
+#[tracing::instrument]
+pub fn number<T: FromStr<Err = ParseIntError>>(input: &str) -> Parsed<'_, T> {
+    let (rest, token) =
+        take_while1::<_, _, Failure>(|c: char| c.is_alphanumeric() || c == '-' || c == '+')(input)?;
+
+    token
+        .parse()
+        .map(|number| (rest, number))
+        .map_err(|e| nom::Err::Failure(Failure::from_external_error(token, ErrorKind::MapRes, e)))
+}
+
+/// `label:` then space-separated numbers, like day 5's `seeds: 79 14`. The
+/// list may be empty; whether that's allowed is up to each day.
+#[tracing::instrument]
+pub fn labelled_numbers<'a, T: FromStr<Err = ParseIntError>>(
+    label: &'a str,
+) -> impl FnMut(&'a str) -> Parsed<'a, Vec<T>> {
+    preceded(
+        pair(tag(label), ws(char(':'))),
+        separated_list0(space1, number),
+    )
+}
+
+/// One or more lines of `line`, without the last line's ending.
+#[tracing::instrument(skip(line))]
+pub fn lines<'a, O>(
+    line: impl Parser<&'a str, O, Failure<'a>>,
+) -> impl FnMut(&'a str) -> Parsed<'a, Vec<O>> {
+    separated_list1(line_ending, line)
+}
+
+/// One or more `section`s with blank lines between them, like day 5's maps.
+#[tracing::instrument(skip(section))]
+pub fn sections<'a, O>(
+    section: impl Parser<&'a str, O, Failure<'a>>,
+) -> impl FnMut(&'a str) -> Parsed<'a, Vec<O>> {
+    separated_list1(blank_lines, section)
+}
+
+#[tracing::instrument]
+fn blank_lines(input: &str) -> Parsed<'_, ()> {
+    value((), pair(line_ending, many1(pair(space0, line_ending))))(input)
+}
+
+/// Rows of cells that are all as wide as the first, like day 3's schematic or
+/// day 11's image. Any character but whitespace is a cell.
+#[tracing::instrument]
+pub fn grid_block(input: &str) -> Parsed<'_, Vec<&str>> {
+    verify(
+        lines(take_while1(|c: char| !c.is_whitespace())),
+        |rows: &Vec<&str>| {
+            let width = rows[0].chars().count();
+            rows.iter().all(|row| row.chars().count() == width)
+        },
+    )(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use miette::SourceSpan;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn it_should_read_labelled_numbers() -> miette::Result<()> {
+        assert_eq!(
+            Ok(vec![79, 14, 55, 13]),
+            finish(labelled_numbers::<u64>("seeds"), "seeds: 79 14 55 13")
+        );
+        assert_eq!(
+            Ok(vec![]),
+            finish(labelled_numbers::<u64>("seeds"), "seeds:")
+        );
+        assert_eq!(
+            Ok(vec![-3, 7]),
+            finish(labelled_numbers::<i64>("deltas"), "deltas :-3  7")
+        );
+
+        let Err(failure) = finish(labelled_numbers::<u64>("seeds"), "seeds: 1 x 2") else {
+            panic!("expected x not to be a number");
+        };
+        assert_eq!("x", failure.at);
+        assert!(matches!(failure.reason, Reason::Number(_)));
+
+        let Err(failure) = finish(labelled_numbers::<u64>("seeds"), "soil: 1 2") else {
+            panic!("expected the wrong label to fail");
+        };
+        assert_eq!(Reason::Expected(ErrorKind::Tag), failure.reason);
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_split_sections_on_blank_lines() -> miette::Result<()> {
+        let input = "a map:\n1 2 3\n\n\t\nb map:\n4 5 6\n7 8 9";
+
+        assert_eq!(
+            Ok(vec![
+                vec!["a map:", "1 2 3"],
+                vec!["b map:", "4 5 6", "7 8 9"]
+            ]),
+            finish(sections(lines(take_while1(|c: char| c != '\n'))), input)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_only_take_rectangular_grids() -> miette::Result<()> {
+        assert_eq!(
+            Ok(vec!["#..", ".€.", "..#"]),
+            finish(grid_block, "#..\n.€.\n..#")
+        );
+        assert_eq!(
+            Ok(vec![vec!["#.", ".#"], vec!["..", ".."]]),
+            finish(sections(grid_block), "#.\n.#\n\n..\n..")
+        );
+
+        let Err(failure) = finish(grid_block, "#..\n.#\n..#") else {
+            panic!("expected a ragged grid to fail");
+        };
+        assert_eq!(Reason::Expected(ErrorKind::Verify), failure.reason);
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_point_at_the_bad_number() -> miette::Result<()> {
+        let input = "seeds: 1 x2 3";
+        let Err(failure) = finish(labelled_numbers::<u64>("seeds"), input) else {
+            panic!("expected x2 not to be a number");
+        };
+
+        let Error::Unparsed { span, .. } = failure.to_error(input) else {
+            panic!("expected an unparsed error");
+        };
+        assert_eq!(SourceSpan::from((9, 2)), span);
+        Ok(())
+    }
+}
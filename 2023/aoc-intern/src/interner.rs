@@ -0,0 +1,153 @@
+use std::{borrow::Borrow, collections::HashMap, hash::Hash};
+
+/// Hands out a dense index for each distinct label, in the order they're
+/// first seen, so anything keyed by label can live in a `Vec` instead of a
+/// map (e.g. day 8's node names).
+#[derive(Clone)]
+pub struct Interner<T> {
+    indices: HashMap<T, u32>,
+    labels: Vec<T>,
+}
+
+impl<T> std::fmt::Debug for Interner<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Interner")
+            .field("len", &self.labels.len())
+            .finish()
+    }
+}
+
+/// The indices follow from the labels, so two interners are equal when they
+/// saw the same labels in the same order.
+impl<T: PartialEq> PartialEq for Interner<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.labels == other.labels
+    }
+}
+
+impl<T: Eq> Eq for Interner<T> {}
+
+impl<T> Default for Interner<T> {
+    fn default() -> Self {
+        Self {
+            indices: HashMap::new(),
+            labels: vec![],
+        }
+    }
+}
+
+impl<T: Hash + Eq + Clone> Interner<T> {
+    #[tracing::instrument]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// `label`'s index, giving it the next one if it's new.
+    #[tracing::instrument(skip(label))]
+    pub fn intern(&mut self, label: T) -> u32 {
+        if let Some(index) = self.indices.get(&label) {
+            return *index;
+        }
+
+        let index = self.labels.len() as u32;
+        self.indices.insert(label.clone(), index);
+        self.labels.push(label);
+        index
+    }
+
+    /// `label`'s index, if it has one.
+    #[tracing::instrument(skip(label))]
+    pub fn get<Q>(&self, label: &Q) -> Option<u32>
+    where
+        T: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.indices.get(label).copied()
+    }
+
+    /// The label given `index`.
+    #[tracing::instrument]
+    pub fn label(&self, index: u32) -> Option<&T> {
+        self.labels.get(index as usize)
+    }
+
+    #[tracing::instrument]
+    pub fn len(&self) -> usize {
+        self.labels.len()
+    }
+
+    #[tracing::instrument]
+    pub fn is_empty(&self) -> bool {
+        self.labels.is_empty()
+    }
+
+    /// Every `(index, label)`, in index order.
+    #[tracing::instrument]
+    pub fn iter(&self) -> impl Iterator<Item = (u32, &T)> {
+        self.labels
+            .iter()
+            .enumerate()
+            .map(|(index, label)| (index as u32, label))
+    }
+}
+
+impl<T: Hash + Eq + Clone> FromIterator<T> for Interner<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(labels: I) -> Self {
+        let mut interner = Self::new();
+        for label in labels {
+            interner.intern(label);
+        }
+        interner
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn it_should_number_labels_in_first_seen_order() -> miette::Result<()> {
+        let mut interner = Interner::new();
+
+        assert_eq!(0, interner.intern("AAA"));
+        assert_eq!(1, interner.intern("BBB"));
+        assert_eq!(0, interner.intern("AAA"));
+        assert_eq!(2, interner.intern("ZZZ"));
+
+        assert_eq!(3, interner.len());
+        assert_eq!(Some(1), interner.get("BBB"));
+        assert_eq!(None, interner.get("CCC"));
+        assert_eq!(Some(&"ZZZ"), interner.label(2));
+        assert_eq!(None, interner.label(3));
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_look_up_owned_labels_by_borrowed_ones() -> miette::Result<()> {
+        let interner = ["11A", "11Z", "11A"]
+            .into_iter()
+            .map(String::from)
+            .collect::<Interner<_>>();
+
+        assert_eq!(Some(1), interner.get("11Z"));
+        assert_eq!(
+            vec![(0, "11A"), (1, "11Z")],
+            interner
+                .iter()
+                .map(|(index, label)| (index, label.as_str()))
+                .collect::<Vec<_>>()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_start_empty() -> miette::Result<()> {
+        let interner = Interner::<u32>::default();
+
+        assert!(interner.is_empty());
+        assert_eq!(0, interner.iter().count());
+        assert_eq!("Interner { len: 0 }", format!("{:?}", interner));
+        Ok(())
+    }
+}
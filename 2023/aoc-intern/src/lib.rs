@@ -0,0 +1,3 @@
+pub mod interner;
+
+pub use interner::Interner;
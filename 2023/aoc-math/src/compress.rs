@@ -0,0 +1,61 @@
+/// Coordinate compression. `boundaries` holds each distinct value once, in
+/// ascending order, and `ranks[i]` is the index of `values[i]` in it. Equal
+/// values share a rank, and the gap between neighbouring boundaries is the
+/// stretch of empty space that was squeezed out (e.g. day 11's empty columns).
+#[tracing::instrument(skip(values))]
+pub fn compress<T: Ord + Copy>(values: &[T]) -> (Vec<usize>, Vec<T>) {
+    let mut boundaries = values.to_vec();
+    boundaries.sort_unstable();
+    boundaries.dedup();
+
+    let ranks = values
+        .iter()
+        .map(|value| boundaries.partition_point(|boundary| boundary < value))
+        .collect();
+
+    (ranks, boundaries)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn it_should_rank_unsorted_values() -> miette::Result<()> {
+        let (ranks, boundaries) = compress(&[1_000_000, -5, 42, 7]);
+
+        assert_eq!(vec![3, 0, 2, 1], ranks);
+        assert_eq!(vec![-5, 7, 42, 1_000_000], boundaries);
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_share_ranks_between_duplicates() -> miette::Result<()> {
+        let (ranks, boundaries) = compress(&[9u64, 3, 9, 3, 3, 12]);
+
+        assert_eq!(vec![1, 0, 1, 0, 0, 2], ranks);
+        assert_eq!(vec![3, 9, 12], boundaries);
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_map_ranks_back_to_values() -> miette::Result<()> {
+        let values = [(4, 'b'), (0, 'a'), (9, 'c'), (4, 'b')];
+        let (ranks, boundaries) = compress(&values);
+
+        for (value, rank) in values.iter().zip(&ranks) {
+            assert_eq!(*value, boundaries[*rank]);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_handle_empty_input() -> miette::Result<()> {
+        let (ranks, boundaries) = compress::<u32>(&[]);
+
+        assert!(ranks.is_empty());
+        assert!(boundaries.is_empty());
+        Ok(())
+    }
+}
@@ -0,0 +1,105 @@
+#[tracing::instrument]
+pub fn gcd(a: u64, b: u64) -> u64 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+#[tracing::instrument]
+pub fn lcm(a: u64, b: u64) -> u64 {
+    if a == 0 || b == 0 {
+        return 0;
+    }
+
+    a / gcd(a, b) * b
+}
+
+/// `a * x + b * y == gcd(a, b)`, as `(gcd, x, y)`.
+#[tracing::instrument]
+fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    if b == 0 {
+        (a, 1, 0)
+    } else {
+        let (gcd, x, y) = extended_gcd(b, a % b);
+        (gcd, y, x - (a / b) * y)
+    }
+}
+
+/// Chinese remainder theorem, for moduli that needn't be coprime. Given
+/// `t ≡ a.0 (mod a.1)` and `t ≡ b.0 (mod b.1)`, the smallest `t` that meets
+/// both and the modulus it repeats with, `(t, lcm(a.1, b.1))`. `None` when
+/// nothing meets both, which can only happen when the moduli share a factor.
+#[tracing::instrument]
+pub fn crt(a: (u64, u64), b: (u64, u64)) -> Option<(u64, u64)> {
+    let (a_residue, a_modulus) = (a.0 as i128, a.1 as i128);
+    let (b_residue, b_modulus) = (b.0 as i128, b.1 as i128);
+
+    let (gcd, x, _) = extended_gcd(a_modulus, b_modulus);
+    let difference = b_residue - a_residue;
+    if difference % gcd != 0 {
+        return None;
+    }
+
+    let modulus = a_modulus / gcd * b_modulus;
+    let step = b_modulus / gcd;
+    let k = (difference / gcd * x).rem_euclid(step);
+    let t = (a_residue + a_modulus * k).rem_euclid(modulus);
+
+    Some((u64::try_from(t).ok()?, u64::try_from(modulus).ok()?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn it_should_find_common_factors_and_multiples() -> miette::Result<()> {
+        assert_eq!(6, gcd(12, 18));
+        assert_eq!(1, gcd(13, 7));
+        assert_eq!(5, gcd(0, 5));
+        assert_eq!(36, lcm(12, 18));
+        assert_eq!(91, lcm(13, 7));
+        assert_eq!(0, lcm(0, 7));
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_combine_coprime_moduli() -> miette::Result<()> {
+        assert_eq!(
+            Some((23, 105)),
+            crt((2, 3), (3, 5)).and_then(|a| crt(a, (2, 7)))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_combine_moduli_that_share_a_factor() -> miette::Result<()> {
+        assert_eq!(Some((10, 12)), crt((4, 6), (2, 4)));
+        assert_eq!(None, crt((1, 6), (2, 4)));
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_agree_with_counting_up() -> miette::Result<()> {
+        for a_modulus in 1..12 {
+            for b_modulus in 1..12 {
+                for a_residue in 0..a_modulus {
+                    for b_residue in 0..b_modulus {
+                        let expected = (0..a_modulus * b_modulus)
+                            .find(|t| t % a_modulus == a_residue && t % b_modulus == b_residue)
+                            .map(|t| (t, lcm(a_modulus, b_modulus)));
+
+                        assert_eq!(
+                            expected,
+                            crt((a_residue, a_modulus), (b_residue, b_modulus))
+                        );
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
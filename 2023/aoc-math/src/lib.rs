@@ -0,0 +1,5 @@
+pub mod compress;
+pub mod crt;
+
+pub use compress::compress;
+pub use crt::{crt, gcd, lcm};
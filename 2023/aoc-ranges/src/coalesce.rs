@@ -0,0 +1,78 @@
+use crate::Interval;
+
+/// The same values as `intervals`, as few intervals as possible: sorted,
+/// with empty ones dropped and overlapping or touching ones merged.
+#[tracing::instrument(skip(intervals))]
+pub fn coalesce<T: Copy + Ord + std::fmt::Debug>(
+    intervals: impl IntoIterator<Item = Interval<T>>,
+) -> Vec<Interval<T>> {
+    let mut intervals = intervals
+        .into_iter()
+        .filter(|interval| !interval.is_empty())
+        .collect::<Vec<_>>();
+    intervals.sort_unstable();
+
+    let mut coalesced: Vec<Interval<T>> = Vec::with_capacity(intervals.len());
+    for interval in intervals {
+        match coalesced.last_mut() {
+            Some(last) if interval.start <= last.end => last.end = last.end.max(interval.end),
+            _ => coalesced.push(interval),
+        }
+    }
+
+    coalesced
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use proptest::prelude::*;
+    use std::{collections::BTreeSet, ops::Range};
+
+    #[test]
+    fn it_should_merge_overlapping_and_touching_intervals() -> miette::Result<()> {
+        let intervals = [(10, 14), (1, 3), (3, 5), (12, 20), (7, 7), (8, 9)]
+            .map(|(start, end)| Interval::new(start, end));
+
+        assert_eq!(
+            vec![
+                Interval::new(1, 5),
+                Interval::new(8, 9),
+                Interval::new(10, 20)
+            ],
+            coalesce(intervals)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_handle_no_intervals() -> miette::Result<()> {
+        assert_eq!(Vec::<Interval<u64>>::new(), coalesce([]));
+        Ok(())
+    }
+
+    proptest! {
+        #[test]
+        fn it_should_keep_the_same_values(
+            intervals in prop::collection::vec((0u8..60, 0u8..60), 0..8)
+        ) {
+            let intervals = intervals
+                .into_iter()
+                .map(|(start, end)| Interval::new(start, end))
+                .collect::<Vec<_>>();
+            let values = |intervals: &[Interval<u8>]| {
+                intervals
+                    .iter()
+                    .flat_map(|interval| Range::from(*interval))
+                    .collect::<BTreeSet<_>>()
+            };
+
+            let coalesced = coalesce(intervals.clone());
+
+            prop_assert_eq!(values(&intervals), values(&coalesced));
+            prop_assert!(coalesced.iter().all(|interval| !interval.is_empty()));
+            prop_assert!(coalesced.windows(2).all(|pair| pair[0].end < pair[1].start));
+        }
+    }
+}
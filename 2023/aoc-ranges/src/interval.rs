@@ -0,0 +1,225 @@
+use std::ops::{Range, Sub};
+
+/// The integers from `start` up to but not including `end`. Empty whenever
+/// `start >= end`, so splitting and subtracting never need a `+ 1` or `- 1`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Interval<T> {
+    pub start: T,
+    pub end: T,
+}
+
+impl<T: Copy + Ord + std::fmt::Debug> Interval<T> {
+    #[tracing::instrument]
+    pub fn new(start: T, end: T) -> Self {
+        Self { start, end }
+    }
+
+    /// `Some` only when there's something in it, for operations that can
+    /// leave nothing behind.
+    #[tracing::instrument]
+    fn non_empty(start: T, end: T) -> Option<Self> {
+        (start < end).then_some(Self { start, end })
+    }
+
+    #[tracing::instrument]
+    pub fn is_empty(&self) -> bool {
+        self.start >= self.end
+    }
+
+    #[tracing::instrument]
+    pub fn contains(&self, value: T) -> bool {
+        self.start <= value && value < self.end
+    }
+
+    #[tracing::instrument]
+    pub fn overlaps(&self, other: &Self) -> bool {
+        self.intersection(other).is_some()
+    }
+
+    /// The values in both, if any.
+    #[tracing::instrument]
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        Self::non_empty(self.start.max(other.start), self.end.min(other.end))
+    }
+
+    /// Both as one interval, if they overlap or one ends where the other
+    /// starts. Anything else would cover values in neither.
+    #[tracing::instrument]
+    pub fn union(&self, other: &Self) -> Option<Self> {
+        if self.is_empty() {
+            return Some(*other);
+        }
+        if other.is_empty() {
+            return Some(*self);
+        }
+        if self.start > other.end || other.start > self.end {
+            return None;
+        }
+
+        Some(Self::new(
+            self.start.min(other.start),
+            self.end.max(other.end),
+        ))
+    }
+
+    /// The values not in `other`: those below it and those above it.
+    #[tracing::instrument]
+    pub fn difference(&self, other: &Self) -> (Option<Self>, Option<Self>) {
+        if other.is_empty() {
+            return (Self::non_empty(self.start, self.end), None);
+        }
+
+        (
+            Self::non_empty(self.start, self.end.min(other.start)),
+            Self::non_empty(self.start.max(other.end), self.end),
+        )
+    }
+
+    /// The values below `at`, and those from `at` on.
+    #[tracing::instrument]
+    pub fn split_at(&self, at: T) -> (Option<Self>, Option<Self>) {
+        (
+            Self::non_empty(self.start, self.end.min(at)),
+            Self::non_empty(self.start.max(at), self.end),
+        )
+    }
+}
+
+impl<T: Copy + Ord + Sub<Output = T> + Default + std::fmt::Debug> Interval<T> {
+    /// How many values it holds; zero when empty.
+    #[tracing::instrument]
+    pub fn len(&self) -> T {
+        if self.start >= self.end {
+            return T::default();
+        }
+
+        self.end - self.start
+    }
+}
+
+impl<T> From<Range<T>> for Interval<T> {
+    fn from(range: Range<T>) -> Self {
+        Self {
+            start: range.start,
+            end: range.end,
+        }
+    }
+}
+
+impl<T> From<Interval<T>> for Range<T> {
+    fn from(interval: Interval<T>) -> Self {
+        interval.start..interval.end
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use proptest::prelude::*;
+    use std::collections::BTreeSet;
+
+    fn interval(start: u8, end: u8) -> Interval<u8> {
+        Interval::new(start, end)
+    }
+
+    fn values(interval: Option<Interval<u8>>) -> BTreeSet<u8> {
+        interval.map(Range::from).into_iter().flatten().collect()
+    }
+
+    fn small() -> impl Strategy<Value = Interval<u8>> {
+        (0u8..40, 0u8..40).prop_map(|(start, end)| interval(start, end))
+    }
+
+    #[test]
+    fn it_should_treat_end_as_exclusive() -> miette::Result<()> {
+        let range = interval(50, 55);
+
+        assert!(range.contains(50));
+        assert!(range.contains(54));
+        assert!(!range.contains(55));
+        assert_eq!(5, range.len());
+        assert!(interval(7, 7).is_empty());
+        assert!(interval(8, 7).is_empty());
+        assert_eq!(0, interval(8, 7).len());
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_split_around_another_interval() -> miette::Result<()> {
+        let range = interval(48, 58);
+
+        assert_eq!(
+            Some(interval(50, 55)),
+            range.intersection(&interval(50, 55))
+        );
+        assert_eq!(
+            (Some(interval(48, 50)), Some(interval(55, 58))),
+            range.difference(&interval(50, 55))
+        );
+        assert_eq!((Some(range), None), range.difference(&interval(60, 70)));
+        assert_eq!((None, None), range.difference(&interval(0, 100)));
+        assert_eq!(
+            (Some(interval(48, 53)), Some(interval(53, 58))),
+            range.split_at(53)
+        );
+        assert_eq!((Some(range), None), range.split_at(58));
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_only_join_touching_intervals() -> miette::Result<()> {
+        assert_eq!(Some(interval(1, 9)), interval(1, 5).union(&interval(5, 9)));
+        assert_eq!(Some(interval(1, 9)), interval(3, 9).union(&interval(1, 4)));
+        assert_eq!(None, interval(1, 4).union(&interval(5, 9)));
+        assert_eq!(Some(interval(5, 9)), interval(3, 3).union(&interval(5, 9)));
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_convert_to_and_from_ranges() -> miette::Result<()> {
+        assert_eq!(interval(3, 6), Interval::from(3..6));
+        assert_eq!(3..6, Range::from(interval(3, 6)));
+        Ok(())
+    }
+
+    proptest! {
+        #[test]
+        fn it_should_intersect_like_sets(a in small(), b in small()) {
+            let expected = &values(Some(a)) & &values(Some(b));
+
+            prop_assert_eq!(expected, values(a.intersection(&b)));
+            prop_assert_eq!(a.overlaps(&b), a.intersection(&b).is_some());
+        }
+
+        #[test]
+        fn it_should_subtract_like_sets(a in small(), b in small()) {
+            let expected = &values(Some(a)) - &values(Some(b));
+            let (below, above) = a.difference(&b);
+
+            prop_assert_eq!(expected, &values(below) | &values(above));
+            if let (Some(below), Some(above)) = (below, above) {
+                prop_assert!(below.end < above.start);
+            }
+        }
+
+        #[test]
+        fn it_should_unite_like_sets(a in small(), b in small()) {
+            let expected = &values(Some(a)) | &values(Some(b));
+
+            match a.union(&b) {
+                Some(union) => prop_assert_eq!(expected, values(Some(union))),
+                None => prop_assert!(!a.overlaps(&b) && a.end != b.start && b.end != a.start),
+            }
+        }
+
+        #[test]
+        fn it_should_split_without_losing_values(a in small(), at in 0u8..40) {
+            let (below, above) = a.split_at(at);
+
+            prop_assert_eq!(values(Some(a)), &values(below) | &values(above));
+            prop_assert!(values(below).iter().all(|v| *v < at));
+            prop_assert!(values(above).iter().all(|v| *v >= at));
+        }
+    }
+}
@@ -0,0 +1,7 @@
+pub mod coalesce;
+pub mod interval;
+pub mod sorted;
+
+pub use coalesce::coalesce;
+pub use interval::Interval;
+pub use sorted::{intersect_sorted, merge_sorted_by, subtract_sorted};
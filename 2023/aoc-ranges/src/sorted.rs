@@ -0,0 +1,192 @@
+//! Two-pointer passes over interval lists that are already sorted, so
+//! combining them is linear rather than every interval against every other.
+//! Where a list must also be disjoint, `coalesce` makes it so.
+
+use crate::Interval;
+
+/// Both lists as one, sorted by `key`, taking from `a` first on ties. Each
+/// list must already be sorted by `key`.
+#[tracing::instrument(skip(a, b, key))]
+pub fn merge_sorted_by<I, K: Ord>(
+    a: impl IntoIterator<Item = I>,
+    b: impl IntoIterator<Item = I>,
+    mut key: impl FnMut(&I) -> K,
+) -> Vec<I> {
+    let mut a = a.into_iter().peekable();
+    let mut b = b.into_iter().peekable();
+    let mut merged = Vec::with_capacity(a.size_hint().0 + b.size_hint().0);
+
+    loop {
+        let next = match (a.peek(), b.peek()) {
+            (Some(x), Some(y)) if key(y) < key(x) => b.next(),
+            (Some(_), _) => a.next(),
+            (None, _) => b.next(),
+        };
+
+        match next {
+            Some(item) => merged.push(item),
+            None => return merged,
+        }
+    }
+}
+
+/// The values in both lists. Each must be sorted and disjoint.
+#[tracing::instrument(skip(a, b))]
+pub fn intersect_sorted<T: Copy + Ord + std::fmt::Debug>(
+    a: &[Interval<T>],
+    b: &[Interval<T>],
+) -> Vec<Interval<T>> {
+    let mut intersection = vec![];
+    let (mut i, mut j) = (0, 0);
+
+    while let (Some(x), Some(y)) = (a.get(i), b.get(j)) {
+        intersection.extend(x.intersection(y));
+
+        // Whichever ends first can't overlap anything further along the other
+        if x.end < y.end {
+            i += 1;
+        } else {
+            j += 1;
+        }
+    }
+
+    intersection
+}
+
+/// The values in `a` but not in `b`. Each must be sorted and disjoint.
+#[tracing::instrument(skip(a, b))]
+pub fn subtract_sorted<T: Copy + Ord + std::fmt::Debug>(
+    a: &[Interval<T>],
+    b: &[Interval<T>],
+) -> Vec<Interval<T>> {
+    let mut difference = vec![];
+    let mut first_cut = 0;
+
+    for interval in a {
+        // Cuts ending before this interval end before every later one too
+        while b
+            .get(first_cut)
+            .is_some_and(|cut| cut.end <= interval.start)
+        {
+            first_cut += 1;
+        }
+
+        let mut start = interval.start;
+        for cut in b[first_cut..]
+            .iter()
+            .take_while(|cut| cut.start < interval.end)
+        {
+            difference.extend(Interval::new(start, cut.start).intersection(interval));
+            start = start.max(cut.end);
+        }
+
+        difference.extend(Interval::new(start, interval.end).intersection(interval));
+    }
+
+    difference
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::coalesce;
+    use pretty_assertions::assert_eq;
+    use proptest::prelude::*;
+    use std::{collections::BTreeSet, ops::Range};
+
+    fn intervals(pairs: &[(u8, u8)]) -> Vec<Interval<u8>> {
+        pairs
+            .iter()
+            .map(|(start, end)| Interval::new(*start, *end))
+            .collect()
+    }
+
+    fn values(intervals: &[Interval<u8>]) -> BTreeSet<u8> {
+        intervals
+            .iter()
+            .flat_map(|interval| Range::from(*interval))
+            .collect()
+    }
+
+    /// Sorted and disjoint, as the intersect and subtract helpers need.
+    fn disjoint() -> impl Strategy<Value = Vec<Interval<u8>>> {
+        prop::collection::vec((0u8..60, 0u8..60), 0..8)
+            .prop_map(|pairs| coalesce(intervals(&pairs)))
+    }
+
+    fn sorted() -> impl Strategy<Value = Vec<Interval<u8>>> {
+        prop::collection::vec((0u8..60, 0u8..60), 0..8).prop_map(|pairs| {
+            let mut intervals = intervals(&pairs);
+            intervals.sort_by_key(|interval| interval.start);
+            intervals
+        })
+    }
+
+    fn is_sorted_and_disjoint(intervals: &[Interval<u8>]) -> bool {
+        intervals.iter().all(|interval| !interval.is_empty())
+            && intervals
+                .windows(2)
+                .all(|pair| pair[0].end <= pair[1].start)
+    }
+
+    #[test]
+    fn it_should_merge_sorted_lists() -> miette::Result<()> {
+        assert_eq!(
+            vec![1, 2, 3, 3, 5, 8],
+            merge_sorted_by([1, 3, 8], [2, 3, 5], |n| *n)
+        );
+        assert_eq!(
+            intervals(&[(0, 4), (2, 9), (2, 3), (6, 7)]),
+            merge_sorted_by(
+                intervals(&[(0, 4), (2, 9)]),
+                intervals(&[(2, 3), (6, 7)]),
+                |interval| interval.start
+            )
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_intersect_and_subtract_sorted_lists() -> miette::Result<()> {
+        let a = intervals(&[(0, 10), (20, 30)]);
+        let b = intervals(&[(5, 22), (25, 26), (28, 40)]);
+
+        assert_eq!(
+            intervals(&[(5, 10), (20, 22), (25, 26), (28, 30)]),
+            intersect_sorted(&a, &b)
+        );
+        assert_eq!(
+            intervals(&[(0, 5), (22, 25), (26, 28)]),
+            subtract_sorted(&a, &b)
+        );
+        assert_eq!(a, subtract_sorted(&a, &[]));
+        assert_eq!(Vec::<Interval<u8>>::new(), intersect_sorted(&a, &[]));
+        Ok(())
+    }
+
+    proptest! {
+        #[test]
+        fn it_should_merge_like_a_stable_sort(a in sorted(), b in sorted()) {
+            let mut naive = a.iter().chain(&b).copied().collect::<Vec<_>>();
+            naive.sort_by_key(|interval| interval.start);
+
+            prop_assert_eq!(naive, merge_sorted_by(a, b, |interval| interval.start));
+        }
+
+        #[test]
+        fn it_should_intersect_like_sets(a in disjoint(), b in disjoint()) {
+            let intersection = intersect_sorted(&a, &b);
+
+            prop_assert_eq!(&values(&a) & &values(&b), values(&intersection));
+            prop_assert!(is_sorted_and_disjoint(&intersection));
+        }
+
+        #[test]
+        fn it_should_subtract_like_sets(a in disjoint(), b in disjoint()) {
+            let difference = subtract_sorted(&a, &b);
+
+            prop_assert_eq!(&values(&a) - &values(&b), values(&difference));
+            prop_assert!(is_sorted_and_disjoint(&difference));
+        }
+    }
+}
@@ -0,0 +1,38 @@
+use std::ops::Range;
+
+use proptest::prelude::*;
+
+/// A rectangular grid of `cells`, one line per row without a trailing
+/// newline, `width` by `height`.
+pub fn grid(
+    cells: &'static str,
+    width: Range<usize>,
+    height: Range<usize>,
+) -> impl Strategy<Value = String> {
+    let cells = cells.chars().collect::<Vec<_>>();
+
+    (width, height).prop_flat_map(move |(width, height)| {
+        let row = prop::collection::vec(prop::sample::select(cells.clone()), width)
+            .prop_map(String::from_iter);
+
+        prop::collection::vec(row, height).prop_map(|rows| rows.join("\n"))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::Grid;
+
+    proptest! {
+        #[test]
+        fn it_should_round_trip_through_a_grid(input in grid(".#", 1..12, 1..12)) {
+            let grid = input
+                .parse::<Grid<char>>()
+                .map_err(|e| TestCaseError::fail(e.to_string()))?;
+
+            prop_assert_eq!(input.lines().count(), grid.height());
+            prop_assert_eq!(format!("{}\n", input), grid.to_string());
+        }
+    }
+}
@@ -0,0 +1,26 @@
+use aoc_core::alphabet::CardRank;
+use proptest::prelude::*;
+
+/// One Camel Cards card (day 7), as it's written.
+pub fn card() -> impl Strategy<Value = char> {
+    prop::sample::select(CardRank::SYMBOLS.map(char::from).to_vec())
+}
+
+/// Five cards, as a hand is written. Repeats are as likely as in a real
+/// deal, so every hand type turns up.
+pub fn hand() -> impl Strategy<Value = String> {
+    prop::collection::vec(card(), 5).prop_map(String::from_iter)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn it_should_deal_five_known_cards(hand in hand()) {
+            prop_assert_eq!(5, hand.len());
+            prop_assert!(hand.chars().all(|c| CardRank::from_char(c).is_some()));
+        }
+    }
+}
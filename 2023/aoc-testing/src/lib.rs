@@ -0,0 +1,13 @@
+//! Proptest strategies for the shapes puzzle inputs come in, shared so every
+//! crate's property tests draw from the same ones, plus the seeded generator
+//! behind the synthetic inputs.
+
+pub mod grids;
+pub mod hands;
+pub mod ranges;
+pub mod rng;
+
+pub use grids::grid;
+pub use hands::hand;
+pub use ranges::{disjoint_intervals, interval, values};
+pub use rng::XorShift;
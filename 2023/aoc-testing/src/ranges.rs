@@ -0,0 +1,48 @@
+use std::{collections::BTreeSet, ops::Range};
+
+use aoc_ranges::{coalesce, Interval};
+use proptest::prelude::*;
+
+/// Any interval with both ends in `bounds`, empty ones included.
+pub fn interval(bounds: Range<u64>) -> impl Strategy<Value = Interval<u64>> {
+    (bounds.clone(), bounds).prop_map(|(start, end)| Interval::new(start, end))
+}
+
+/// Up to `count` intervals within `bounds`, sorted and disjoint as
+/// `coalesce` leaves them.
+pub fn disjoint_intervals(
+    bounds: Range<u64>,
+    count: Range<usize>,
+) -> impl Strategy<Value = Vec<Interval<u64>>> {
+    prop::collection::vec(interval(bounds), count).prop_map(coalesce)
+}
+
+/// Every value in `intervals`, to check interval arithmetic against plain
+/// sets. Only sensible for small bounds.
+pub fn values(intervals: &[Interval<u64>]) -> BTreeSet<u64> {
+    intervals
+        .iter()
+        .flat_map(|interval| Range::from(*interval))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn it_should_stay_within_bounds(interval in interval(10..20)) {
+            prop_assert!((10..20).contains(&interval.start));
+            prop_assert!((10..20).contains(&interval.end));
+        }
+
+        #[test]
+        fn it_should_generate_sorted_disjoint_intervals(
+            intervals in disjoint_intervals(0..60, 0..8)
+        ) {
+            prop_assert!(intervals.iter().all(|interval| !interval.is_empty()));
+            prop_assert!(intervals.windows(2).all(|pair| pair[0].end < pair[1].start));
+        }
+    }
+}
@@ -0,0 +1,38 @@
+/// Small xorshift so synthetic inputs and sampled checks are reproducible
+/// without pulling in `rand`.
+#[derive(Debug, Clone)]
+pub struct XorShift {
+    state: u64,
+}
+
+impl XorShift {
+    /// A zero seed would only ever give zeros, so it's taken as one.
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed.max(1) }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state ^= self.state << 13;
+        self.state ^= self.state >> 7;
+        self.state ^= self.state << 17;
+        self.state
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_should_repeat_for_the_same_seed() {
+        let draw = |seed| {
+            let mut rng = XorShift::new(seed);
+            (0..4).map(|_| rng.next_u64()).collect::<Vec<_>>()
+        };
+
+        assert_eq!(draw(7), draw(7));
+        assert_ne!(draw(7), draw(8));
+        assert_eq!(draw(0), draw(1));
+        assert!(draw(0).iter().all(|value| *value != 0));
+    }
+}
@@ -0,0 +1,9 @@
+pub mod progress;
+pub mod style;
+pub mod summary;
+pub mod table;
+
+pub use progress::Progress;
+pub use style::{Backend, Status};
+pub use summary::Summary;
+pub use table::{Align, Table};
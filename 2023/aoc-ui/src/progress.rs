@@ -0,0 +1,91 @@
+use crate::style::Backend;
+
+/// A fixed-width bar with a `done/total` count.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Progress {
+    pub done: usize,
+    pub total: usize,
+    pub width: usize,
+}
+
+impl Progress {
+    #[tracing::instrument]
+    pub fn new(total: usize) -> Self {
+        Self {
+            done: 0,
+            total,
+            width: 20,
+        }
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub fn advance(&mut self) {
+        self.done = (self.done + 1).min(self.total);
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub fn render(&self, label: &str) -> String {
+        let filled = match self.total {
+            0 => self.width,
+            total => self.done * self.width / total,
+        };
+
+        format!(
+            "[{}{}] {}/{} {}",
+            "=".repeat(filled),
+            " ".repeat(self.width - filled),
+            self.done,
+            self.total,
+            label
+        )
+    }
+
+    /// Redraws the bar in place on a terminal. Plain output gets nothing, as a
+    /// line per step would just be noise in logs.
+    #[tracing::instrument(skip(self))]
+    pub fn draw(&self, backend: Backend, label: &str) {
+        if backend.is_ansi() {
+            eprint!("\r\x1b[2K{}", self.render(label));
+        }
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub fn clear(&self, backend: Backend) {
+        if backend.is_ansi() {
+            eprint!("\r\x1b[2K");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn it_should_render_bar() -> miette::Result<()> {
+        let mut progress = Progress::new(4);
+        assert_eq!(
+            "[                    ] 0/4 day 01",
+            progress.render("day 01")
+        );
+
+        progress.advance();
+        assert_eq!(
+            "[=====               ] 1/4 day 02",
+            progress.render("day 02")
+        );
+
+        for _ in 0..10 {
+            progress.advance();
+        }
+        assert_eq!("[====================] 4/4 done", progress.render("done"));
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_render_empty_as_complete() -> miette::Result<()> {
+        assert_eq!("[====================] 0/0 ", Progress::new(0).render(""));
+        Ok(())
+    }
+}
@@ -0,0 +1,100 @@
+use std::io::IsTerminal;
+
+use aoc_viz::Color;
+
+/// How a check came out. Each has a colour so results read the same in every
+/// subcommand.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Status {
+    Ok,
+    Warn,
+    Fail,
+}
+
+impl Status {
+    #[tracing::instrument]
+    pub fn color(&self) -> Color {
+        match self {
+            Status::Ok => Color::Green,
+            Status::Warn => Color::Yellow,
+            Status::Fail => Color::Red,
+        }
+    }
+}
+
+/// Where output is going. `Plain` never writes escape codes, so tests can
+/// compare rendered text directly.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Backend {
+    #[default]
+    Plain,
+    Ansi,
+}
+
+impl Backend {
+    /// Colour for a terminal, plain when piped or when `no_color` is set.
+    #[tracing::instrument]
+    pub fn stdout(no_color: bool) -> Self {
+        Self::detect(no_color, std::io::stdout().is_terminal())
+    }
+
+    #[tracing::instrument]
+    pub fn stderr(no_color: bool) -> Self {
+        Self::detect(no_color, std::io::stderr().is_terminal())
+    }
+
+    #[tracing::instrument]
+    fn detect(no_color: bool, terminal: bool) -> Self {
+        if terminal && !no_color {
+            Backend::Ansi
+        } else {
+            Backend::Plain
+        }
+    }
+
+    #[tracing::instrument]
+    pub fn is_ansi(&self) -> bool {
+        *self == Backend::Ansi
+    }
+
+    #[tracing::instrument(skip(text))]
+    pub fn paint(&self, color: Color, text: &str) -> String {
+        match self {
+            Backend::Plain => text.to_string(),
+            Backend::Ansi => format!("\x1b[{}m{}\x1b[0m", color.ansi_code(), text),
+        }
+    }
+
+    /// `label: text`, with `text` in the status colour.
+    #[tracing::instrument(skip(label, text))]
+    pub fn status(&self, status: Status, label: &str, text: &str) -> String {
+        format!("{}: {}", label, self.paint(status.color(), text))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn it_should_only_colour_on_terminals() -> miette::Result<()> {
+        assert_eq!(Backend::Ansi, Backend::detect(false, true));
+        assert_eq!(Backend::Plain, Backend::detect(true, true));
+        assert_eq!(Backend::Plain, Backend::detect(false, false));
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_render_status() -> miette::Result<()> {
+        assert_eq!(
+            "2023 day 09 part 1: ok",
+            Backend::Plain.status(Status::Ok, "2023 day 09 part 1", "ok")
+        );
+        assert_eq!(
+            "2023 day 09 part 1: \x1b[31m1 then 2\x1b[0m",
+            Backend::Ansi.status(Status::Fail, "2023 day 09 part 1", "1 then 2")
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,77 @@
+use crate::style::{Backend, Status};
+
+/// Running count of results, rendered as one line at the end of a command.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Summary {
+    pub ok: usize,
+    pub warn: usize,
+    pub fail: usize,
+}
+
+impl Summary {
+    #[tracing::instrument(skip(self))]
+    pub fn record(&mut self, status: Status) {
+        match status {
+            Status::Ok => self.ok += 1,
+            Status::Warn => self.warn += 1,
+            Status::Fail => self.fail += 1,
+        }
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub fn passed(&self) -> bool {
+        self.fail == 0
+    }
+
+    /// e.g. `12 ok, 1 warning, 2 failed`. Zero counts other than `ok` are left out.
+    #[tracing::instrument(skip(self))]
+    pub fn render(&self, backend: Backend) -> String {
+        let mut parts = vec![backend.paint(Status::Ok.color(), &format!("{} ok", self.ok))];
+
+        if self.warn > 0 {
+            let noun = if self.warn == 1 {
+                "warning"
+            } else {
+                "warnings"
+            };
+            parts.push(backend.paint(Status::Warn.color(), &format!("{} {}", self.warn, noun)));
+        }
+
+        if self.fail > 0 {
+            parts.push(backend.paint(Status::Fail.color(), &format!("{} failed", self.fail)));
+        }
+
+        parts.join(", ")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn it_should_count_results() -> miette::Result<()> {
+        let mut summary = Summary::default();
+        for status in [Status::Ok, Status::Ok, Status::Warn, Status::Fail] {
+            summary.record(status);
+        }
+
+        assert!(!summary.passed());
+        assert_eq!("2 ok, 1 warning, 1 failed", summary.render(Backend::Plain));
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_leave_out_empty_counts() -> miette::Result<()> {
+        let summary = Summary {
+            ok: 3,
+            ..Default::default()
+        };
+
+        assert!(summary.passed());
+        assert_eq!("3 ok", summary.render(Backend::Plain));
+        assert_eq!("\x1b[32m3 ok\x1b[0m", summary.render(Backend::Ansi));
+        Ok(())
+    }
+}
@@ -0,0 +1,118 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Align {
+    #[default]
+    Left,
+    Right,
+}
+
+/// Columns padded to their widest cell and separated by two spaces. Trailing
+/// padding is trimmed so plain output is easy to compare.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Table {
+    headers: Vec<String>,
+    align: Vec<Align>,
+    rows: Vec<Vec<String>>,
+}
+
+impl Table {
+    #[tracing::instrument]
+    pub fn new(headers: &[&str]) -> Self {
+        Self {
+            headers: headers.iter().map(|h| h.to_string()).collect(),
+            align: vec![Align::Left; headers.len()],
+            rows: vec![],
+        }
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub fn align(mut self, column: usize, align: Align) -> Self {
+        if let Some(current) = self.align.get_mut(column) {
+            *current = align;
+        }
+        self
+    }
+
+    /// Missing cells are left blank and extra ones are dropped.
+    #[tracing::instrument(skip(self))]
+    pub fn row(&mut self, cells: Vec<String>) {
+        let mut cells = cells;
+        cells.resize(self.headers.len(), String::new());
+        self.rows.push(cells);
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub fn is_empty(&self) -> bool {
+        self.rows.is_empty()
+    }
+
+    #[tracing::instrument(skip(self))]
+    fn widths(&self) -> Vec<usize> {
+        self.headers
+            .iter()
+            .enumerate()
+            .map(|(column, header)| {
+                self.rows
+                    .iter()
+                    .map(|row| row[column].chars().count())
+                    .chain([header.chars().count()])
+                    .max()
+                    .unwrap_or(0)
+            })
+            .collect()
+    }
+
+    #[tracing::instrument(skip(self))]
+    fn render_line(&self, cells: &[String], widths: &[usize]) -> String {
+        let line = cells
+            .iter()
+            .zip(widths)
+            .zip(&self.align)
+            .map(|((cell, width), align)| match align {
+                Align::Left => format!("{:<width$}", cell, width = width),
+                Align::Right => format!("{:>width$}", cell, width = width),
+            })
+            .collect::<Vec<_>>()
+            .join("  ");
+
+        line.trim_end().to_string()
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub fn render(&self) -> String {
+        let widths = self.widths();
+
+        std::iter::once(&self.headers)
+            .chain(&self.rows)
+            .map(|cells| self.render_line(cells, &widths) + "\n")
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn it_should_pad_columns() -> miette::Result<()> {
+        let mut table = Table::new(&["day", "variant", "answer"]).align(2, Align::Right);
+        table.row(vec!["01".into(), "part1".into(), "55017".into()]);
+        table.row(vec!["11".into(), "part2_opt".into(), "7".into()]);
+
+        assert_eq!(
+            "day  variant    answer\n01   part1       55017\n11   part2_opt       7\n",
+            table.render()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_fill_short_rows() -> miette::Result<()> {
+        let mut table = Table::new(&["a", "b"]);
+        table.row(vec!["x".into()]);
+        table.row(vec!["1".into(), "2".into(), "3".into()]);
+
+        assert_eq!("a  b\nx\n1  2\n", table.render());
+        Ok(())
+    }
+}
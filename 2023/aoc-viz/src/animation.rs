@@ -0,0 +1,129 @@
+use std::{
+    fmt,
+    io::{self, Write},
+    thread,
+    time::Duration,
+};
+
+use crate::canvas::Canvas;
+
+/// Moves the cursor home and clears the screen, so each frame draws over the last.
+const CLEAR: &str = "\x1b[H\x1b[2J";
+
+/// Plays canvases one after another on a terminal. With `ansi` each frame
+/// replaces the one before; without it frames are printed one under another
+/// with a blank line between, which is what tests and pipes want.
+pub struct Animation<W> {
+    out: W,
+    delay: Duration,
+    ansi: bool,
+    frames: usize,
+}
+
+impl<W> fmt::Debug for Animation<W> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Animation")
+            .field("delay", &self.delay)
+            .field("ansi", &self.ansi)
+            .field("frames", &self.frames)
+            .finish()
+    }
+}
+
+impl<W: Write> Animation<W> {
+    #[tracing::instrument(skip(out))]
+    pub fn new(out: W, delay: Duration, ansi: bool) -> Self {
+        Self {
+            out,
+            delay,
+            ansi,
+            frames: 0,
+        }
+    }
+
+    /// How many frames `step` has shown.
+    #[tracing::instrument]
+    pub fn frames(&self) -> usize {
+        self.frames
+    }
+
+    /// Wipes the last frame, or separates it from the next one when the
+    /// output can't be redrawn.
+    #[tracing::instrument]
+    pub fn clear(&mut self) -> io::Result<()> {
+        if self.ansi {
+            self.out.write_all(CLEAR.as_bytes())
+        } else if self.frames > 0 {
+            self.out.write_all(b"\n")
+        } else {
+            Ok(())
+        }
+    }
+
+    #[tracing::instrument(skip(canvas))]
+    pub fn draw(&mut self, canvas: &Canvas) -> io::Result<()> {
+        self.out.write_all(canvas.render(self.ansi).as_bytes())?;
+        self.out.flush()
+    }
+
+    /// Holds the current frame on screen for the animation's delay.
+    #[tracing::instrument]
+    pub fn sleep(&self) {
+        if !self.delay.is_zero() {
+            thread::sleep(self.delay);
+        }
+    }
+
+    /// Shows `canvas` as the next frame.
+    #[tracing::instrument(skip(canvas))]
+    pub fn step(&mut self, canvas: &Canvas) -> io::Result<()> {
+        self.clear()?;
+        self.draw(canvas)?;
+        self.frames += 1;
+        self.sleep();
+        Ok(())
+    }
+
+    #[tracing::instrument]
+    pub fn into_inner(self) -> W {
+        self.out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Color;
+    use pretty_assertions::assert_eq;
+
+    /// Every frame shown in turn, and what came out.
+    fn play(frames: &[char], ansi: bool) -> miette::Result<(usize, String)> {
+        let mut animation = Animation::new(Vec::new(), Duration::ZERO, ansi);
+        for c in frames {
+            let mut canvas = Canvas::new(2, 1);
+            canvas.set(1, 0, *c, Color::Red);
+            animation
+                .step(&canvas)
+                .map_err(|e| miette::miette!("{}", e))?;
+        }
+
+        let frames = animation.frames();
+        let output = String::from_utf8_lossy(&animation.into_inner()).into_owned();
+        Ok((frames, output))
+    }
+
+    #[test]
+    fn it_should_print_plain_frames_one_under_another() -> miette::Result<()> {
+        assert_eq!((2, " a\n\n b\n".to_string()), play(&['a', 'b'], false)?);
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_redraw_over_ansi_frames() -> miette::Result<()> {
+        assert_eq!(
+            (1, "\x1b[H\x1b[2J \x1b[31ma\x1b[0m\n".to_string()),
+            play(&['a'], true)?
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,183 @@
+use crate::color::Color;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Cell {
+    c: char,
+    color: Color,
+}
+
+const BLANK: Cell = Cell {
+    c: ' ',
+    color: Color::Default,
+};
+
+/// Fixed size grid of coloured characters that renders to a terminal string.
+/// Anything drawn outside the canvas is clipped.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Canvas {
+    width: usize,
+    height: usize,
+    cells: Vec<Cell>,
+}
+
+impl Canvas {
+    #[tracing::instrument]
+    pub fn new(width: usize, height: usize) -> Self {
+        Self {
+            width,
+            height,
+            cells: vec![BLANK; width * height],
+        }
+    }
+
+    #[tracing::instrument]
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    #[tracing::instrument]
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    #[tracing::instrument]
+    pub fn get(&self, x: usize, y: usize) -> Option<(char, Color)> {
+        if x >= self.width || y >= self.height {
+            return None;
+        }
+
+        let cell = self.cells[y * self.width + x];
+        Some((cell.c, cell.color))
+    }
+
+    #[tracing::instrument]
+    pub fn set(&mut self, x: usize, y: usize, c: char, color: Color) {
+        if x >= self.width || y >= self.height {
+            return;
+        }
+
+        self.cells[y * self.width + x] = Cell { c, color };
+    }
+
+    #[tracing::instrument]
+    pub fn text(&mut self, x: usize, y: usize, text: &str, color: Color) {
+        for (i, c) in text.chars().enumerate() {
+            self.set(x + i, y, c, color);
+        }
+    }
+
+    #[tracing::instrument]
+    pub fn hline(&mut self, x: usize, y: usize, length: usize, c: char, color: Color) {
+        for i in 0..length {
+            self.set(x + i, y, c, color);
+        }
+    }
+
+    #[tracing::instrument]
+    pub fn vline(&mut self, x: usize, y: usize, length: usize, c: char, color: Color) {
+        for i in 0..length {
+            self.set(x, y + i, c, color);
+        }
+    }
+
+    /// A `width` by `height` block with its top left at (`x`, `y`), each cell
+    /// drawn as `cell` gives it from the cell's position in the block.
+    #[tracing::instrument(skip(cell))]
+    pub fn grid(
+        &mut self,
+        x: usize,
+        y: usize,
+        width: usize,
+        height: usize,
+        cell: impl Fn(usize, usize) -> (char, Color),
+    ) {
+        for dy in 0..height {
+            for dx in 0..width {
+                let (c, color) = cell(dx, dy);
+                self.set(x + dx, y + dy, c, color);
+            }
+        }
+    }
+
+    /// Renders row by row, trimming trailing blanks. With `ansi` each colour
+    /// change is emitted as an escape code, otherwise only the characters are kept.
+    #[tracing::instrument]
+    pub fn render(&self, ansi: bool) -> String {
+        let mut output = String::new();
+
+        for row in self.cells.chunks(self.width.max(1)).take(self.height) {
+            let used = row
+                .iter()
+                .rposition(|cell| *cell != BLANK)
+                .map_or(0, |i| i + 1);
+
+            let mut current = Color::Default;
+
+            for cell in &row[..used] {
+                if ansi && cell.color != current {
+                    output.push_str(&format!("\x1b[{}m", cell.color.ansi_code()));
+                    current = cell.color;
+                }
+                output.push(cell.c);
+            }
+
+            if ansi && current != Color::Default {
+                output.push_str("\x1b[0m");
+            }
+
+            output.push('\n');
+        }
+
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn it_should_render_plain_text() -> miette::Result<()> {
+        let mut canvas = Canvas::new(6, 2);
+        canvas.text(0, 0, "ab", Color::Red);
+        canvas.hline(1, 1, 3, '#', Color::Default);
+        assert_eq!("ab\n ###\n", canvas.render(false));
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_clip_outside_canvas() -> miette::Result<()> {
+        let mut canvas = Canvas::new(3, 1);
+        canvas.text(1, 0, "abcdef", Color::Default);
+        canvas.vline(0, 0, 5, '|', Color::Default);
+        assert_eq!("|ab\n", canvas.render(false));
+        assert_eq!(None, canvas.get(3, 0));
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_draw_a_grid_from_a_function() -> miette::Result<()> {
+        let mut canvas = Canvas::new(4, 3);
+        canvas.grid(1, 1, 3, 2, |x, y| {
+            if x == y {
+                ('#', Color::Yellow)
+            } else {
+                ('.', Color::Grey)
+            }
+        });
+        assert_eq!("\n #..\n .#.\n", canvas.render(false));
+        assert_eq!(Some(('#', Color::Yellow)), canvas.get(2, 2));
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_emit_colour_changes() -> miette::Result<()> {
+        let mut canvas = Canvas::new(3, 1);
+        canvas.set(0, 0, 'a', Color::Red);
+        canvas.set(1, 0, 'b', Color::Red);
+        canvas.set(2, 0, 'c', Color::Default);
+        assert_eq!("\x1b[31mab\x1b[39mc\n", canvas.render(true));
+        Ok(())
+    }
+}
@@ -0,0 +1,25 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Color {
+    #[default]
+    Default,
+    Red,
+    Green,
+    Blue,
+    Yellow,
+    Grey,
+}
+
+impl Color {
+    /// ANSI SGR code for the foreground colour.
+    #[tracing::instrument]
+    pub fn ansi_code(&self) -> &'static str {
+        match self {
+            Color::Default => "39",
+            Color::Red => "31",
+            Color::Green => "32",
+            Color::Blue => "34",
+            Color::Yellow => "33",
+            Color::Grey => "90",
+        }
+    }
+}
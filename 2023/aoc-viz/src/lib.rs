@@ -0,0 +1,7 @@
+pub mod animation;
+pub mod canvas;
+pub mod color;
+
+pub use animation::Animation;
+pub use canvas::Canvas;
+pub use color::Color;
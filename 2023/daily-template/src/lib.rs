@@ -3,5 +3,5 @@ pub mod prelude;
 
 pub mod part1;
 pub mod part2;
-pub mod part1_opt;
-pub mod part2_opt;
+
+aoc_core::solution!({{solution}}, {{year}}, {{day}}, part1::process, part2::process);
@@ -1,21 +1,22 @@
+use aoc_core::Answer;
+
 use crate::prelude::*;
 
 #[tracing::instrument]
-pub fn process(
-    _input: &str,
-) -> miette::Result<String> {
-    todo!("day 01 - part 1");
+pub fn process(_input: &str) -> Result<Answer> {
+    todo!("{{year}} day {{day}} part 2");
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use pretty_assertions::assert_eq;
 
     #[test]
+    #[ignore = "no example yet"]
     fn test_process() -> miette::Result<()> {
-        todo!("haven't built test yet");
         let input = "";
-        assert_eq!("", process(input)?);
+        assert_eq!(Answer::UInt(0), process(input)?);
         Ok(())
     }
-}
\ No newline at end of file
+}
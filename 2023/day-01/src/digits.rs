@@ -0,0 +1,137 @@
+//! Finds digits in a day 1 line, as any of the patterns in a table. A match
+//! can start at any byte and patterns may share letters, so with words
+//! "oneight" holds 1 then 8 and "sevenine" holds 7 then 9, including when the
+//! second word runs into the end of the line. Every pattern is ASCII, so bytes
+//! that aren't never start a match and lines never need slicing on a char
+//! boundary.
+
+/// `(pattern, digit)` pairs, tried in order at each byte.
+pub type Table = [(&'static str, u8)];
+
+/// Digits written as digits, for part 1.
+pub const NUMERALS: &Table = &[
+    ("0", 0),
+    ("1", 1),
+    ("2", 2),
+    ("3", 3),
+    ("4", 4),
+    ("5", 5),
+    ("6", 6),
+    ("7", 7),
+    ("8", 8),
+    ("9", 9),
+];
+
+/// Digits written as digits or spelled out from "zero" to "nine", for part 2.
+pub const NUMERALS_AND_WORDS: &Table = &[
+    ("0", 0),
+    ("1", 1),
+    ("2", 2),
+    ("3", 3),
+    ("4", 4),
+    ("5", 5),
+    ("6", 6),
+    ("7", 7),
+    ("8", 8),
+    ("9", 9),
+    ("zero", 0),
+    ("one", 1),
+    ("two", 2),
+    ("three", 3),
+    ("four", 4),
+    ("five", 5),
+    ("six", 6),
+    ("seven", 7),
+    ("eight", 8),
+    ("nine", 9),
+];
+
+/// The digit starting at byte `index` of `line`, if any.
+#[tracing::instrument]
+pub fn digit_at(line: &str, index: usize, table: &Table) -> Option<u8> {
+    let rest = line.as_bytes().get(index..)?;
+
+    table
+        .iter()
+        .find(|(pattern, _)| rest.starts_with(pattern.as_bytes()))
+        .map(|(_, digit)| *digit)
+}
+
+/// Every digit in `line`, in order, overlapping words included.
+#[tracing::instrument]
+pub fn all_digits<'a>(line: &'a str, table: &'a Table) -> impl Iterator<Item = u8> + 'a {
+    (0..line.len()).filter_map(move |i| digit_at(line, i, table))
+}
+
+/// Scans forward from the start of the line.
+#[tracing::instrument]
+pub fn first_digit(line: &str, table: &Table) -> Option<u8> {
+    all_digits(line, table).next()
+}
+
+/// Scans backward from the end of the line, so agrees with the last item of
+/// `all_digits`.
+#[tracing::instrument]
+pub fn last_digit(line: &str, table: &Table) -> Option<u8> {
+    (0..line.len()).rev().find_map(|i| digit_at(line, i, table))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn words(line: &str) -> Vec<u8> {
+        all_digits(line, NUMERALS_AND_WORDS).collect()
+    }
+
+    #[test]
+    fn it_should_find_overlapping_words_at_line_end() -> miette::Result<()> {
+        assert_eq!(vec![1, 8], words("oneight"));
+        assert_eq!(vec![7, 9], words("sevenine"));
+        assert_eq!(vec![2, 1, 8], words("twoneight"));
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_scan_both_ways_on_overlaps() -> miette::Result<()> {
+        for (line, first, last) in [
+            ("oneight", 1, 8),
+            ("sevenine", 7, 9),
+            ("eightwo", 8, 2),
+            ("xtwone", 2, 1),
+            ("3nineight", 3, 8),
+        ] {
+            let table = NUMERALS_AND_WORDS;
+            assert_eq!(Some(first), first_digit(line, table), "{}", line);
+            assert_eq!(Some(last), last_digit(line, table), "{}", line);
+            assert_eq!(all_digits(line, table).last(), last_digit(line, table));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_ignore_words_cut_off_by_line_end() -> miette::Result<()> {
+        assert_eq!(vec![7], words("sevenin"));
+        assert_eq!(vec![2], words("twon"));
+        assert_eq!(None, last_digit("eigh", NUMERALS_AND_WORDS));
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_skip_non_ascii_bytes() -> miette::Result<()> {
+        assert_eq!(vec![1, 8], words("éoneïght8"));
+        assert_eq!(Some(8), last_digit("éoneïght8", NUMERALS_AND_WORDS));
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_only_read_numerals_without_words() -> miette::Result<()> {
+        assert_eq!(
+            vec![3, 8],
+            all_digits("pqr3stu8vwx", NUMERALS).collect::<Vec<_>>()
+        );
+        assert_eq!(None, first_digit("oneight", NUMERALS));
+        assert_eq!(Some(2), last_digit("a1b2two", NUMERALS));
+        Ok(())
+    }
+}
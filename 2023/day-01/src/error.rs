@@ -13,6 +13,27 @@ pub enum Error {
     NoLastDigitInLine,
     #[error("could not parse int")]
     ParseIntError(#[from] std::num::ParseIntError),
-    #[error("could not parse int")]
-    ParseBasicIntError(),
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{part1, part1_opt, part2, part2_opt};
+
+    fn error_from(report: miette::Report) -> Error {
+        report.downcast::<Error>().expect("expected a day 1 error")
+    }
+
+    #[test]
+    fn it_should_reach_no_first_digit_in_line() -> miette::Result<()> {
+        for error in [
+            error_from(part1::process("abc").unwrap_err()),
+            error_from(part1_opt::process("abc").unwrap_err()),
+            error_from(part2::process("abc").unwrap_err()),
+            error_from(part2_opt::process("abc").unwrap_err()),
+        ] {
+            assert!(matches!(error, Error::NoFirstDigitInLine));
+        }
+        Ok(())
+    }
+}
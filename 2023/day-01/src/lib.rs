@@ -1,3 +1,4 @@
+pub mod digits;
 pub mod error;
 pub mod prelude;
 
@@ -5,3 +6,5 @@ pub mod part1;
 pub mod part2;
 pub mod part1_opt;
 pub mod part2_opt;
+
+aoc_core::solution!(Day01, 2023, 1, part1::process, part2::process);
@@ -1,25 +1,19 @@
-use crate::{error::Error, prelude::*};
+use aoc_core::Answer;
+
+use crate::{
+    digits::{first_digit, last_digit, NUMERALS},
+    error::Error,
+    prelude::*,
+};
 
 #[tracing::instrument]
 fn extract_first_digit(input: &str) -> Result<u8> {
-    for c in input.chars() {
-        if let Some(digit) = c.to_digit(10) {
-            return Ok(digit as u8);
-        }
-    }
-
-    Err(Error::NoFirstDigitInLine)
+    first_digit(input, NUMERALS).ok_or(Error::NoFirstDigitInLine)
 }
 
 #[tracing::instrument]
 fn extract_last_digit(input: &str) -> Result<u8> {
-    for c in input.chars().rev() {
-        if let Some(digit) = c.to_digit(10) {
-            return Ok(digit as u8);
-        }
-    }
-
-    Err(Error::NoLastDigitInLine)
+    last_digit(input, NUMERALS).ok_or(Error::NoLastDigitInLine)
 }
 
 #[tracing::instrument]
@@ -30,12 +24,14 @@ fn number_for_line(line: &str) -> Result<u32> {
 }
 
 #[tracing::instrument]
-pub fn process(input: &str) -> miette::Result<u32> {
-    Ok(input
+pub fn process(input: &str) -> miette::Result<Answer> {
+    let total = input
         .lines()
         .map(number_for_line)
         .collect::<Result<Vec<u32>>>()
-        .map(|v| v.iter().sum())?)
+        .map(|v| v.iter().sum::<u32>())?;
+
+    Ok(Answer::from(total))
 }
 
 #[cfg(test)]
@@ -48,7 +44,7 @@ mod tests {
         pqr3stu8vwx
         a1b2c3d4e5f
         treb7uchet";
-        assert_eq!(142, process(input)?);
+        assert_eq!(Answer::UInt(142), process(input)?);
         Ok(())
     }
 }
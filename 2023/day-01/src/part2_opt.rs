@@ -1,80 +1,19 @@
-use crate::{error::Error, prelude::*};
+use aoc_core::Answer;
 
-#[tracing::instrument]
-fn parse_digit(input: &str) -> Result<u8> {
-    let first_char = input
-        .chars()
-        .next()
-        .ok_or_else(|| Error::NoFirstDigitInLine)?;
-
-    if let Some(digit) = first_char.to_digit(10) {
-        return Ok(digit as u8);
-    }
-
-    if input.starts_with("zero") {
-        return Ok(0);
-    }
-
-    if input.starts_with("one") {
-        return Ok(1);
-    }
-
-    if input.starts_with("two") {
-        return Ok(2);
-    }
-
-    if input.starts_with("three") {
-        return Ok(3);
-    }
-
-    if input.starts_with("four") {
-        return Ok(4);
-    }
-
-    if input.starts_with("five") {
-        return Ok(5);
-    }
-
-    if input.starts_with("six") {
-        return Ok(6);
-    }
-
-    if input.starts_with("seven") {
-        return Ok(7);
-    }
-
-    if input.starts_with("eight") {
-        return Ok(8);
-    }
-
-    if input.starts_with("nine") {
-        return Ok(9);
-    }
-
-    Err(Error::ParseBasicIntError())
-}
+use crate::{
+    digits::{first_digit, last_digit, NUMERALS_AND_WORDS},
+    error::Error,
+    prelude::*,
+};
 
 #[tracing::instrument]
 fn parse_first_digit(input: &str) -> Result<u8> {
-    for i in 0..input.len() {
-        match parse_digit(&input[i..]) {
-            Ok(d) => return Ok(d),
-            Err(_) => continue,
-        }
-    }
-    Err(Error::NoFirstDigitInLine)
+    first_digit(input, NUMERALS_AND_WORDS).ok_or(Error::NoFirstDigitInLine)
 }
 
 #[tracing::instrument]
 fn parse_last_digit(input: &str) -> Result<u8> {
-    for i in 0..input.len() {
-        let i = input.len() - i - 1;
-        match parse_digit(&input[i..]) {
-            Ok(d) => return Ok(d),
-            Err(_) => continue,
-        }
-    }
-    Err(Error::NoLastDigitInLine)
+    last_digit(input, NUMERALS_AND_WORDS).ok_or(Error::NoLastDigitInLine)
 }
 
 #[tracing::instrument]
@@ -85,21 +24,47 @@ fn number_for_line(line: &str) -> Result<u64> {
 }
 
 #[tracing::instrument]
-pub fn process(input: &str) -> miette::Result<u64> {
-    Ok(input
+pub fn process(input: &str) -> miette::Result<Answer> {
+    let total = input
         .lines()
         .map(number_for_line)
         .collect::<Result<Vec<u64>>>()
-        .map(|v| v.iter().sum())?)
+        .map(|v| v.iter().sum::<u64>())?;
+
+    Ok(Answer::from(total))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::digits::digit_at;
 
     #[test]
     fn it_should_parse_digit() -> miette::Result<()> {
-        assert_eq!(0, parse_digit("zero")?);
+        assert_eq!(Some(0), digit_at("zero", 0, NUMERALS_AND_WORDS));
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_read_overlapping_words_as_whole_lines() -> miette::Result<()> {
+        assert_eq!(Answer::UInt(18), process("oneight")?);
+        assert_eq!(Answer::UInt(79), process("sevenine")?);
+        assert_eq!(Answer::UInt(77), process("sevenin")?);
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_agree_with_part2_on_overlaps() -> miette::Result<()> {
+        for line in [
+            "oneight",
+            "sevenine",
+            "eightwo",
+            "twoneight",
+            "9sevenin",
+            "éoneïght8",
+        ] {
+            assert_eq!(crate::part2::process(line)?, process(line)?, "{}", line);
+        }
         Ok(())
     }
 
@@ -112,7 +77,7 @@ mod tests {
         4nineeightseven2
         zoneight234
         7pqrstsixteen";
-        assert_eq!(281, process(input)?);
+        assert_eq!(Answer::UInt(281), process(input)?);
         Ok(())
     }
 }
@@ -6,41 +6,69 @@ fn main() {
 }
 
 #[divan::bench]
-fn part1() {
-    part1::process(divan::black_box(include_str!(
-        "../input1.txt",
-    )))
-    .unwrap();
+fn part1(bencher: divan::Bencher) {
+    let input = aoc_core::puzzle_input!("input1.txt").unwrap();
+    bencher.bench(|| part1::process(divan::black_box(&input)).unwrap());
 }
 
 #[divan::bench]
-fn part2() {
-    part2::process(divan::black_box(include_str!(
-        "../input2.txt",
-    )))
-    .unwrap();
+fn part2(bencher: divan::Bencher) {
+    let input = aoc_core::puzzle_input!("input2.txt").unwrap();
+    bencher.bench(|| part2::process(divan::black_box(&input)).unwrap());
 }
 
 #[divan::bench]
-fn part1_opt() {
-    part1_opt::process(divan::black_box(include_str!(
-        "../input1.txt",
-    )))
-    .unwrap();
+fn part1_opt(bencher: divan::Bencher) {
+    let input = aoc_core::puzzle_input!("input1.txt").unwrap();
+    bencher.bench(|| part1_opt::process(divan::black_box(&input)).unwrap());
 }
 
 #[divan::bench]
-fn part1_opt2() {
-    part1_opt2::process(divan::black_box(include_str!(
-        "../input1.txt",
-    )))
-    .unwrap();
+fn part1_opt2(bencher: divan::Bencher) {
+    let input = aoc_core::puzzle_input!("input1.txt").unwrap();
+    bencher.bench(|| part1_opt2::process(divan::black_box(&input)).unwrap());
 }
 
 #[divan::bench]
-fn part2_opt() {
-    part2_opt::process(divan::black_box(include_str!(
-        "../input2.txt",
-    )))
-    .unwrap();
-}
\ No newline at end of file
+fn part2_opt(bencher: divan::Bencher) {
+    let input = aoc_core::puzzle_input!("input2.txt").unwrap();
+    bencher.bench(|| part2_opt::process(divan::black_box(&input)).unwrap());
+}
+
+#[divan::bench]
+fn part2_opt2(bencher: divan::Bencher) {
+    let input = aoc_core::puzzle_input!("input2.txt").unwrap();
+    bencher.bench(|| part2_opt2::process(divan::black_box(&input)).unwrap());
+}
+
+#[divan::bench]
+fn part1_parse(bencher: divan::Bencher) {
+    let input = aoc_core::puzzle_input!("input1.txt").unwrap();
+    bencher.bench(|| part1::parse(divan::black_box(&input)).unwrap());
+}
+
+#[divan::bench]
+fn part1_solve(bencher: divan::Bencher) {
+    let input = aoc_core::puzzle_input!("input1.txt").unwrap();
+    let games = aoc_core::preload(&input, part1::parse).unwrap();
+    bencher.bench(|| part1::solve(divan::black_box(&games)).unwrap());
+}
+
+#[divan::bench]
+fn part2_parse(bencher: divan::Bencher) {
+    let input = aoc_core::puzzle_input!("input2.txt").unwrap();
+    bencher.bench(|| part2::parse(divan::black_box(&input)).unwrap());
+}
+
+#[divan::bench]
+fn part2_solve(bencher: divan::Bencher) {
+    let input = aoc_core::puzzle_input!("input2.txt").unwrap();
+    let games = aoc_core::preload(&input, part2::parse).unwrap();
+    bencher.bench(|| part2::solve(divan::black_box(&games)).unwrap());
+}
+
+#[divan::bench]
+fn part1_implicit(bencher: divan::Bencher) {
+    let input = aoc_core::puzzle_input!("input1.txt").unwrap();
+    bencher.bench(|| part1_implicit::process(divan::black_box(&input)).unwrap());
+}
@@ -3,8 +3,8 @@ use miette::Context;
 
 #[tracing::instrument]
 fn main() -> miette::Result<()> {
-    let file = include_str!("../../input1.txt");
-    let result = process(file).context("process part 2")?;
+    let file = aoc_core::puzzle_input!("input1.txt")?;
+    let result = process(&file).context("process part 2")?;
     println!("{}", result);
     Ok(())
 }
\ No newline at end of file
@@ -1,4 +1,4 @@
-
+use aoc_core::AtLine;
 use miette::Diagnostic;
 use thiserror::Error;
 
@@ -7,15 +7,114 @@ pub enum Error {
     #[error(transparent)]
     #[diagnostic(code(aoc::io_error))]
     IoError(#[from] std::io::Error),
-    #[error("Could not parse color count from hand {0}")]
-    CouldNotParseColorCount(String),
-    #[error("Unknown color {0}")]
-    UnknownColor(String),
-    #[error("Could not parse count {0}")]
-    CouldNotParseCount(String),
-    #[error("Could not parse game id {0}")]
-    CouldNotParseGameId(String),
-    #[error("Could not parse game hands {0}")]
-    CouldNotParseGameHands(String),
-
-}
\ No newline at end of file
+    #[error("Could not parse color count from hand {hand} on line {line}")]
+    CouldNotParseColorCount { line: usize, hand: String },
+    #[error("Unknown color {color} on line {line}")]
+    UnknownColor { line: usize, color: String },
+    #[error("Could not parse count {count} on line {line}")]
+    CouldNotParseCount { line: usize, count: String },
+    #[error("Could not parse game id {game} on line {line}")]
+    CouldNotParseGameId { line: usize, game: String },
+    #[error("Could not parse game hands {game} on line {line}")]
+    CouldNotParseGameHands { line: usize, game: String },
+    #[error("Line {line} says {found:?} but is game {expected} by position")]
+    #[diagnostic(severity(Warning))]
+    GameIdMismatch {
+        line: usize,
+        expected: u32,
+        found: String,
+    },
+}
+
+impl AtLine for Error {
+    fn at_line(self, line: usize) -> Self {
+        match self {
+            Error::CouldNotParseColorCount { hand, .. } => {
+                Error::CouldNotParseColorCount { line, hand }
+            }
+            Error::UnknownColor { color, .. } => Error::UnknownColor { line, color },
+            Error::CouldNotParseCount { count, .. } => Error::CouldNotParseCount { line, count },
+            Error::CouldNotParseGameId { game, .. } => Error::CouldNotParseGameId { line, game },
+            Error::CouldNotParseGameHands { game, .. } => {
+                Error::CouldNotParseGameHands { line, game }
+            }
+            Error::GameIdMismatch {
+                expected, found, ..
+            } => Error::GameIdMismatch {
+                line,
+                expected,
+                found,
+            },
+            other => other,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{part1, part1_opt, part1_opt2, part2, part2_opt, part2_opt2};
+
+    fn error_from(report: miette::Report) -> Error {
+        report.downcast::<Error>().expect("expected a day 2 error")
+    }
+
+    #[test]
+    fn it_should_reach_unknown_color() -> miette::Result<()> {
+        let input = "Game 1: 3 purple";
+        for error in [
+            error_from(part1::process(input).unwrap_err()),
+            error_from(part1_opt::process(input).unwrap_err()),
+            error_from(part1_opt2::process(input).unwrap_err()),
+            error_from(part2::process(input).unwrap_err()),
+            error_from(part2_opt::process(input).unwrap_err()),
+            error_from(part2_opt2::process(input).unwrap_err()),
+        ] {
+            assert!(matches!(error, Error::UnknownColor { .. }));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_report_line_of_unknown_color() -> miette::Result<()> {
+        let input = "Game 1: 3 blue, 4 red
+        Game 2: 1 blue, 2 green
+        Game 3: 8 purple, 6 blue
+        Game 4: 1 green, 3 red";
+        for error in [
+            error_from(part1::process(input).unwrap_err()),
+            error_from(part1_opt::process(input).unwrap_err()),
+            error_from(part1_opt2::process(input).unwrap_err()),
+            error_from(part2::process(input).unwrap_err()),
+            error_from(part2_opt::process(input).unwrap_err()),
+            error_from(part2_opt2::process(input).unwrap_err()),
+        ] {
+            assert!(matches!(error, Error::UnknownColor { line: 3, .. }));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_reach_could_not_parse_count() -> miette::Result<()> {
+        let input = "Game 1: x red";
+        for error in [
+            error_from(part1::process(input).unwrap_err()),
+            error_from(part2::process(input).unwrap_err()),
+        ] {
+            assert!(matches!(error, Error::CouldNotParseCount { .. }));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_reach_could_not_parse_game_id() -> miette::Result<()> {
+        let input = "Game x: 3 red";
+        for error in [
+            error_from(part1::process(input).unwrap_err()),
+            error_from(part2::process(input).unwrap_err()),
+        ] {
+            assert!(matches!(error, Error::CouldNotParseGameId { .. }));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,59 @@
+use aoc_viz::{Canvas, Color};
+
+use crate::{
+    games::{games_with, IdMode, ELF_BAG},
+    prelude::*,
+};
+
+/// Every game numbered by position, with whether it fits the elf bag and its
+/// power, after a yellow warning for each line whose written id disagrees.
+#[tracing::instrument(skip(input))]
+pub fn render(input: &str, ansi: bool) -> Result<String> {
+    let (games, warnings) = games_with(input, IdMode::Implicit)?;
+
+    let rows = warnings
+        .into_iter()
+        .map(|warning| (format!("warning: {}", warning), Color::Yellow))
+        .chain(games.iter().map(|game| {
+            let bag = game.min_bag();
+            let (verdict, color) = if bag.fits_in(&ELF_BAG) {
+                ("possible", Color::Default)
+            } else {
+                ("impossible", Color::Red)
+            };
+            let text = format!("Game {}: {}, power {}", game.id, verdict, bag.power());
+            (text, color)
+        }))
+        .collect::<Vec<_>>();
+
+    let width = rows.iter().map(|(text, _)| text.len()).max().unwrap_or(0);
+    let mut canvas = Canvas::new(width, rows.len());
+    for (y, (text, color)) in rows.iter().enumerate() {
+        canvas.text(0, y, text, *color);
+    }
+
+    Ok(canvas.render(ansi))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn it_should_render_games_after_warnings() -> miette::Result<()> {
+        let output = render(
+            "Game 1: 3 blue, 4 red, 1 green\nGame 9: 20 red, 1 green, 1 blue",
+            false,
+        )?;
+
+        assert_eq!(
+            "warning: Line 2 says \"Game 9\" but is game 2 by position
+Game 1: possible, power 12
+Game 2: impossible, power 20
+",
+            output
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,333 @@
+use aoc_core::{numbered_lines, AtLine};
+use aoc_input::{
+    key_value,
+    parse::{self, number, ws, Reason},
+};
+use nom::{
+    bytes::complete::tag,
+    character::complete::{alpha1, char, space1},
+    multi::separated_list1,
+    sequence::{pair, preceded, separated_pair},
+};
+
+use crate::{error::Error, prelude::*};
+
+/// Counts of each colour, used both for a single hand and for a bag of cubes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Cubes {
+    pub red: u8,
+    pub green: u8,
+    pub blue: u8,
+}
+
+/// The bag part 1 checks every game against.
+pub const ELF_BAG: Cubes = Cubes {
+    red: 12,
+    green: 13,
+    blue: 14,
+};
+
+impl Cubes {
+    #[tracing::instrument]
+    fn from_str(input: &str) -> Result<Self> {
+        let counts = parse::finish(
+            separated_list1(ws(char(',')), separated_pair(number::<u8>, space1, alpha1)),
+            input.trim(),
+        )
+        .map_err(|failure| match failure.reason {
+            Reason::Number(_) => Error::CouldNotParseCount {
+                line: 0,
+                count: failure.at.to_string(),
+            },
+            Reason::Expected(_) => Error::CouldNotParseColorCount {
+                line: 0,
+                hand: input.to_string(),
+            },
+        })?;
+
+        let mut cubes = Self::default();
+
+        for (count, color) in counts {
+            match color {
+                "red" => cubes.red = count,
+                "green" => cubes.green = count,
+                "blue" => cubes.blue = count,
+                _ => {
+                    return Err(Error::UnknownColor {
+                        line: 0,
+                        color: color.to_string(),
+                    })
+                }
+            }
+        }
+
+        Ok(cubes)
+    }
+
+    #[tracing::instrument]
+    pub fn fits_in(&self, bag: &Cubes) -> bool {
+        self.red <= bag.red && self.green <= bag.green && self.blue <= bag.blue
+    }
+
+    #[tracing::instrument]
+    pub fn power(&self) -> u32 {
+        self.red as u32 * self.green as u32 * self.blue as u32
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Game {
+    pub id: u32,
+    pub hands: Vec<Cubes>,
+}
+
+/// Where a game's id comes from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum IdMode {
+    /// The number after `Game`, as written.
+    #[default]
+    Parsed,
+    /// The game's position among the non-blank lines, counting from 1, so a
+    /// garbled id can't quietly change which games are summed. The written id
+    /// is still checked against it.
+    Implicit,
+}
+
+impl Game {
+    #[tracing::instrument]
+    fn from_str(input: &str) -> Result<Self> {
+        let (id, hands) = Self::split(input)?;
+
+        let id = Self::parse_id(id).ok_or_else(|| Error::CouldNotParseGameId {
+            line: 0,
+            game: input.to_string(),
+        })?;
+
+        Ok(Self { id, hands })
+    }
+
+    /// The game numbered `id` whatever its line says, with a warning if the
+    /// line says something else.
+    #[tracing::instrument]
+    fn from_str_as(input: &str, id: u32) -> Result<(Self, Option<Error>)> {
+        let (written, hands) = Self::split(input)?;
+
+        let warning = (Self::parse_id(written) != Some(id)).then(|| Error::GameIdMismatch {
+            line: 0,
+            expected: id,
+            found: written.to_string(),
+        });
+
+        Ok((Self { id, hands }, warning))
+    }
+
+    /// The `Game n` prefix, unparsed, and the parsed hands.
+    #[tracing::instrument]
+    fn split(input: &str) -> Result<(&str, Vec<Cubes>)> {
+        let (id, hands) = key_value(input, ':').map_err(|_| Error::CouldNotParseGameHands {
+            line: 0,
+            game: input.to_string(),
+        })?;
+
+        let hands = hands
+            .split(';')
+            .map(Cubes::from_str)
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok((id, hands))
+    }
+
+    #[tracing::instrument]
+    fn parse_id(prefix: &str) -> Option<u32> {
+        parse::finish(preceded(pair(tag("Game"), space1), number), prefix).ok()
+    }
+
+    /// The smallest bag that could have produced every hand in the game.
+    #[tracing::instrument]
+    pub fn min_bag(&self) -> Cubes {
+        self.hands.iter().fold(Cubes::default(), |bag, hand| Cubes {
+            red: bag.red.max(hand.red),
+            green: bag.green.max(hand.green),
+            blue: bag.blue.max(hand.blue),
+        })
+    }
+
+    #[tracing::instrument]
+    pub fn is_possible(&self, bag: &Cubes) -> bool {
+        self.min_bag().fits_in(bag)
+    }
+}
+
+/// Parses the puzzle input one game per line, for callers that want the
+/// structured games rather than just an answer.
+#[tracing::instrument(skip(input))]
+pub fn games(input: &str) -> impl Iterator<Item = Result<Game>> + '_ {
+    numbered_lines(input)
+        .filter(|(_, line)| !line.is_empty())
+        .map(|(number, line)| Game::from_str(line).at_line(number))
+}
+
+/// Every game with its id taken as `mode` says, and a `GameIdMismatch`
+/// warning for each line whose written id disagrees with its position.
+/// Parsed ids are trusted, so they never warn.
+#[tracing::instrument(skip(input))]
+pub fn games_with(input: &str, mode: IdMode) -> Result<(Vec<Game>, Vec<Error>)> {
+    if mode == IdMode::Parsed {
+        return Ok((games(input).collect::<Result<Vec<_>>>()?, vec![]));
+    }
+
+    let mut games = vec![];
+    let mut warnings = vec![];
+
+    let lines = numbered_lines(input).filter(|(_, line)| !line.is_empty());
+    for (id, (number, line)) in (1..).zip(lines) {
+        let (game, warning) = Game::from_str_as(line, id).at_line(number)?;
+        games.push(game);
+        warnings.extend(warning.map(|warning| warning.at_line(number)));
+    }
+
+    Ok((games, warnings))
+}
+
+/// Part 1's answer: the ids of every game `ELF_BAG` could have played, summed.
+#[tracing::instrument(skip(games), fields(games = games.len()))]
+pub fn possible_id_sum(games: &[Game]) -> u32 {
+    games
+        .iter()
+        .filter(|game| game.is_possible(&ELF_BAG))
+        .map(|game| game.id)
+        .sum()
+}
+
+/// Part 2's answer: the power of each game's smallest bag, summed.
+#[tracing::instrument(skip(games), fields(games = games.len()))]
+pub fn min_bag_power_sum(games: &[Game]) -> u32 {
+    games.iter().map(|game| game.min_bag().power()).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+    Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+    Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+    Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+    Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
+
+    #[test]
+    fn it_should_parse_games() -> miette::Result<()> {
+        let games = games(EXAMPLE).collect::<Result<Vec<_>>>()?;
+        assert_eq!(5, games.len());
+        assert_eq!(3, games[0].hands.len());
+        assert_eq!(
+            Cubes {
+                red: 4,
+                green: 2,
+                blue: 6
+            },
+            games[0].min_bag()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_match_part_answers() -> miette::Result<()> {
+        let games = games(EXAMPLE).collect::<Result<Vec<_>>>()?;
+
+        assert_eq!(8, possible_id_sum(&games));
+        assert_eq!(2286, min_bag_power_sum(&games));
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_reject_bad_games() -> miette::Result<()> {
+        assert!(matches!(
+            games("Game x: 3 red").next(),
+            Some(Err(Error::CouldNotParseGameId { .. }))
+        ));
+        assert!(matches!(
+            games("Game 1: 3 purple").next(),
+            Some(Err(Error::UnknownColor { .. }))
+        ));
+        assert!(matches!(
+            games("Game 1: 3").next(),
+            Some(Err(Error::CouldNotParseColorCount { .. }))
+        ));
+        assert!(matches!(
+            games("Game 1: 3 red\n\nGame x: 3 red").nth(1),
+            Some(Err(Error::CouldNotParseGameId { line: 3, .. }))
+        ));
+        assert!(matches!(
+            games("Game 1 3 red").next(),
+            Some(Err(Error::CouldNotParseGameHands { .. }))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_number_games_by_position_in_implicit_mode() -> miette::Result<()> {
+        let input = "Game 1: 3 blue, 4 red
+
+        Game 7: 1 blue, 2 green
+        Game x: 20 red
+        Game 4: 1 green, 3 red";
+
+        let (games, warnings) = games_with(input, IdMode::Implicit)?;
+
+        assert_eq!(
+            vec![1, 2, 3, 4],
+            games.iter().map(|game| game.id).collect::<Vec<_>>()
+        );
+        assert_eq!(2, warnings.len());
+        assert!(matches!(
+            &warnings[0],
+            Error::GameIdMismatch { line: 3, expected: 2, found } if found == "Game 7"
+        ));
+        assert!(matches!(
+            &warnings[1],
+            Error::GameIdMismatch {
+                line: 4,
+                expected: 3,
+                ..
+            }
+        ));
+        assert_eq!(
+            Some(miette::Severity::Warning),
+            miette::Diagnostic::severity(&warnings[0])
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_agree_with_parsed_ids_on_sequential_input() -> miette::Result<()> {
+        let (implicit, warnings) = games_with(EXAMPLE, IdMode::Implicit)?;
+
+        assert_eq!(games(EXAMPLE).collect::<Result<Vec<_>>>()?, implicit);
+        assert!(warnings.is_empty());
+        assert!(matches!(
+            games_with("Game x: 3 red", IdMode::Parsed),
+            Err(Error::CouldNotParseGameId { .. })
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_accept_any_whitespace_between_columns() -> miette::Result<()> {
+        let expected = games("Game 1: 3 blue, 4 red; 2 green").collect::<Result<Vec<_>>>()?;
+
+        for input in [
+            "Game\t1:\t3\tblue,\t4 red;\t2 green",
+            "Game   1 :  3  blue,   4 red ;  2   green",
+            "Game 1: 3 blue , 4 red; 2 green  \t",
+        ] {
+            assert_eq!(
+                expected,
+                games(input).collect::<Result<Vec<_>>>()?,
+                "{:?}",
+                input
+            );
+        }
+        Ok(())
+    }
+}
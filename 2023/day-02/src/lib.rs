@@ -6,3 +6,35 @@ pub mod part2;
 pub mod part1_opt;
 pub mod part2_opt;
 pub mod part1_opt2;
+pub mod part2_opt2;
+pub mod part1_implicit;
+
+pub mod scanner;
+
+pub mod games;
+pub mod viz;
+pub mod explain;
+
+/// Day 2 behind the shared `aoc_core::Solution` interface, with the games
+/// parsed once for both parts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Day02;
+
+impl aoc_core::Solution for Day02 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 2;
+
+    type Parsed<'a> = Vec<games::Game>;
+
+    fn parse(input: &str) -> aoc_core::solution::Result<Vec<games::Game>> {
+        Ok(games::games(input).collect::<prelude::Result<Vec<_>>>()?)
+    }
+
+    fn part1(games: &Vec<games::Game>) -> aoc_core::solution::Result<aoc_core::Answer> {
+        Ok(games::possible_id_sum(games).into())
+    }
+
+    fn part2(games: &Vec<games::Game>) -> aoc_core::solution::Result<aoc_core::Answer> {
+        Ok(games::min_bag_power_sum(games).into())
+    }
+}
@@ -0,0 +1,55 @@
+use aoc_core::Answer;
+
+use crate::games::{games_with, possible_id_sum, IdMode};
+
+/// Part 1 with each game numbered by its position rather than its written id,
+/// so a garbled id is logged as a warning instead of silently changing which
+/// ids get summed.
+#[tracing::instrument]
+pub fn process(input: &str) -> miette::Result<Answer> {
+    let (games, warnings) = games_with(input, IdMode::Implicit)?;
+
+    for warning in &warnings {
+        tracing::warn!("{}", warning);
+    }
+
+    Ok(Answer::from(possible_id_sum(&games)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::part1;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_process() -> miette::Result<()> {
+        let input = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+        Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+        Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+        Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+        Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
+        assert_eq!(Answer::UInt(8), process(input)?);
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_ignore_corrupted_ids() -> miette::Result<()> {
+        let input = "Game 1: 3 blue
+        Game 20: 1 red
+        Game 3: 20 red";
+
+        assert_eq!(Answer::UInt(21), part1::process(input)?);
+        assert_eq!(Answer::UInt(3), process(input)?);
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_match_part1_on_the_puzzle_input() -> miette::Result<()> {
+        assert_eq!(
+            process(&aoc_core::puzzle_input!("input1.txt")?)?,
+            part1::process(&aoc_core::puzzle_input!("input1.txt")?)?
+        );
+        Ok(())
+    }
+}
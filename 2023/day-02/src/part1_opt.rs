@@ -1,3 +1,5 @@
+use aoc_core::{numbered_lines, Answer, AtLine};
+
 use crate::{error::Error, prelude::*};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -24,15 +26,22 @@ fn parse_hand_color(input: &str, bag: &Bag) -> Result<bool> {
 
     let count = count_chars
         .parse::<u8>()
-        .map_err(|_| Error::CouldNotParseCount(input.to_string()))?;
+        .map_err(|_| Error::CouldNotParseCount {
+            line: 0,
+            count: input.to_string(),
+        })?;
 
     if count > bag.red || count > bag.green || count > bag.blue {
         return Ok(false);
     }
 
-    let color = input
-        .get(color_start..color_start + 1)
-        .ok_or_else(|| Error::CouldNotParseColorCount(input.to_string()))?;
+    let color =
+        input
+            .get(color_start..color_start + 1)
+            .ok_or_else(|| Error::CouldNotParseColorCount {
+                line: 0,
+                hand: input.to_string(),
+            })?;
 
     match color {
         "r" => {
@@ -50,7 +59,12 @@ fn parse_hand_color(input: &str, bag: &Bag) -> Result<bool> {
                 return Ok(false);
             }
         }
-        _ => return Err(Error::UnknownColor(color.to_string())),
+        _ => {
+            return Err(Error::UnknownColor {
+                line: 0,
+                color: color.to_string(),
+            })
+        }
     }
 
     Ok(true)
@@ -79,7 +93,10 @@ fn parse_game(input: &str, bag: &Bag) -> Result<GameResult> {
 
     let game_id = id_chars
         .parse::<u32>()
-        .map_err(|_| Error::CouldNotParseGameId(id_chars))?;
+        .map_err(|_| Error::CouldNotParseGameId {
+            line: 0,
+            game: id_chars,
+        })?;
 
     let hands_text = input[hands_start..].trim();
 
@@ -93,7 +110,7 @@ fn parse_game(input: &str, bag: &Bag) -> Result<GameResult> {
 }
 
 #[tracing::instrument]
-pub fn process(input: &str) -> miette::Result<u32> {
+pub fn process(input: &str) -> miette::Result<Answer> {
     let bag = Bag {
         red: 12,
         green: 13,
@@ -102,8 +119,8 @@ pub fn process(input: &str) -> miette::Result<u32> {
 
     let mut possible_game_ids = vec![];
 
-    for line in input.lines() {
-        let game_result = parse_game(line.trim(), &bag)?;
+    for (number, line) in numbered_lines(input) {
+        let game_result = parse_game(line, &bag).at_line(number)?;
         match game_result {
             GameResult::Possible { game_id } => {
                 possible_game_ids.push(game_id);
@@ -112,7 +129,7 @@ pub fn process(input: &str) -> miette::Result<u32> {
         }
     }
 
-    Ok(possible_game_ids.iter().sum())
+    Ok(Answer::from(possible_game_ids.iter().sum::<u32>()))
 }
 
 #[cfg(test)]
@@ -170,7 +187,7 @@ mod tests {
         Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
         Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
         Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
-        assert_eq!(8, process(input)?);
+        assert_eq!(Answer::UInt(8), process(input)?);
         Ok(())
     }
 }
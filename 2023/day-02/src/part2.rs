@@ -1,3 +1,15 @@
+use aoc_core::{numbered_lines, Answer, AtLine};
+use aoc_input::{
+    key_value,
+    parse::{self, number, ws, Reason},
+};
+use nom::{
+    bytes::complete::tag,
+    character::complete::{alpha1, char, space1},
+    multi::separated_list1,
+    sequence::{pair, preceded, separated_pair},
+};
+
 use crate::{error::Error, prelude::*};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -17,31 +29,38 @@ struct Hand {
 impl Hand {
     #[tracing::instrument]
     fn from_str(input: &str) -> Result<Self> {
+        let counts = parse::finish(
+            separated_list1(ws(char(',')), separated_pair(number::<u8>, space1, alpha1)),
+            input.trim(),
+        )
+        .map_err(|failure| match failure.reason {
+            Reason::Number(_) => Error::CouldNotParseCount {
+                line: 0,
+                count: failure.at.to_string(),
+            },
+            Reason::Expected(_) => Error::CouldNotParseColorCount {
+                line: 0,
+                hand: input.to_string(),
+            },
+        })?;
+
         let mut hand = Self {
             red: 0,
             green: 0,
             blue: 0,
         };
 
-        for card in input.split(',') {
-            let parts = card.trim().split(' ').collect::<Vec<_>>();
-
-            let count = parts
-                .first()
-                .ok_or_else(|| Error::CouldNotParseColorCount(card.to_string()))?;
-            let color = parts
-                .last()
-                .ok_or_else(|| Error::CouldNotParseColorCount(card.to_string()))?;
-
-            let count = count
-                .parse::<u8>()
-                .map_err(|_| Error::CouldNotParseCount(count.to_string()))?;
-
-            match *color {
+        for (count, color) in counts {
+            match color {
                 "red" => hand.red = count,
                 "green" => hand.green = count,
                 "blue" => hand.blue = count,
-                _ => return Err(Error::UnknownColor(color.to_string())),
+                _ => {
+                    return Err(Error::UnknownColor {
+                        line: 0,
+                        color: color.to_string(),
+                    })
+                }
             }
         }
 
@@ -50,7 +69,7 @@ impl Hand {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
-struct Game {
+pub struct Game {
     id: u32,
     hands: Vec<Hand>,
 }
@@ -58,21 +77,17 @@ struct Game {
 impl Game {
     #[tracing::instrument]
     fn from_str(input: &str) -> Result<Self> {
-        let id_and_hands = input.split(':').collect::<Vec<_>>();
-
-        let id = id_and_hands
-            .first()
-            .ok_or_else(|| Error::CouldNotParseGameId(input.to_string()))?
-            .trim()
-            .split(' ')
-            .nth(1)
-            .ok_or_else(|| Error::CouldNotParseGameId(input.to_string()))?
-            .parse::<u32>()
-            .map_err(|_| Error::CouldNotParseGameId(input.to_string()))?;
-
-        let hands = id_and_hands
-            .last()
-            .ok_or_else(|| Error::CouldNotParseGameHands(input.to_string()))?;
+        let (id, hands) = key_value(input, ':').map_err(|_| Error::CouldNotParseGameHands {
+            line: 0,
+            game: input.to_string(),
+        })?;
+
+        let id = parse::finish(preceded(pair(tag("Game"), space1), number), id).map_err(|_| {
+            Error::CouldNotParseGameId {
+                line: 0,
+                game: input.to_string(),
+            }
+        })?;
 
         let hands = hands
             .split(';')
@@ -107,13 +122,16 @@ impl Game {
     }
 }
 
-#[tracing::instrument]
-pub fn process(input: &str) -> miette::Result<u32> {
-    let games = input
-        .lines()
-        .map(Game::from_str)
-        .collect::<Result<Vec<_>>>()?;
+/// Every game, parsed apart from `solve` so the two can be benchmarked separately.
+#[tracing::instrument(skip(input))]
+pub fn parse(input: &str) -> miette::Result<Vec<Game>> {
+    Ok(numbered_lines(input)
+        .map(|(number, line)| Game::from_str(line).at_line(number))
+        .collect::<Result<Vec<_>>>()?)
+}
 
+#[tracing::instrument(skip(games), fields(games = games.len()))]
+pub fn solve(games: &[Game]) -> miette::Result<u32> {
     let power_sets = games
         .iter()
         .map(|game| game.power_set())
@@ -122,6 +140,11 @@ pub fn process(input: &str) -> miette::Result<u32> {
     Ok(power_sets.iter().sum())
 }
 
+#[tracing::instrument]
+pub fn process(input: &str) -> miette::Result<Answer> {
+    solve(&parse(input)?).map(Answer::from)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -160,7 +183,7 @@ mod tests {
         Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
         Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
         Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
-        assert_eq!(2286, process(input)?);
+        assert_eq!(Answer::UInt(2286), process(input)?);
         Ok(())
     }
 }
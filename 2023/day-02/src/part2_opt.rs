@@ -1,3 +1,5 @@
+use aoc_core::{numbered_lines, Answer, AtLine};
+
 use crate::{error::Error, prelude::*};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -22,11 +24,18 @@ fn parse_hand_color(input: &str) -> Result<Hand> {
 
     let count = count_chars
         .parse::<u8>()
-        .map_err(|_| Error::CouldNotParseCount(input.to_string()))?;
-
-    let color = input
-        .get(color_start..color_start + 1)
-        .ok_or_else(|| Error::CouldNotParseColorCount(input.to_string()))?;
+        .map_err(|_| Error::CouldNotParseCount {
+            line: 0,
+            count: input.to_string(),
+        })?;
+
+    let color =
+        input
+            .get(color_start..color_start + 1)
+            .ok_or_else(|| Error::CouldNotParseColorCount {
+                line: 0,
+                hand: input.to_string(),
+            })?;
 
     match color {
         "r" => Ok(Hand::Red {
@@ -41,14 +50,17 @@ fn parse_hand_color(input: &str) -> Result<Hand> {
             consumed: color_start as u8 + 4,
             count,
         }),
-        _ => return Err(Error::UnknownColor(color.to_string())),
+        _ => Err(Error::UnknownColor {
+            line: 0,
+            color: color.to_string(),
+        }),
     }
 }
 
 #[tracing::instrument]
 fn parse_game(input: &str) -> Result<u32> {
     let input = input.trim();
-        
+
     let mut hands_start = 0;
 
     for c in input[0..10].chars() {
@@ -94,10 +106,12 @@ fn parse_game(input: &str) -> Result<u32> {
 }
 
 #[tracing::instrument]
-pub fn process(input: &str) -> miette::Result<u32> {
-    let power_sets = input.lines().map(parse_game).collect::<Result<Vec<_>>>()?;
+pub fn process(input: &str) -> miette::Result<Answer> {
+    let power_sets = numbered_lines(input)
+        .map(|(number, line)| parse_game(line).at_line(number))
+        .collect::<Result<Vec<_>>>()?;
 
-    Ok(power_sets.iter().sum())
+    Ok(Answer::from(power_sets.iter().sum::<u32>()))
 }
 
 #[cfg(test)]
@@ -146,7 +160,7 @@ mod tests {
         Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
         Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
         Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
-        assert_eq!(2286, process(input)?);
+        assert_eq!(Answer::UInt(2286), process(input)?);
         Ok(())
     }
 }
@@ -0,0 +1,107 @@
+use aoc_core::{numbered_lines, Answer, AtLine};
+
+use crate::{
+    prelude::*,
+    scanner::{Color, Draws},
+};
+
+/// Keeps a running maximum per colour while scanning, so no hands are built.
+#[tracing::instrument]
+fn power(line: &str) -> Result<u32> {
+    let (mut red, mut green, mut blue) = (0u32, 0u32, 0u32);
+
+    for draw in Draws::new(line)? {
+        let (color, count) = draw?;
+        let max = match color {
+            Color::Red => &mut red,
+            Color::Green => &mut green,
+            Color::Blue => &mut blue,
+        };
+        *max = (*max).max(count as u32);
+    }
+
+    Ok(red * green * blue)
+}
+
+#[tracing::instrument(skip(input))]
+pub fn process(input: &str) -> miette::Result<Answer> {
+    let mut total = 0;
+
+    for (number, line) in numbered_lines(input) {
+        total += power(line).at_line(number)?;
+    }
+
+    Ok(Answer::from(total))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{part2, part2_opt};
+    use proptest::prelude::*;
+
+    fn hand() -> impl Strategy<Value = String> {
+        (
+            prop::sample::subsequence(vec!["red", "green", "blue"], 1..=3).prop_shuffle(),
+            prop::collection::vec(1u8..=20, 3),
+        )
+            .prop_map(|(colors, counts)| {
+                colors
+                    .iter()
+                    .zip(counts)
+                    .map(|(color, count)| format!("{} {}", count, color))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            })
+    }
+
+    fn games() -> impl Strategy<Value = String> {
+        prop::collection::vec(prop::collection::vec(hand(), 1..=4), 1..=8).prop_map(|games| {
+            games
+                .iter()
+                .enumerate()
+                .map(|(i, hands)| format!("Game {}: {}", i + 1, hands.join("; ")))
+                .collect::<Vec<_>>()
+                .join("\n")
+        })
+    }
+
+    #[test]
+    fn it_should_calculate_power() -> miette::Result<()> {
+        assert_eq!(
+            48,
+            power("Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green")?
+        );
+        assert_eq!(
+            1560,
+            power("Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red")?
+        );
+        assert_eq!(0, power("Game 4: 3 red; 2 blue")?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_process() -> miette::Result<()> {
+        let input = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+        Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+        Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+        Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+        Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
+        assert_eq!(Answer::UInt(2286), process(input)?);
+        Ok(())
+    }
+
+    fn answer(result: miette::Result<Answer>) -> std::result::Result<Answer, TestCaseError> {
+        result.map_err(|e| TestCaseError::fail(e.to_string()))
+    }
+
+    proptest! {
+        #[test]
+        fn it_should_match_other_part2_variants(input in games()) {
+            let expected = answer(part2::process(&input))?;
+
+            prop_assert_eq!(&expected, &answer(part2_opt::process(&input))?);
+            prop_assert_eq!(&expected, &answer(process(&input))?);
+        }
+    }
+}
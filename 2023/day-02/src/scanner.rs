@@ -0,0 +1,173 @@
+use crate::{error::Error, prelude::*};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Color {
+    Red,
+    Green,
+    Blue,
+}
+
+/// Walks the bytes of one game line, yielding every `count color` draw in
+/// order. Hands aren't grouped and nothing is allocated unless the line is bad.
+#[derive(Debug, Clone)]
+pub struct Draws<'a> {
+    line: &'a str,
+    index: usize,
+}
+
+impl<'a> Draws<'a> {
+    /// Starts just after the `Game n:` prefix.
+    #[tracing::instrument]
+    pub fn new(line: &'a str) -> Result<Self> {
+        let index =
+            line.bytes()
+                .position(|b| b == b':')
+                .ok_or_else(|| Error::CouldNotParseGameHands {
+                    line: 0,
+                    game: line.to_string(),
+                })?;
+
+        Ok(Self {
+            line,
+            index: index + 1,
+        })
+    }
+
+    #[tracing::instrument(skip(self))]
+    fn next_draw(&mut self) -> Result<(Color, u8)> {
+        let bytes = self.line.as_bytes();
+        let start = self.index;
+
+        let mut count: u32 = 0;
+        while let Some(digit) = bytes.get(self.index).filter(|b| b.is_ascii_digit()) {
+            count = count * 10 + (digit - b'0') as u32;
+            self.index += 1;
+
+            if count > u8::MAX as u32 {
+                return Err(Error::CouldNotParseCount {
+                    line: 0,
+                    count: self.line[start..].to_string(),
+                });
+            }
+        }
+
+        if self.index == start {
+            return Err(Error::CouldNotParseCount {
+                line: 0,
+                count: self.line[start..].to_string(),
+            });
+        }
+
+        if bytes.get(self.index) != Some(&b' ') {
+            return Err(Error::CouldNotParseColorCount {
+                line: 0,
+                hand: self.line[start..].to_string(),
+            });
+        }
+        self.index += 1;
+
+        let word_start = self.index;
+        while bytes
+            .get(self.index)
+            .is_some_and(|b| b.is_ascii_alphabetic())
+        {
+            self.index += 1;
+        }
+
+        let color = match &bytes[word_start..self.index] {
+            b"red" => Color::Red,
+            b"green" => Color::Green,
+            b"blue" => Color::Blue,
+            [] => {
+                return Err(Error::CouldNotParseColorCount {
+                    line: 0,
+                    hand: self.line[start..].to_string(),
+                })
+            }
+            _ => {
+                return Err(Error::UnknownColor {
+                    line: 0,
+                    color: self.line[word_start..self.index].to_string(),
+                })
+            }
+        };
+
+        Ok((color, count as u8))
+    }
+}
+
+impl Iterator for Draws<'_> {
+    type Item = Result<(Color, u8)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let bytes = self.line.as_bytes();
+
+        while bytes
+            .get(self.index)
+            .is_some_and(|b| matches!(b, b' ' | b',' | b';'))
+        {
+            self.index += 1;
+        }
+
+        if self.index >= bytes.len() {
+            return None;
+        }
+
+        let draw = self.next_draw();
+
+        if draw.is_err() {
+            // Don't keep scanning a line that's already wrong.
+            self.index = bytes.len();
+        }
+
+        Some(draw)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_should_scan_draws() -> miette::Result<()> {
+        let draws = Draws::new("Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green")?
+            .collect::<Result<Vec<_>>>()?;
+
+        assert_eq!(
+            vec![
+                (Color::Blue, 3),
+                (Color::Red, 4),
+                (Color::Red, 1),
+                (Color::Green, 2),
+                (Color::Blue, 6),
+                (Color::Green, 2),
+            ],
+            draws
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_reject_bad_draws() -> miette::Result<()> {
+        let first = |line| Draws::new(line).and_then(|mut d| d.next().transpose());
+
+        assert!(matches!(
+            first("Game 1: 3 purple"),
+            Err(Error::UnknownColor { color, .. }) if color == "purple"
+        ));
+        assert!(matches!(
+            first("Game 1: 3"),
+            Err(Error::CouldNotParseColorCount { .. })
+        ));
+        assert!(matches!(
+            first("Game 1: 256 red"),
+            Err(Error::CouldNotParseCount { .. })
+        ));
+        assert!(matches!(
+            first("Game 1 3 red"),
+            Err(Error::CouldNotParseGameHands { .. })
+        ));
+        assert!(matches!(first("Game 1:"), Ok(None)));
+        Ok(())
+    }
+}
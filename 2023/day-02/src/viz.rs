@@ -0,0 +1,97 @@
+use aoc_viz::{Canvas, Color};
+
+use crate::{
+    games::{games, Cubes, ELF_BAG},
+    prelude::*,
+};
+
+const LABEL_WIDTH: usize = 12;
+
+/// One bar per colour per game showing its minimum bag, with the elf bag's
+/// limit marked by `|`. Cubes beyond the limit and impossible games are red.
+#[tracing::instrument(skip(input))]
+pub fn render(input: &str, ansi: bool) -> Result<String> {
+    let games = games(input).collect::<Result<Vec<_>>>()?;
+
+    let max_count = games
+        .iter()
+        .map(|game| {
+            let bag = game.min_bag();
+            bag.red.max(bag.green).max(bag.blue)
+        })
+        .chain([ELF_BAG.red, ELF_BAG.green, ELF_BAG.blue])
+        .max()
+        .unwrap_or(0) as usize;
+
+    let mut canvas = Canvas::new(LABEL_WIDTH + max_count + 2, games.len() * 3);
+
+    for (i, game) in games.iter().enumerate() {
+        let y = i * 3;
+        let bag = game.min_bag();
+        let possible = bag.fits_in(&ELF_BAG);
+
+        let label = if possible {
+            format!("Game {}", game.id)
+        } else {
+            format!("Game {} !", game.id)
+        };
+        let label_color = if possible { Color::Default } else { Color::Red };
+        canvas.text(0, y, &label, label_color);
+
+        for (row, (name, count, limit, color)) in bars(&bag).into_iter().enumerate() {
+            let y = y + row;
+            canvas.set(LABEL_WIDTH - 2, y, name, Color::Grey);
+            canvas.set(LABEL_WIDTH + limit as usize, y, '|', Color::Yellow);
+
+            for x in 0..count as usize {
+                let color = if x < limit as usize {
+                    color
+                } else {
+                    Color::Red
+                };
+                canvas.set(LABEL_WIDTH + x, y, '█', color);
+            }
+        }
+    }
+
+    Ok(canvas.render(ansi))
+}
+
+#[tracing::instrument]
+fn bars(bag: &Cubes) -> [(char, u8, u8, Color); 3] {
+    [
+        ('r', bag.red, ELF_BAG.red, Color::Red),
+        ('g', bag.green, ELF_BAG.green, Color::Green),
+        ('b', bag.blue, ELF_BAG.blue, Color::Blue),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_should_render_bars_with_threshold() -> miette::Result<()> {
+        let output = render("Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue", false)?;
+        let lines = output.lines().collect::<Vec<_>>();
+
+        assert_eq!(3, lines.len());
+        assert_eq!("Game 1    r ████        |", lines[0]);
+        assert_eq!("          g ██           |", lines[1]);
+        assert_eq!("          b ██████        |", lines[2]);
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_flag_impossible_games() -> miette::Result<()> {
+        let output = render("Game 3: 8 green, 6 blue, 20 red", false)?;
+        let lines = output.lines().collect::<Vec<_>>();
+
+        assert!(lines[0].starts_with("Game 3 !"));
+        assert_eq!(LABEL_WIDTH + 20, lines[0].chars().count());
+
+        let coloured = render("Game 3: 8 green, 6 blue, 20 red", true)?;
+        assert!(coloured.starts_with("\x1b[31mGame 3 !"));
+        Ok(())
+    }
+}
@@ -6,25 +6,44 @@ fn main() {
 }
 
 #[divan::bench]
-fn part1() {
-    part1::process(divan::black_box(include_str!(
-        "../input1.txt",
-    )))
-    .unwrap();
+fn part1(bencher: divan::Bencher) {
+    let input = aoc_core::puzzle_input!("input1.txt").unwrap();
+    bencher.bench(|| part1::process(divan::black_box(&input)).unwrap());
 }
 
 #[divan::bench]
-fn part2() {
-    part2::process(divan::black_box(include_str!(
-        "../input2.txt",
-    )))
-    .unwrap();
+fn part2(bencher: divan::Bencher) {
+    let input = aoc_core::puzzle_input!("input2.txt").unwrap();
+    bencher.bench(|| part2::process(divan::black_box(&input)).unwrap());
 }
 
 #[divan::bench]
-fn part1_opt() {
-    part1_opt::process(divan::black_box(include_str!(
-        "../input1.txt",
-    )))
-    .unwrap();
+fn part1_opt(bencher: divan::Bencher) {
+    let input = aoc_core::puzzle_input!("input1.txt").unwrap();
+    bencher.bench(|| part1_opt::process(divan::black_box(&input)).unwrap());
+}
+
+// 200 wide with a fifth of the cells symbols, far denser than the real input
+fn schematic(lines: usize) -> String {
+    generator::schematic(lines, 200, 20, 2023)
+}
+
+// part1 and part2 check every number against every symbol, so 5000 lines
+// would take minutes per iteration; they stop at 500
+#[divan::bench(args = [50, 500], sample_count = 10)]
+fn part1_stress(bencher: divan::Bencher, lines: usize) {
+    let input = schematic(lines);
+    bencher.bench(|| part1::process(divan::black_box(&input)).unwrap());
+}
+
+#[divan::bench(args = [50, 500, 5000])]
+fn part1_opt_stress(bencher: divan::Bencher, lines: usize) {
+    let input = schematic(lines);
+    bencher.bench(|| part1_opt::process(divan::black_box(&input)).unwrap());
+}
+
+#[divan::bench(args = [50, 500], sample_count = 10)]
+fn part2_stress(bencher: divan::Bencher, lines: usize) {
+    let input = schematic(lines);
+    bencher.bench(|| part2::process(divan::black_box(&input)).unwrap());
 }
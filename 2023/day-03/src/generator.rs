@@ -0,0 +1,92 @@
+use aoc_testing::XorShift;
+
+const SYMBOLS: &[u8; 10] = b"*#+$/=%@&-";
+
+/// Generates a `lines` by `width` engine schematic. Roughly `symbol_percent` of
+/// the cells are symbols and about a third are digits, in numbers of up to
+/// three digits with a gap after each.
+#[tracing::instrument]
+pub fn schematic(lines: usize, width: usize, symbol_percent: u64, seed: u64) -> String {
+    let mut rng = XorShift::new(seed);
+    let mut output = String::with_capacity(lines * (width + 1));
+
+    for _ in 0..lines {
+        let mut x = 0;
+
+        while x < width {
+            let roll = rng.next_u64() % 100;
+
+            if roll < symbol_percent {
+                output.push(SYMBOLS[(rng.next_u64() % SYMBOLS.len() as u64) as usize] as char);
+                x += 1;
+            } else if roll < symbol_percent + (100 - symbol_percent) / 4 {
+                let digits = (1 + rng.next_u64() % 3).min((width - x) as u64);
+                for _ in 0..digits {
+                    output.push((b'0' + (rng.next_u64() % 10) as u8) as char);
+                }
+                x += digits as usize;
+
+                if x < width {
+                    output.push('.');
+                    x += 1;
+                }
+            } else {
+                output.push('.');
+                x += 1;
+            }
+        }
+
+        output.push('\n');
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{part1, part1_opt};
+    use aoc_core::Answer;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn it_should_generate_requested_shape() -> miette::Result<()> {
+        let input = schematic(50, 200, 10, 3);
+
+        assert_eq!(50, input.lines().count());
+        assert!(input.lines().all(|line| line.len() == 200));
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_respect_symbol_density() -> miette::Result<()> {
+        let count = |input: &str| input.bytes().filter(|b| SYMBOLS.contains(b)).count();
+
+        assert_eq!(0, count(&schematic(100, 200, 0, 3)));
+        assert!(count(&schematic(100, 200, 30, 3)) > count(&schematic(100, 200, 5, 3)));
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_be_reproducible() -> miette::Result<()> {
+        assert_eq!(schematic(20, 200, 10, 42), schematic(20, 200, 10, 42));
+        assert!(schematic(20, 200, 10, 42) != schematic(20, 200, 10, 43));
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_agree_on_dense_symbols() -> miette::Result<()> {
+        let input = schematic(50, 200, 20, 2023);
+
+        assert_eq!(part1::process(&input)?, part1_opt::process(&input)?);
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_handle_stress_input() -> miette::Result<()> {
+        let input = schematic(5000, 200, 20, 2023);
+
+        assert_ne!(Answer::UInt(0), part1_opt::process(&input)?);
+        Ok(())
+    }
+}
@@ -1,6 +1,10 @@
 pub mod error;
+pub mod generator;
 pub mod prelude;
 
 pub mod part1;
 pub mod part2;
 pub mod part1_opt;
+pub mod symbols;
+
+aoc_core::solution!(Day03, 2023, 3, part1::process, part2::process);
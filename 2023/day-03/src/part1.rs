@@ -1,3 +1,8 @@
+use aoc_core::{Answer, PointSet};
+use aoc_geometry::Point2;
+
+use crate::symbols::SymbolClassifier;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 struct PartNumber {
     x: u32,
@@ -17,22 +22,14 @@ impl PartNumber {
         }
     }
 
-    #[tracing::instrument]
-    fn has_adjacent_symbol(&self, symbol: &[Symbol]) -> bool {
-        let start_x = if self.x == 0 { 0 } else { self.x - 1 };
-        let end_x = self.x + self.width + 1;
-        let start_y = if self.y == 0 { 0 } else { self.y - 1 };
-        let end_y = self.y + 1;
-
-        for x in start_x..end_x {
-            for y in start_y..=end_y {
-                if symbol.iter().any(|s| s.postition_equals(x, y)) {
-                    return true;
-                }
-            }
-        }
-
-        false
+    #[tracing::instrument(skip(symbols))]
+    fn has_adjacent_symbol(&self, symbols: &PointSet) -> bool {
+        (self.x..self.x + self.width).any(|x| {
+            symbols
+                .neighbors8_of(Point2::new(x as i64, self.y as i64))
+                .next()
+                .is_some()
+        })
     }
 }
 
@@ -50,42 +47,46 @@ impl Symbol {
     }
 
     #[tracing::instrument]
-    fn postition_equals(&self, x: u32, y: u32) -> bool {
-        self.x == x && self.y == y
+    fn point(&self) -> Point2 {
+        Point2::new(self.x as i64, self.y as i64)
     }
 }
 
+/// Positions are in characters, so anything multi-byte before a number
+/// doesn't shift it, while the number itself is sliced by byte offset.
 #[tracing::instrument]
 fn extract_part_numbers_from_line(line: &str, line_index: u32) -> Vec<PartNumber> {
     let mut part_numbers = Vec::new();
 
     let mut in_digits = false;
     let mut number_start = 0;
+    let mut number_start_byte = 0;
 
-    for (i, c) in line.char_indices() {
+    for (x, (i, c)) in line.char_indices().enumerate() {
         if c.is_ascii_digit() {
             if !in_digits {
                 in_digits = true;
-                number_start = i;
+                number_start = x;
+                number_start_byte = i;
             }
         } else if in_digits {
             in_digits = false;
-            let number = line.get(number_start..i).unwrap().parse::<u32>().unwrap();
+            let number = line[number_start_byte..i].parse::<u32>().unwrap();
             part_numbers.push(PartNumber::new(
                 number_start as u32,
                 line_index,
-                i as u32 - number_start as u32,
+                x as u32 - number_start as u32,
                 number,
             ));
         }
     }
 
     if in_digits {
-        let number = line.get(number_start..).unwrap().parse::<u32>().unwrap();
+        let number = line[number_start_byte..].parse::<u32>().unwrap();
         part_numbers.push(PartNumber::new(
             number_start as u32,
             line_index,
-            line.len() as u32 - number_start as u32,
+            line.chars().count() as u32 - number_start as u32,
             number,
         ));
     }
@@ -94,20 +95,36 @@ fn extract_part_numbers_from_line(line: &str, line_index: u32) -> Vec<PartNumber
 }
 
 #[tracing::instrument]
-fn extract_symbols_from_line(line: &str, line_index: u32) -> Vec<Symbol> {
+fn extract_symbols_from_line(
+    line: &str,
+    line_index: u32,
+    classifier: &SymbolClassifier,
+) -> Vec<Symbol> {
     let mut symbols = Vec::new();
 
-    for (i, c) in line.char_indices() {
-        if !c.is_ascii_digit() && c != '.' {
-            symbols.push(Symbol::new(i as u32, line_index, c));
+    for (x, c) in line.chars().enumerate() {
+        if classifier.is_symbol(c) {
+            symbols.push(Symbol::new(x as u32, line_index, c));
         }
     }
 
     symbols
 }
 
-#[tracing::instrument]
-fn part_numbers_adaject_to_a_symbol(part_numbers: &[PartNumber], symbols: &[Symbol]) -> Vec<u32> {
+/// Where every symbol is, so a number checks the cells around its digits with
+/// lookups rather than a scan of every symbol.
+#[tracing::instrument(skip(input))]
+fn locate_symbols(input: &str, classifier: &SymbolClassifier) -> PointSet {
+    input
+        .lines()
+        .enumerate()
+        .flat_map(|(i, line)| extract_symbols_from_line(line.trim(), i as u32, classifier))
+        .map(|symbol| symbol.point())
+        .collect()
+}
+
+#[tracing::instrument(skip(symbols))]
+fn part_numbers_adaject_to_a_symbol(part_numbers: &[PartNumber], symbols: &PointSet) -> Vec<u32> {
     part_numbers
         .iter()
         .filter(|part_number| part_number.has_adjacent_symbol(symbols))
@@ -116,18 +133,19 @@ fn part_numbers_adaject_to_a_symbol(part_numbers: &[PartNumber], symbols: &[Symb
 }
 
 #[tracing::instrument]
-pub fn process(input: &str) -> miette::Result<u32> {
+pub fn process(input: &str) -> miette::Result<Answer> {
+    process_with(input, &SymbolClassifier::default()).map(Answer::from)
+}
+
+#[tracing::instrument]
+pub fn process_with(input: &str, classifier: &SymbolClassifier) -> miette::Result<u32> {
     let part_numbers = input
         .lines()
         .enumerate()
         .flat_map(|(i, line)| extract_part_numbers_from_line(line.trim(), i as u32))
         .collect::<Vec<_>>();
 
-    let symbols = input
-        .lines()
-        .enumerate()
-        .flat_map(|(i, line)| extract_symbols_from_line(line.trim(), i as u32))
-        .collect::<Vec<_>>();
+    let symbols = locate_symbols(input, classifier);
 
     let parts_next_to_symbols = part_numbers_adaject_to_a_symbol(&part_numbers, &symbols);
 
@@ -156,12 +174,55 @@ mod tests {
     #[test]
     fn it_should_extract_symbols_from_line() -> miette::Result<()> {
         let input = "617*......";
-        let symbols = extract_symbols_from_line(input, 0);
+        let symbols = extract_symbols_from_line(input, 0, &SymbolClassifier::default());
         assert_eq!(vec![Symbol::new(3, 0, '*')], symbols);
 
         Ok(())
     }
 
+    #[test]
+    fn it_should_use_char_columns_with_multi_byte_input() -> miette::Result<()> {
+        assert_eq!(
+            vec![PartNumber::new(2, 0, 2, 12), PartNumber::new(6, 0, 1, 7)],
+            extract_part_numbers_from_line("€€12.é7", 0)
+        );
+        assert_eq!(
+            vec![
+                Symbol::new(0, 0, 'é'),
+                Symbol::new(2, 0, '€'),
+                Symbol::new(3, 0, '!')
+            ],
+            extract_symbols_from_line("é.€!\u{a0}.", 0, &SymbolClassifier::default())
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_not_treat_unicode_whitespace_as_symbol() -> miette::Result<()> {
+        // The 5 is at column 8 but byte 12, and only touches the * by column
+        let input = "1.\u{a0}\u{a0}\u{a0}\u{a0}..5
+        .........*";
+        assert_eq!(Answer::UInt(5), process(input)?);
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_count_numbers_next_to_unusual_symbols() -> miette::Result<()> {
+        let input = "1~.2`.3|.4\\.5^.6?.7a.8:.9\"
+        ..........................";
+        assert_eq!(
+            Answer::UInt(1 + 2 + 3 + 4 + 5 + 6 + 7 + 8 + 9),
+            process(input)?
+        );
+
+        let classifier = SymbolClassifier {
+            empty: '_',
+            gear: '*',
+        };
+        assert_eq!(12 + 3, process_with("12._3\n____.", &classifier)?);
+        Ok(())
+    }
+
     // This test was part of me debugging and is not finished, so it will fail, see my blog post about it https://zoeaubert.me/blog/advent-of-code-2023-day-03/
     // #[test]
     // fn it_should_extract_part_numbers_adjacent_to_symbol() -> miette::Result<()> {
@@ -282,11 +343,7 @@ mod tests {
 
         assert_eq!(expected_part_numbers, part_numbers.iter().map(|p| p.number).collect::<Vec<_>>());
 
-        let symbols = input
-            .lines()
-            .enumerate()
-            .flat_map(|(i, line)| extract_symbols_from_line(line.trim(), i as u32))
-            .collect::<Vec<_>>();
+        let symbols = locate_symbols(input, &SymbolClassifier::default());
 
         let parts_next_to_symbols = part_numbers_adaject_to_a_symbol(&part_numbers, &symbols);
 
@@ -313,7 +370,7 @@ mod tests {
         ......755.
         ...$.*....
         .664.598..";
-        assert_eq!(4361, process(input)?);
+        assert_eq!(Answer::UInt(4361), process(input)?);
         Ok(())
     }
 }
@@ -1,3 +1,7 @@
+use aoc_core::Answer;
+
+use crate::symbols::SymbolClassifier;
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 struct Data {
     symbol_map: Vec<bool>,
@@ -6,11 +10,11 @@ struct Data {
 
 impl Data {
     #[tracing::instrument]
-    fn new(input: &String) -> Self {
+    fn new(input: &String, classifier: &SymbolClassifier) -> Self {
         let symbol_map = input
             .lines()
-            .flat_map(|line| line.chars())
-            .map(|c| is_symbol(Some(c)))
+            .flat_map(|line| line.bytes())
+            .map(|byte| classifier.is_symbol_byte(byte))
             .collect::<Vec<_>>();
         let width = input.lines().next().unwrap().len();
 
@@ -30,16 +34,6 @@ impl Data {
     }
 }
 
-#[tracing::instrument]
-fn is_symbol(char: Option<char>) -> bool {
-    match char {
-        Some(c) => {
-            matches!(c, '-' | '%' | '+' | '=' | '*' | '/' | '$' | '#' | '&' | '@')
-        }
-        None => false,
-    }
-}
-
 #[tracing::instrument]
 fn parse_line(line: &str, y: i32, data: &Data) -> Vec<u32> {
     let mut in_number = false;
@@ -48,7 +42,7 @@ fn parse_line(line: &str, y: i32, data: &Data) -> Vec<u32> {
 
     let mut numbers = vec![];
 
-    for (i, c) in line.chars().enumerate() {
+    for (i, c) in line.bytes().enumerate() {
         let i_as_i32 = i as i32;
         if c.is_ascii_digit() {
             if !in_number {
@@ -97,15 +91,25 @@ fn parse_line(line: &str, y: i32, data: &Data) -> Vec<u32> {
     numbers
 }
 
+/// Works a byte per cell, so anything outside ASCII goes to the char-aware `part1`.
+#[tracing::instrument]
+pub fn process(input: &str) -> miette::Result<Answer> {
+    process_with(input, &SymbolClassifier::default()).map(Answer::from)
+}
+
 #[tracing::instrument]
-pub fn process(input: &str) -> miette::Result<u32> {
+pub fn process_with(input: &str, classifier: &SymbolClassifier) -> miette::Result<u32> {
+    if !input.is_ascii() {
+        return crate::part1::process_with(input, classifier);
+    }
+
     let input = input
         .lines()
         .map(|line| line.trim())
         .collect::<Vec<_>>()
         .join("\n");
 
-    let data = Data::new(&input);
+    let data = Data::new(&input, classifier);
 
     let sum = input
         .lines()
@@ -132,14 +136,47 @@ mod tests {
         ......755.
         ...$.*....
         .664.598..";
-        assert_eq!(4361, process(input)?);
+        assert_eq!(Answer::UInt(4361), process(input)?);
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_count_any_non_digit_non_dot_as_symbol() -> miette::Result<()> {
+        assert_eq!(Answer::UInt(12 + 3), process("12!.3\n....?")?);
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_match_part1_on_unusual_symbols() -> miette::Result<()> {
+        let input = "1~.2`.3|.4\\.5^
+        .6?.7a.8:.9\"..
+        ..............";
+        assert_eq!(crate::part1::process(input)?, process(input)?);
+        assert_eq!(Answer::UInt(45), process(input)?);
+
+        let classifier = SymbolClassifier {
+            empty: '_',
+            gear: '*',
+        };
+        assert_eq!(15, process_with("12._3\n____.", &classifier)?);
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_match_part1_on_multi_byte_input() -> miette::Result<()> {
+        let input = "ü.12\n.€..\n3...";
+        assert_eq!(crate::part1::process(input)?, process(input)?);
+        assert_eq!(Answer::UInt(15), process(input)?);
         Ok(())
     }
 
     #[test]
     fn test_full() -> miette::Result<()> {
-        let input = include_str!("../input1.txt");
-        assert_eq!(528819, process(input)?);
+        let input = &aoc_core::puzzle_input!("input1.txt")?;
+        assert_eq!(
+            aoc_core::manifest::answer(2023, 3, 1)?,
+            process(input)?.to_string()
+        );
         Ok(())
     }
 }
@@ -1,14 +1,19 @@
+use aoc_core::{Answer, SparseGrid};
+use aoc_geometry::Point2;
+
+use crate::symbols::SymbolClassifier;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 struct PartNumber {
-    x: i32,
-    y: i32,
-    width: i32,
+    x: usize,
+    y: usize,
+    width: usize,
     number: i32,
 }
 
 impl PartNumber {
     #[tracing::instrument]
-    fn new(x: i32, y: i32, width: i32, number: i32) -> Self {
+    fn new(x: usize, y: usize, width: usize, number: i32) -> Self {
         Self {
             x,
             y,
@@ -17,78 +22,92 @@ impl PartNumber {
         }
     }
 
+    /// Every cell the number's digits cover.
     #[tracing::instrument]
-    fn contains_point(&self, x: i32, y: i32) -> bool {
-        let start_x = self.x;
-        let end_x = self.x + self.width;
-
-        x >= start_x && x < end_x && y == self.y
+    fn cells(&self) -> impl Iterator<Item = Point2> {
+        let y = self.y as i64;
+        (self.x..self.x + self.width).map(move |x| Point2::new(x as i64, y))
     }
 }
 
+/// Which part number, by index, covers each cell. Built once, so a symbol
+/// finds its neighbours in eight lookups rather than a scan of every part.
+#[tracing::instrument]
+fn index_part_numbers(part_numbers: &[PartNumber]) -> SparseGrid<usize> {
+    part_numbers
+        .iter()
+        .enumerate()
+        .flat_map(|(id, part_number)| part_number.cells().map(move |cell| (cell, id)))
+        .collect()
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 struct Symbol {
-    x: i32,
-    y: i32,
+    point: Point2,
     symbol: char,
 }
 
 impl Symbol {
     #[tracing::instrument]
-    fn new(x: i32, y: i32, symbol: char) -> Self {
-        Self { x, y, symbol }
+    fn new(x: usize, y: usize, symbol: char) -> Self {
+        Self {
+            point: Point2::new(x as i64, y as i64),
+            symbol,
+        }
     }
 
+    /// A number can touch the symbol with more than one digit, so each part
+    /// is only counted once.
     #[tracing::instrument]
-    fn adjacent_part_numbers(&self, part_numbers: &[PartNumber]) -> Vec<i32> {
-        part_numbers
-            .iter()
-            .filter(|part_number| {
-                part_number.contains_point(self.x - 1, self.y) // left
-                    || part_number.contains_point(self.x + 1, self.y) // right
-                    || part_number.contains_point(self.x, self.y - 1) // top
-                    || part_number.contains_point(self.x, self.y + 1) // bottom
-                    || part_number.contains_point(self.x - 1, self.y - 1) // top left
-                    || part_number.contains_point(self.x + 1, self.y - 1) // top right
-                    || part_number.contains_point(self.x - 1, self.y + 1) // bottom left
-                    || part_number.contains_point(self.x + 1, self.y + 1) // bottom right
-            })
-            .map(|part_number| part_number.number)
-            .collect::<Vec<_>>()
+    fn adjacent_part_numbers(
+        &self,
+        index: &SparseGrid<usize>,
+        part_numbers: &[PartNumber],
+    ) -> Vec<i32> {
+        let mut ids = index
+            .neighbours8(self.point)
+            .map(|(_, id)| *id)
+            .collect::<Vec<_>>();
+        ids.sort_unstable();
+        ids.dedup();
+
+        ids.into_iter().map(|id| part_numbers[id].number).collect()
     }
 }
 
 #[tracing::instrument]
-fn extract_part_numbers_from_line(line: &str, line_index: i32) -> Vec<PartNumber> {
+fn extract_part_numbers_from_line(line: &str, line_index: usize) -> Vec<PartNumber> {
     let mut part_numbers = Vec::new();
 
     let mut in_digits = false;
     let mut number_start = 0;
+    let mut number_start_byte = 0;
 
-    for (i, c) in line.char_indices() {
+    for (x, (i, c)) in line.char_indices().enumerate() {
         if c.is_ascii_digit() {
             if !in_digits {
                 in_digits = true;
-                number_start = i;
+                number_start = x;
+                number_start_byte = i;
             }
         } else if in_digits {
             in_digits = false;
-            let number = line.get(number_start..i).unwrap().parse::<i32>().unwrap();
+            let number = line[number_start_byte..i].parse::<i32>().unwrap();
             part_numbers.push(PartNumber::new(
-                number_start as i32,
+                number_start,
                 line_index,
-                i as i32 - number_start as i32,
+                x - number_start,
                 number,
             ));
         }
     }
 
     if in_digits {
-        let number = line.get(number_start..).unwrap().parse::<i32>().unwrap();
+        let number = line[number_start_byte..].parse::<i32>().unwrap();
         part_numbers.push(PartNumber::new(
-            number_start as i32,
+            number_start,
             line_index,
-            line.len() as i32 - number_start as i32,
+            line.chars().count() - number_start,
             number,
         ));
     }
@@ -97,12 +116,16 @@ fn extract_part_numbers_from_line(line: &str, line_index: i32) -> Vec<PartNumber
 }
 
 #[tracing::instrument]
-fn extract_symbols_from_line(line: &str, line_index: i32) -> Vec<Symbol> {
+fn extract_gears_from_line(
+    line: &str,
+    line_index: usize,
+    classifier: &SymbolClassifier,
+) -> Vec<Symbol> {
     let mut symbols = Vec::new();
 
-    for (i, c) in line.char_indices() {
-        if c == '*' {
-            symbols.push(Symbol::new(i as i32, line_index, c));
+    for (x, c) in line.chars().enumerate() {
+        if classifier.is_gear(c) {
+            symbols.push(Symbol::new(x, line_index, c));
         }
     }
 
@@ -114,26 +137,33 @@ fn symbols_with_2_adjacent_part_numbers(
     symbols: &[Symbol],
     part_numbers: &[PartNumber],
 ) -> Vec<i32> {
+    let index = index_part_numbers(part_numbers);
+
     symbols
         .iter()
-        .map(|symbol| symbol.adjacent_part_numbers(part_numbers))
+        .map(|symbol| symbol.adjacent_part_numbers(&index, part_numbers))
         .filter(|adjacent_part_numbers| adjacent_part_numbers.len() == 2)
         .map(|adjacent_part_numbers| adjacent_part_numbers.iter().product())
         .collect::<Vec<_>>()
 }
 
 #[tracing::instrument]
-pub fn process(input: &str) -> miette::Result<i32> {
+pub fn process(input: &str) -> miette::Result<Answer> {
+    process_with(input, &SymbolClassifier::default()).map(Answer::from)
+}
+
+#[tracing::instrument]
+pub fn process_with(input: &str, classifier: &SymbolClassifier) -> miette::Result<i32> {
     let part_numbers = input
         .lines()
         .enumerate()
-        .flat_map(|(i, line)| extract_part_numbers_from_line(line.trim(), i as i32))
+        .flat_map(|(i, line)| extract_part_numbers_from_line(line.trim(), i))
         .collect::<Vec<_>>();
 
     let symbols = input
         .lines()
         .enumerate()
-        .flat_map(|(i, line)| extract_symbols_from_line(line.trim(), i as i32))
+        .flat_map(|(i, line)| extract_gears_from_line(line.trim(), i, classifier))
         .collect::<Vec<_>>();
 
     let gear_ratios = symbols_with_2_adjacent_part_numbers(&symbols, &part_numbers);
@@ -148,6 +178,39 @@ mod tests {
     use super::*;
     use pretty_assertions::assert_eq;
 
+    #[test]
+    fn it_should_find_gears_after_multi_byte_characters() -> miette::Result<()> {
+        let input = "ü.12
+        .*..
+        3...";
+        assert_eq!(Answer::Int(36), process(input)?);
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_count_a_part_touching_a_gear_twice_once() -> miette::Result<()> {
+        let input = "123.
+        .*..
+        45..";
+        assert_eq!(Answer::Int(5535), process(input)?);
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_only_treat_gear_symbols_as_gears() -> miette::Result<()> {
+        let input = "2.3.5.7
+        ~.|.€.x
+        4.6.8.9";
+        assert_eq!(Answer::Int(0), process(input)?);
+
+        let classifier = SymbolClassifier {
+            empty: '.',
+            gear: 'x',
+        };
+        assert_eq!(7 * 9, process_with(input, &classifier)?);
+        Ok(())
+    }
+
     #[test]
     fn test_process() -> miette::Result<()> {
         let input = "467..114..
@@ -160,7 +223,7 @@ mod tests {
         ......755.
         ...$.*....
         .664.598..";
-        assert_eq!(467835, process(input)?);
+        assert_eq!(Answer::Int(467835), process(input)?);
         Ok(())
     }
 }
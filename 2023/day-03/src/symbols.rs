@@ -0,0 +1,91 @@
+/// Which schematic cells are symbols and which symbols are gears, shared by
+/// every part so they can't disagree. A symbol is anything that isn't a
+/// digit, the empty cell or whitespace, whatever its width in bytes, rather
+/// than a list that breaks on the first input using a character it left out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SymbolClassifier {
+    pub empty: char,
+    pub gear: char,
+}
+
+impl Default for SymbolClassifier {
+    fn default() -> Self {
+        Self {
+            empty: '.',
+            gear: '*',
+        }
+    }
+}
+
+impl SymbolClassifier {
+    #[tracing::instrument]
+    pub fn is_symbol(&self, c: char) -> bool {
+        !c.is_ascii_digit() && c != self.empty && !c.is_whitespace()
+    }
+
+    /// `is_symbol` for a byte of ASCII input. Bytes of longer characters
+    /// aren't cells, so they never count.
+    #[tracing::instrument]
+    pub fn is_symbol_byte(&self, byte: u8) -> bool {
+        byte.is_ascii() && self.is_symbol(byte as char)
+    }
+
+    #[tracing::instrument]
+    pub fn is_gear(&self, c: char) -> bool {
+        c == self.gear
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn it_should_count_anything_but_digits_empty_and_whitespace() -> miette::Result<()> {
+        let symbols = SymbolClassifier::default();
+        let cells = "*#+$/=%@&-~`|\\^!?:;'\"<>()[]{}_,aZ€é.7 \t\u{a0}";
+
+        assert_eq!(
+            "*#+$/=%@&-~`|\\^!?:;'\"<>()[]{}_,aZ€é",
+            cells
+                .chars()
+                .filter(|c| symbols.is_symbol(*c))
+                .collect::<String>()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_agree_on_bytes_and_chars() -> miette::Result<()> {
+        let symbols = SymbolClassifier::default();
+
+        for byte in 0..=u8::MAX {
+            if byte.is_ascii() {
+                assert_eq!(
+                    symbols.is_symbol(byte as char),
+                    symbols.is_symbol_byte(byte),
+                    "{:?}",
+                    byte as char
+                );
+            } else {
+                assert!(!symbols.is_symbol_byte(byte));
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_take_other_empty_and_gear_cells() -> miette::Result<()> {
+        let symbols = SymbolClassifier {
+            empty: '_',
+            gear: 'x',
+        };
+
+        assert!(symbols.is_symbol('.'));
+        assert!(!symbols.is_symbol('_'));
+        assert!(symbols.is_gear('x'));
+        assert!(!symbols.is_gear('*'));
+        Ok(())
+    }
+}
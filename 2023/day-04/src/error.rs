@@ -1,4 +1,4 @@
-
+use aoc_core::AtLine;
 use miette::Diagnostic;
 use thiserror::Error;
 
@@ -18,5 +18,73 @@ pub enum Error {
     #[error("Could not find card numer {0}")]
     CannotFindCardNumber(String),
     #[error("Could not parse card number {0}")]
-    CouldNotParseCardNumber(String)
-}
\ No newline at end of file
+    CouldNotParseCardNumber(String),
+}
+
+impl AtLine for Error {
+    fn at_line(self, line: usize) -> Self {
+        match self {
+            Error::CannotFindNumbers { .. } => Error::CannotFindNumbers { line },
+            Error::CannotFindWinningNumbers { .. } => Error::CannotFindWinningNumbers { line },
+            Error::CannotFindScratchedNumbers { .. } => Error::CannotFindScratchedNumbers { line },
+            other => other,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{part1, part1_opt, part2, part2_opt};
+
+    fn error_from(report: miette::Report) -> Error {
+        report.downcast::<Error>().expect("expected a day 4 error")
+    }
+
+    #[test]
+    fn it_should_reach_could_not_parse_number() -> miette::Result<()> {
+        let input = "Card 1: 41 x | 83 86";
+        for error in [
+            error_from(part1::process(input).unwrap_err()),
+            error_from(part2::process(input).unwrap_err()),
+        ] {
+            assert!(matches!(error, Error::CouldNotParseNumber(_)));
+        }
+
+        let error = error_from(part2_opt::process("Card 1: 99999999999 | 1").unwrap_err());
+        assert!(matches!(error, Error::CouldNotParseNumber(_)));
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_report_line_of_missing_scratched_numbers() -> miette::Result<()> {
+        let input = "Card 1: 41 48 | 83 86
+        Card 2: 13 32 | 61 30
+        Card 3: 41 92 73
+        Card 4: 87 83 | 88 30";
+        for error in [
+            error_from(part1::process(input).unwrap_err()),
+            error_from(part1_opt::process(input).unwrap_err()),
+            error_from(part2::process(input).unwrap_err()),
+            error_from(part2_opt::process(input).unwrap_err()),
+        ] {
+            assert!(matches!(
+                error,
+                Error::CannotFindScratchedNumbers { line: 3 }
+            ));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_reach_could_not_parse_card_number() -> miette::Result<()> {
+        let input = "Card x: 41 48 | 83 86";
+        for error in [
+            error_from(part2::process(input).unwrap_err()),
+            error_from(part2_opt::process(input).unwrap_err()),
+        ] {
+            assert!(matches!(error, Error::CouldNotParseCardNumber(_)));
+        }
+        Ok(())
+    }
+}
@@ -1,7 +1,10 @@
 pub mod error;
 pub mod prelude;
+pub mod scoring;
 
 pub mod part1;
 pub mod part2;
 pub mod part1_opt;
 pub mod part2_opt;
+
+aoc_core::solution!(Day04, 2023, 4, part1::process, part2::process);
@@ -1,3 +1,5 @@
+use aoc_core::{numbered_lines, Answer, AtLine};
+
 use crate::{error::Error, prelude::*};
 
 #[tracing::instrument]
@@ -8,7 +10,7 @@ fn parse_numbers(input: &str) -> Result<Vec<u32>> {
     let mut numbers = vec![];
     let mut number_start = 0;
 
-    for (i, c) in input.chars().enumerate() {
+    for (i, c) in input.char_indices() {
         if c.is_ascii_digit() {
             if !in_number {
                 in_number = true;
@@ -39,7 +41,7 @@ fn parse_numbers(input: &str) -> Result<Vec<u32>> {
 fn score_line(line: &str) -> Result<u32> {
     let numbers = line
         .split(':')
-        .last()
+        .next_back()
         .ok_or(Error::CannotFindNumbers { line: 0 })?;
 
     let mut numbers = numbers.split('|');
@@ -51,7 +53,7 @@ fn score_line(line: &str) -> Result<u32> {
     let winning_numbers = parse_numbers(winning_numbers)?;
 
     let scratch_numbers = numbers
-        .last()
+        .next_back()
         .ok_or(Error::CannotFindScratchedNumbers { line: 0 })?;
 
     let scratch_numbers = parse_numbers(scratch_numbers)?;
@@ -69,14 +71,13 @@ fn score_line(line: &str) -> Result<u32> {
 }
 
 #[tracing::instrument]
-pub fn process(input: &str) -> miette::Result<u32> {
-    let x = input
-        .lines()
-        .map(score_line)
+pub fn process(input: &str) -> miette::Result<Answer> {
+    let x: u32 = numbered_lines(input)
+        .map(|(number, line)| score_line(line).at_line(number))
         .collect::<Result<Vec<_>>>()
         .map(|v| v.iter().sum())?;
 
-    Ok(x)
+    Ok(Answer::from(x))
 }
 
 #[cfg(test)]
@@ -131,14 +132,14 @@ mod tests {
     //     Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
     //     Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
     //     Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
-    //     assert_eq!(13, process(input)?);
+    //     assert_eq!(Answer::UInt(13), process(input)?);
     //     Ok(())
     // }
 
     // #[test]
     // fn test_full() -> miette::Result<()> {
     //     let input = include_str!("../input1.txt");
-    //     assert_eq!(27845, process(input)?);
+    //     assert_eq!(Answer::UInt(27845), process(input)?);
     //     Ok(())
     // }
 }
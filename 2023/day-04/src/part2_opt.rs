@@ -1,5 +1,7 @@
 use std::collections::HashMap;
 
+use aoc_core::{numbered_lines, Answer, AtLine};
+
 use crate::{error::Error, prelude::*};
 
 #[tracing::instrument]
@@ -10,7 +12,7 @@ fn parse_numbers(input: &str) -> Result<Vec<u32>> {
     let mut numbers = vec![];
     let mut number_start = 0;
 
-    for (i, c) in input.chars().enumerate() {
+    for (i, c) in input.char_indices() {
         if c.is_ascii_digit() {
             if !in_number {
                 in_number = true;
@@ -67,7 +69,7 @@ impl Cards {
 }
 
 #[tracing::instrument]
-pub fn score_line(line: &str, cards: Cards) -> Result<Cards> {
+fn score_line(line: &str, cards: Cards) -> Result<Cards> {
     let mut cards = cards;
 
     let mut card_and_numbers = line.split(':');
@@ -76,7 +78,7 @@ pub fn score_line(line: &str, cards: Cards) -> Result<Cards> {
         .next()
         .ok_or_else(|| Error::CannotFindCardNumber(line.to_owned()))?
         .split(' ')
-        .last()
+        .next_back()
         .ok_or_else(|| Error::CannotFindCardNumber(line.to_owned()))?
         .parse::<u32>()
         .map_err(|_| Error::CouldNotParseCardNumber(line.to_owned()))?;
@@ -84,7 +86,7 @@ pub fn score_line(line: &str, cards: Cards) -> Result<Cards> {
     cards.add_card(card_number);
 
     let numbers = card_and_numbers
-        .last()
+        .next_back()
         .ok_or(Error::CannotFindNumbers { line: 0 })?;
 
     let mut numbers = numbers.split('|');
@@ -96,7 +98,7 @@ pub fn score_line(line: &str, cards: Cards) -> Result<Cards> {
     let winning_numbers = parse_numbers(winning_numbers)?;
 
     let scratch_numbers = numbers
-        .last()
+        .next_back()
         .ok_or(Error::CannotFindScratchedNumbers { line: 0 })?;
 
     let scratch_numbers = parse_numbers(scratch_numbers)?;
@@ -116,10 +118,10 @@ pub fn score_line(line: &str, cards: Cards) -> Result<Cards> {
 }
 
 #[tracing::instrument]
-pub fn process(input: &str) -> miette::Result<u32> {
-    let mut lines = input.lines();
-
-    let cards = lines.try_fold(Cards::new(), |cards, line| score_line(line.trim(), cards))?;
+pub fn process(input: &str) -> miette::Result<Answer> {
+    let cards = numbered_lines(input).try_fold(Cards::new(), |cards, (number, line)| {
+        score_line(line, cards).at_line(number)
+    })?;
 
     let card_count = input
         .lines()
@@ -127,7 +129,7 @@ pub fn process(input: &str) -> miette::Result<u32> {
         .map(|(i, _line)| cards.get_count(i as u32 + 1))
         .sum::<u32>();
 
-    Ok(card_count)
+    Ok(Answer::from(card_count))
 }
 
 #[cfg(test)]
@@ -142,7 +144,7 @@ mod tests {
         Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
         Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
         Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
-        assert_eq!(30, process(input)?);
+        assert_eq!(Answer::UInt(30), process(input)?);
         Ok(())
     }
 }
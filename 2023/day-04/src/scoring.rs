@@ -0,0 +1,141 @@
+use aoc_core::{numbered_lines, AtLine};
+
+use crate::{error::Error, prelude::*};
+
+/// How a pile of scratchcards turns into a single number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Strategy {
+    /// One point for the first match, doubled for each match after it.
+    Doubling,
+    /// Each match wins a copy of one of the following cards; counts every
+    /// card you end up holding.
+    CopyCascade,
+    /// One point per match.
+    Linear,
+}
+
+/// A card's number and how many of its scratched numbers are winners.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Card {
+    pub number: u32,
+    pub matches: u32,
+}
+
+#[tracing::instrument]
+fn parse_numbers(numbers: &str) -> Result<Vec<u32>> {
+    numbers
+        .split(' ')
+        .filter(|n| !n.is_empty())
+        .map(|n| {
+            n.parse::<u32>()
+                .map_err(|_| Error::CouldNotParseNumber(n.to_string()))
+        })
+        .collect()
+}
+
+impl Card {
+    #[tracing::instrument]
+    pub fn parse(line: &str) -> Result<Self> {
+        let mut card_and_numbers = line.split(':');
+
+        let number = card_and_numbers
+            .next()
+            .ok_or_else(|| Error::CannotFindCardNumber(line.to_owned()))?
+            .split(' ')
+            .next_back()
+            .ok_or_else(|| Error::CannotFindCardNumber(line.to_owned()))?
+            .parse::<u32>()
+            .map_err(|_| Error::CouldNotParseCardNumber(line.to_owned()))?;
+
+        let mut numbers = card_and_numbers
+            .next_back()
+            .ok_or(Error::CannotFindNumbers { line: 0 })?
+            .split('|');
+
+        let winning_numbers = parse_numbers(
+            numbers
+                .next()
+                .ok_or(Error::CannotFindWinningNumbers { line: 0 })?,
+        )?;
+
+        let scratch_numbers = parse_numbers(
+            numbers
+                .next_back()
+                .ok_or(Error::CannotFindScratchedNumbers { line: 0 })?,
+        )?;
+
+        let matches = winning_numbers
+            .iter()
+            .filter(|n| scratch_numbers.contains(n))
+            .count() as u32;
+
+        Ok(Self { number, matches })
+    }
+}
+
+#[tracing::instrument(skip(cards))]
+fn cascade(cards: &[Card]) -> u32 {
+    let mut copies = vec![1; cards.len()];
+
+    for (i, card) in cards.iter().enumerate() {
+        let held = copies[i];
+        for won in copies.iter_mut().skip(i + 1).take(card.matches as usize) {
+            *won += held;
+        }
+    }
+
+    copies.iter().sum()
+}
+
+/// Scores every card in `input` with `strategy`.
+#[tracing::instrument(skip(input))]
+pub fn score(input: &str, strategy: Strategy) -> miette::Result<u32> {
+    let cards = numbered_lines(input)
+        .map(|(number, line)| Card::parse(line).at_line(number))
+        .collect::<Result<Vec<_>>>()?;
+
+    let score = match strategy {
+        Strategy::Doubling => cards
+            .iter()
+            .filter(|card| card.matches > 0)
+            .map(|card| 1 << (card.matches - 1))
+            .sum(),
+        Strategy::CopyCascade => cascade(&cards),
+        Strategy::Linear => cards.iter().map(|card| card.matches).sum(),
+    };
+
+    Ok(score)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    const EXAMPLE: &str = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+        Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+        Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+        Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+        Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+        Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
+
+    #[test]
+    fn it_should_count_matches() -> miette::Result<()> {
+        assert_eq!(
+            Card {
+                number: 3,
+                matches: 2
+            },
+            Card::parse("Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1")?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_score_with_each_strategy() -> miette::Result<()> {
+        assert_eq!(13, score(EXAMPLE, Strategy::Doubling)?);
+        assert_eq!(30, score(EXAMPLE, Strategy::CopyCascade)?);
+        assert_eq!(4 + 2 + 2 + 1, score(EXAMPLE, Strategy::Linear)?);
+        Ok(())
+    }
+}
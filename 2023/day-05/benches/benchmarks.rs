@@ -6,9 +6,20 @@ fn main() {
 }
 
 #[divan::bench]
-fn part1() {
-    part1::process(divan::black_box(include_str!(
-        "../input1.txt",
-    )))
-    .unwrap();
+fn part1(bencher: divan::Bencher) {
+    let input = aoc_core::puzzle_input!("input1.txt").unwrap();
+    bencher.bench(|| part1::process(divan::black_box(&input)).unwrap());
+}
+
+#[divan::bench]
+fn part1_parse(bencher: divan::Bencher) {
+    let input = aoc_core::puzzle_input!("input1.txt").unwrap();
+    bencher.bench(|| part1::parse(divan::black_box(&input)).unwrap());
+}
+
+#[divan::bench]
+fn part1_solve(bencher: divan::Bencher) {
+    let input = aoc_core::puzzle_input!("input1.txt").unwrap();
+    let data = aoc_core::preload(&input, part1::parse).unwrap();
+    bencher.bench(|| part1::solve(divan::black_box(&data)).unwrap());
 }
@@ -0,0 +1,47 @@
+use std::time::Duration;
+
+use aoc_core::{Attempt, Outcome};
+
+pub const ATTEMPTS: &[Attempt] = &[
+    Attempt {
+        name: "part1_attempt_1",
+        date: "2023-12-05",
+        timing: None,
+        outcome: Outcome::Replaced {
+            by: "part1",
+            why: "its line cursor parser became aoc-input's HeaderAndBody; the file is kept for reference but not compiled",
+        },
+    },
+    Attempt {
+        name: "part1",
+        date: "2023-12-05",
+        timing: Some(Duration::from_nanos(36_020)),
+        outcome: Outcome::Kept,
+    },
+    Attempt {
+        name: "part1_opt",
+        date: "2023-12-05",
+        timing: Some(Duration::from_nanos(37_180)),
+        outcome: Outcome::Abandoned {
+            why: "its median moved between 27 and 61 µs across runs and never clearly beat part1",
+        },
+    },
+    Attempt {
+        name: "part2",
+        date: "2023-12-05",
+        timing: None,
+        outcome: Outcome::Kept,
+    },
+    Attempt {
+        name: "part2_opt",
+        date: "2026-10-16",
+        timing: None,
+        outcome: Outcome::Kept,
+    },
+    Attempt {
+        name: "part2_bruteforce",
+        date: "2026-10-16",
+        timing: None,
+        outcome: Outcome::Kept,
+    },
+];
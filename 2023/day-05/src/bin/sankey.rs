@@ -0,0 +1,11 @@
+use day_05::sankey::flow;
+use miette::Context;
+
+/// Prints the part 2 range flow as Graphviz, e.g. `cargo run --bin sankey | dot -Tsvg`.
+#[tracing::instrument]
+fn main() -> miette::Result<()> {
+    let file = aoc_core::puzzle_input!("input2.txt")?;
+    let flow = flow(&file).context("build range flow")?;
+    print!("{}", flow.to_dot());
+    Ok(())
+}
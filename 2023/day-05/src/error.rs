@@ -9,14 +9,104 @@ pub enum Error {
     IoError(#[from] std::io::Error),
     #[error("Could not parse number {0}")]
     CouldNotParseNumber(#[from] std::num::ParseIntError),
-    #[error("Next line no available, line {0}")]
-    CannotFindNextLine(usize),
+    #[error(transparent)]
+    #[diagnostic(transparent)]
+    Input(#[from] aoc_input::Error),
     #[error("Could not find seeds header")]
     CannotFindSeedsHeader,
     #[error("Cannot find map hearder")]
     CannotFindMapHeader,
     #[error("Unexpected number of values for map {0}")]
     UnexpectedNumberOfValuesForMap(String),
+    #[error("Expected 7 maps, found {0}")]
+    UnexpectedNumberOfMaps(usize),
     #[error("No min value")]
     NoMinValue
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{part1, part2};
+
+    fn error_from(report: miette::Report) -> Error {
+        report.downcast::<Error>().expect("expected a day 5 error")
+    }
+
+    fn assert_reaches(input: &str, expected: fn(&Error) -> bool) {
+        for error in [
+            error_from(part1::process(input).unwrap_err()),
+            error_from(part2::process(input).unwrap_err()),
+        ] {
+            assert!(expected(&error), "unexpected error {:?}", error);
+        }
+    }
+
+    #[test]
+    fn it_should_reach_cannot_find_seeds_header() -> miette::Result<()> {
+        assert_reaches("soil: 1 2", |e| matches!(e, Error::CannotFindSeedsHeader));
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_reach_could_not_parse_number() -> miette::Result<()> {
+        assert_reaches("seeds: 1 x", |e| matches!(e, Error::CouldNotParseNumber(_)));
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_reach_missing_separator() -> miette::Result<()> {
+        assert_reaches("seeds: 1 2\nseed-to-soil map:", |e| {
+            matches!(
+                e,
+                Error::Input(aoc_input::Error::MissingSeparator { line: 2 })
+            )
+        });
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_reach_unexpected_number_of_maps() -> miette::Result<()> {
+        assert_reaches("seeds: 1 2", |e| {
+            matches!(e, Error::UnexpectedNumberOfMaps(0))
+        });
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_reach_cannot_find_map_header() -> miette::Result<()> {
+        assert_reaches("seeds: 1 2\n\n50 98 2", |e| {
+            matches!(e, Error::CannotFindMapHeader)
+        });
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_reach_unexpected_number_of_values_for_map() -> miette::Result<()> {
+        assert_reaches("seeds: 1 2\n\nseed-to-soil map:\n50 98", |e| {
+            matches!(e, Error::UnexpectedNumberOfValuesForMap(_))
+        });
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_reach_no_min_value() -> miette::Result<()> {
+        let input = "seeds:
+
+        seed-to-soil map:
+
+        soil-to-fertilizer map:
+
+        fertilizer-to-water map:
+
+        water-to-light map:
+
+        light-to-temperature map:
+
+        temperature-to-humidity map:
+
+        humidity-to-location map:";
+        assert_reaches(input, |e| matches!(e, Error::NoMinValue));
+        Ok(())
+    }
+}
@@ -1,6 +1,36 @@
 pub mod error;
 pub mod prelude;
 
+pub mod attempts;
+pub mod map_range;
 pub mod part1;
 pub mod part2;
+pub mod part2_bruteforce;
 pub mod part2_opt;
+pub mod sankey;
+pub mod seeds;
+
+/// Day 5 behind the shared `aoc_core::Solution` interface. Both parts read
+/// the almanac part 1 parses, part 2 through `part2_opt`'s ranges, since the
+/// reference `part2` lists every seed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Day05;
+
+impl aoc_core::Solution for Day05 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 5;
+
+    type Parsed<'a> = part1::Data;
+
+    fn parse(input: &str) -> aoc_core::solution::Result<part1::Data> {
+        part1::parse(input)
+    }
+
+    fn part1(almanac: &part1::Data) -> aoc_core::solution::Result<aoc_core::Answer> {
+        Ok(part1::solve(almanac)?.into())
+    }
+
+    fn part2(almanac: &part1::Data) -> aoc_core::solution::Result<aoc_core::Answer> {
+        Ok(part2_opt::solve(almanac)?.into())
+    }
+}
@@ -0,0 +1,172 @@
+use aoc_input::parse::{self, number, ws, Reason};
+use aoc_ranges::Interval;
+use nom::{
+    character::complete::space1,
+    sequence::{preceded, tuple},
+};
+
+use crate::{error::Error, prelude::*};
+
+/// One line of a map: the values in `source` move to start at
+/// `destination_start`, keeping their order. Every part reads ranges through
+/// this, so there's one answer to whether the last value is in or out: the
+/// source is half-open, and `source.end` is the first value it doesn't move.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MapRange {
+    pub destination_start: u64,
+    pub source: Interval<u64>,
+}
+
+impl MapRange {
+    /// In the puzzle's order: destination start, source start, length.
+    #[tracing::instrument]
+    pub fn new(destination_start: u64, source_start: u64, range: u64) -> MapRange {
+        MapRange {
+            destination_start,
+            source: Interval::new(source_start, source_start + range),
+        }
+    }
+
+    #[tracing::instrument]
+    pub fn parse(line: &str) -> Result<MapRange> {
+        let (destination_start, source_start, range) = parse::finish(
+            ws(tuple((
+                number,
+                preceded(space1, number),
+                preceded(space1, number),
+            ))),
+            line,
+        )
+        .map_err(|failure| match failure.reason {
+            Reason::Number(e) => Error::CouldNotParseNumber(e),
+            Reason::Expected(_) => Error::UnexpectedNumberOfValuesForMap(line.to_string()),
+        })?;
+
+        Ok(MapRange::new(destination_start, source_start, range))
+    }
+
+    #[tracing::instrument]
+    pub fn contains_value(&self, value: u64) -> bool {
+        self.source.contains(value)
+    }
+
+    /// Where `value` moves to, or `value` itself when it's not in the source.
+    #[tracing::instrument]
+    pub fn map_value(&self, value: u64) -> u64 {
+        if !self.contains_value(value) {
+            return value;
+        }
+
+        self.destination_start + (value - self.source.start)
+    }
+
+    /// Where the whole source moves to.
+    #[tracing::instrument]
+    pub fn destination(&self) -> Interval<u64> {
+        self.map_interval(self.source)
+    }
+
+    /// Where `interval` moves to. It must lie inside the source, like an
+    /// intersection with it.
+    #[tracing::instrument]
+    pub fn map_interval(&self, interval: Interval<u64>) -> Interval<u64> {
+        let start = self.map_value(interval.start);
+
+        Interval::new(start, start + interval.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_testing::{interval, values};
+    use pretty_assertions::assert_eq;
+    use proptest::prelude::*;
+    use std::collections::BTreeSet;
+
+    #[test]
+    fn it_should_parse_in_puzzle_order() -> miette::Result<()> {
+        assert_eq!(MapRange::new(50, 98, 2), MapRange::parse("50 98 2")?);
+        assert_eq!(Interval::new(98, 100), MapRange::parse("50 98 2")?.source);
+        assert!(matches!(
+            MapRange::parse("50 98"),
+            Err(Error::UnexpectedNumberOfValuesForMap(_))
+        ));
+        assert!(matches!(
+            MapRange::parse("50 98 2 1"),
+            Err(Error::UnexpectedNumberOfValuesForMap(_))
+        ));
+        assert!(matches!(
+            MapRange::parse("50 x 2"),
+            Err(Error::CouldNotParseNumber(_))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_only_move_values_from_start_up_to_end() -> miette::Result<()> {
+        let map_range = MapRange::new(52, 50, 48);
+
+        for value in 0..120 {
+            let inside = (50..98).contains(&value);
+            let expected = if inside { value + 2 } else { value };
+
+            assert_eq!(inside, map_range.contains_value(value), "value {}", value);
+            assert_eq!(expected, map_range.map_value(value), "value {}", value);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_move_intervals_inside_the_source() -> miette::Result<()> {
+        let map_range = MapRange::new(70, 50, 5);
+
+        assert_eq!(Interval::new(70, 75), map_range.destination());
+        assert_eq!(
+            Interval::new(71, 73),
+            map_range.map_interval(Interval::new(51, 53))
+        );
+        assert_eq!(
+            Interval::new(74, 75),
+            map_range.map_interval(Interval::new(54, 55))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_move_nothing_with_zero_length() -> miette::Result<()> {
+        let map_range = MapRange::new(70, 50, 0);
+
+        assert!(map_range.source.is_empty());
+        assert!(map_range.destination().is_empty());
+        assert!(!map_range.contains_value(50));
+        assert_eq!(50, map_range.map_value(50));
+        Ok(())
+    }
+
+    proptest! {
+        #[test]
+        fn it_should_move_an_overlap_value_by_value(
+            destination_start in 0u64..100,
+            source in interval(0..100),
+            other in interval(0..100),
+        ) {
+            let map_range = MapRange { destination_start, source };
+            let overlap = source.intersection(&other);
+
+            prop_assert_eq!(
+                &values(&[source]) & &values(&[other]),
+                values(overlap.as_slice())
+            );
+
+            if let Some(overlap) = overlap {
+                let moved = values(&[overlap])
+                    .into_iter()
+                    .map(|value| map_range.map_value(value))
+                    .collect::<BTreeSet<_>>();
+
+                prop_assert_eq!(moved, values(&[map_range.map_interval(overlap)]));
+            }
+        }
+    }
+}
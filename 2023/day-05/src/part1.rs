@@ -1,44 +1,7 @@
-use crate::{error::Error, prelude::*};
+use aoc_core::{Answer, Footprint, SkipDebug};
+use aoc_input::{normalize, HeaderAndBody};
 
-#[derive(Debug, Clone, PartialEq, Eq)]
-struct Input {
-    lines: Vec<String>,
-    cursor: usize,
-}
-
-impl Input {
-    #[tracing::instrument]
-    fn from_str(input: &str) -> Result<Input> {
-        let lines = input
-            .lines()
-            .map(|s| s.trim().to_string())
-            .collect::<Vec<_>>();
-
-        Ok(Input { lines, cursor: 0 })
-    }
-
-    #[tracing::instrument]
-    fn peak(&self) -> Option<&String> {
-        self.lines.get(self.cursor)
-    }
-
-    #[tracing::instrument]
-    fn next(&mut self) -> Result<&String> {
-        let next = self
-            .lines
-            .get(self.cursor)
-            .ok_or_else(|| Error::CannotFindNextLine(self.cursor));
-        self.cursor += 1;
-        next
-    }
-
-    #[tracing::instrument]
-    fn to_string(&self) -> String {
-        self.lines.join("\n")
-    }
-}
-
-type ParserOutput<T> = (T, Input);
+use crate::{error::Error, map_range::MapRange, prelude::*, seeds};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 struct Seeds {
@@ -47,105 +10,43 @@ struct Seeds {
 
 impl Seeds {
     #[tracing::instrument]
-    fn from_input(mut input: Input) -> Result<ParserOutput<Seeds>> {
-        let first_line = input.next().map_err(|_| Error::CannotFindSeedsHeader)?;
-
-        if !first_line.starts_with("seeds:") {
-            return Err(Error::CannotFindSeedsHeader);
-        }
-
-        let seeds = first_line
-            .split(':')
-            .last()
-            .ok_or_else(|| Error::CannotFindSeedsHeader)?
-            .split(' ')
-            .filter(|s| !s.is_empty())
-            .map(|s| s.trim().parse::<u64>().map_err(Error::CouldNotParseNumber))
-            .collect::<Result<Vec<_>>>()?;
+    fn from_header(first_line: &str) -> Result<Seeds> {
+        let seeds = seeds::parse(first_line)?;
 
-        Ok((Seeds { seeds }, input))
+        Ok(Seeds { seeds })
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
-struct MapRange {
-    destination_start: u64,
-    source_start: u64,
-    range: u64,
+struct Map {
+    mapped_ranges: Vec<MapRange>,
 }
 
-impl MapRange {
-    #[tracing::instrument]
-    fn new(destination_start: u64, source_start: u64, range: u64) -> Result<MapRange> {
-        Ok(MapRange {
-            destination_start,
-            source_start,
-            range,
-        })
-    }
-
-    #[tracing::instrument]
-    fn contains_value(&self, value: u64) -> bool {
-        value >= self.source_start && value < self.source_start + self.range
-    }
-
-    #[tracing::instrument]
-    fn map_value(&self, value: u64) -> u64 {
-        if !self.contains_value(value) {
-            return value;
-        }
-
-        let offset = value - self.source_start;
-        let destination = self.destination_start + offset;
-
-        destination
+impl Footprint for Map {
+    fn footprint(&self) -> usize {
+        self.mapped_ranges.len()
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
-struct Map {
-    mapped_ranges: Vec<MapRange>,
-}
-
 impl Map {
-    #[tracing::instrument]
-    fn from_input(mut input: Input) -> Result<ParserOutput<Map>> {
+    #[tracing::instrument(skip(block), fields(block = ?SkipDebug(block)))]
+    fn from_block(block: &[&str]) -> Result<Map> {
         let mut mapped_ranges = Vec::new();
 
-        if !input.next()?.ends_with("map:") {
+        let (header, lines) = block.split_first().ok_or(Error::CannotFindMapHeader)?;
+
+        if !header.ends_with("map:") {
             return Err(Error::CannotFindMapHeader);
         }
 
-        while let Some(line) = input.peak() {
-            if line.is_empty() {
-                break;
-            }
-
-            let line = input.next()?;
-
-            let numbers = line
-                .split(' ')
-                .filter(|s| !s.is_empty())
-                .map(|s| s.trim().parse::<u64>().map_err(Error::CouldNotParseNumber))
-                .collect::<Result<Vec<_>>>()?;
-
-            if numbers.len() != 3 {
-                return Err(Error::UnexpectedNumberOfValuesForMap(line.to_string()));
-            }
-
-            let destination_start = numbers[0];
-            let source_start = numbers[1];
-            let range = numbers[2];
-
-            let map_range = MapRange::new(destination_start, source_start, range)?;
-
-            mapped_ranges.push(map_range);            
+        for line in lines {
+            mapped_ranges.push(MapRange::parse(line)?);
         }
 
-        Ok((Map { mapped_ranges }, input))
+        Ok(Map { mapped_ranges })
     }
 
-    #[tracing::instrument]
+    #[tracing::instrument(skip(self), fields(map = ?SkipDebug(self)))]
     fn get_mapped_value(&self, value: u64) -> u64 {
         self.mapped_ranges
             .iter()
@@ -156,7 +57,7 @@ impl Map {
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
-struct Data {
+pub struct Data {
     seeds: Seeds,
     seed_to_soil_map: Map,
     soil_to_fertilizer_map: Map,
@@ -167,58 +68,81 @@ struct Data {
     humidity_to_location_map: Map,
 }
 
-impl Data {
-    #[tracing::instrument]
-    fn from_input(input: Input) -> Result<Data> {
-        let (seeds, mut input) = Seeds::from_input(input)?;
-
-        input.next()?;
-
-        let (seed_to_soil_map, mut input) = Map::from_input(input)?;
-
-        input.next()?;
-
-        let (soil_to_fertilizer_map, mut input) = Map::from_input(input)?;
-
-        input.next()?;
-
-        let (fertilizer_to_water_map, mut input) = Map::from_input(input)?;
-
-        input.next()?;
-
-        let (water_to_light_map, mut input) = Map::from_input(input)?;
-
-        input.next()?;
+impl Footprint for Data {
+    fn footprint(&self) -> usize {
+        [
+            &self.seed_to_soil_map,
+            &self.soil_to_fertilizer_map,
+            &self.fertilizer_to_water_map,
+            &self.water_to_light_map,
+            &self.light_to_temperature_map,
+            &self.temparure_to_humity_map,
+            &self.humidity_to_location_map,
+        ]
+        .iter()
+        .map(|map| map.footprint())
+        .sum()
+    }
+}
 
-        let (light_to_temperature_map, mut input) = Map::from_input(input)?;
+impl Data {
+    #[tracing::instrument(skip(input))]
+    fn from_str(input: &str) -> Result<Data> {
+        let input = normalize(input);
+        let input = HeaderAndBody::parse(&input)?;
 
-        input.next()?;
+        let seeds = Seeds::from_header(input.header)?;
 
-        let (temparure_to_humity_map, mut input) = Map::from_input(input)?;
+        let maps = input
+            .blocks
+            .iter()
+            .map(|block| Map::from_block(block))
+            .collect::<Result<Vec<_>>>()?;
 
-        input.next()?;
+        let maps =
+            <[Map; 7]>::try_from(maps).map_err(|maps| Error::UnexpectedNumberOfMaps(maps.len()))?;
 
-        let (humidity_to_location_map, _) = Map::from_input(input)?;
+        let [soil, fertilizer, water, light, temperature, humidity, location] = maps;
 
         Ok(Data {
             seeds,
-            seed_to_soil_map,
-            soil_to_fertilizer_map,
-            fertilizer_to_water_map,
-            water_to_light_map,
-            light_to_temperature_map,
-            temparure_to_humity_map,
-            humidity_to_location_map,
+            seed_to_soil_map: soil,
+            soil_to_fertilizer_map: fertilizer,
+            fertilizer_to_water_map: water,
+            water_to_light_map: light,
+            light_to_temperature_map: temperature,
+            temparure_to_humity_map: humidity,
+            humidity_to_location_map: location,
         })
     }
 
-    #[tracing::instrument]
+    #[tracing::instrument(skip(self), fields(data = ?SkipDebug(self)))]
     fn seeds(&self) -> &Seeds {
         &self.seeds
     }
 
-    #[tracing::instrument]
-    fn map_seed(&self, seed: u64) -> u64 {
+    /// The seed line as plain numbers, for parts that read it as pairs.
+    #[tracing::instrument(skip(self), fields(data = ?SkipDebug(self)))]
+    pub(crate) fn seed_numbers(&self) -> &[u64] {
+        &self.seeds.seeds
+    }
+
+    /// Each map's ranges, in the order a seed passes through them.
+    #[tracing::instrument(skip(self), fields(data = ?SkipDebug(self)))]
+    pub(crate) fn map_ranges(&self) -> [&[MapRange]; 7] {
+        [
+            &self.seed_to_soil_map.mapped_ranges,
+            &self.soil_to_fertilizer_map.mapped_ranges,
+            &self.fertilizer_to_water_map.mapped_ranges,
+            &self.water_to_light_map.mapped_ranges,
+            &self.light_to_temperature_map.mapped_ranges,
+            &self.temparure_to_humity_map.mapped_ranges,
+            &self.humidity_to_location_map.mapped_ranges,
+        ]
+    }
+
+    #[tracing::instrument(skip(self), fields(data = ?SkipDebug(self)))]
+    pub(crate) fn map_seed(&self, seed: u64) -> u64 {
         let soil = self.seed_to_soil_map.get_mapped_value(seed);
         let fertilizer = self.soil_to_fertilizer_map.get_mapped_value(soil);
         let water = self.fertilizer_to_water_map.get_mapped_value(fertilizer);
@@ -230,12 +154,14 @@ impl Data {
     }
 }
 
-#[tracing::instrument]
-pub fn process(input: &str) -> miette::Result<u64> {
-    let input = Input::from_str(input)?;
-
-    let data = Data::from_input(input)?;
+/// The almanac, parsed apart from `solve` so the two can be benchmarked separately.
+#[tracing::instrument(skip(input))]
+pub fn parse(input: &str) -> miette::Result<Data> {
+    Ok(Data::from_str(input)?)
+}
 
+#[tracing::instrument(skip(data), fields(data = ?SkipDebug(data)))]
+pub fn solve(data: &Data) -> miette::Result<u64> {
     let min_location = data
         .seeds()
         .seeds
@@ -247,6 +173,11 @@ pub fn process(input: &str) -> miette::Result<u64> {
     Ok(min_location)
 }
 
+#[tracing::instrument]
+pub fn process(input: &str) -> miette::Result<Answer> {
+    solve(&parse(input)?).map(Answer::from)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -254,8 +185,7 @@ mod tests {
 
     #[test]
     fn it_should_parse_seed() -> miette::Result<()> {
-        let input = Input::from_str("seeds: 79 14 55 13")?;
-        let (seeds, _) = Seeds::from_input(input)?;
+        let seeds = Seeds::from_header("seeds: 79 14 55 13")?;
         assert_eq!(vec![79, 14, 55, 13], seeds.seeds);
 
         Ok(())
@@ -263,13 +193,7 @@ mod tests {
 
     #[test]
     fn it_should_parse_map() -> miette::Result<()> {
-        let input = Input::from_str(
-            "seed-to-soil map:
-        50 98 2
-        52 50 48",
-        )?;
-
-        let (map, _) = Map::from_input(input)?;
+        let map = Map::from_block(&["seed-to-soil map:", "50 98 2", "52 50 48"])?;
 
         assert_eq!(map.get_mapped_value(0), 0);
         assert_eq!(map.get_mapped_value(1), 1);
@@ -295,23 +219,30 @@ mod tests {
     #[test]
     fn it_should_process_data() -> miette::Result<()> {
         let input = include_str!("../example1.txt");
-        let input = Input::from_str(input)?;
 
-        let data = Data::from_input(input)?;
+        let data = Data::from_str(input)?;
 
         assert_eq!(data.map_seed(79), 82);
         assert_eq!(data.map_seed(14), 43);
         assert_eq!(data.map_seed(55), 86);
         assert_eq!(data.map_seed(13), 35);
 
-        // assert_eq!(35, process(input)?);
+        // assert_eq!(Answer::UInt(35), process(input)?);
         Ok(())
     }
 
     #[test]
     fn test_process() -> miette::Result<()> {
         let input = include_str!("../example1.txt");
-        assert_eq!(35, process(input)?);
+        assert_eq!(Answer::UInt(35), process(input)?);
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_process_hand_edited_line_endings() -> miette::Result<()> {
+        let input = include_str!("../example1.txt").replace('\n', "\r\n");
+        let input = format!("\u{feff}{}", input);
+        assert_eq!(Answer::UInt(35), process(&input)?);
         Ok(())
     }
 }
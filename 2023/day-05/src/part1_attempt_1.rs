@@ -1,34 +1,45 @@
 use std::collections::HashMap;
 
+use aoc_core::{Answer, Footprint, SkipDebug};
+
 use crate::{error::Error, prelude::*};
 
-#[derive(Debug, Clone, PartialEq, Eq)]
-struct Input {
-    lines: Vec<String>,
+#[derive(Clone, PartialEq, Eq)]
+struct Input<'a> {
+    lines: Vec<&'a str>,
     cursor: usize,
 }
 
-impl Input {
+// Every parser method is instrumented, so keep this to a size and position
+// rather than formatting the whole almanac into each span.
+impl std::fmt::Debug for Input<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Input")
+            .field("lines", &self.lines.len())
+            .field("cursor", &self.cursor)
+            .finish()
+    }
+}
+
+impl<'a> Input<'a> {
     #[tracing::instrument]
-    fn from_str(input: &str) -> Result<Input> {
-        let lines = input
-            .lines()
-            .map(|s| s.trim().to_string())
-            .collect::<Vec<_>>();
+    fn from_str(input: &'a str) -> Result<Input<'a>> {
+        let lines = input.lines().map(|s| s.trim()).collect::<Vec<_>>();
 
         Ok(Input { lines, cursor: 0 })
     }
 
     #[tracing::instrument]
-    fn peak(&self) -> Option<&String> {
-        self.lines.get(self.cursor)
+    fn peak(&self) -> Option<&'a str> {
+        self.lines.get(self.cursor).copied()
     }
 
     #[tracing::instrument]
-    fn next(&mut self) -> Result<&String> {
+    fn next(&mut self) -> Result<&'a str> {
         let next = self
             .lines
             .get(self.cursor)
+            .copied()
             .ok_or_else(|| Error::CannotFindNextLine(self.cursor));
         self.cursor += 1;
         next
@@ -40,7 +51,7 @@ impl Input {
     }
 }
 
-type ParserOutput<T> = (T, Input);
+type ParserOutput<'a, T> = (T, Input<'a>);
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 struct Seeds {
@@ -49,7 +60,7 @@ struct Seeds {
 
 impl Seeds {
     #[tracing::instrument]
-    fn from_input(mut input: Input) -> Result<ParserOutput<Seeds>> {
+    fn from_input(mut input: Input) -> Result<ParserOutput<'_, Seeds>> {
         let first_line = input.next().map_err(|_| Error::CannotFindSeedsHeader)?;
 
         if !first_line.starts_with("seeds:") {
@@ -74,9 +85,15 @@ struct Map {
     mapped_values: HashMap<u32, u32>,
 }
 
+impl Footprint for Map {
+    fn footprint(&self) -> usize {
+        self.mapped_values.len()
+    }
+}
+
 impl Map {
     #[tracing::instrument]
-    fn from_input(mut input: Input) -> Result<ParserOutput<Map>> {
+    fn from_input(mut input: Input) -> Result<ParserOutput<'_, Map>> {
         let mut mapped_values = HashMap::new();
 
         if !input.next()?.ends_with("map:") {
@@ -117,7 +134,7 @@ impl Map {
         Ok((Map { mapped_values }, input))
     }
 
-    #[tracing::instrument]
+    #[tracing::instrument(skip(self), fields(map = ?SkipDebug(self)))]
     fn get_mapped_value(&self, value: u32) -> u32 {
         *self.mapped_values.get(&value).unwrap_or(&value)
     }
@@ -135,6 +152,23 @@ struct Data {
     humidity_to_location_map: Map,
 }
 
+impl Footprint for Data {
+    fn footprint(&self) -> usize {
+        [
+            &self.seed_to_soil_map,
+            &self.soil_to_fertilizer_map,
+            &self.fertilizer_to_water_map,
+            &self.water_to_light_map,
+            &self.light_to_temperature_map,
+            &self.temparure_to_humity_map,
+            &self.humidity_to_location_map,
+        ]
+        .iter()
+        .map(|map| map.footprint())
+        .sum()
+    }
+}
+
 impl Data {
     #[tracing::instrument]
     fn from_input(input: Input) -> Result<Data> {
@@ -196,12 +230,12 @@ impl Data {
         })
     }
 
-    #[tracing::instrument]
+    #[tracing::instrument(skip(self), fields(data = ?SkipDebug(self)))]
     fn seeds(&self) -> &Seeds {
         &self.seeds
     }
 
-    #[tracing::instrument]
+    #[tracing::instrument(skip(self), fields(data = ?SkipDebug(self)))]
     fn map_seed(&self, seed: u32) -> u32 {
         let soil = self.seed_to_soil_map.get_mapped_value(seed);
         let fertilizer = self.soil_to_fertilizer_map.get_mapped_value(soil);
@@ -221,7 +255,7 @@ impl Data {
 }
 
 #[tracing::instrument]
-pub fn process(input: &str) -> miette::Result<u32> {
+pub fn process(input: &str) -> miette::Result<Answer> {
     let input = Input::from_str(input)?;
 
     let data = Data::from_input(input)?;
@@ -234,7 +268,7 @@ pub fn process(input: &str) -> miette::Result<u32> {
         .min()
         .ok_or(Error::NoMinValue)?;
 
-    Ok(min_location)
+    Ok(Answer::from(min_location))
 }
 
 #[cfg(test)]
@@ -294,14 +328,14 @@ mod tests {
         assert_eq!(data.map_seed(55), 86);
         assert_eq!(data.map_seed(13), 35);
 
-        // assert_eq!(35, process(input)?);
+        // assert_eq!(Answer::UInt(35), process(input)?);
         Ok(())
     }
 
     #[test]
     fn test_process() -> miette::Result<()> {
         let input = include_str!("../example1.txt");
-        assert_eq!(35, process(input)?);
+        assert_eq!(Answer::UInt(35), process(input)?);
         Ok(())
     }
 }
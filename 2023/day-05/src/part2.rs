@@ -1,45 +1,8 @@
-use crate::{error::Error, prelude::*};
-use rayon::prelude::*;
-
-#[derive(Debug, Clone, PartialEq, Eq)]
-struct Input {
-    lines: Vec<String>,
-    cursor: usize,
-}
+use aoc_core::{Answer, Footprint, SkipDebug, StreamingMin};
+use aoc_input::{normalize, HeaderAndBody};
 
-impl Input {
-    #[tracing::instrument]
-    fn from_str(input: &str) -> Result<Input> {
-        let lines = input
-            .lines()
-            .map(|s| s.trim().to_string())
-            .collect::<Vec<_>>();
-
-        Ok(Input { lines, cursor: 0 })
-    }
-
-    #[tracing::instrument]
-    fn peak(&self) -> Option<&String> {
-        self.lines.get(self.cursor)
-    }
-
-    #[tracing::instrument]
-    fn next(&mut self) -> Result<&String> {
-        let next = self
-            .lines
-            .get(self.cursor)
-            .ok_or_else(|| Error::CannotFindNextLine(self.cursor));
-        self.cursor += 1;
-        next
-    }
-
-    #[tracing::instrument]
-    fn to_string(&self) -> String {
-        self.lines.join("\n")
-    }
-}
-
-type ParserOutput<T> = (T, Input);
+use crate::{error::Error, map_range::MapRange, prelude::*, seeds};
+use rayon::prelude::*;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 struct Seeds {
@@ -48,21 +11,8 @@ struct Seeds {
 
 impl Seeds {
     #[tracing::instrument]
-    fn from_input(mut input: Input) -> Result<ParserOutput<Seeds>> {
-        let first_line = input.next().map_err(|_| Error::CannotFindSeedsHeader)?;
-
-        if !first_line.starts_with("seeds:") {
-            return Err(Error::CannotFindSeedsHeader);
-        }
-
-        let seed_pairs = first_line
-            .split(':')
-            .last()
-            .ok_or_else(|| Error::CannotFindSeedsHeader)?
-            .split(' ')
-            .filter(|s| !s.is_empty())
-            .map(|s| s.trim().parse::<u64>().map_err(Error::CouldNotParseNumber))
-            .collect::<Result<Vec<_>>>()?;
+    fn from_header(first_line: &str) -> Result<Seeds> {
+        let seed_pairs = seeds::parse(first_line)?;
 
         let mut seeds = Vec::new();
 
@@ -75,89 +25,40 @@ impl Seeds {
             }
         }
 
-        Ok((Seeds { seeds }, input))
+        Ok(Seeds { seeds })
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
-struct MapRange {
-    destination_start: u64,
-    source_start: u64,
-    range: u64,
+struct Map {
+    mapped_values: Vec<MapRange>,
 }
 
-impl MapRange {
-    #[tracing::instrument]
-    fn new(destination_start: u64, source_start: u64, range: u64) -> Result<MapRange> {
-        Ok(MapRange {
-            destination_start,
-            source_start,
-            range,
-        })
-    }
-
-    #[tracing::instrument]
-    fn contains_value(&self, value: u64) -> bool {
-        value >= self.source_start && value < self.source_start + self.range
-    }
-
-    #[tracing::instrument]
-    fn map_value(&self, value: u64) -> u64 {
-        if !self.contains_value(value) {
-            return value;
-        }
-
-        let offset = value - self.source_start;
-        let destination = self.destination_start + offset;
-
-        destination
+impl Footprint for Map {
+    fn footprint(&self) -> usize {
+        self.mapped_values.len()
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
-struct Map {
-    mapped_values: Vec<MapRange>,
-}
-
 impl Map {
-    #[tracing::instrument]
-    fn from_input(mut input: Input) -> Result<ParserOutput<Map>> {
+    #[tracing::instrument(skip(block), fields(block = ?SkipDebug(block)))]
+    fn from_block(block: &[&str]) -> Result<Map> {
         let mut mapped_values = Vec::new();
 
-        if !input.next()?.ends_with("map:") {
+        let (header, lines) = block.split_first().ok_or(Error::CannotFindMapHeader)?;
+
+        if !header.ends_with("map:") {
             return Err(Error::CannotFindMapHeader);
         }
 
-        while let Some(line) = input.peak() {
-            if line.is_empty() {
-                break;
-            }
-
-            let line = input.next()?;
-
-            let numbers = line
-                .split(' ')
-                .filter(|s| !s.is_empty())
-                .map(|s| s.trim().parse::<u64>().map_err(Error::CouldNotParseNumber))
-                .collect::<Result<Vec<_>>>()?;
-
-            if numbers.len() != 3 {
-                return Err(Error::UnexpectedNumberOfValuesForMap(line.to_string()));
-            }
-
-            let destination_start = numbers[0];
-            let source_start = numbers[1];
-            let range = numbers[2];
-
-            let map_range = MapRange::new(destination_start, source_start, range)?;
-
-            mapped_values.push(map_range);
+        for line in lines {
+            mapped_values.push(MapRange::parse(line)?);
         }
 
-        Ok((Map { mapped_values }, input))
+        Ok(Map { mapped_values })
     }
 
-    #[tracing::instrument]
+    #[tracing::instrument(skip(self), fields(map = ?SkipDebug(self)))]
     fn get_mapped_value(&self, value: u64) -> u64 {
         self.mapped_values
             .iter()
@@ -165,6 +66,27 @@ impl Map {
             .map(|map_range| map_range.map_value(value))
             .unwrap_or(value)
     }
+
+    /// Maps `value` and also returns how many values from it onwards shift by the same offset.
+    #[tracing::instrument(skip(self), fields(map = ?SkipDebug(self)))]
+    fn get_mapped_value_and_span(&self, value: u64) -> (u64, u64) {
+        let span = match self
+            .mapped_values
+            .iter()
+            .find(|map_range| map_range.contains_value(value))
+        {
+            Some(map_range) => map_range.source.end - value,
+            None => self
+                .mapped_values
+                .iter()
+                .filter(|map_range| map_range.source.start > value)
+                .map(|map_range| map_range.source.start - value)
+                .min()
+                .unwrap_or(u64::MAX),
+        };
+
+        (self.get_mapped_value(value), span)
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -179,97 +101,136 @@ struct Data {
     humidity_to_location_map: Map,
 }
 
-impl Data {
-    #[tracing::instrument]
-    fn from_input(input: Input) -> Result<Data> {
-        let (seeds, mut input) = Seeds::from_input(input)?;
-
-        input.next()?;
-
-        let (seed_to_soil_map, mut input) = Map::from_input(input)?;
-
-        input.next()?;
-
-        let (soil_to_fertilizer_map, mut input) = Map::from_input(input)?;
-
-        input.next()?;
-
-        let (fertilizer_to_water_map, mut input) = Map::from_input(input)?;
-
-        input.next()?;
-
-        let (water_to_light_map, mut input) = Map::from_input(input)?;
-
-        input.next()?;
+impl Footprint for Data {
+    fn footprint(&self) -> usize {
+        [
+            &self.seed_to_soil_map,
+            &self.soil_to_fertilizer_map,
+            &self.fertilizer_to_water_map,
+            &self.water_to_light_map,
+            &self.light_to_temperature_map,
+            &self.temparure_to_humity_map,
+            &self.humidity_to_location_map,
+        ]
+        .iter()
+        .map(|map| map.footprint())
+        .sum()
+    }
+}
 
-        let (light_to_temperature_map, mut input) = Map::from_input(input)?;
+impl Data {
+    #[tracing::instrument(skip(input))]
+    fn from_str(input: &str) -> Result<Data> {
+        let input = normalize(input);
+        let input = HeaderAndBody::parse(&input)?;
 
-        input.next()?;
+        let seeds = Seeds::from_header(input.header)?;
 
-        let (temparure_to_humity_map, mut input) = Map::from_input(input)?;
+        let maps = input
+            .blocks
+            .iter()
+            .map(|block| Map::from_block(block))
+            .collect::<Result<Vec<_>>>()?;
 
-        input.next()?;
+        let maps =
+            <[Map; 7]>::try_from(maps).map_err(|maps| Error::UnexpectedNumberOfMaps(maps.len()))?;
 
-        let (humidity_to_location_map, _) = Map::from_input(input)?;
+        let [soil, fertilizer, water, light, temperature, humidity, location] = maps;
 
         Ok(Data {
             seeds,
-            seed_to_soil_map,
-            soil_to_fertilizer_map,
-            fertilizer_to_water_map,
-            water_to_light_map,
-            light_to_temperature_map,
-            temparure_to_humity_map,
-            humidity_to_location_map,
+            seed_to_soil_map: soil,
+            soil_to_fertilizer_map: fertilizer,
+            fertilizer_to_water_map: water,
+            water_to_light_map: light,
+            light_to_temperature_map: temperature,
+            temparure_to_humity_map: humidity,
+            humidity_to_location_map: location,
         })
     }
 
-    #[tracing::instrument]
+    #[tracing::instrument(skip(self), fields(data = ?SkipDebug(self)))]
     fn seeds(&self) -> &Seeds {
         &self.seeds
     }
 
-    #[tracing::instrument]
-    fn map_seed(&self, seed: u64) -> u64 {
-        let soil = self.seed_to_soil_map.get_mapped_value(seed);
-        let fertilizer = self.soil_to_fertilizer_map.get_mapped_value(soil);
-        let water = self.fertilizer_to_water_map.get_mapped_value(fertilizer);
-        let light = self.water_to_light_map.get_mapped_value(water);
-        let temperature = self.light_to_temperature_map.get_mapped_value(light);
-        let humidity = self.temparure_to_humity_map.get_mapped_value(temperature);
-
-        self.humidity_to_location_map.get_mapped_value(humidity)
+    /// Where `seed` lands, plus how many seeds from this one onwards map to consecutive locations.
+    #[tracing::instrument(skip(self), fields(data = ?SkipDebug(self)))]
+    fn map_seed_with_span(&self, seed: u64) -> (u64, u64) {
+        [
+            &self.seed_to_soil_map,
+            &self.soil_to_fertilizer_map,
+            &self.fertilizer_to_water_map,
+            &self.water_to_light_map,
+            &self.light_to_temperature_map,
+            &self.temparure_to_humity_map,
+            &self.humidity_to_location_map,
+        ]
+        .iter()
+        .fold((seed, u64::MAX), |(value, span), map| {
+            let (mapped, map_span) = map.get_mapped_value_and_span(value);
+            (mapped, span.min(map_span))
+        })
     }
-}
 
-#[tracing::instrument]
-pub fn process(input: &str) -> miette::Result<u64> {
-    let input = Input::from_str(input)?;
+    #[tracing::instrument(skip(self, seeds), fields(data = ?SkipDebug(self), seeds = seeds.len()))]
+    fn min_location(&self, seeds: &[u64]) -> StreamingMin<u64> {
+        let mut min = StreamingMin::new();
+        let mut i = 0;
+
+        while i < seeds.len() {
+            let start = seeds[i];
+            let (location, span) = self.map_seed_with_span(start);
+            min.offer(location);
+
+            // Seeds that carry on from `start` inside the span land on location + 1, + 2...
+            // so they can only be skipped once they are no longer worth processing
+            let mut next = i + 1;
+            while next < seeds.len() {
+                let step = (next - i) as u64;
+                if seeds[next] != start + step
+                    || step >= span
+                    || min.worth_processing(location + step)
+                {
+                    break;
+                }
+                next += 1;
+            }
+
+            i = next;
+        }
+
+        min
+    }
+}
 
-    let data = Data::from_input(input)?;
+const SEEDS_PER_CHUNK: usize = 1 << 16;
 
-    println!("built data");
+#[tracing::instrument]
+pub fn process(input: &str) -> miette::Result<Answer> {
+    let data = Data::from_str(input)?;
 
     let min_location = data
         .seeds()
         .seeds
-        .par_iter()
-        .map(|seed| data.map_seed(*seed))
-        .min()
+        .par_chunks(SEEDS_PER_CHUNK)
+        .map(|seeds| data.min_location(seeds))
+        .reduce(StreamingMin::new, StreamingMin::merge)
+        .best()
         .ok_or(Error::NoMinValue)?;
 
-    Ok(min_location)
+    Ok(Answer::from(min_location))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::part1;
     use pretty_assertions::assert_eq;
 
     #[test]
     fn it_should_parse_seed() -> miette::Result<()> {
-        let input = Input::from_str("seeds: 79 14 55 13")?;
-        let (seeds, _) = Seeds::from_input(input)?;
+        let seeds = Seeds::from_header("seeds: 79 14 55 13")?;
         assert_eq!(
             vec![
                 79, 80, 81, 82, 83, 84, 85, 86, 87, 88, 89, 90, 91, 92, 55, 56, 57, 58, 59, 60, 61,
@@ -283,13 +244,7 @@ mod tests {
 
     #[test]
     fn it_should_parse_map() -> miette::Result<()> {
-        let input = Input::from_str(
-            "seed-to-soil map:
-        50 98 2
-        52 50 48",
-        )?;
-
-        let (map, _) = Map::from_input(input)?;
+        let map = Map::from_block(&["seed-to-soil map:", "50 98 2", "52 50 48"])?;
 
         assert_eq!(map.get_mapped_value(0), 0);
         assert_eq!(map.get_mapped_value(1), 1);
@@ -315,23 +270,55 @@ mod tests {
     #[test]
     fn it_should_process_data() -> miette::Result<()> {
         let input = include_str!("../example1.txt");
-        let input = Input::from_str(input)?;
 
-        let data = Data::from_input(input)?;
+        let data = Data::from_str(input)?;
+
+        assert_eq!(data.map_seed_with_span(79).0, 82);
+        assert_eq!(data.map_seed_with_span(14).0, 43);
+        assert_eq!(data.map_seed_with_span(55).0, 86);
+        assert_eq!(data.map_seed_with_span(13).0, 35);
+
+        // assert_eq!(Answer::UInt(35), process(input)?);
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_map_consecutive_seeds_within_span() -> miette::Result<()> {
+        let data = Data::from_str(include_str!("../example1.txt"))?;
+        let reference = part1::parse(include_str!("../example1.txt"))?;
+
+        for seed in 0..100 {
+            let (location, span) = data.map_seed_with_span(seed);
+            assert_eq!(reference.map_seed(seed), location);
+            for step in 1..span.min(100 - seed) {
+                assert_eq!(location + step, reference.map_seed(seed + step));
+            }
+        }
+        Ok(())
+    }
 
-        assert_eq!(data.map_seed(79), 82);
-        assert_eq!(data.map_seed(14), 43);
-        assert_eq!(data.map_seed(55), 86);
-        assert_eq!(data.map_seed(13), 35);
+    #[test]
+    fn it_should_find_same_min_location_when_skipping() -> miette::Result<()> {
+        let data = Data::from_str(include_str!("../example1.txt"))?;
 
-        // assert_eq!(35, process(input)?);
+        let expected = data
+            .seeds()
+            .seeds
+            .iter()
+            .map(|seed| data.map_seed_with_span(*seed).0)
+            .min();
+        assert_eq!(expected, data.min_location(&data.seeds().seeds).best());
+        assert_eq!(
+            Some(46),
+            data.min_location(&data.seeds().seeds[..14]).best()
+        );
         Ok(())
     }
 
     #[test]
     fn test_process() -> miette::Result<()> {
         let input = include_str!("../example1.txt");
-        assert_eq!(46, process(input)?);
+        assert_eq!(Answer::UInt(46), process(input)?);
         Ok(())
     }
 }
@@ -0,0 +1,90 @@
+use std::sync::Mutex;
+
+use aoc_core::{Answer, SkipDebug};
+use aoc_ranges::Interval;
+use aoc_ui::{Backend, Progress};
+use rayon::prelude::*;
+
+use crate::{error::Error, part1};
+
+/// Seeds per unit of work: enough to keep the bar's bookkeeping out of the
+/// profile, few enough that every core stays busy until the end.
+const CHUNK_SIZE: u64 = 1 << 20;
+
+/// Every seed of every range, in chunks of at most `CHUNK_SIZE`.
+#[tracing::instrument(skip(seeds), fields(seeds = ?SkipDebug(seeds)))]
+fn chunks(seeds: &[u64]) -> Vec<Interval<u64>> {
+    seeds
+        .chunks_exact(2)
+        .flat_map(|pair| {
+            let range = Interval::new(pair[0], pair[0] + pair[1]);
+
+            (range.start..range.end)
+                .step_by(CHUNK_SIZE as usize)
+                .map(move |start| Interval::new(start, (start + CHUNK_SIZE).min(range.end)))
+        })
+        .collect()
+}
+
+/// Maps every seed one at a time, with none of part2's range splitting. Far
+/// too slow to be the answer, but simple enough to trust when cross-checking
+/// it. Progress goes to stderr on a terminal only.
+#[tracing::instrument]
+pub fn process(input: &str) -> miette::Result<Answer> {
+    let data = part1::parse(input)?;
+    let chunks = chunks(data.seed_numbers());
+
+    let bar = Backend::stderr(false);
+    let progress = Mutex::new(Progress::new(chunks.len()));
+
+    let min_location = chunks
+        .par_iter()
+        .filter_map(|chunk| {
+            let min = (chunk.start..chunk.end)
+                .map(|seed| data.map_seed(seed))
+                .min();
+
+            if let Ok(mut progress) = progress.lock() {
+                progress.advance();
+                progress.draw(bar, "chunks");
+            }
+
+            min
+        })
+        .min();
+
+    if let Ok(progress) = progress.lock() {
+        progress.clear(bar);
+    }
+
+    Ok(Answer::from(min_location.ok_or(Error::NoMinValue)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn it_should_cover_each_range_in_chunks() -> miette::Result<()> {
+        let size = CHUNK_SIZE;
+
+        assert_eq!(
+            vec![
+                Interval::new(79, 79 + size),
+                Interval::new(79 + size, 80 + size),
+                Interval::new(55, 68),
+            ],
+            chunks(&[79, size + 1, 55, 13])
+        );
+        assert_eq!(Vec::<Interval<u64>>::new(), chunks(&[79, 0]));
+        Ok(())
+    }
+
+    #[test]
+    fn test_process() -> miette::Result<()> {
+        let input = include_str!("../example1.txt");
+        assert_eq!(Answer::UInt(46), process(input)?);
+        Ok(())
+    }
+}
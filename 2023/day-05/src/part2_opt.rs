@@ -1,266 +1,68 @@
-use crate::{error::Error, prelude::*};
-use rayon::prelude::*;
+use aoc_core::{Answer, Footprint, SkipDebug, StreamingMin};
+use aoc_input::{normalize, HeaderAndBody};
+use aoc_ranges::{coalesce, intersect_sorted, merge_sorted_by, subtract_sorted, Interval};
 
-#[derive(Debug, Clone, PartialEq, Eq)]
-struct Input {
-    lines: Vec<String>,
-    cursor: usize,
-}
-
-impl Input {
-    #[tracing::instrument]
-    fn from_str(input: &str) -> Result<Input> {
-        let lines = input
-            .lines()
-            .map(|s| s.trim().to_string())
-            .collect::<Vec<_>>();
-
-        Ok(Input { lines, cursor: 0 })
-    }
-
-    #[tracing::instrument]
-    fn peak(&self) -> Option<&String> {
-        self.lines.get(self.cursor)
-    }
-
-    #[tracing::instrument]
-    fn next(&mut self) -> Result<&String> {
-        let next = self
-            .lines
-            .get(self.cursor)
-            .ok_or_else(|| Error::CannotFindNextLine(self.cursor));
-        self.cursor += 1;
-        next
-    }
-
-    #[tracing::instrument]
-    fn to_string(&self) -> String {
-        self.lines.join("\n")
-    }
-}
-
-type ParserOutput<T> = (T, Input);
-
-#[derive(Debug, Clone, PartialEq, Eq)]
-struct SeedRange {
-    start: u64,
-    end: u64,
-}
-
-impl SeedRange {
-    #[tracing::instrument]
-    fn new(start: u64, end: u64) -> SeedRange {
-        SeedRange { start, end }
-    }
-
-    #[tracing::instrument]
-    fn is_empty(&self) -> bool {
-        self.start == self.end
-    }
-}
+use crate::{error::Error, map_range::MapRange, part1, prelude::*, seeds};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 struct Seeds {
-    seeds: Vec<SeedRange>,
+    seeds: Vec<Interval<u64>>,
 }
 
 impl Seeds {
     #[tracing::instrument]
-    fn from_input(mut input: Input) -> Result<ParserOutput<Seeds>> {
-        let first_line = input.next().map_err(|_| Error::CannotFindSeedsHeader)?;
-
-        if !first_line.starts_with("seeds:") {
-            return Err(Error::CannotFindSeedsHeader);
-        }
-
-        let seed_pairs = first_line
-            .split(':')
-            .last()
-            .ok_or_else(|| Error::CannotFindSeedsHeader)?
-            .split(' ')
-            .filter(|s| !s.is_empty())
-            .map(|s| s.trim().parse::<u64>().map_err(Error::CouldNotParseNumber))
-            .collect::<Result<Vec<_>>>()?;
+    fn from_header(first_line: &str) -> Result<Seeds> {
+        Ok(Self::from_pairs(&seeds::parse(first_line)?))
+    }
 
+    /// Seed numbers read as start and count pairs.
+    #[tracing::instrument]
+    fn from_pairs(seed_pairs: &[u64]) -> Seeds {
         let mut seeds = Vec::new();
 
         for seed_pair in seed_pairs.chunks(2) {
             let seed = seed_pair[0];
             let count = seed_pair[1];
 
-            let seed_range = SeedRange::new(seed, seed + count - 1);
-
-            seeds.push(seed_range);
+            seeds.push(Interval::new(seed, seed + count));
         }
 
-        Ok((Seeds { seeds }, input))
+        Seeds { seeds }
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
-struct MapRange {
-    destination_start: u64,
-    source_start: u64,
-    range: u64,
+struct Map {
+    mapped_ranges: Vec<MapRange>,
 }
 
-impl MapRange {
-    #[tracing::instrument]
-    fn new(destination_start: u64, source_start: u64, range: u64) -> MapRange {
-        MapRange {
-            destination_start,
-            source_start,
-            range,
-        }
-    }
-
-    #[tracing::instrument]
-    fn contains_value(&self, value: u64) -> bool {
-        value >= self.source_start && value < self.source_start + self.range
-    }
-
-    #[tracing::instrument]
-    fn map_value(&self, value: u64) -> u64 {
-        if !self.contains_value(value) {
-            return value;
-        }
-
-        let offset = value - self.source_start;
-        let destination = self.destination_start + offset;
-
-        destination
+impl Footprint for Map {
+    fn footprint(&self) -> usize {
+        self.mapped_ranges.len()
     }
-
-    #[tracing::instrument]
-    fn map_contained_seed_range(&self, seed_range: &SeedRange) -> bool {
-        if seed_range.start < self.source_start && seed_range.end < self.source_start {
-            return false;
-        }
-
-        if seed_range.start >= self.source_start + self.range
-            && seed_range.end >= self.source_start + self.range
-        {
-            return false;
-        }
-
-        true
-    }
-
-    #[tracing::instrument]
-    fn map_seed_range(&self, seed_range: SeedRange) -> Vec<SeedRange> {
-        // Out of bounds
-        if seed_range.end < self.source_start {
-            return vec![seed_range];
-        }
-
-        if seed_range.start >= self.source_start + self.range {
-            return vec![seed_range];
-        }
-
-        // Contained
-        if seed_range.start >= self.source_start && seed_range.end <= self.source_start + self.range
-        {
-            let start = self.map_value(seed_range.start);
-            let end = self.map_value(seed_range.end);
-
-            return vec![SeedRange::new(start, end)];
-        }
-
-        // Left partial
-
-        if seed_range.start < self.source_start && seed_range.end <= self.source_start + self.range
-        {
-            let end = self.map_value(seed_range.end);
-
-            return vec![
-                SeedRange::new(seed_range.start, self.source_start - 1),
-                SeedRange::new(self.map_value(self.source_start), self.map_value(end)),
-            ];
-        }
-
-        // Right partial
-
-        if seed_range.start >= self.source_start && seed_range.end > self.source_start + self.range
-        {
-            let start = self.map_value(seed_range.start);
-            let map_end = self.map_value(self.source_start + self.range - 1);
-            let included_span = map_end - start + 1;
-
-            dbg!(start, included_span, map_end);
-
-            return vec![
-                SeedRange::new(start, map_end),
-                SeedRange::new(self.source_start + self.range, seed_range.end),
-            ];
-        }
-
-        let mut mapped_ranges = Vec::new();
-
-        let before_range = SeedRange::new(seed_range.start, self.source_start - 1);
-        let in_range = SeedRange::new(
-            self.map_value(self.source_start),
-            self.map_value(self.source_start + self.range - 1),
-        );
-        let after_range = SeedRange::new(self.source_start + self.range, seed_range.end);
-
-        if !before_range.is_empty() {
-            mapped_ranges.push(before_range);
-        }
-
-        mapped_ranges.push(in_range);
-
-        if !after_range.is_empty() {
-            mapped_ranges.push(after_range);
-        }
-
-        mapped_ranges
-    }
-}
-
-#[derive(Debug, Clone, PartialEq, Eq)]
-struct Map {
-    mapped_ranges: Vec<MapRange>,
 }
 
 impl Map {
-    #[tracing::instrument]
-    fn from_input(mut input: Input) -> Result<ParserOutput<Map>> {
+    #[tracing::instrument(skip(block), fields(block = ?SkipDebug(block)))]
+    fn from_block(block: &[&str]) -> Result<Map> {
         let mut mapped_ranges = Vec::new();
 
-        if !input.next()?.ends_with("map:") {
+        let (header, lines) = block.split_first().ok_or(Error::CannotFindMapHeader)?;
+
+        if !header.ends_with("map:") {
             return Err(Error::CannotFindMapHeader);
         }
 
-        while let Some(line) = input.peak() {
-            if line.is_empty() {
-                break;
-            }
-
-            let line = input.next()?;
-
-            let numbers = line
-                .split(' ')
-                .filter(|s| !s.is_empty())
-                .map(|s| s.trim().parse::<u64>().map_err(Error::CouldNotParseNumber))
-                .collect::<Result<Vec<_>>>()?;
-
-            if numbers.len() != 3 {
-                return Err(Error::UnexpectedNumberOfValuesForMap(line.to_string()));
-            }
-
-            let destination_start = numbers[0];
-            let source_start = numbers[1];
-            let range = numbers[2];
-
-            let map_range = MapRange::new(destination_start, source_start, range);
-
-            mapped_ranges.push(map_range);
+        for line in lines {
+            mapped_ranges.push(MapRange::parse(line)?);
         }
 
-        Ok((Map { mapped_ranges }, input))
+        Ok(Map { mapped_ranges })
     }
 
-    #[tracing::instrument]
+    /// One value at a time, for checking the range mapping against.
+    #[cfg(test)]
+    #[tracing::instrument(skip(self), fields(map = ?SkipDebug(self)))]
     fn get_mapped_value(&self, value: u64) -> u64 {
         self.mapped_ranges
             .iter()
@@ -269,26 +71,69 @@ impl Map {
             .unwrap_or(value)
     }
 
-    #[tracing::instrument]
-    fn map_seed_ranges(&self, seed_ranges: Vec<SeedRange>) -> Vec<SeedRange> {
-        let mut new_seed_ranges = vec![];
-        for seed_range in seed_ranges {
-            let mut mapped = false;
-            for map_range in &self.mapped_ranges {
-                if map_range.map_contained_seed_range(&seed_range) {
-                    println!("here");
-                    let mapped_seed_ranges = map_range.map_seed_range(seed_range.clone());
-                    new_seed_ranges.extend(mapped_seed_ranges);
-                    mapped = true;
-                }
-            }
+    /// Every value below `u64::MAX`, split into the ranges this moves and the
+    /// gaps between them, which map to themselves. Sorted by source.
+    #[tracing::instrument(skip(self), fields(map = ?SkipDebug(self)))]
+    fn pieces(&self) -> Vec<MapRange> {
+        let mut mapped_ranges = self.mapped_ranges.clone();
+        mapped_ranges.sort_by_key(|map_range| map_range.source.start);
+
+        let moved = coalesce(mapped_ranges.iter().map(|map_range| map_range.source));
+        let gaps = subtract_sorted(&[Interval::new(0, u64::MAX)], &moved)
+            .into_iter()
+            .map(|gap| MapRange::new(gap.start, gap.start, gap.len()));
+
+        merge_sorted_by(mapped_ranges, gaps, |piece| piece.source.start)
+    }
 
-            if !mapped {
-                new_seed_ranges.push(seed_range.clone());
+    /// This map followed by `other`, as one map, so a seed only needs looking
+    /// up once however many maps it passes through.
+    #[tracing::instrument(skip(self, other), fields(map = ?SkipDebug(self), other = ?SkipDebug(other)))]
+    fn compose(&self, other: &Map) -> Map {
+        let next_pieces = other.pieces();
+        let mut mapped_ranges = vec![];
+
+        for piece in self.pieces() {
+            let landing = piece.destination();
+
+            for next in &next_pieces {
+                let Some(overlap) = landing.intersection(&next.source) else {
+                    continue;
+                };
+
+                let source_start = piece.source.start + (overlap.start - landing.start);
+                let destination_start = next.map_value(overlap.start);
+
+                // Anything left where it started needs no range of its own
+                if source_start != destination_start {
+                    mapped_ranges.push(MapRange::new(
+                        destination_start,
+                        source_start,
+                        overlap.len(),
+                    ));
+                }
             }
         }
 
-        new_seed_ranges
+        Map { mapped_ranges }
+    }
+
+    /// Where every seed in `seed_ranges` ends up, split wherever the map
+    /// moves neighbouring seeds apart. The seeds and pieces are both sorted,
+    /// so they're walked together, and each overlap finds its piece by
+    /// binary search rather than a scan.
+    #[tracing::instrument(skip(self), fields(map = ?SkipDebug(self)))]
+    fn map_seed_ranges(&self, seed_ranges: Vec<Interval<u64>>) -> Vec<Interval<u64>> {
+        let pieces = self.pieces();
+        let sources = pieces.iter().map(|piece| piece.source).collect::<Vec<_>>();
+
+        intersect_sorted(&coalesce(seed_ranges), &sources)
+            .into_iter()
+            .map(|overlap| {
+                let piece = sources.partition_point(|source| source.end <= overlap.start);
+                pieces[piece].map_interval(overlap)
+            })
+            .collect()
     }
 }
 
@@ -304,118 +149,116 @@ struct Data {
     humidity_to_location_map: Map,
 }
 
-impl Data {
-    #[tracing::instrument]
-    fn from_input(input: Input) -> Result<Data> {
-        let (seeds, mut input) = Seeds::from_input(input)?;
-
-        input.next()?;
-
-        let (seed_to_soil_map, mut input) = Map::from_input(input)?;
-
-        input.next()?;
-
-        let (soil_to_fertilizer_map, mut input) = Map::from_input(input)?;
-
-        input.next()?;
-
-        let (fertilizer_to_water_map, mut input) = Map::from_input(input)?;
-
-        input.next()?;
-
-        let (water_to_light_map, mut input) = Map::from_input(input)?;
-
-        input.next()?;
+impl Footprint for Data {
+    fn footprint(&self) -> usize {
+        [
+            &self.seed_to_soil_map,
+            &self.soil_to_fertilizer_map,
+            &self.fertilizer_to_water_map,
+            &self.water_to_light_map,
+            &self.light_to_temperature_map,
+            &self.temparure_to_humity_map,
+            &self.humidity_to_location_map,
+        ]
+        .iter()
+        .map(|map| map.footprint())
+        .sum()
+    }
+}
 
-        let (light_to_temperature_map, mut input) = Map::from_input(input)?;
+impl Data {
+    #[tracing::instrument(skip(input))]
+    fn from_str(input: &str) -> Result<Data> {
+        let input = normalize(input);
+        let input = HeaderAndBody::parse(&input)?;
 
-        input.next()?;
+        let seeds = Seeds::from_header(input.header)?;
 
-        let (temparure_to_humity_map, mut input) = Map::from_input(input)?;
+        let maps = input
+            .blocks
+            .iter()
+            .map(|block| Map::from_block(block))
+            .collect::<Result<Vec<_>>>()?;
 
-        input.next()?;
+        let maps =
+            <[Map; 7]>::try_from(maps).map_err(|maps| Error::UnexpectedNumberOfMaps(maps.len()))?;
 
-        let (humidity_to_location_map, _) = Map::from_input(input)?;
+        let [soil, fertilizer, water, light, temperature, humidity, location] = maps;
 
         Ok(Data {
             seeds,
-            seed_to_soil_map,
-            soil_to_fertilizer_map,
-            fertilizer_to_water_map,
-            water_to_light_map,
-            light_to_temperature_map,
-            temparure_to_humity_map,
-            humidity_to_location_map,
+            seed_to_soil_map: soil,
+            soil_to_fertilizer_map: fertilizer,
+            fertilizer_to_water_map: water,
+            water_to_light_map: light,
+            light_to_temperature_map: temperature,
+            temparure_to_humity_map: humidity,
+            humidity_to_location_map: location,
         })
     }
 
-    #[tracing::instrument]
+    /// The almanac part 1 parsed, with its seeds read as ranges.
+    #[tracing::instrument(skip(almanac), fields(almanac = ?SkipDebug(almanac)))]
+    fn from_almanac(almanac: &part1::Data) -> Data {
+        let [soil, fertilizer, water, light, temperature, humidity, location] =
+            almanac.map_ranges().map(|mapped_ranges| Map {
+                mapped_ranges: mapped_ranges.to_vec(),
+            });
+
+        Data {
+            seeds: Seeds::from_pairs(almanac.seed_numbers()),
+            seed_to_soil_map: soil,
+            soil_to_fertilizer_map: fertilizer,
+            fertilizer_to_water_map: water,
+            water_to_light_map: light,
+            light_to_temperature_map: temperature,
+            temparure_to_humity_map: humidity,
+            humidity_to_location_map: location,
+        }
+    }
+
+    #[tracing::instrument(skip(self), fields(data = ?SkipDebug(self)))]
     fn seeds(&self) -> &Seeds {
         &self.seeds
     }
 
-    #[tracing::instrument]
-    fn map_seeds(&self, seed_ranges: Vec<SeedRange>) -> u64 {
-        println!("seeds {:?}", seed_ranges);
-
-        let soil = self.seed_to_soil_map.map_seed_ranges(seed_ranges.clone());
-
-        println!("soil {:?}", soil);
-
-        let fertilizer = self.soil_to_fertilizer_map.map_seed_ranges(soil);
-
-        println!("fertilizer {:?}", fertilizer);
-
-        let water = self.fertilizer_to_water_map.map_seed_ranges(fertilizer);
-
-        println!("water {:?}", water);
-
-        let light = self.water_to_light_map.map_seed_ranges(water);
-
-        println!("light {:?}", light);
-
-        let temperature = self.light_to_temperature_map.map_seed_ranges(light);
-
-        println!("temperature {:?}", temperature);
-
-        let humidity = self.temparure_to_humity_map.map_seed_ranges(temperature);
-
-        println!("humidity {:?}", humidity);
-
-        let location = self.humidity_to_location_map.map_seed_ranges(humidity);
-
-        println!("location {:?}", location);
+    /// All seven maps composed into one that goes straight from seed to location.
+    #[tracing::instrument(skip(self), fields(data = ?SkipDebug(self)))]
+    fn seed_to_location_map(&self) -> Map {
+        [
+            &self.soil_to_fertilizer_map,
+            &self.fertilizer_to_water_map,
+            &self.water_to_light_map,
+            &self.light_to_temperature_map,
+            &self.temparure_to_humity_map,
+            &self.humidity_to_location_map,
+        ]
+        .iter()
+        .fold(self.seed_to_soil_map.clone(), |map, next| map.compose(next))
+    }
 
-        location.iter().map(|r| r.start).min().unwrap()
+    #[tracing::instrument(skip(self), fields(data = ?SkipDebug(self)))]
+    fn min_location(&self) -> Result<u64> {
+        self.seed_to_location_map()
+            .map_seed_ranges(self.seeds().seeds.clone())
+            .iter()
+            .map(|seed_range| seed_range.start)
+            .collect::<StreamingMin<_>>()
+            .best()
+            .ok_or(Error::NoMinValue)
     }
 }
 
-#[tracing::instrument]
-pub fn process(input: &str) -> miette::Result<u64> {
-    let input = Input::from_str(input)?;
-
-    let data = Data::from_input(input)?;
-
-    println!("built data");
-
-    let x = data
-        .seeds()
-        .seeds
-        .iter()
-        .map(|seed| data.map_seeds(vec![seed.clone()]))
-        .collect::<Vec<_>>();
-
-    println!("{:?}", x);
-
-    let min_location = data
-        .seeds()
-        .seeds
-        .iter()
-        .map(|seed| data.map_seeds(vec![seed.clone()]))
-        .min()
-        .ok_or(Error::NoMinValue)?;
+/// Part 2 on the almanac as `part1::parse` leaves it, so both parts can share
+/// one parse.
+#[tracing::instrument(skip(almanac), fields(almanac = ?SkipDebug(almanac)))]
+pub fn solve(almanac: &part1::Data) -> miette::Result<u64> {
+    Ok(Data::from_almanac(almanac).min_location()?)
+}
 
-    Ok(min_location)
+#[tracing::instrument]
+pub fn process(input: &str) -> miette::Result<Answer> {
+    Ok(Answer::from(Data::from_str(input)?.min_location()?))
 }
 
 #[cfg(test)]
@@ -438,82 +281,94 @@ mod tests {
     //     Ok(())
     // }
 
+    #[test]
+    fn it_should_read_seed_pairs_as_half_open_intervals() -> miette::Result<()> {
+        let seeds = Seeds::from_header("seeds: 79 14 55 13")?;
+        assert_eq!(
+            vec![Interval::new(79, 93), Interval::new(55, 68)],
+            seeds.seeds
+        );
+        Ok(())
+    }
+
     #[test]
     fn it_should_map_seed_range() -> miette::Result<()> {
-        let map_range = MapRange::new(70, 50, 5);
+        let map = Map {
+            mapped_ranges: vec![MapRange::new(70, 50, 5)],
+        };
 
         // Not in range
         assert_eq!(
-            map_range.map_seed_range(SeedRange::new(90, 92)),
-            vec![SeedRange::new(90, 92)]
+            map.map_seed_ranges(vec![Interval::new(90, 93)]),
+            vec![Interval::new(90, 93)]
         );
 
         assert_eq!(
-            map_range.map_seed_range(SeedRange::new(92, 92)),
-            vec![SeedRange::new(92, 92)]
+            map.map_seed_ranges(vec![Interval::new(92, 93)]),
+            vec![Interval::new(92, 93)]
         );
 
         assert_eq!(
-            map_range.map_seed_range(SeedRange::new(30, 32)),
-            vec![SeedRange::new(30, 32)]
+            map.map_seed_ranges(vec![Interval::new(30, 33)]),
+            vec![Interval::new(30, 33)]
         );
 
         assert_eq!(
-            map_range.map_seed_range(SeedRange::new(30, 30)),
-            vec![SeedRange::new(30, 30)]
+            map.map_seed_ranges(vec![Interval::new(30, 31)]),
+            vec![Interval::new(30, 31)]
         );
 
         // Competely containd
         assert_eq!(
-            map_range.map_seed_range(SeedRange::new(50, 52)),
-            vec![SeedRange::new(70, 72)]
+            map.map_seed_ranges(vec![Interval::new(50, 53)]),
+            vec![Interval::new(70, 73)]
         );
 
         assert_eq!(
-            map_range.map_seed_range(SeedRange::new(51, 51)),
-            vec![SeedRange::new(71, 71)]
+            map.map_seed_ranges(vec![Interval::new(51, 52)]),
+            vec![Interval::new(71, 72)]
         );
 
         // Left partial
         assert_eq!(
-            map_range.map_seed_range(SeedRange::new(48, 51)),
-            vec![SeedRange::new(48, 49), SeedRange::new(70, 71)]
+            map.map_seed_ranges(vec![Interval::new(48, 52)]),
+            vec![Interval::new(48, 50), Interval::new(70, 72)]
         );
 
         assert_eq!(
-            map_range.map_seed_range(SeedRange::new(48, 48)),
-            vec![SeedRange::new(48, 48)]
+            map.map_seed_ranges(vec![Interval::new(48, 49)]),
+            vec![Interval::new(48, 49)]
         );
 
         assert_eq!(
-            map_range.map_seed_range(SeedRange::new(50, 50)),
-            vec![SeedRange::new(70, 70)]
+            map.map_seed_ranges(vec![Interval::new(50, 51)]),
+            vec![Interval::new(70, 71)]
         );
 
         // Right partial
         assert_eq!(
-            map_range.map_seed_range(SeedRange::new(53, 57)),
-            vec![SeedRange::new(73, 74), SeedRange::new(55, 57)]
+            map.map_seed_ranges(vec![Interval::new(53, 58)]),
+            vec![Interval::new(73, 75), Interval::new(55, 58)]
         );
 
         assert_eq!(
-            map_range.map_seed_range(SeedRange::new(57, 57)),
-            vec![SeedRange::new(57, 57)]
+            map.map_seed_ranges(vec![Interval::new(57, 58)]),
+            vec![Interval::new(57, 58)]
         );
 
         assert_eq!(
-            map_range.map_seed_range(SeedRange::new(53, 53)),
-            vec![SeedRange::new(73, 73)]
+            map.map_seed_ranges(vec![Interval::new(53, 54)]),
+            vec![Interval::new(73, 74)]
         );
 
         // Partial
 
         assert_eq!(
-            map_range.map_seed_range(SeedRange::new(48, 57)),
+            map.map_seed_ranges(vec![Interval::new(48, 58)]),
             vec![
-                SeedRange::new(48, 49),
-                SeedRange::new(70, 74),
-                SeedRange::new(55, 57)
+                Interval::new(48, 50),
+                Interval::new(70, 75),
+                Interval::new(55, 58)
             ]
         );
 
@@ -523,43 +378,94 @@ mod tests {
     #[test]
     fn it_should_map_range_single() -> miette::Result<()> {
         let input = include_str!("../example1.txt");
-        let input = Input::from_str(input)?;
 
-        let data = Data::from_input(input)?;
+        let data = Data::from_str(input)?;
 
-        let seed_range = vec![SeedRange::new(79, 79)];
+        let seed_range = vec![Interval::new(79, 80)];
 
         let mapped_ranges = data.seed_to_soil_map.map_seed_ranges(seed_range);
-        let expected = vec![SeedRange::new(81, 81)];
+        let expected = vec![Interval::new(81, 82)];
         assert_eq!(mapped_ranges, expected);
 
         let mapped_ranges = data.soil_to_fertilizer_map.map_seed_ranges(mapped_ranges);
-        let expected = vec![SeedRange::new(81, 81)];
+        let expected = vec![Interval::new(81, 82)];
         assert_eq!(mapped_ranges, expected);
 
         let mapped_ranges = data.fertilizer_to_water_map.map_seed_ranges(mapped_ranges);
-        let expected = vec![SeedRange::new(81, 81)];
+        let expected = vec![Interval::new(81, 82)];
         assert_eq!(mapped_ranges, expected);
 
         let mapped_ranges = data.water_to_light_map.map_seed_ranges(mapped_ranges);
-        let expected = vec![SeedRange::new(74, 74)];
+        let expected = vec![Interval::new(74, 75)];
         assert_eq!(mapped_ranges, expected);
 
         let mapped_ranges = data.light_to_temperature_map.map_seed_ranges(mapped_ranges);
-        let expected = vec![SeedRange::new(78, 78)];
+        let expected = vec![Interval::new(78, 79)];
         assert_eq!(mapped_ranges, expected);
 
         let mapped_ranges = data.temparure_to_humity_map.map_seed_ranges(mapped_ranges);
-        let expected = vec![SeedRange::new(78, 78)];
+        let expected = vec![Interval::new(78, 79)];
         assert_eq!(mapped_ranges, expected);
 
         let mapped_ranges = data.humidity_to_location_map.map_seed_ranges(mapped_ranges);
-        let expected = vec![SeedRange::new(82, 82)];
+        let expected = vec![Interval::new(82, 83)];
         assert_eq!(mapped_ranges, expected);
 
         Ok(())
     }
 
+    #[test]
+    fn it_should_compose_maps() -> miette::Result<()> {
+        let first = Map::from_block(&["seed-to-soil map:", "50 98 2", "52 50 48"])?;
+        let second =
+            Map::from_block(&["soil-to-fertilizer map:", "0 15 37", "37 52 2", "39 0 15"])?;
+        let composed = first.compose(&second);
+
+        for value in 0..120 {
+            assert_eq!(
+                second.get_mapped_value(first.get_mapped_value(value)),
+                composed.get_mapped_value(value),
+                "value {}",
+                value
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_map_every_seed_to_its_location_at_once() -> miette::Result<()> {
+        let data = Data::from_str(include_str!("../example1.txt"))?;
+        let seed_to_location = data.seed_to_location_map();
+
+        for seed in 0..120 {
+            let location = [
+                &data.seed_to_soil_map,
+                &data.soil_to_fertilizer_map,
+                &data.fertilizer_to_water_map,
+                &data.water_to_light_map,
+                &data.light_to_temperature_map,
+                &data.temparure_to_humity_map,
+                &data.humidity_to_location_map,
+            ]
+            .iter()
+            .fold(seed, |value, map| map.get_mapped_value(value));
+
+            assert_eq!(
+                vec![Interval::new(location, location + 1)],
+                seed_to_location.map_seed_ranges(vec![Interval::new(seed, seed + 1)])
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_process() -> miette::Result<()> {
+        let input = include_str!("../example1.txt");
+        assert_eq!(Answer::UInt(46), process(input)?);
+        assert_eq!(46, solve(&part1::parse(input)?)?);
+        Ok(())
+    }
+
     // #[test]
     // fn it_should_map_range_multi() -> miette::Result<()> {
     //     let input = include_str!("../example1.txt");
@@ -567,37 +473,37 @@ mod tests {
 
     //     let data = Data::from_input(input)?;
 
-    //     let seed_range = vec![SeedRange::new(79, 93)];
+    //     let seed_range = vec![Interval::new(79, 94)];
 
     //     let mapped_ranges = data.seed_to_soil_map.map_seed_ranges(seed_range);
-    //     let expected = vec![SeedRange::new(81, 95)];
+    //     let expected = vec![Interval::new(81, 96)];
     //     assert_eq!(mapped_ranges, expected);
 
     //     let mapped_ranges = data.soil_to_fertilizer_map.map_seed_ranges(mapped_ranges);
-    //     let expected = vec![SeedRange::new(81, 95)];
+    //     let expected = vec![Interval::new(81, 96)];
     //     assert_eq!(mapped_ranges, expected);
 
     //     let mapped_ranges = data.fertilizer_to_water_map.map_seed_ranges(mapped_ranges);
-    //     let expected = vec![SeedRange::new(81, 95)];
+    //     let expected = vec![Interval::new(81, 96)];
     //     assert_eq!(mapped_ranges, expected);
 
     //     let mapped_ranges = data.water_to_light_map.map_seed_ranges(mapped_ranges);
-    //     let expected = vec![SeedRange::new(74, 95)];
+    //     let expected = vec![Interval::new(74, 96)];
     //     assert_eq!(mapped_ranges, expected);
 
     //     let mapped_ranges = data.light_to_temperature_map.map_seed_ranges(mapped_ranges);
-    //     let expected = vec![SeedRange::new(45, 56), SeedRange::new(78, 81)];
+    //     let expected = vec![Interval::new(45, 57), Interval::new(78, 82)];
     //     assert_eq!(mapped_ranges, expected);
 
     //     let mapped_ranges = data.temparure_to_humity_map.map_seed_ranges(mapped_ranges);
-    //     let expected = vec![SeedRange::new(46, 57), SeedRange::new(78, 81)];
+    //     let expected = vec![Interval::new(46, 58), Interval::new(78, 82)];
     //     assert_eq!(mapped_ranges, expected);
 
     //     let mapped_ranges = data.humidity_to_location_map.map_seed_ranges(mapped_ranges);
     //     let expected = vec![
-    //         SeedRange::new(46, 56),
-    //         SeedRange::new(60, 61),
-    //         SeedRange::new(82, 85),
+    //         Interval::new(46, 57),
+    //         Interval::new(60, 62),
+    //         Interval::new(82, 86),
     //     ];
     //     assert_eq!(mapped_ranges, expected);
 
@@ -611,14 +517,13 @@ mod tests {
 
     //     let data = Data::from_input(input)?;
 
-    //     let seed_range = vec![SeedRange::new(74, 88)];
+    //     let seed_range = vec![Interval::new(74, 89)];
 
     //     let mapped_ranges = data.light_to_temperature_map.map_seed_ranges(seed_range);
-    //     let expected = vec![SeedRange::new(45, 56), SeedRange::new(78, 81)];
+    //     let expected = vec![Interval::new(45, 57), Interval::new(78, 82)];
     //     println!("{:?}", mapped_ranges);
     //     assert_eq!(mapped_ranges, expected);
 
-
     //     Ok(())
     // }
 
@@ -660,25 +565,17 @@ mod tests {
 
     //     let data = Data::from_input(input)?;
 
-    //     assert_eq!(data.map_seeds(vec![SeedRange::new(79, 79)]), 82);
-    //     assert_eq!(data.map_seeds(vec![SeedRange::new(14, 14)]), 43);
-    //     assert_eq!(data.map_seeds(vec![SeedRange::new(55, 55)]), 86);
-    //     assert_eq!(data.map_seeds(vec![SeedRange::new(13, 13)]), 35);
-    //     Ok(())
-    // }
-
-    // #[test]
-    // fn test_process() -> miette::Result<()> {
-    //     let input = include_str!("../example1.txt");
-    //     assert_eq!(46, process(input)?);
-    //     // assert_eq!(463, process(input)?);
+    //     assert_eq!(data.map_seeds(vec![Interval::new(79, 80)]), 82);
+    //     assert_eq!(data.map_seeds(vec![Interval::new(14, 15)]), 43);
+    //     assert_eq!(data.map_seeds(vec![Interval::new(55, 56)]), 86);
+    //     assert_eq!(data.map_seeds(vec![Interval::new(13, 14)]), 35);
     //     Ok(())
     // }
 
     // #[test]
     // fn it_should_be_correct_for_real_data() -> miette::Result<()> {
     //     let input = include_str!("../input2.txt");
-    //     assert_eq!(process(input)?, 56931769);
+    //     assert_eq!(process(input)?, Answer::UInt(56931769));
     //     Ok(())
     // }
 }
@@ -0,0 +1,304 @@
+use aoc_input::{normalize, HeaderAndBody};
+
+use crate::{error::Error, prelude::*, seeds};
+
+/// Half-open range of category numbers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Span {
+    pub start: u64,
+    pub end: u64,
+}
+
+impl Span {
+    #[tracing::instrument]
+    pub fn len(&self) -> u64 {
+        self.end - self.start
+    }
+
+    #[tracing::instrument]
+    pub fn is_empty(&self) -> bool {
+        self.start >= self.end
+    }
+
+    #[tracing::instrument]
+    fn intersect(&self, other: &Span) -> Span {
+        Span {
+            start: self.start.max(other.start),
+            end: self.end.min(other.end),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Rule {
+    destination: u64,
+    source: Span,
+}
+
+/// Every range of one category, in the order they were produced.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Stage {
+    pub category: String,
+    pub ranges: Vec<Span>,
+}
+
+/// A sub-range flowing from `from` in `stage` to `to` in the next stage.
+/// Unmapped edges are numbers that passed through the map unchanged.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Edge {
+    pub stage: usize,
+    pub from: usize,
+    pub to: usize,
+    pub width: u64,
+    pub mapped: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Flow {
+    pub stages: Vec<Stage>,
+    pub edges: Vec<Edge>,
+}
+
+#[tracing::instrument]
+fn parse_numbers(line: &str) -> Result<Vec<u64>> {
+    line.split_whitespace()
+        .map(|s| s.parse::<u64>().map_err(Error::CouldNotParseNumber))
+        .collect()
+}
+
+#[tracing::instrument]
+fn parse_seeds(line: &str) -> Result<Vec<Span>> {
+    let numbers = seeds::parse(line)?;
+
+    Ok(numbers
+        .chunks_exact(2)
+        .map(|pair| Span {
+            start: pair[0],
+            end: pair[0] + pair[1],
+        })
+        .collect())
+}
+
+#[tracing::instrument]
+fn parse_map(block: &[&str]) -> Result<(String, Vec<Rule>)> {
+    let category = block
+        .first()
+        .and_then(|header| header.strip_suffix(" map:"))
+        .and_then(|header| header.split_once("-to-"))
+        .map(|(_, to)| to.to_string())
+        .ok_or(Error::CannotFindMapHeader)?;
+
+    let rules = block[1..]
+        .iter()
+        .map(|line| match parse_numbers(line)?[..] {
+            [destination, source, range] => Ok(Rule {
+                destination,
+                source: Span {
+                    start: source,
+                    end: source + range,
+                },
+            }),
+            _ => Err(Error::UnexpectedNumberOfValuesForMap(line.to_string())),
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok((category, rules))
+}
+
+/// Splits `span` by the rules it overlaps, returning each piece in the next
+/// category and whether a rule moved it.
+#[tracing::instrument]
+fn map_span(span: Span, rules: &[Rule]) -> Vec<(Span, bool)> {
+    let mut pieces = vec![];
+    let mut unmapped = vec![span];
+
+    for rule in rules {
+        let mut remaining = vec![];
+
+        for piece in unmapped {
+            let overlap = piece.intersect(&rule.source);
+
+            if overlap.is_empty() {
+                remaining.push(piece);
+                continue;
+            }
+
+            let shifted = Span {
+                start: rule.destination + (overlap.start - rule.source.start),
+                end: rule.destination + (overlap.end - rule.source.start),
+            };
+            pieces.push((shifted, true));
+
+            remaining.extend(
+                [
+                    Span {
+                        start: piece.start,
+                        end: overlap.start,
+                    },
+                    Span {
+                        start: overlap.end,
+                        end: piece.end,
+                    },
+                ]
+                .into_iter()
+                .filter(|part| !part.is_empty()),
+            );
+        }
+
+        unmapped = remaining;
+    }
+
+    pieces.extend(unmapped.into_iter().map(|piece| (piece, false)));
+    pieces
+}
+
+/// Follows every seed range through the seven maps, keeping each fragment as
+/// its own node so splits stay visible.
+#[tracing::instrument(skip(input))]
+pub fn flow(input: &str) -> Result<Flow> {
+    let input = normalize(input);
+    let input = HeaderAndBody::parse(&input)?;
+    let seeds = parse_seeds(input.header)?;
+
+    let mut stages = vec![Stage {
+        category: "seed".to_string(),
+        ranges: seeds,
+    }];
+    let mut edges = vec![];
+
+    for block in &input.blocks {
+        let (category, rules) = parse_map(block)?;
+        let stage = stages.len() - 1;
+        let mut ranges = vec![];
+
+        for (from, span) in stages[stage].ranges.iter().enumerate() {
+            for (piece, mapped) in map_span(*span, &rules) {
+                edges.push(Edge {
+                    stage,
+                    from,
+                    to: ranges.len(),
+                    width: piece.len(),
+                    mapped,
+                });
+                ranges.push(piece);
+            }
+        }
+
+        stages.push(Stage { category, ranges });
+    }
+
+    Ok(Flow { stages, edges })
+}
+
+impl Flow {
+    /// Graphviz description with one cluster per category. Edge pen widths
+    /// scale with the sub-range size, and unmapped pass-throughs are dashed.
+    #[tracing::instrument(skip(self))]
+    pub fn to_dot(&self) -> String {
+        let max_width = self.edges.iter().map(|e| e.width).max().unwrap_or(1).max(1);
+
+        let mut dot = String::from("digraph almanac {\n    rankdir=LR;\n    node [shape=box];\n");
+
+        for (i, stage) in self.stages.iter().enumerate() {
+            dot.push_str(&format!(
+                "    subgraph cluster_{} {{\n        label=\"{}\";\n",
+                i, stage.category
+            ));
+            for (j, span) in stage.ranges.iter().enumerate() {
+                dot.push_str(&format!(
+                    "        n{}_{} [label=\"{}..{}\"];\n",
+                    i, j, span.start, span.end
+                ));
+            }
+            dot.push_str("    }\n");
+        }
+
+        for edge in &self.edges {
+            let pen_width = 1.0 + 9.0 * edge.width as f64 / max_width as f64;
+            let style = if edge.mapped { "solid" } else { "dashed" };
+            dot.push_str(&format!(
+                "    n{}_{} -> n{}_{} [label=\"{}\", penwidth={:.2}, style={}];\n",
+                edge.stage,
+                edge.from,
+                edge.stage + 1,
+                edge.to,
+                edge.width,
+                pen_width,
+                style
+            ));
+        }
+
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn it_should_split_span_across_rules() -> miette::Result<()> {
+        let rules = [Rule {
+            destination: 100,
+            source: Span { start: 10, end: 20 },
+        }];
+
+        assert_eq!(
+            vec![
+                (
+                    Span {
+                        start: 100,
+                        end: 110
+                    },
+                    true
+                ),
+                (Span { start: 5, end: 10 }, false),
+                (Span { start: 20, end: 25 }, false),
+            ],
+            map_span(Span { start: 5, end: 25 }, &rules)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_conserve_seeds_through_every_stage() -> miette::Result<()> {
+        let flow = flow(include_str!("../example1.txt"))?;
+
+        assert_eq!(8, flow.stages.len());
+        assert_eq!("location", flow.stages[7].category);
+
+        for stage in &flow.stages {
+            assert_eq!(27, stage.ranges.iter().map(Span::len).sum::<u64>());
+        }
+
+        let nodes = flow.stages.iter().map(|s| s.ranges.len()).sum::<usize>();
+        assert_eq!(nodes - flow.stages[0].ranges.len(), flow.edges.len());
+
+        let lowest = flow.stages[7].ranges.iter().map(|s| s.start).min();
+        assert_eq!(Some(46), lowest);
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_write_dot() -> miette::Result<()> {
+        let dot = flow(include_str!("../example1.txt"))?.to_dot();
+
+        assert!(dot.starts_with("digraph almanac {"));
+        assert!(dot.contains("label=\"seed\""));
+        assert!(dot.contains("n0_0 [label=\"79..93\"]"));
+        assert!(dot.contains("n0_0 -> n1_0 [label=\"14\", penwidth=10.00, style=solid]"));
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_reject_bad_headers() -> miette::Result<()> {
+        assert!(matches!(flow("79 14"), Err(Error::CannotFindSeedsHeader)));
+        assert!(matches!(
+            flow("seeds: 79 14\n\nseed soil\n1 2 3"),
+            Err(Error::CannotFindMapHeader)
+        ));
+        Ok(())
+    }
+}
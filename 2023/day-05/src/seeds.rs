@@ -0,0 +1,33 @@
+use aoc_input::parse::{finish, labelled_numbers, Reason};
+
+use crate::{error::Error, prelude::*};
+
+/// The numbers on the `seeds:` line, however each part goes on to read them.
+#[tracing::instrument]
+pub fn parse(line: &str) -> Result<Vec<u64>> {
+    finish(labelled_numbers("seeds"), line).map_err(|failure| match failure.reason {
+        Reason::Number(e) => Error::CouldNotParseNumber(e),
+        Reason::Expected(_) => Error::CannotFindSeedsHeader,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn it_should_parse_the_seeds_line() -> miette::Result<()> {
+        assert_eq!(vec![79, 14, 55, 13], parse("seeds: 79 14 55 13")?);
+        assert_eq!(Vec::<u64>::new(), parse("seeds:")?);
+        assert!(matches!(
+            parse("soil: 79 14"),
+            Err(Error::CannotFindSeedsHeader)
+        ));
+        assert!(matches!(
+            parse("seeds: 79 x"),
+            Err(Error::CouldNotParseNumber(_))
+        ));
+        Ok(())
+    }
+}
@@ -1,5 +1,4 @@
-
-use miette::Diagnostic;
+use miette::{Diagnostic, SourceSpan};
 use thiserror::Error;
 
 #[derive(Error, Diagnostic, Debug)]
@@ -9,6 +8,88 @@ pub enum Error {
     IoError(#[from] std::io::Error),
     #[error("Could not parse number {0}")]
     CouldNotParseNumber(#[from] std::num::ParseIntError),
-    #[error("Missing distance {0}")]
-    MissingDistance(usize),
-}
\ No newline at end of file
+    #[error("No line starts with {prefix}")]
+    MissingLine {
+        prefix: &'static str,
+        #[source_code]
+        input: String,
+        #[label("expected a {prefix} line in here")]
+        span: SourceSpan,
+    },
+    #[error("{times} race times but {distances} distances")]
+    RaceCountMismatch {
+        times: usize,
+        distances: usize,
+        #[source_code]
+        input: String,
+        #[label("{times} times")]
+        time_span: SourceSpan,
+        #[label("{distances} distances")]
+        distance_span: SourceSpan,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{part1, part1_opt, part2, part2_opt};
+
+    fn error_from(report: miette::Report) -> Error {
+        report.downcast::<Error>().expect("expected a day 6 error")
+    }
+
+    #[test]
+    fn it_should_reach_could_not_parse_number() -> miette::Result<()> {
+        let input = "Time: 7 x
+        Distance: 9 40";
+        for error in [
+            error_from(part1::process(input).unwrap_err()),
+            error_from(part1_opt::process(input).unwrap_err()),
+            error_from(part2::process(input).unwrap_err()),
+            error_from(part2_opt::process(input).unwrap_err()),
+        ] {
+            assert!(matches!(error, Error::CouldNotParseNumber(_)));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_reach_race_count_mismatch() -> miette::Result<()> {
+        let input = "Time: 7 15
+        Distance: 9";
+        for error in [
+            error_from(part1::process(input).unwrap_err()),
+            error_from(part1_opt::process(input).unwrap_err()),
+        ] {
+            assert!(matches!(
+                error,
+                Error::RaceCountMismatch {
+                    times: 2,
+                    distances: 1,
+                    ..
+                }
+            ));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_reach_missing_line() -> miette::Result<()> {
+        let input = "Distance: 9 40";
+        for error in [
+            error_from(part1::process(input).unwrap_err()),
+            error_from(part1_opt::process(input).unwrap_err()),
+            error_from(part2::process(input).unwrap_err()),
+            error_from(part2_opt::process(input).unwrap_err()),
+        ] {
+            assert!(matches!(
+                error,
+                Error::MissingLine {
+                    prefix: "Time:",
+                    ..
+                }
+            ));
+        }
+        Ok(())
+    }
+}
@@ -5,3 +5,8 @@ pub mod part1;
 pub mod part2;
 pub mod part1_opt;
 pub mod part2_opt;
+pub mod race;
+pub mod sheet;
+pub mod viz;
+
+aoc_core::solution!(Day06, 2023, 6, part1::process, part2::process);
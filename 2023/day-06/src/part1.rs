@@ -1,4 +1,6 @@
-use crate::{error::Error, prelude::*};
+use aoc_core::Answer;
+
+use crate::{error::Error, prelude::*, sheet::Sheet};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 struct Race {
@@ -8,9 +10,7 @@ struct Race {
 
 #[tracing::instrument]
 fn numbers_from_line(input: &str) -> Result<Vec<u64>> {
-    let colon_split: Vec<&str> = input.split(": ").collect();
-
-    colon_split[1]
+    input
         .split(' ')
         .filter(|s| !s.is_empty())
         .map(|s| s.parse::<u64>().map_err(Error::CouldNotParseNumber))
@@ -19,22 +19,16 @@ fn numbers_from_line(input: &str) -> Result<Vec<u64>> {
 
 #[tracing::instrument]
 fn input_to_races(input: &str) -> Result<Vec<Race>> {
-    let mut races = vec![];
+    let sheet = Sheet::parse(input)?;
 
-    let lines: Vec<&str> = input.split('\n').map(|l| l.trim()).collect();
+    let times = numbers_from_line(sheet.time.values)?;
+    let distances = numbers_from_line(sheet.distance.values)?;
 
-    let times = numbers_from_line(lines[0])?;
-    let distances = numbers_from_line(lines[1])?;
-
-    for (i, time) in times.iter().enumerate() {
-        match distances.get(i) {
-            Some(distance) => races.push(Race {
-                time: *time,
-                distance: *distance,
-            }),
-            None => return Err(Error::MissingDistance(i)),
-        }
-    }
+    let races = sheet
+        .pair(times, distances)?
+        .into_iter()
+        .map(|(time, distance)| Race { time, distance })
+        .collect();
 
     Ok(races)
 }
@@ -54,10 +48,14 @@ fn number_of_ways_to_beat_race(race: &Race) -> u64 {
 }
 
 #[tracing::instrument]
-pub fn process(input: &str) -> miette::Result<u64> {
+pub fn process(input: &str) -> miette::Result<Answer> {
     let races = input_to_races(input)?;
+    let ways = races
+        .iter()
+        .map(number_of_ways_to_beat_race)
+        .product::<u64>();
 
-    Ok(races.iter().map(number_of_ways_to_beat_race).product())
+    Ok(Answer::from(ways))
 }
 
 #[cfg(test)]
@@ -67,7 +65,7 @@ mod tests {
 
     #[test]
     fn it_should_parse_races() -> miette::Result<()> {
-        let input = include_str!("../input1.txt");
+        let input = &aoc_core::puzzle_input!("input1.txt")?;
 
         let expected = vec![
             Race {
@@ -118,7 +116,7 @@ mod tests {
     fn test_process() -> miette::Result<()> {
         let input = "Time:      7  15   30
         Distance:  9  40  200";
-        assert_eq!(288, process(input)?);
+        assert_eq!(Answer::UInt(288), process(input)?);
         Ok(())
     }
 }
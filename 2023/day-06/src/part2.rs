@@ -1,4 +1,6 @@
-use crate::{error::Error, prelude::*};
+use aoc_core::Answer;
+
+use crate::{error::Error, prelude::*, sheet::Sheet};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 struct Race {
@@ -8,9 +10,7 @@ struct Race {
 
 #[tracing::instrument]
 fn number_from_line(input: &str) -> Result<u64> {
-    let colon_split: Vec<&str> = input.split(": ").collect();
-
-    colon_split[1]
+    input
         .chars()
         .filter(|s| s != &' ')
         .collect::<String>()
@@ -20,10 +20,10 @@ fn number_from_line(input: &str) -> Result<u64> {
 
 #[tracing::instrument]
 fn input_to_race(input: &str) -> Result<Race> {
-    let lines: Vec<&str> = input.split('\n').map(|l| l.trim()).collect();
+    let sheet = Sheet::parse(input)?;
 
-    let time = number_from_line(lines[0])?;
-    let distance = number_from_line(lines[1])?;
+    let time = number_from_line(sheet.time.values)?;
+    let distance = number_from_line(sheet.distance.values)?;
 
     Ok(Race { time, distance })
 }
@@ -43,10 +43,10 @@ fn number_of_ways_to_beat_race(race: &Race) -> u64 {
 }
 
 #[tracing::instrument]
-pub fn process(input: &str) -> miette::Result<u64> {
+pub fn process(input: &str) -> miette::Result<Answer> {
     let race = input_to_race(input)?;
 
-    Ok(number_of_ways_to_beat_race(&race))
+    Ok(Answer::from(number_of_ways_to_beat_race(&race)))
 }
 
 #[cfg(test)]
@@ -56,7 +56,7 @@ mod tests {
 
     #[test]
     fn it_should_parse_races() -> miette::Result<()> {
-        let input = include_str!("../input1.txt");
+        let input = &aoc_core::puzzle_input!("input1.txt")?;
 
         let expected = Race {
             time: 41777096,
@@ -93,7 +93,7 @@ mod tests {
     fn test_process() -> miette::Result<()> {
         let input = "Time:      7  15   30
         Distance:  9  40  200";
-        assert_eq!(71503, process(input)?);
+        assert_eq!(Answer::UInt(71503), process(input)?);
         Ok(())
     }
 }
@@ -0,0 +1,142 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Race {
+    pub time: u64,
+    pub distance: u64,
+}
+
+#[tracing::instrument]
+pub fn calculate_max_distance_for_time(press_down_time: u64, max_time: u64) -> u64 {
+    let time_remaining = max_time - press_down_time;
+    time_remaining * press_down_time
+}
+
+#[tracing::instrument]
+fn beats_record(race: &Race, press_down_time: u64) -> bool {
+    calculate_max_distance_for_time(press_down_time, race.time) > race.distance
+}
+
+/// The first and last press times (inclusive) that beat the record, or `None`
+/// when even the best press can't. Distance is symmetric about `time / 2`, so
+/// only the first winner is searched for and the last is mirrored from it.
+#[tracing::instrument]
+pub fn winning_interval(race: &Race) -> Option<(u64, u64)> {
+    let peak = race.time / 2;
+
+    if !beats_record(race, peak) {
+        return None;
+    }
+
+    // Pressing for 0 never moves the boat, so `low` always loses and `high` always wins.
+    let mut low = 0;
+    let mut high = peak;
+
+    while high - low > 1 {
+        let index = low + (high - low) / 2;
+
+        if beats_record(race, index) {
+            high = index;
+        } else {
+            low = index;
+        }
+    }
+
+    Some((high, race.time - high))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn brute_force(race: &Race) -> Option<(u64, u64)> {
+        let first = (0..=race.time).find(|t| beats_record(race, *t))?;
+        let last = (0..=race.time).rev().find(|t| beats_record(race, *t))?;
+        Some((first, last))
+    }
+
+    #[test]
+    fn it_should_calculate_max_distance_for_time() -> miette::Result<()> {
+        assert_eq!(0, calculate_max_distance_for_time(0, 7));
+        assert_eq!(6, calculate_max_distance_for_time(1, 7));
+        assert_eq!(12, calculate_max_distance_for_time(3, 7));
+        assert_eq!(12, calculate_max_distance_for_time(4, 7));
+        assert_eq!(0, calculate_max_distance_for_time(7, 7));
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_find_example_intervals() -> miette::Result<()> {
+        assert_eq!(
+            Some((2, 5)),
+            winning_interval(&Race {
+                time: 7,
+                distance: 9
+            })
+        );
+        assert_eq!(
+            Some((4, 11)),
+            winning_interval(&Race {
+                time: 15,
+                distance: 40
+            })
+        );
+        assert_eq!(
+            Some((11, 19)),
+            winning_interval(&Race {
+                time: 30,
+                distance: 200
+            })
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_find_no_interval_when_record_is_unbeatable() -> miette::Result<()> {
+        for (time, distance) in [(4, 4), (7, 12), (1, 0), (0, 0)] {
+            assert_eq!(None, winning_interval(&Race { time, distance }));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_find_single_winning_press() -> miette::Result<()> {
+        assert_eq!(
+            Some((2, 2)),
+            winning_interval(&Race {
+                time: 4,
+                distance: 3
+            })
+        );
+        assert_eq!(
+            Some((1, 1)),
+            winning_interval(&Race {
+                time: 2,
+                distance: 0
+            })
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_find_full_range_when_any_movement_wins() -> miette::Result<()> {
+        assert_eq!(
+            Some((1, 6)),
+            winning_interval(&Race {
+                time: 7,
+                distance: 0
+            })
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_match_brute_force() -> miette::Result<()> {
+        for time in 0..40 {
+            for distance in 0..=(time * time / 4 + 1) {
+                let race = Race { time, distance };
+                assert_eq!(brute_force(&race), winning_interval(&race), "{:?}", race);
+            }
+        }
+        Ok(())
+    }
+}
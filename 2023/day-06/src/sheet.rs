@@ -0,0 +1,156 @@
+use miette::SourceSpan;
+
+use crate::{error::Error, prelude::*};
+
+/// The numbers after one of the sheet's prefixes, and where its line sits in
+/// the input so errors can point at it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Line<'a> {
+    pub values: &'a str,
+    pub span: SourceSpan,
+}
+
+/// The `Time:` and `Distance:` lines, found by prefix wherever they are so
+/// blank lines, reordering and anything else in the input don't matter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Sheet<'a> {
+    input: &'a str,
+    pub time: Line<'a>,
+    pub distance: Line<'a>,
+}
+
+#[tracing::instrument(skip(input))]
+fn find_line<'a>(input: &'a str, prefix: &'static str) -> Result<Line<'a>> {
+    let mut offset = 0;
+
+    for line in input.split_inclusive('\n') {
+        let trimmed = line.trim();
+
+        if let Some(values) = trimmed.strip_prefix(prefix) {
+            let start = offset + line.len() - line.trim_start().len();
+            return Ok(Line {
+                values,
+                span: (start, trimmed.len()).into(),
+            });
+        }
+
+        offset += line.len();
+    }
+
+    Err(Error::MissingLine {
+        prefix,
+        input: input.to_string(),
+        span: (0, input.len()).into(),
+    })
+}
+
+impl<'a> Sheet<'a> {
+    #[tracing::instrument(skip(input))]
+    pub fn parse(input: &'a str) -> Result<Self> {
+        Ok(Self {
+            input,
+            time: find_line(input, "Time:")?,
+            distance: find_line(input, "Distance:")?,
+        })
+    }
+
+    /// Pairs each time with its distance, failing when a race is missing either.
+    #[tracing::instrument(skip(self, times, distances))]
+    pub fn pair<T>(&self, times: Vec<T>, distances: Vec<T>) -> Result<Vec<(T, T)>> {
+        if times.len() != distances.len() {
+            return Err(Error::RaceCountMismatch {
+                times: times.len(),
+                distances: distances.len(),
+                input: self.input.to_string(),
+                time_span: self.time.span,
+                distance_span: self.distance.span,
+            });
+        }
+
+        Ok(times.into_iter().zip(distances).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{part1, part1_opt, part2, part2_opt};
+    use aoc_core::Answer;
+    use pretty_assertions::assert_eq;
+
+    fn answers(input: &str) -> miette::Result<[Answer; 4]> {
+        Ok([
+            part1::process(input)?,
+            part1_opt::process(input)?,
+            part2::process(input)?,
+            part2_opt::process(input)?,
+        ])
+    }
+
+    #[test]
+    fn it_should_find_lines_by_prefix() -> miette::Result<()> {
+        let input = "\n  Time:  7 15\nDistance: 9 40\n";
+        let sheet = Sheet::parse(input)?;
+
+        assert_eq!("  7 15", sheet.time.values);
+        assert_eq!(SourceSpan::from((3, 11)), sheet.time.span);
+        assert_eq!(" 9 40", sheet.distance.values);
+        assert_eq!(SourceSpan::from((15, 14)), sheet.distance.span);
+        assert_eq!(
+            vec![(7, 9), (15, 40)],
+            sheet.pair(vec![7, 15], vec![9, 40])?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_solve_blank_line_padded_input() -> miette::Result<()> {
+        let input = "
+
+        Time:      7  15   30
+
+        Distance:  9  40  200
+
+        ";
+        assert_eq!([288, 288, 71503, 71503].map(Answer::UInt), answers(input)?);
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_solve_reordered_input() -> miette::Result<()> {
+        let input = "Distance:  9  40  200
+        notes: the example, upside down
+        Time:      7  15   30";
+        assert_eq!([288, 288, 71503, 71503].map(Answer::UInt), answers(input)?);
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_point_at_a_missing_line() -> miette::Result<()> {
+        let error = Sheet::parse("Time: 7 15\n").unwrap_err();
+
+        assert!(matches!(
+            error,
+            Error::MissingLine {
+                prefix: "Distance:",
+                ..
+            }
+        ));
+        assert_eq!(1, miette::Diagnostic::labels(&error).unwrap().count());
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_point_at_both_lines_when_counts_differ() -> miette::Result<()> {
+        let sheet = Sheet::parse("Time: 7 15 30\nDistance: 9 40")?;
+        let error = sheet.pair(vec![7, 15, 30], vec![9, 40]).unwrap_err();
+
+        assert_eq!("3 race times but 2 distances", error.to_string());
+        let spans = miette::Diagnostic::labels(&error)
+            .unwrap()
+            .map(|label| *label.inner())
+            .collect::<Vec<_>>();
+        assert_eq!(vec![sheet.time.span, sheet.distance.span], spans);
+        Ok(())
+    }
+}
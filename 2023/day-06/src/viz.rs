@@ -0,0 +1,200 @@
+use aoc_viz::{Canvas, Color};
+
+use crate::{
+    part1_opt::input_to_races,
+    prelude::*,
+    race::{calculate_max_distance_for_time, winning_interval, Race},
+};
+
+const PLOT_WIDTH: u64 = 60;
+const PLOT_HEIGHT: usize = 12;
+
+/// Maps press times and distances onto plot cells. Long races are sampled so
+/// every column is still a real press time.
+#[derive(Debug, Clone, Copy)]
+struct Scale {
+    time: u64,
+    columns: u64,
+    peak: u64,
+}
+
+impl Scale {
+    #[tracing::instrument]
+    fn new(race: &Race) -> Self {
+        Self {
+            time: race.time,
+            columns: (race.time + 1).min(PLOT_WIDTH),
+            peak: calculate_max_distance_for_time(race.time / 2, race.time).max(1),
+        }
+    }
+
+    #[tracing::instrument]
+    fn press_time(&self, x: u64) -> u64 {
+        if self.columns <= 1 {
+            return 0;
+        }
+        (x as u128 * self.time as u128 / (self.columns - 1) as u128) as u64
+    }
+
+    #[tracing::instrument]
+    fn column(&self, press_time: u64) -> usize {
+        if self.time == 0 {
+            return 0;
+        }
+        (press_time as u128 * (self.columns - 1) as u128 / self.time as u128) as usize
+    }
+
+    #[tracing::instrument]
+    fn row(&self, distance: u64) -> usize {
+        let scaled =
+            distance.min(self.peak) as u128 * (PLOT_HEIGHT - 1) as u128 / self.peak as u128;
+        PLOT_HEIGHT - 1 - scaled as usize
+    }
+}
+
+/// One plot per race of distance against press time. The record is the
+/// yellow line, and the ends of the winning interval are marked in blue so
+/// their boundaries can be checked.
+#[tracing::instrument(skip(input))]
+pub fn render(input: &str, ansi: bool) -> Result<String> {
+    let races = input_to_races(input)?;
+
+    Ok(races
+        .iter()
+        .enumerate()
+        .map(|(i, race)| render_race(i + 1, race, ansi))
+        .collect::<Vec<_>>()
+        .join("\n"))
+}
+
+#[tracing::instrument]
+fn render_race(number: usize, race: &Race, ansi: bool) -> String {
+    let scale = Scale::new(race);
+    let width = scale.columns as usize;
+    let record_label = format!(" record {}", race.distance);
+
+    let winners = winning_interval(race);
+
+    let header = match winners {
+        Some((first, last)) => format!(
+            "Race {}: time {}, record {}, wins {}..={}",
+            number, race.time, race.distance, first, last
+        ),
+        None => format!(
+            "Race {}: time {}, record {}, no winning press times",
+            number, race.time, race.distance
+        ),
+    };
+
+    let mut canvas = Canvas::new(
+        (width + record_label.len()).max(header.len()),
+        PLOT_HEIGHT + 3,
+    );
+    canvas.text(0, 0, &header, Color::Default);
+
+    let record_row = 1 + scale.row(race.distance);
+    canvas.hline(0, record_row, width, '-', Color::Yellow);
+    canvas.text(width, record_row, &record_label, Color::Yellow);
+
+    if let Some((first, last)) = winners {
+        for press_time in [first, last] {
+            let x = scale.column(press_time);
+            canvas.vline(
+                x,
+                record_row,
+                PLOT_HEIGHT + 1 - record_row,
+                ':',
+                Color::Blue,
+            );
+        }
+    }
+
+    for x in 0..scale.columns {
+        let press_time = scale.press_time(x);
+        let distance = calculate_max_distance_for_time(press_time, race.time);
+        let color = if distance > race.distance {
+            Color::Green
+        } else {
+            Color::Grey
+        };
+        canvas.set(x as usize, 1 + scale.row(distance), '*', color);
+    }
+
+    canvas.hline(0, PLOT_HEIGHT + 1, width, '=', Color::Grey);
+
+    match winners {
+        Some((first, last)) => {
+            let first_label = first.to_string();
+            let last_label = last.to_string();
+            let last_x = (scale.column(last) + 1).saturating_sub(last_label.len());
+            canvas.text(
+                scale.column(first),
+                PLOT_HEIGHT + 2,
+                &first_label,
+                Color::Blue,
+            );
+            canvas.text(
+                last_x.max(scale.column(first) + first_label.len() + 1),
+                PLOT_HEIGHT + 2,
+                &last_label,
+                Color::Blue,
+            );
+        }
+        None => canvas.text(0, PLOT_HEIGHT + 2, "0", Color::Grey),
+    }
+
+    canvas.render(ansi)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn it_should_mark_winning_interval() -> miette::Result<()> {
+        let output = render("Time:      7\nDistance:  9", false)?;
+        let lines = output.lines().collect::<Vec<_>>();
+
+        assert_eq!("Race 1: time 7, record 9, wins 2..=5", lines[0]);
+        assert_eq!(PLOT_HEIGHT + 3, lines.len());
+        assert_eq!("  2  5", lines[PLOT_HEIGHT + 2]);
+        assert_eq!(8, lines[PLOT_HEIGHT + 1].len());
+
+        let top = lines[1];
+        assert_eq!(Some(3), top.find('*'));
+        assert_eq!(Some(4), top.rfind('*'));
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_render_each_race() -> miette::Result<()> {
+        let output = render(
+            "Time:      7  15   30
+            Distance:  9  40  200",
+            true,
+        )?;
+
+        assert_eq!(3, output.matches("Race ").count());
+        assert!(output.contains("wins 11..=19"));
+        assert!(output.contains("\x1b[33m"));
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_handle_unwinnable_race() -> miette::Result<()> {
+        let output = render("Time: 4\nDistance: 4", false)?;
+        assert!(output.starts_with("Race 1: time 4, record 4, no winning press times"));
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_sample_long_races() -> miette::Result<()> {
+        let output = render("Time: 71530\nDistance: 940200", false)?;
+        let lines = output.lines().collect::<Vec<_>>();
+
+        assert!(lines[0].ends_with("wins 14..=71516"));
+        assert_eq!(PLOT_WIDTH as usize, lines[PLOT_HEIGHT + 1].len());
+        Ok(())
+    }
+}
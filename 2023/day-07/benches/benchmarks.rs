@@ -6,27 +6,44 @@ fn main() {
 }
 
 #[divan::bench]
-fn part1() {
-    part1::process(divan::black_box(include_str!(
-        "../input1.txt",
-    )))
-    .unwrap();
+fn part1(bencher: divan::Bencher) {
+    let input = aoc_core::puzzle_input!("input1.txt").unwrap();
+    bencher.bench(|| part1::process(divan::black_box(&input)).unwrap());
 }
 
 #[divan::bench]
-fn part2() {
-    part2::process(divan::black_box(include_str!(
-        "../input2.txt",
-    )))
-    .unwrap();
+fn part2(bencher: divan::Bencher) {
+    let input = aoc_core::puzzle_input!("input2.txt").unwrap();
+    bencher.bench(|| part2::process(divan::black_box(&input)).unwrap());
 }
 
 #[divan::bench]
-fn part1_opt() {
-    part1_opt::process(divan::black_box(include_str!(
-        "../input1.txt",
-    )))
-    .unwrap();
+fn part1_opt(bencher: divan::Bencher) {
+    let input = aoc_core::puzzle_input!("input1.txt").unwrap();
+    bencher.bench(|| part1_opt::process(divan::black_box(&input)).unwrap());
+}
+
+#[divan::bench]
+fn part1_opt2(bencher: divan::Bencher) {
+    let input = aoc_core::puzzle_input!("input1.txt").unwrap();
+    bencher.bench(|| part1_opt2::process(divan::black_box(&input)).unwrap());
+}
+
+// part1 sums winnings into a u32, so keep bets small enough that 100k hands can't overflow it
+fn synthetic_hands() -> String {
+    generator::hands(100_000, 400, 2023)
+}
+
+#[divan::bench]
+fn part1_synthetic_100k(bencher: divan::Bencher) {
+    let input = synthetic_hands();
+    bencher.bench(|| part1::process(divan::black_box(&input)).unwrap());
+}
+
+#[divan::bench]
+fn part1_opt2_synthetic_100k(bencher: divan::Bencher) {
+    let input = synthetic_hands();
+    bencher.bench(|| part1_opt2::process(divan::black_box(&input)).unwrap());
 }
 
 // #[divan::bench]
@@ -35,4 +52,30 @@ fn part1_opt() {
 //         "../input2.txt",
 //     )))
 //     .unwrap();
-// }
\ No newline at end of file
+// }
+
+#[divan::bench]
+fn part1_parse(bencher: divan::Bencher) {
+    let input = aoc_core::puzzle_input!("input1.txt").unwrap();
+    bencher.bench(|| part1::parse(divan::black_box(&input)).unwrap());
+}
+
+#[divan::bench]
+fn part1_solve(bencher: divan::Bencher) {
+    let input = aoc_core::puzzle_input!("input1.txt").unwrap();
+    let hands = aoc_core::preload(&input, part1::parse).unwrap();
+    bencher.bench(|| part1::solve(divan::black_box(&hands)).unwrap());
+}
+
+#[divan::bench]
+fn part2_parse(bencher: divan::Bencher) {
+    let input = aoc_core::puzzle_input!("input2.txt").unwrap();
+    bencher.bench(|| part2::parse(divan::black_box(&input)).unwrap());
+}
+
+#[divan::bench]
+fn part2_solve(bencher: divan::Bencher) {
+    let input = aoc_core::puzzle_input!("input2.txt").unwrap();
+    let hands = aoc_core::preload(&input, part2::parse).unwrap();
+    bencher.bench(|| part2::solve(divan::black_box(&hands)).unwrap());
+}
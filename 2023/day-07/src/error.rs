@@ -1,3 +1,5 @@
+use aoc_core::AtLine;
+use itertools::Itertools;
 use miette::Diagnostic;
 use thiserror::Error;
 
@@ -5,12 +7,90 @@ use thiserror::Error;
 pub enum Error {
     #[error(transparent)]
     IoError(#[from] std::io::Error),
-    #[error(transparent)]
-    CouldNotParseNumber(#[from] std::num::ParseIntError),
-    #[error("Could not parse card {0}")]
-    CouldNotParseCard(String),
-    #[error("Unexpected number of cards in hand")]
-    UnexpectedNumberOfCards,
-    #[error("Could not parse hand and bet {0}")]
-    CouldNotParseHandAndBet(String),
-}
\ No newline at end of file
+    #[error("Could not parse bet {bet} on line {line}")]
+    CouldNotParseBet { line: usize, bet: String },
+    #[error("Could not parse card {card} on line {line}")]
+    CouldNotParseCard { line: usize, card: String },
+    #[error("Expected 5 cards in hand but found {count} on line {line}")]
+    UnexpectedNumberOfCards { line: usize, count: usize },
+    #[error("Expected a hand and a bet but found {input} on line {line}")]
+    CouldNotParseHandAndBet { line: usize, input: String },
+    #[error("Hand {hand} appears on lines {}, so they rank in input order", lines.iter().join(", "))]
+    #[diagnostic(severity(Warning))]
+    DuplicateHand { hand: String, lines: Vec<usize> },
+}
+
+impl AtLine for Error {
+    fn at_line(self, line: usize) -> Self {
+        match self {
+            Error::CouldNotParseBet { bet, .. } => Error::CouldNotParseBet { line, bet },
+            Error::CouldNotParseCard { card, .. } => Error::CouldNotParseCard { line, card },
+            Error::UnexpectedNumberOfCards { count, .. } => {
+                Error::UnexpectedNumberOfCards { line, count }
+            }
+            Error::CouldNotParseHandAndBet { input, .. } => {
+                Error::CouldNotParseHandAndBet { line, input }
+            }
+            other => other,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{part1, part1_opt, part1_opt2, part2};
+
+    fn error_from(report: miette::Report) -> Error {
+        report.downcast::<Error>().expect("expected a day 7 error")
+    }
+
+    fn assert_reaches(input: &str, expected: fn(&Error) -> bool) {
+        for error in [
+            error_from(part1::process(input).unwrap_err()),
+            error_from(part1_opt::process(input).unwrap_err()),
+            error_from(part1_opt2::process(input).unwrap_err()),
+            error_from(part2::process(input).unwrap_err()),
+        ] {
+            assert!(expected(&error), "unexpected error {:?}", error);
+        }
+    }
+
+    #[test]
+    fn it_should_reach_could_not_parse_card() -> miette::Result<()> {
+        assert_reaches("32T3K 765\n32T3X 765", |e| {
+            matches!(e, Error::CouldNotParseCard { line: 2, .. })
+        });
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_reach_unexpected_number_of_cards() -> miette::Result<()> {
+        assert_reaches("32T3 765", |e| {
+            matches!(e, Error::UnexpectedNumberOfCards { line: 1, count: 4 })
+        });
+        assert_reaches("32T3K 765\nKK677 28\n32T3KA 765", |e| {
+            matches!(e, Error::UnexpectedNumberOfCards { line: 3, count: 6 })
+        });
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_reach_could_not_parse_hand_and_bet() -> miette::Result<()> {
+        assert_reaches("32T3K", |e| {
+            matches!(e, Error::CouldNotParseHandAndBet { line: 1, .. })
+        });
+        assert_reaches("32T3K 765\nKK677 28 1", |e| {
+            matches!(e, Error::CouldNotParseHandAndBet { line: 2, .. })
+        });
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_reach_could_not_parse_bet() -> miette::Result<()> {
+        assert_reaches("32T3K x", |e| {
+            matches!(e, Error::CouldNotParseBet { line: 1, .. })
+        });
+        Ok(())
+    }
+}
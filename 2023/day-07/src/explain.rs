@@ -0,0 +1,151 @@
+use std::collections::HashMap;
+
+use aoc_core::{numbered_lines, AtLine};
+use aoc_viz::{Canvas, Color};
+
+use crate::{error::Error, part1_opt2::parse_line, prelude::*};
+
+/// A `DuplicateHand` warning for every hand that appears on more than one
+/// line, in the order the hands first appear. Blank lines are skipped.
+#[tracing::instrument(skip(input))]
+pub fn duplicate_hands(input: &str) -> Vec<Error> {
+    let mut seen = HashMap::<&str, usize>::new();
+    let mut duplicates: Vec<(&str, Vec<usize>)> = vec![];
+
+    for (number, line) in numbered_lines(input) {
+        let Some(hand) = line.split_whitespace().next() else {
+            continue;
+        };
+
+        let index = *seen.entry(hand).or_insert_with(|| {
+            duplicates.push((hand, vec![]));
+            duplicates.len() - 1
+        });
+        duplicates[index].1.push(number);
+    }
+
+    duplicates
+        .into_iter()
+        .filter(|(_, lines)| lines.len() > 1)
+        .map(|(hand, lines)| Error::DuplicateHand {
+            hand: hand.to_string(),
+            lines,
+        })
+        .collect()
+}
+
+/// Every hand's part 1 rank and winnings, in input order and numbered as in
+/// `input`, after a yellow warning for each duplicated hand.
+#[tracing::instrument(skip(input))]
+pub fn render(input: &str, ansi: bool) -> Result<String> {
+    let hands = numbered_lines(input)
+        .filter(|(_, line)| !line.is_empty())
+        .map(|(number, line)| Ok((number, line, parse_line(line).at_line(number)?)))
+        .collect::<Result<Vec<_>>>()?;
+
+    // Ties rank in input order, as they do in `ranking::rank`.
+    let mut order = (0..hands.len()).collect::<Vec<_>>();
+    order.sort_by_key(|i| hands[*i].2 .0);
+
+    let mut ranks = vec![0; hands.len()];
+    for (rank, i) in order.into_iter().enumerate() {
+        ranks[i] = rank + 1;
+    }
+
+    let rows = duplicate_hands(input)
+        .into_iter()
+        .map(|warning| (format!("warning: {}", warning), Color::Yellow))
+        .chain(
+            hands
+                .iter()
+                .zip(&ranks)
+                .map(|((number, line, (_, bet)), rank)| {
+                    let hand = line.split_whitespace().next().unwrap_or_default();
+                    let text = format!(
+                        "Line {}: {} bet {}, rank {}, wins {}",
+                        number,
+                        hand,
+                        bet,
+                        rank,
+                        *bet as u64 * *rank as u64
+                    );
+                    (text, Color::Default)
+                }),
+        )
+        .collect::<Vec<_>>();
+
+    let width = rows.iter().map(|(text, _)| text.len()).max().unwrap_or(0);
+    let mut canvas = Canvas::new(width, rows.len());
+    for (y, (text, color)) in rows.iter().enumerate() {
+        canvas.text(0, y, text, *color);
+    }
+
+    Ok(canvas.render(ansi))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{part1, part1_opt, part1_opt2, part2};
+    use aoc_core::Answer;
+    use pretty_assertions::assert_eq;
+
+    const DUPLICATED: &str = "32T3K 1
+KK677 100
+32T3K 10";
+
+    #[test]
+    fn it_should_report_duplicate_hands_with_lines() -> miette::Result<()> {
+        let warnings = duplicate_hands("32T3K 1\n\nKK677 2\n32T3K 3\nKK677 4\nQQQJA 5\n32T3K 6");
+
+        assert_eq!(2, warnings.len());
+        assert_eq!(
+            "Hand 32T3K appears on lines 1, 4, 7, so they rank in input order",
+            warnings[0].to_string()
+        );
+        assert!(matches!(
+            &warnings[1],
+            Error::DuplicateHand { hand, lines } if hand == "KK677" && lines == &[3, 5]
+        ));
+        assert_eq!(
+            Some(miette::Severity::Warning),
+            miette::Diagnostic::severity(&warnings[0])
+        );
+        assert!(duplicate_hands(&aoc_core::puzzle_input!("input1.txt")?).is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_rank_duplicates_in_input_order() -> miette::Result<()> {
+        // Input order gives 1 * 1 + 10 * 2 + 100 * 3; the other way round would be 312
+        assert_eq!(Answer::UInt(321), part1::process(DUPLICATED)?);
+        assert_eq!(Answer::UInt(321), part1_opt::process(DUPLICATED)?);
+        assert_eq!(Answer::UInt(321), part1_opt2::process(DUPLICATED)?);
+        assert_eq!(Answer::UInt(321), part2::process(DUPLICATED)?);
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_render_ranks_after_warnings() -> miette::Result<()> {
+        let output = render(DUPLICATED, false)?;
+
+        assert_eq!(
+            "warning: Hand 32T3K appears on lines 1, 3, so they rank in input order
+Line 1: 32T3K bet 1, rank 1, wins 1
+Line 2: KK677 bet 100, rank 3, wins 300
+Line 3: 32T3K bet 10, rank 2, wins 20
+",
+            output
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_skip_blank_lines_when_rendering() -> miette::Result<()> {
+        let output = render("\nKK677 28\n\n32T3K 765", true)?;
+
+        assert!(output.starts_with("Line 2: KK677 bet 28, rank 2, wins 56"));
+        assert!(!output.contains("warning"));
+        Ok(())
+    }
+}
@@ -0,0 +1,77 @@
+use aoc_core::alphabet::CardRank;
+use aoc_testing::XorShift;
+
+const CARDS: &[u8; 13] = CardRank::SYMBOLS;
+
+/// Generates `count` random "hand bet" lines in the puzzle's format.
+#[tracing::instrument]
+pub fn hands(count: usize, max_bet: u32, seed: u64) -> String {
+    let mut rng = XorShift::new(seed);
+    let mut output = String::with_capacity(count * 10);
+
+    for _ in 0..count {
+        for _ in 0..5 {
+            output.push(CARDS[(rng.next_u64() % CARDS.len() as u64) as usize] as char);
+        }
+        output.push(' ');
+        output.push_str(&(1 + rng.next_u64() % max_bet as u64).to_string());
+        output.push('\n');
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{part1, part1_opt, part1_opt2, part2};
+    use aoc_core::Answer;
+    use pretty_assertions::assert_eq;
+
+    type Process = fn(&str) -> miette::Result<Answer>;
+
+    #[test]
+    fn it_should_generate_requested_number_of_hands() -> miette::Result<()> {
+        let input = hands(100, 1000, 7);
+        assert_eq!(100, input.lines().count());
+        assert!(input.lines().all(|line| line.len() >= 7 && line.len() <= 10));
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_be_reproducible() -> miette::Result<()> {
+        assert_eq!(hands(50, 1000, 42), hands(50, 1000, 42));
+        assert!(hands(50, 1000, 42) != hands(50, 1000, 43));
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_total_winnings_past_u32_max() -> miette::Result<()> {
+        // Every bet is the same, so the total is bet * n(n + 1) / 2 whatever the ranking
+        let input = hands(1_000, 1, 7);
+        let just_under = input.replace(" 1\n", " 8581\n");
+        let just_over = input.replace(" 1\n", " 8582\n");
+
+        let variants: [(&str, Process); 4] = [
+            ("part1", part1::process),
+            ("part1_opt", part1_opt::process),
+            ("part1_opt2", part1_opt2::process),
+            ("part2", part2::process),
+        ];
+        for (name, process) in variants {
+            assert_eq!(
+                Answer::UInt(4_294_790_500),
+                process(&just_under)?,
+                "{}",
+                name
+            );
+            assert_eq!(
+                Answer::UInt(4_295_291_000),
+                process(&just_over)?,
+                "{}",
+                name
+            );
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,181 @@
+use aoc_core::alphabet::CardRank;
+
+use crate::{error::Error, prelude::*};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Ord, PartialOrd, Hash)]
+pub enum HandType {
+    HighCard,
+    OnePair,
+    TwoPair,
+    ThreeOfAKind,
+    FullHouse,
+    FourOfAKind,
+    FiveOfAKind,
+}
+
+impl HandType {
+    /// Classifies five cards from how many there are of each rank, so card
+    /// order never matters. Any `wildcard` cards join the largest group, which
+    /// always makes the strongest hand: part 1 has none, part 2's are jacks.
+    #[tracing::instrument]
+    pub fn from_cards(cards: &[CardRank], wildcard: Option<CardRank>) -> Result<Self> {
+        if cards.len() != 5 {
+            return Err(Error::UnexpectedNumberOfCards {
+                line: 0,
+                count: cards.len(),
+            });
+        }
+
+        let mut counts = [0u8; 13];
+        for card in cards {
+            counts[card.index()] += 1;
+        }
+
+        let wildcards =
+            wildcard.map_or(0, |wildcard| std::mem::take(&mut counts[wildcard.index()]));
+
+        counts.sort_unstable_by(|a, b| b.cmp(a));
+        counts[0] += wildcards;
+
+        Ok(match (counts[0], counts[1]) {
+            (5, _) => HandType::FiveOfAKind,
+            (4, _) => HandType::FourOfAKind,
+            (3, 2) => HandType::FullHouse,
+            (3, _) => HandType::ThreeOfAKind,
+            (2, 2) => HandType::TwoPair,
+            (2, _) => HandType::OnePair,
+            _ => HandType::HighCard,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn cards(hand: &str) -> Vec<CardRank> {
+        hand.chars().filter_map(CardRank::from_char).collect()
+    }
+
+    fn jokers(hand: &str) -> miette::Result<HandType> {
+        Ok(HandType::from_cards(&cards(hand), Some(CardRank::Jack))?)
+    }
+
+    /// How many distinct ranks there are and the size of the largest group
+    /// pins down the type without any wildcards, independently of `counts`.
+    fn by_shape(cards: &[CardRank]) -> HandType {
+        let distinct = CardRank::ALL
+            .iter()
+            .filter(|rank| cards.contains(rank))
+            .count();
+        let largest = CardRank::ALL
+            .iter()
+            .map(|rank| cards.iter().filter(|card| *card == rank).count())
+            .max()
+            .unwrap_or_default();
+
+        match (distinct, largest) {
+            (1, _) => HandType::FiveOfAKind,
+            (2, 4) => HandType::FourOfAKind,
+            (2, _) => HandType::FullHouse,
+            (3, 3) => HandType::ThreeOfAKind,
+            (3, _) => HandType::TwoPair,
+            (4, _) => HandType::OnePair,
+            _ => HandType::HighCard,
+        }
+    }
+
+    #[test]
+    fn it_should_classify_every_type_without_wildcards() -> miette::Result<()> {
+        for (hand, expected) in [
+            ("AAAAA", HandType::FiveOfAKind),
+            ("AA8AA", HandType::FourOfAKind),
+            ("23332", HandType::FullHouse),
+            ("TTT98", HandType::ThreeOfAKind),
+            ("23432", HandType::TwoPair),
+            ("A23A4", HandType::OnePair),
+            ("23456", HandType::HighCard),
+            ("JJJJJ", HandType::FiveOfAKind),
+            ("KTJJT", HandType::TwoPair),
+        ] {
+            assert_eq!(
+                expected,
+                HandType::from_cards(&cards(hand), None)?,
+                "{}",
+                hand
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_classify_every_type_with_each_number_of_jokers() -> miette::Result<()> {
+        // Types that can't be reached with that many jokers are missing, as
+        // two or more jokers always make at least three of a kind.
+        let table = [
+            (0, "AAAAA", HandType::FiveOfAKind),
+            (0, "AAAA2", HandType::FourOfAKind),
+            (0, "AAA22", HandType::FullHouse),
+            (0, "AAA23", HandType::ThreeOfAKind),
+            (0, "AA223", HandType::TwoPair),
+            (0, "AA234", HandType::OnePair),
+            (0, "A2345", HandType::HighCard),
+            (1, "AAAAJ", HandType::FiveOfAKind),
+            (1, "AAA2J", HandType::FourOfAKind),
+            (1, "AA22J", HandType::FullHouse),
+            (1, "AA23J", HandType::ThreeOfAKind),
+            (1, "A234J", HandType::OnePair),
+            (2, "AAAJJ", HandType::FiveOfAKind),
+            (2, "AA2JJ", HandType::FourOfAKind),
+            (2, "A23JJ", HandType::ThreeOfAKind),
+            (3, "AAJJJ", HandType::FiveOfAKind),
+            (3, "A2JJJ", HandType::FourOfAKind),
+            (4, "AJJJJ", HandType::FiveOfAKind),
+            (5, "JJJJJ", HandType::FiveOfAKind),
+        ];
+
+        for (count, hand, expected) in table {
+            assert_eq!(count, hand.matches('J').count(), "{}", hand);
+            assert_eq!(expected, jokers(hand)?, "{}", hand);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_match_the_best_replacement_for_every_hand() -> miette::Result<()> {
+        let ranks = CardRank::ALL;
+        let mut hand = [CardRank::Two; 5];
+
+        for i in 0..ranks.len().pow(5) {
+            let mut n = i;
+            for card in hand.iter_mut() {
+                *card = ranks[n % ranks.len()];
+                n /= ranks.len();
+            }
+
+            assert_eq!(by_shape(&hand), HandType::from_cards(&hand, None)?);
+
+            let best = ranks
+                .iter()
+                .map(|rank| {
+                    by_shape(&hand.map(|card| if card == CardRank::Jack { *rank } else { card }))
+                })
+                .max();
+            assert_eq!(
+                best,
+                Some(HandType::from_cards(&hand, Some(CardRank::Jack))?)
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_need_five_cards() -> miette::Result<()> {
+        assert!(matches!(
+            HandType::from_cards(&cards("AAAA"), None),
+            Err(Error::UnexpectedNumberOfCards { count: 4, .. })
+        ));
+        Ok(())
+    }
+}
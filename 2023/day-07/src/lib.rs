@@ -1,7 +1,48 @@
 pub mod error;
 pub mod prelude;
 
+pub mod explain;
+pub mod generator;
+pub mod hand_type;
+pub mod ranking;
+
 pub mod part1;
 pub mod part2;
 pub mod part1_opt;
+pub mod part1_opt2;
 pub mod part2_opt;
+
+/// Every hand and bet as each part reads them, since part 2 takes `J` for a
+/// joker rather than a jack.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Hands {
+    pub jacks: Vec<part1::HandAndBet>,
+    pub jokers: Vec<part2::HandAndBet>,
+}
+
+/// Day 7 behind the shared `aoc_core::Solution` interface, with the hands
+/// parsed once for both parts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Day07;
+
+impl aoc_core::Solution for Day07 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 7;
+
+    type Parsed<'a> = Hands;
+
+    fn parse(input: &str) -> aoc_core::solution::Result<Hands> {
+        Ok(Hands {
+            jacks: part1::parse(input)?,
+            jokers: part2::parse(input)?,
+        })
+    }
+
+    fn part1(hands: &Hands) -> aoc_core::solution::Result<aoc_core::Answer> {
+        Ok(part1::solve(&hands.jacks)?.into())
+    }
+
+    fn part2(hands: &Hands) -> aoc_core::solution::Result<aoc_core::Answer> {
+        Ok(part2::solve(&hands.jokers)?.into())
+    }
+}
@@ -1,101 +1,15 @@
-use crate::{error::Error, prelude::*};
-use rayon::prelude::*;
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Ord, PartialOrd, Hash)]
-enum Card {
-    Two,
-    Three,
-    Four,
-    Five,
-    Six,
-    Seven,
-    Eight,
-    Nine,
-    Ten,
-    Jack,
-    Queen,
-    King,
-    Ace,
-}
-
-impl Card {
-    #[tracing::instrument]
-    fn from_str(input: char) -> Result<Self> {
-        match input {
-            'A' => Ok(Self::Ace),
-            'K' => Ok(Self::King),
-            'Q' => Ok(Self::Queen),
-            'J' => Ok(Self::Jack),
-            'T' => Ok(Self::Ten),
-            '9' => Ok(Self::Nine),
-            '8' => Ok(Self::Eight),
-            '7' => Ok(Self::Seven),
-            '6' => Ok(Self::Six),
-            '5' => Ok(Self::Five),
-            '4' => Ok(Self::Four),
-            '3' => Ok(Self::Three),
-            '2' => Ok(Self::Two),
-            _ => Err(Error::CouldNotParseCard(input.to_string())),
-        }
-    }
-}
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Ord, PartialOrd, Hash)]
-enum HandType {
-    HighCard,
-    OnePair,
-    TwoPair,
-    ThreeOfAKind,
-    FullHouse,
-    FourOfAKind,
-    FiveOfAKind,
-}
+use aoc_core::{alphabet::CardRank as Card, numbered_lines, Answer, AtLine};
+use aoc_input::split_once_ws;
 
-impl HandType {
-    #[tracing::instrument]
-    fn from_cards(cards: &[Card]) -> Result<Self> {
-        if cards.len() != 5 {
-            return Err(Error::UnexpectedNumberOfCards);
-        }
-
-        let mut cards = cards.to_vec();
-        cards.sort();
-
-        if cards[0] == cards[4] {
-            return Ok(HandType::FiveOfAKind);
-        }
-
-        if cards[0] == cards[3] || cards[1] == cards[4] {
-            return Ok(HandType::FourOfAKind);
-        }
-
-        if (cards[0] == cards[2] && cards[3] == cards[4])
-            || (cards[0] == cards[1] && cards[2] == cards[4])
-        {
-            return Ok(HandType::FullHouse);
-        }
-
-        if cards[0] == cards[2] || cards[1] == cards[3] || cards[2] == cards[4] {
-            return Ok(HandType::ThreeOfAKind);
-        }
-
-        if (cards[0] == cards[1] && cards[2] == cards[3])
-            || (cards[0] == cards[1] && cards[3] == cards[4])
-            || (cards[1] == cards[2] && cards[3] == cards[4])
-        {
-            return Ok(HandType::TwoPair);
-        }
-
-        if cards[0] == cards[1]
-            || cards[1] == cards[2]
-            || cards[2] == cards[3]
-            || cards[3] == cards[4]
-        {
-            return Ok(HandType::OnePair);
-        }
+use crate::{error::Error, hand_type::HandType, prelude::*, ranking};
+use rayon::prelude::*;
 
-        Ok(HandType::HighCard)
-    }
+#[tracing::instrument]
+fn parse_card(input: char) -> Result<Card> {
+    Card::from_char(input).ok_or_else(|| Error::CouldNotParseCard {
+        line: 0,
+        card: input.to_string(),
+    })
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -107,15 +21,20 @@ struct Hand {
 impl Hand {
     #[tracing::instrument]
     fn new(cards: [Card; 5]) -> Result<Self> {
-        let hand_type = HandType::from_cards(&cards)?;
+        let hand_type = HandType::from_cards(&cards, None)?;
         Ok(Self { cards, hand_type })
     }
 
     #[tracing::instrument]
     fn from_str(input: &str) -> Result<Self> {
+        let count = input.chars().count();
+        if count != 5 {
+            return Err(Error::UnexpectedNumberOfCards { line: 0, count });
+        }
+
         let mut cards = [Card::Two; 5];
         for (i, card) in input.chars().enumerate() {
-            cards[i] = Card::from_str(card)?;
+            cards[i] = parse_card(card)?;
         }
         Self::new(cards)
     }
@@ -144,13 +63,6 @@ impl PartialOrd for Hand {
     }
 }
 
-#[tracing::instrument]
-fn order_hands(hands: &[Hand]) -> Vec<Hand> {
-    let mut hands = hands.to_vec();
-    hands.sort();
-    hands
-}
-
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 struct HandAndBet {
     hand: Hand,
@@ -160,19 +72,17 @@ struct HandAndBet {
 impl HandAndBet {
     #[tracing::instrument]
     fn from_str(input: &str) -> Result<Self> {
-        let mut split = input.split_whitespace();
-
-        let hand = split
-            .next()
-            .ok_or_else(|| Error::CouldNotParseHandAndBet(input.to_string()))?;
+        let (hand, bet) = split_once_ws(input).map_err(|_| Error::CouldNotParseHandAndBet {
+            line: 0,
+            input: input.to_string(),
+        })?;
 
         let hand = Hand::from_str(hand)?;
 
-        let bet = split
-            .next()
-            .ok_or_else(|| Error::CouldNotParseHandAndBet(input.to_string()))?
-            .parse::<u32>()
-            .map_err(Error::CouldNotParseNumber)?;
+        let bet = bet.parse::<u32>().map_err(|_| Error::CouldNotParseBet {
+            line: 0,
+            bet: bet.to_string(),
+        })?;
 
         Ok(Self { hand, bet })
     }
@@ -191,28 +101,22 @@ impl PartialOrd for HandAndBet {
 }
 
 #[tracing::instrument]
-fn sort_hands_and_bets(hands_and_bets: &[HandAndBet]) -> Vec<HandAndBet> {
-    let mut hands_and_bets = hands_and_bets.to_vec();
-    hands_and_bets.sort();
-    hands_and_bets
-}
-
-#[tracing::instrument]
-pub fn process(input: &str) -> miette::Result<u32> {
-    let bets_and_hands = input
-        .par_lines()
-        .map(|line| HandAndBet::from_str(line.trim()))
+pub fn process(input: &str) -> miette::Result<Answer> {
+    let bets_and_hands = numbered_lines(input)
+        .collect::<Vec<_>>()
+        .into_par_iter()
+        .map(|(number, line)| HandAndBet::from_str(line).at_line(number))
         .collect::<Result<Vec<HandAndBet>>>()?;
 
-    let ordered_hands_and_bets = sort_hands_and_bets(&bets_and_hands);
+    let ordered_hands_and_bets = ranking::rank(&bets_and_hands);
 
     let total_winnings = ordered_hands_and_bets
         .iter()
         .enumerate()
-        .map(|(i, hand_and_bet)| hand_and_bet.bet * (i + 1) as u32)
-        .sum::<u32>();
+        .map(|(i, hand_and_bet)| hand_and_bet.bet as u64 * (i + 1) as u64)
+        .sum::<u64>();
 
-    Ok(total_winnings)
+    Ok(Answer::from(total_winnings))
 }
 
 #[cfg(test)]
@@ -222,19 +126,19 @@ mod tests {
 
     #[test]
     fn it_should_parse_cards_correctly() -> miette::Result<()> {
-        assert_eq!(Card::Ace, Card::from_str('A')?);
-        assert_eq!(Card::King, Card::from_str('K')?);
-        assert_eq!(Card::Queen, Card::from_str('Q')?);
-        assert_eq!(Card::Jack, Card::from_str('J')?);
-        assert_eq!(Card::Ten, Card::from_str('T')?);
-        assert_eq!(Card::Nine, Card::from_str('9')?);
-        assert_eq!(Card::Eight, Card::from_str('8')?);
-        assert_eq!(Card::Seven, Card::from_str('7')?);
-        assert_eq!(Card::Six, Card::from_str('6')?);
-        assert_eq!(Card::Five, Card::from_str('5')?);
-        assert_eq!(Card::Four, Card::from_str('4')?);
-        assert_eq!(Card::Three, Card::from_str('3')?);
-        assert_eq!(Card::Two, Card::from_str('2')?);
+        assert_eq!(Card::Ace, parse_card('A')?);
+        assert_eq!(Card::King, parse_card('K')?);
+        assert_eq!(Card::Queen, parse_card('Q')?);
+        assert_eq!(Card::Jack, parse_card('J')?);
+        assert_eq!(Card::Ten, parse_card('T')?);
+        assert_eq!(Card::Nine, parse_card('9')?);
+        assert_eq!(Card::Eight, parse_card('8')?);
+        assert_eq!(Card::Seven, parse_card('7')?);
+        assert_eq!(Card::Six, parse_card('6')?);
+        assert_eq!(Card::Five, parse_card('5')?);
+        assert_eq!(Card::Four, parse_card('4')?);
+        assert_eq!(Card::Three, parse_card('3')?);
+        assert_eq!(Card::Two, parse_card('2')?);
 
         Ok(())
     }
@@ -252,67 +156,106 @@ mod tests {
     fn it_should_make_right_hand_type() -> miette::Result<()> {
         assert_eq!(
             HandType::FiveOfAKind,
-            HandType::from_cards(&[Card::Ace, Card::Ace, Card::Ace, Card::Ace, Card::Ace])?
+            HandType::from_cards(
+                &[Card::Ace, Card::Ace, Card::Ace, Card::Ace, Card::Ace],
+                None
+            )?
         );
 
         assert_eq!(
             HandType::FourOfAKind,
-            HandType::from_cards(&[Card::Ace, Card::Ace, Card::Ace, Card::Ace, Card::Eight])?
+            HandType::from_cards(
+                &[Card::Ace, Card::Ace, Card::Ace, Card::Ace, Card::Eight],
+                None
+            )?
         );
 
         assert_eq!(
             HandType::FourOfAKind,
-            HandType::from_cards(&[Card::Ace, Card::Ace, Card::Eight, Card::Ace, Card::Ace])?
+            HandType::from_cards(
+                &[Card::Ace, Card::Ace, Card::Eight, Card::Ace, Card::Ace],
+                None
+            )?
         );
 
         assert_eq!(
             HandType::FullHouse,
-            HandType::from_cards(&[Card::Ace, Card::Ace, Card::Eight, Card::Eight, Card::Ace])?
+            HandType::from_cards(
+                &[Card::Ace, Card::Ace, Card::Eight, Card::Eight, Card::Ace],
+                None
+            )?
         );
 
         assert_eq!(
             HandType::ThreeOfAKind,
-            HandType::from_cards(&[Card::King, Card::Eight, Card::Eight, Card::Eight, Card::Ace])?
+            HandType::from_cards(
+                &[Card::King, Card::Eight, Card::Eight, Card::Eight, Card::Ace],
+                None
+            )?
         );
 
         assert_eq!(
             HandType::ThreeOfAKind,
-            HandType::from_cards(&[Card::Ace, Card::Eight, Card::Eight, Card::Eight, Card::King])?
+            HandType::from_cards(
+                &[Card::Ace, Card::Eight, Card::Eight, Card::Eight, Card::King],
+                None
+            )?
         );
 
         assert_eq!(
             HandType::TwoPair,
-            HandType::from_cards(&[Card::Ace, Card::Ace, Card::Eight, Card::Eight, Card::King])?
+            HandType::from_cards(
+                &[Card::Ace, Card::Ace, Card::Eight, Card::Eight, Card::King],
+                None
+            )?
         );
 
         assert_eq!(
             HandType::TwoPair,
-            HandType::from_cards(&[Card::Ace, Card::Ace, Card::Eight, Card::King, Card::King])?
+            HandType::from_cards(
+                &[Card::Ace, Card::Ace, Card::Eight, Card::King, Card::King],
+                None
+            )?
         );
 
         assert_eq!(
             HandType::TwoPair,
-            HandType::from_cards(&[Card::Ace, Card::King, Card::Eight, Card::King, Card::Eight])?
+            HandType::from_cards(
+                &[Card::Ace, Card::King, Card::Eight, Card::King, Card::Eight],
+                None
+            )?
         );
 
         assert_eq!(
             HandType::OnePair,
-            HandType::from_cards(&[Card::Ace, Card::King, Card::Ace, Card::Four, Card::Eight])?
+            HandType::from_cards(
+                &[Card::Ace, Card::King, Card::Ace, Card::Four, Card::Eight],
+                None
+            )?
         );
 
         assert_eq!(
             HandType::OnePair,
-            HandType::from_cards(&[Card::Ace, Card::King, Card::King, Card::Four, Card::Eight])?
+            HandType::from_cards(
+                &[Card::Ace, Card::King, Card::King, Card::Four, Card::Eight],
+                None
+            )?
         );
 
         assert_eq!(
             HandType::OnePair,
-            HandType::from_cards(&[Card::Ace, Card::King, Card::Eight, Card::Four, Card::Eight])?
+            HandType::from_cards(
+                &[Card::Ace, Card::King, Card::Eight, Card::Four, Card::Eight],
+                None
+            )?
         );
 
         assert_eq!(
             HandType::OnePair,
-            HandType::from_cards(&[Card::Ace, Card::King, Card::Four, Card::Four, Card::Eight])?
+            HandType::from_cards(
+                &[Card::Ace, Card::King, Card::Four, Card::Four, Card::Eight],
+                None
+            )?
         );
 
         Ok(())
@@ -350,7 +293,7 @@ mod tests {
 
         let hands = vec![hand1, hand2, hand3, hand4, hand5];
 
-        let ordered_hands = order_hands(&hands);
+        let ordered_hands = ranking::rank(&hands);
 
         assert_eq!(ordered_hands[0], Hand::from_str("32T3K")?);
         assert_eq!(ordered_hands[1], Hand::from_str("KTJJT")?);
@@ -368,7 +311,7 @@ mod tests {
         KK677 28
         KTJJT 220
         QQQJA 483";
-        assert_eq!(6440, process(input)?);
+        assert_eq!(Answer::UInt(6440), process(input)?);
         Ok(())
     }
 }
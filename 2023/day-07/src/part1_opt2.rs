@@ -0,0 +1,150 @@
+use aoc_core::{alphabet::CardRank, numbered_lines, Answer, AtLine};
+use aoc_input::split_once_ws;
+
+use crate::{error::Error, hand_type::HandType, prelude::*};
+
+const CARD_VALUES: u32 = 13;
+const CARDS_KEY_SPACE: u32 = CARD_VALUES.pow(5);
+
+#[tracing::instrument]
+fn card(card: char) -> Result<CardRank> {
+    CardRank::from_char(card).ok_or_else(|| Error::CouldNotParseCard {
+        line: 0,
+        card: card.to_string(),
+    })
+}
+
+/// Packs a hand into a dense key where ordering the keys orders the hands:
+/// `hand_type * 13^5 + cards read as a base 13 number`.
+#[tracing::instrument]
+fn hand_key(hand: &str) -> Result<u32> {
+    let count = hand.chars().count();
+    if count != 5 {
+        return Err(Error::UnexpectedNumberOfCards { line: 0, count });
+    }
+
+    let mut cards = [CardRank::Two; 5];
+    let mut cards_key = 0;
+    for (i, c) in hand.chars().enumerate() {
+        cards[i] = card(c)?;
+        cards_key = cards_key * CARD_VALUES + cards[i].index() as u32;
+    }
+
+    let hand_type = HandType::from_cards(&cards, None)?;
+
+    Ok(hand_type as u32 * CARDS_KEY_SPACE + cards_key)
+}
+
+#[tracing::instrument]
+pub(crate) fn parse_line(line: &str) -> Result<(u32, u32)> {
+    let (hand, bet) = split_once_ws(line).map_err(|_| Error::CouldNotParseHandAndBet {
+        line: 0,
+        input: line.to_string(),
+    })?;
+
+    let bet = bet.parse::<u32>().map_err(|_| Error::CouldNotParseBet {
+        line: 0,
+        bet: bet.to_string(),
+    })?;
+
+    Ok((hand_key(hand)?, bet))
+}
+
+/// Sorts by the packed keys alone, stably, so equal hands keep their input
+/// order as with `ranking::rank`.
+#[tracing::instrument(skip(hands))]
+fn total_winnings(hands: &[(u32, u32)]) -> u64 {
+    let mut hands = hands.to_vec();
+    hands.sort_by_key(|(key, _)| *key);
+
+    hands
+        .iter()
+        .enumerate()
+        .map(|(i, (_, bet))| (i as u64 + 1) * *bet as u64)
+        .sum()
+}
+
+#[tracing::instrument(skip(input))]
+pub fn process(input: &str) -> miette::Result<Answer> {
+    let hands = numbered_lines(input)
+        .map(|(number, line)| parse_line(line).at_line(number))
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(Answer::from(total_winnings(&hands)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generator;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn it_should_order_keys_like_hands() -> miette::Result<()> {
+        assert!(hand_key("32T3K")? < hand_key("KTJJT")?);
+        assert!(hand_key("KTJJT")? < hand_key("KK677")?);
+        assert!(hand_key("KK677")? < hand_key("T55J5")?);
+        assert!(hand_key("T55J5")? < hand_key("QQQJA")?);
+        assert!(hand_key("33332")? > hand_key("2AAAA")?);
+        assert!(hand_key("AAAAA")? > hand_key("KKKKK")?);
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_reject_bad_hands() -> miette::Result<()> {
+        assert!(matches!(
+            hand_key("32T3"),
+            Err(Error::UnexpectedNumberOfCards { count: 4, .. })
+        ));
+        assert!(matches!(
+            hand_key("32T3X"),
+            Err(Error::CouldNotParseCard { .. })
+        ));
+        assert!(matches!(
+            hand_key("AAAé"),
+            Err(Error::UnexpectedNumberOfCards { count: 4, .. })
+        ));
+        assert!(matches!(
+            hand_key("AAAAé"),
+            Err(Error::CouldNotParseCard { .. })
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_split_hand_and_bet_on_any_whitespace() -> miette::Result<()> {
+        let expected = parse_line("32T3K 765")?;
+
+        for line in [
+            "32T3K\t765",
+            "32T3K   765",
+            "32T3K \t 765",
+            "32T3K 765  ",
+            "32T3K 765\t",
+        ] {
+            assert_eq!(expected, parse_line(line)?, "{:?}", line);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_process() -> miette::Result<()> {
+        let input = "32T3K 765
+        T55J5 684
+        KK677 28
+        KTJJT 220
+        QQQJA 483";
+        assert_eq!(Answer::UInt(6440), process(input)?);
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_match_sorting_implementation() -> miette::Result<()> {
+        let input = &aoc_core::puzzle_input!("input1.txt")?;
+        assert_eq!(crate::part1::process(input)?, process(input)?);
+
+        let input = generator::hands(2_000, 1_000, 1234);
+        assert_eq!(crate::part1::process(&input)?, process(&input)?);
+        Ok(())
+    }
+}
@@ -1,4 +1,7 @@
-use crate::{error::Error, prelude::*};
+use aoc_core::{alphabet::CardRank, numbered_lines, Answer, AtLine};
+use aoc_input::split_once_ws;
+
+use crate::{error::Error, hand_type::HandType, prelude::*, ranking};
 
 #[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Eq, Ord, Hash)]
 enum Card {
@@ -17,131 +20,54 @@ enum Card {
     Ace,
 }
 
+/// Part 2's ordering of each `CardRank`, indexed by `CardRank::index`.
+const BY_RANK: [Card; 13] = [
+    Card::Two,
+    Card::Three,
+    Card::Four,
+    Card::Five,
+    Card::Six,
+    Card::Seven,
+    Card::Eight,
+    Card::Nine,
+    Card::Ten,
+    Card::Jack,
+    Card::Queen,
+    Card::King,
+    Card::Ace,
+];
+
+/// Each `Card`'s `CardRank`, in part 2's order, for classifying hands.
+const RANKS: [CardRank; 13] = [
+    CardRank::Jack,
+    CardRank::Two,
+    CardRank::Three,
+    CardRank::Four,
+    CardRank::Five,
+    CardRank::Six,
+    CardRank::Seven,
+    CardRank::Eight,
+    CardRank::Nine,
+    CardRank::Ten,
+    CardRank::Queen,
+    CardRank::King,
+    CardRank::Ace,
+];
+
 impl Card {
     #[tracing::instrument]
-    fn from_str(input: char) -> Result<Self> {
-        match input {
-            'A' => Ok(Self::Ace),
-            'K' => Ok(Self::King),
-            'Q' => Ok(Self::Queen),
-            'J' => Ok(Self::Jack),
-            'T' => Ok(Self::Ten),
-            '9' => Ok(Self::Nine),
-            '8' => Ok(Self::Eight),
-            '7' => Ok(Self::Seven),
-            '6' => Ok(Self::Six),
-            '5' => Ok(Self::Five),
-            '4' => Ok(Self::Four),
-            '3' => Ok(Self::Three),
-            '2' => Ok(Self::Two),
-            _ => Err(Error::CouldNotParseCard(input.to_string())),
-        }
+    fn rank(self) -> CardRank {
+        RANKS[self as usize]
     }
-}
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Ord, PartialOrd, Hash)]
-enum HandType {
-    HighCard,
-    OnePair,
-    TwoPair,
-    ThreeOfAKind,
-    FullHouse,
-    FourOfAKind,
-    FiveOfAKind,
-}
 
-impl HandType {
     #[tracing::instrument]
-    fn from_cards(cards: &[Card]) -> Result<Self> {
-        if cards.len() != 5 {
-            return Err(Error::UnexpectedNumberOfCards);
-        }
-
-        let mut cards = cards.to_vec();
-        cards.sort();
-
-        // Joker is always at start of hand
-
-        if cards[0] == cards[4] {
-            return Ok(HandType::FiveOfAKind);
-        }
-
-        let number_of_jokers = cards.iter().filter(|card| **card == Card::Jack).count();
-
-        match number_of_jokers {
-            5 => Ok(HandType::FiveOfAKind),
-            4 => Ok(HandType::FiveOfAKind),
-            3 => {
-                if cards[3] == cards[4] {
-                    return Ok(HandType::FiveOfAKind);
-                }
-
-                Ok(HandType::FourOfAKind)
-            }
-            2 => {
-                if cards[2] == cards[4] {
-                    return Ok(HandType::FiveOfAKind);
-                }
-
-                if (cards[2] == cards[3]) || (cards[3] == cards[4]) {
-                    return Ok(HandType::FourOfAKind);
-                }
-
-                Ok(HandType::ThreeOfAKind)
-            }
-            1 => {
-                if cards[1] == cards[4] {
-                    return Ok(HandType::FiveOfAKind);
-                }
-
-                if cards[1] == cards[3] || cards[2] == cards[4] {
-                    return Ok(HandType::FourOfAKind);
-                }
-
-                if cards[1] == cards[2] && cards[3] == cards[4] {
-                    return Ok(HandType::FullHouse);
-                }
-
-                if cards[1] == cards[2] || cards[2] == cards[3] || cards[3] == cards[4] {
-                    return Ok(HandType::ThreeOfAKind);
-                }
-
-                Ok(HandType::OnePair)
-            }
-            0 => {
-                if cards[0] == cards[3] || cards[1] == cards[4] {
-                    return Ok(HandType::FourOfAKind);
-                }
-
-                if (cards[0] == cards[2] && cards[3] == cards[4])
-                    || (cards[0] == cards[1] && cards[2] == cards[4])
-                {
-                    return Ok(HandType::FullHouse);
-                }
-
-                if cards[0] == cards[2] || cards[1] == cards[3] || cards[2] == cards[4] {
-                    return Ok(HandType::ThreeOfAKind);
-                }
-
-                if (cards[0] == cards[1] && cards[2] == cards[3])
-                    || (cards[0] == cards[1] && cards[3] == cards[4])
-                    || (cards[1] == cards[2] && cards[3] == cards[4])
-                {
-                    return Ok(HandType::TwoPair);
-                }
-
-                if cards[0] == cards[1]
-                    || cards[1] == cards[2]
-                    || cards[2] == cards[3]
-                    || cards[3] == cards[4]
-                {
-                    return Ok(HandType::OnePair);
-                }
-
-                Ok(HandType::HighCard)
-            }
-            _ => Err(Error::UnexpectedNumberOfCards),
-        }
+    fn from_str(input: char) -> Result<Self> {
+        CardRank::from_char(input)
+            .map(|rank| BY_RANK[rank.index()])
+            .ok_or_else(|| Error::CouldNotParseCard {
+                line: 0,
+                card: input.to_string(),
+            })
     }
 }
 
@@ -154,13 +80,18 @@ struct Hand {
 impl Hand {
     #[tracing::instrument]
     fn new(cards: [Card; 5]) -> Result<Self> {
-        let hand_type = HandType::from_cards(&cards)?;
+        let hand_type = HandType::from_cards(&cards.map(Card::rank), Some(CardRank::Jack))?;
 
         Ok(Self { cards, hand_type })
     }
 
     #[tracing::instrument]
     fn from_str(input: &str) -> Result<Self> {
+        let count = input.chars().count();
+        if count != 5 {
+            return Err(Error::UnexpectedNumberOfCards { line: 0, count });
+        }
+
         let mut cards = [Card::Two; 5];
         for (i, card) in input.chars().enumerate() {
             cards[i] = Card::from_str(card)?;
@@ -193,15 +124,8 @@ impl PartialOrd for Hand {
     }
 }
 
-#[tracing::instrument]
-fn order_hands(hands: &[Hand]) -> Vec<Hand> {
-    let mut hands = hands.to_vec();
-    hands.sort();
-    hands
-}
-
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-struct HandAndBet {
+pub struct HandAndBet {
     hand: Hand,
     bet: u32,
 }
@@ -209,19 +133,17 @@ struct HandAndBet {
 impl HandAndBet {
     #[tracing::instrument]
     fn from_str(input: &str) -> Result<Self> {
-        let mut split = input.split_whitespace();
-
-        let hand = split
-            .next()
-            .ok_or_else(|| Error::CouldNotParseHandAndBet(input.to_string()))?;
+        let (hand, bet) = split_once_ws(input).map_err(|_| Error::CouldNotParseHandAndBet {
+            line: 0,
+            input: input.to_string(),
+        })?;
 
         let hand = Hand::from_str(hand)?;
 
-        let bet = split
-            .next()
-            .ok_or_else(|| Error::CouldNotParseHandAndBet(input.to_string()))?
-            .parse::<u32>()
-            .map_err(Error::CouldNotParseNumber)?;
+        let bet = bet.parse::<u32>().map_err(|_| Error::CouldNotParseBet {
+            line: 0,
+            bet: bet.to_string(),
+        })?;
 
         Ok(Self { hand, bet })
     }
@@ -239,35 +161,39 @@ impl PartialOrd for HandAndBet {
     }
 }
 
-#[tracing::instrument]
-fn sort_hands_and_bets(hands_and_bets: &[HandAndBet]) -> Vec<HandAndBet> {
-    let mut hands_and_bets = hands_and_bets.to_vec();
-    hands_and_bets.sort();
-    hands_and_bets
+/// Every hand and bet, parsed apart from `solve` so the two can be benchmarked
+/// separately.
+#[tracing::instrument(skip(input))]
+pub fn parse(input: &str) -> miette::Result<Vec<HandAndBet>> {
+    Ok(numbered_lines(input)
+        .map(|(number, line)| HandAndBet::from_str(line).at_line(number))
+        .collect::<Result<Vec<HandAndBet>>>()?)
 }
 
-#[tracing::instrument]
-pub fn process(input: &str) -> miette::Result<u32> {
-    let bets_and_hands = input
-        .lines()
-        .map(|line| HandAndBet::from_str(line.trim()))
-        .collect::<Result<Vec<HandAndBet>>>()?;
-
-    let ordered_hands_and_bets = sort_hands_and_bets(&bets_and_hands);
+#[tracing::instrument(skip(bets_and_hands), fields(hands = bets_and_hands.len()))]
+pub fn solve(bets_and_hands: &[HandAndBet]) -> miette::Result<u64> {
+    let ordered_hands_and_bets = ranking::rank(bets_and_hands);
 
     let total_winnings = ordered_hands_and_bets
         .iter()
         .enumerate()
-        .map(|(i, hand_and_bet)| hand_and_bet.bet * (i + 1) as u32)
-        .sum::<u32>();
+        .map(|(i, hand_and_bet)| hand_and_bet.bet as u64 * (i + 1) as u64)
+        .sum::<u64>();
 
     Ok(total_winnings)
 }
 
+#[tracing::instrument]
+pub fn process(input: &str) -> miette::Result<Answer> {
+    solve(&parse(input)?).map(Answer::from)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_testing::hand;
     use pretty_assertions::assert_eq;
+    use proptest::prelude::*;
 
     // #[test]
     // fn it_should_parse_cards_correctly() -> miette::Result<()> {
@@ -390,7 +316,7 @@ mod tests {
 
         let hands = vec![hand1, hand2, hand3, hand4, hand5];
 
-        let ordered_hands = order_hands(&hands);
+        let ordered_hands = ranking::rank(&hands);
 
         assert_eq!(ordered_hands[0], Hand::from_str("32T3K")?);
         assert_eq!(ordered_hands[1], Hand::from_str("KK677")?);
@@ -408,16 +334,33 @@ mod tests {
         KK677 28
         KTJJT 220
         QQQJA 483";
-        assert_eq!(5905, process(input)?);
+        assert_eq!(Answer::UInt(5905), process(input)?);
         Ok(())
     }
 
     #[test]
-    fn test_wrong() -> miette::Result<()> {
-        let input = include_str!("../input2.txt");
-        assert_eq!(true, process(input)? < 250965323);
-        assert_eq!(true, process(input)? < 250779249);
-        assert_eq!(true, process(input)? < 250465001);
+    fn it_should_get_right_output() -> miette::Result<()> {
+        let input = &aoc_core::puzzle_input!("input2.txt")?;
+        assert_eq!(
+            aoc_core::manifest::answer(2023, 7, 2)?,
+            process(input)?.to_string()
+        );
         Ok(())
     }
+
+    proptest! {
+        #[test]
+        fn it_should_order_hands_totally(a in hand(), b in hand(), c in hand()) {
+            let parse = |hand: &str| {
+                Hand::from_str(hand).map_err(|e| TestCaseError::fail(e.to_string()))
+            };
+            let (a, b, c) = (parse(&a)?, parse(&b)?, parse(&c)?);
+
+            prop_assert_eq!(a.cmp(&b), b.cmp(&a).reverse());
+            prop_assert_eq!(a == b, a.cmp(&b) == std::cmp::Ordering::Equal);
+            if a <= b && b <= c {
+                prop_assert!(a <= c, "{:?} <= {:?} <= {:?}", a, b, c);
+            }
+        }
+    }
 }
@@ -1,9 +1,9 @@
-use crate::prelude::*;
+use aoc_core::Answer;
 
 #[tracing::instrument]
 pub fn process(
     _input: &str,
-) -> miette::Result<String> {
+) -> miette::Result<Answer> {
     todo!("day 01 - part 1");
 }
 
@@ -0,0 +1,9 @@
+/// Orders `hands` weakest first, so each one's index is its rank minus one.
+/// `sort` is stable, so duplicate hands keep their input order and the total
+/// doesn't depend on how ties are broken.
+#[tracing::instrument(skip(hands))]
+pub fn rank<T: Ord + Clone>(hands: &[T]) -> Vec<T> {
+    let mut hands = hands.to_vec();
+    hands.sort();
+    hands
+}
@@ -0,0 +1,230 @@
+use std::{collections::HashMap, hash::Hash};
+
+use aoc_math::{crt, lcm};
+
+use crate::prelude::*;
+
+/// Where a walk ends up looping. The map and instructions are finite, so
+/// every walk reaches a state it has been in before and repeats from there.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cycle {
+    /// Steps before the first state that comes round again.
+    pub start: u64,
+    /// Steps to come back round to that state.
+    pub len: u64,
+    /// Steps on an end before `start`. The walk never gets back to these.
+    pub lead_in: Vec<u64>,
+    /// Steps on an end during the first time round, each in
+    /// `start..start + len`. Each one comes round again every `len` steps.
+    pub offsets: Vec<u64>,
+}
+
+impl Cycle {
+    /// Walks from `state` with `next` until a state repeats, noting every
+    /// step that `is_end`. A state has to hold everything that decides the
+    /// next one, like the node and the position in the instructions.
+    #[tracing::instrument(skip(next, is_end))]
+    pub fn detect<S: Copy + Eq + Hash + std::fmt::Debug>(
+        mut state: S,
+        mut next: impl FnMut(S) -> Result<S>,
+        is_end: impl Fn(&S) -> bool,
+    ) -> Result<Self> {
+        let mut seen = HashMap::new();
+        let mut ends = Vec::new();
+        let mut step = 0;
+
+        let start = loop {
+            if let Some(start) = seen.insert(state, step) {
+                break start;
+            }
+            if is_end(&state) {
+                ends.push(step);
+            }
+
+            state = next(state)?;
+            step += 1;
+        };
+
+        let (lead_in, offsets): (Vec<u64>, Vec<u64>) =
+            ends.into_iter().partition(|end| *end < start);
+
+        Ok(Self {
+            start,
+            len: step - start,
+            lead_in,
+            offsets,
+        })
+    }
+
+    #[tracing::instrument]
+    pub fn is_end(&self, step: u64) -> bool {
+        if step < self.start {
+            return self.lead_in.contains(&step);
+        }
+
+        let offset = self.start + (step - self.start) % self.len;
+        self.offsets.contains(&offset)
+    }
+
+    /// One end per full time round, exactly a cycle length in: the shape the
+    /// puzzle's inputs are built with, where the answer is just the LCM.
+    #[tracing::instrument]
+    fn is_aligned(&self) -> bool {
+        self.lead_in.is_empty() && self.offsets == [self.len]
+    }
+}
+
+/// The first step every walk is on an end at once, if there is one.
+#[tracing::instrument]
+pub fn first_common_end(cycles: &[Cycle]) -> Option<u64> {
+    if cycles.is_empty() {
+        return None;
+    }
+
+    // Any step before a walk's cycle starts has to be in its lead in.
+    let lead_in = cycles
+        .iter()
+        .flat_map(|cycle| cycle.lead_in.iter().copied())
+        .filter(|step| cycles.iter().all(|cycle| cycle.is_end(*step)))
+        .min();
+    if lead_in.is_some() {
+        return lead_in;
+    }
+
+    if cycles.iter().all(Cycle::is_aligned) {
+        return Some(cycles.iter().map(|cycle| cycle.len).fold(1, lcm));
+    }
+
+    let start = cycles.iter().map(|cycle| cycle.start).max()?;
+
+    // Every pairing of one offset from each walk meets on its own residue.
+    let mut residues = vec![(0, 1)];
+    for cycle in cycles {
+        residues = residues
+            .iter()
+            .flat_map(|residue| {
+                cycle
+                    .offsets
+                    .iter()
+                    .filter_map(|offset| crt(*residue, (offset % cycle.len, cycle.len)))
+            })
+            .collect();
+    }
+
+    residues
+        .into_iter()
+        .map(|(residue, modulus)| {
+            if residue >= start {
+                residue
+            } else {
+                residue + (start - residue).div_ceil(modulus) * modulus
+            }
+        })
+        .min()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    /// A walk from 0 up to `len`, looping back to `loop_to`, that ends on
+    /// `ends`.
+    fn walk(len: u64, loop_to: u64, ends: &[u64]) -> Result<Cycle> {
+        Cycle::detect(
+            0,
+            |n| Ok(if n + 1 == len { loop_to } else { n + 1 }),
+            |n| ends.contains(n),
+        )
+    }
+
+    /// Every walk's steps, side by side, until they all end together.
+    fn count_up(cycles: &[Cycle]) -> Option<u64> {
+        (0..10_000).find(|step| cycles.iter().all(|cycle| cycle.is_end(*step)))
+    }
+
+    #[test]
+    fn it_should_find_where_a_walk_loops() -> miette::Result<()> {
+        let cycle = walk(7, 3, &[1, 4, 6])?;
+
+        assert_eq!(
+            Cycle {
+                start: 3,
+                len: 4,
+                lead_in: vec![1],
+                offsets: vec![4, 6],
+            },
+            cycle
+        );
+        assert_eq!(
+            vec![1, 4, 6, 8, 10, 12],
+            (0..13)
+                .filter(|step| cycle.is_end(*step))
+                .collect::<Vec<_>>()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_use_the_lcm_when_ends_line_up() -> miette::Result<()> {
+        // The second example's ghosts: `11A` ends every 2 steps, `22A` every 3.
+        let cycles = [walk(3, 1, &[2])?, walk(4, 1, &[3])?];
+
+        assert!(cycles.iter().all(Cycle::is_aligned));
+        assert_eq!(Some(6), first_common_end(&cycles));
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_solve_offset_ends_with_crt() -> miette::Result<()> {
+        let cycles = [walk(5, 0, &[2])?, walk(9, 2, &[5])?];
+
+        assert!(!cycles.iter().any(Cycle::is_aligned));
+        assert_eq!(Some(12), first_common_end(&cycles));
+        assert_eq!(count_up(&cycles), first_common_end(&cycles));
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_meet_in_the_lead_in() -> miette::Result<()> {
+        let cycles = [walk(6, 4, &[1, 5])?, walk(3, 0, &[1])?];
+
+        assert_eq!(Some(1), first_common_end(&cycles));
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_never_meet_when_no_residues_agree() -> miette::Result<()> {
+        let cycles = [walk(2, 0, &[0])?, walk(4, 0, &[1])?];
+
+        assert_eq!(None, first_common_end(&cycles));
+        assert_eq!(None, count_up(&cycles));
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_agree_with_counting_up() -> miette::Result<()> {
+        let shapes = [
+            (5, 0, &[2][..]),
+            (7, 2, &[1, 5]),
+            (4, 3, &[0, 3]),
+            (6, 1, &[4]),
+            (9, 6, &[2, 6, 8]),
+        ];
+
+        for (i, a) in shapes.iter().enumerate() {
+            for b in &shapes[i..] {
+                let cycles = [walk(a.0, a.1, a.2)?, walk(b.0, b.1, b.2)?];
+
+                assert_eq!(
+                    count_up(&cycles),
+                    first_common_end(&cycles),
+                    "{:?} {:?}",
+                    a,
+                    b
+                );
+            }
+        }
+        Ok(())
+    }
+}
@@ -1,10 +1,13 @@
-use miette::Diagnostic;
+use miette::{Diagnostic, SourceSpan};
 use thiserror::Error;
 
 #[derive(Error, Diagnostic, Debug)]
 pub enum Error {
     #[error(transparent)]
     IoError(#[from] std::io::Error),
+    #[error(transparent)]
+    #[diagnostic(transparent)]
+    Input(#[from] aoc_input::Error),
     #[error("Could not parse number {0}")]
     CouldNotParseNumber(#[from] std::num::ParseIntError),
     #[error("Could not find id for instruction {0}")]
@@ -15,16 +18,157 @@ pub enum Error {
     CouldNotFindRightInstruction(String),
     #[error("Invalid number of letters for id {0}")]
     InvalidNumberOfLettersForId(String),
+    #[error("Invalid letter in id {0}")]
+    InvalidLetterInId(String),
     #[error("Could not find instruction for id {0}")]
     CouldNotInspectionForId(String),
-    #[error("No instructions found")]
-    NoInstructionsFound,
     #[error("Unexpected instruction {0}")]
     UnexpectedInstruction(String),
+    #[error("No instructions on the first line")]
+    EmptyInstructions {
+        #[source_code]
+        input: String,
+        #[label("expected L and R instructions here")]
+        span: SourceSpan,
+    },
+    #[error("Invalid instruction {instruction:?}, expected L or R")]
+    InvalidInstruction {
+        instruction: char,
+        #[source_code]
+        input: String,
+        #[label("not L or R")]
+        span: SourceSpan,
+    },
     #[error("Unexpected end of instructions")]
     UnexpectedEndOfInstructions,
-    #[error("Unknown number of min steps")]
-    UnknownNumberOfMinSteps,
-    #[error("Unknown number of max steps")]
-    UnknownNumberOfMaxSteps,
-}
\ No newline at end of file
+    #[error("The ghosts are never all on a node ending in Z at once")]
+    GhostsNeverMeet,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{part1, part2};
+
+    #[test]
+    fn it_should_reach_empty_instructions() -> miette::Result<()> {
+        for input in ["", "\n\nAAA = (AAA, AAA)"] {
+            assert!(matches!(
+                part1::process(input),
+                Err(Error::EmptyInstructions { .. })
+            ));
+            assert!(matches!(
+                part2::process(input),
+                Err(Error::EmptyInstructions { .. })
+            ));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_reach_missing_separator() -> miette::Result<()> {
+        let input = "LR\nAAA = (AAA, AAA)";
+        assert!(matches!(
+            part1::process(input),
+            Err(Error::Input(aoc_input::Error::MissingSeparator { line: 2 }))
+        ));
+        assert!(matches!(
+            part2::process(input),
+            Err(Error::Input(aoc_input::Error::MissingSeparator { line: 2 }))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_reach_could_not_find_id_for_instruction() -> miette::Result<()> {
+        let input = "LR
+
+        AA";
+        assert!(matches!(
+            part1::process(input),
+            Err(Error::CouldNotFindIdForInstruction(_))
+        ));
+        assert!(matches!(
+            part2::process(input),
+            Err(Error::CouldNotFindIdForInstruction(_))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_reach_could_not_find_left_instruction() -> miette::Result<()> {
+        let input = "LR
+
+        AAA = (BB";
+        assert!(matches!(
+            part1::process(input),
+            Err(Error::CouldNotFindLeftInstruction(_))
+        ));
+        assert!(matches!(
+            part2::process(input),
+            Err(Error::CouldNotFindLeftInstruction(_))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_reach_could_not_find_right_instruction() -> miette::Result<()> {
+        let input = "LR
+
+        AAA = (BBB, CC";
+        assert!(matches!(
+            part1::process(input),
+            Err(Error::CouldNotFindRightInstruction(_))
+        ));
+        assert!(matches!(
+            part2::process(input),
+            Err(Error::CouldNotFindRightInstruction(_))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_reach_could_not_inspection_for_id() -> miette::Result<()> {
+        assert!(matches!(
+            part1::process("L\n\nBBB = (BBB, BBB)"),
+            Err(Error::CouldNotInspectionForId(_))
+        ));
+        assert!(matches!(
+            part2::process("L\n\n11A = (11B, 11B)"),
+            Err(Error::CouldNotInspectionForId(_))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_reach_invalid_instruction() -> miette::Result<()> {
+        assert!(matches!(
+            part1::process("LX\n\nAAA = (AAA, AAA)"),
+            Err(Error::InvalidInstruction {
+                instruction: 'X',
+                ..
+            })
+        ));
+        assert!(matches!(
+            part2::process("LX\n\n11A = (11A, 11A)"),
+            Err(Error::InvalidInstruction {
+                instruction: 'X',
+                ..
+            })
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_reach_ghosts_never_meet() -> miette::Result<()> {
+        let input = "L
+
+        11A = (11Z, 11Z)
+        11Z = (11A, 11A)
+        22A = (22B, 22B)
+        22B = (22Z, 22Z)
+        22Z = (22B, 22B)";
+        assert!(matches!(part2::process(input), Err(Error::GhostsNeverMeet)));
+        Ok(())
+    }
+}
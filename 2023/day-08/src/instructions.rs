@@ -0,0 +1,66 @@
+use crate::{error::Error, prelude::*};
+
+/// The first line of `input`, trimmed, once it's known to be a non-empty run
+/// of `L`s and `R`s. Checked up front so walking the map can never divide by
+/// an empty instruction list or stop on a bad letter halfway round.
+#[tracing::instrument(skip(input))]
+pub fn parse(input: &str) -> Result<&str> {
+    let line = input.lines().next().unwrap_or_default();
+    let offset = line.len() - line.trim_start().len();
+    let instructions = line.trim();
+
+    if instructions.is_empty() {
+        return Err(Error::EmptyInstructions {
+            input: input.to_string(),
+            span: (0, line.len()).into(),
+        });
+    }
+
+    if let Some((i, instruction)) = instructions
+        .char_indices()
+        .find(|(_, c)| !matches!(c, 'L' | 'R'))
+    {
+        return Err(Error::InvalidInstruction {
+            instruction,
+            input: input.to_string(),
+            span: (offset + i, instruction.len_utf8()).into(),
+        });
+    }
+
+    Ok(instructions)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use miette::SourceSpan;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn it_should_trim_the_first_line() -> miette::Result<()> {
+        assert_eq!("LLR", parse("  LLR \n\nAAA = (BBB, BBB)")?);
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_reject_empty_instructions() -> miette::Result<()> {
+        for input in ["", "\nAAA = (BBB, BBB)", "   \n\nAAA = (BBB, BBB)"] {
+            assert!(matches!(parse(input), Err(Error::EmptyInstructions { .. })));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_point_at_the_first_invalid_instruction() -> miette::Result<()> {
+        let Err(Error::InvalidInstruction {
+            instruction, span, ..
+        }) = parse("  LRxL\n\nAAA = (BBB, BBB)")
+        else {
+            panic!("expected an invalid instruction");
+        };
+
+        assert_eq!('x', instruction);
+        assert_eq!(SourceSpan::from((4, 1)), span);
+        Ok(())
+    }
+}
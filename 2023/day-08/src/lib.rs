@@ -1,5 +1,10 @@
 pub mod error;
 pub mod prelude;
 
+pub mod cycle;
+pub mod instructions;
+pub mod nodes;
 pub mod part1;
-pub mod part2;
\ No newline at end of file
+pub mod part2;
+
+aoc_core::solution!(Day08, 2023, 8, part1::process, part2::process);
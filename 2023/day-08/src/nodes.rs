@@ -0,0 +1,57 @@
+use aoc_input::parse::{self, ws, Parsed};
+use nom::{
+    bytes::complete::take_while_m_n,
+    character::complete::char,
+    sequence::{delimited, terminated},
+};
+
+use crate::{error::Error, prelude::*};
+
+/// A node's three letters. Which letters are allowed is up to `NodeLetter`,
+/// so this only takes three that could be.
+#[tracing::instrument]
+fn label(input: &str) -> Parsed<'_, &str> {
+    take_while_m_n(3, 3, |c: char| c.is_ascii_alphanumeric())(input)
+}
+
+/// The id, left and right labels of a line like `AAA = (BBB, CCC)`, with any
+/// spacing around the punctuation. Each is looked for in turn so the error
+/// says which one is missing.
+#[tracing::instrument]
+pub fn parse_line(input: &str) -> Result<(&str, &str, &str)> {
+    let (rest, id) = terminated(label, ws(char('=')))(input)
+        .map_err(|_| Error::CouldNotFindIdForInstruction(input.to_string()))?;
+
+    let (rest, left) = delimited(char('('), ws(label), char(','))(rest)
+        .map_err(|_| Error::CouldNotFindLeftInstruction(input.to_string()))?;
+
+    let right = parse::finish(terminated(ws(label), char(')')), rest)
+        .map_err(|_| Error::CouldNotFindRightInstruction(input.to_string()))?;
+
+    Ok((id, left, right))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn it_should_parse_node_lines() -> miette::Result<()> {
+        assert_eq!(("AAA", "BBB", "CCC"), parse_line("AAA = (BBB, CCC)")?);
+        assert_eq!(("11A", "11B", "XXX"), parse_line("11A=( 11B ,XXX )")?);
+        assert!(matches!(
+            parse_line("AA = (BBB, CCC)"),
+            Err(Error::CouldNotFindIdForInstruction(_))
+        ));
+        assert!(matches!(
+            parse_line("AAA = BBB, CCC"),
+            Err(Error::CouldNotFindLeftInstruction(_))
+        ));
+        assert!(matches!(
+            parse_line("AAA = (BBB, CCC) DDD"),
+            Err(Error::CouldNotFindRightInstruction(_))
+        ));
+        Ok(())
+    }
+}
@@ -1,6 +1,8 @@
-use std::collections::HashMap;
+use aoc_core::{alphabet::NodeLetter, Answer, Footprint, SkipDebug};
+use aoc_input::{normalize, HeaderAndBody};
+use aoc_intern::Interner;
 
-use crate::{error::Error, prelude::*};
+use crate::{error::Error, instructions, nodes, prelude::*};
 
 const ZZZ_ID: u32 = 0x005A5A5A;
 
@@ -12,8 +14,10 @@ fn letters_to_id(letters: &str) -> Result<u32> {
 
     let mut id: u32 = 0;
 
-    for (i, letter) in letters.chars().rev().enumerate() {
-        id |= (letter as u32) << (i * 8);
+    for (i, byte) in letters.bytes().rev().enumerate() {
+        let letter = NodeLetter::from_byte(byte)
+            .ok_or_else(|| Error::InvalidLetterInId(letters.to_string()))?;
+        id |= (letter.byte() as u32) << (i * 8);
     }
 
     Ok(id)
@@ -49,17 +53,7 @@ impl Node {
 
     #[tracing::instrument]
     fn from_str(input: &str) -> Result<Self> {
-        let id = input
-            .get(0..=2)
-            .ok_or_else(|| Error::CouldNotFindIdForInstruction(input.to_string()))?;
-
-        let left = input
-            .get(7..=9)
-            .ok_or_else(|| Error::CouldNotFindLeftInstruction(input.to_string()))?;
-
-        let right = input
-            .get(12..=14)
-            .ok_or_else(|| Error::CouldNotFindRightInstruction(input.to_string()))?;
+        let (id, left, right) = nodes::parse_line(input)?;
 
         Ok(Self::new(
             letters_to_id(id)?,
@@ -69,70 +63,85 @@ impl Node {
     }
 }
 
+/// Nodes are numbered in the order they're listed, so a node's neighbours
+/// are a lookup into a `Vec` rather than a hash of its id.
 #[derive(Debug, Clone, PartialEq, Eq)]
 struct Map {
-    nodes: HashMap<u32, Node>,
+    ids: Interner<u32>,
+    neighbours: Vec<(u32, u32)>,
+}
+
+impl Footprint for Map {
+    fn footprint(&self) -> usize {
+        self.neighbours.len()
+    }
 }
 
 impl Map {
-    #[tracing::instrument]
-    fn new(nodes: Vec<Node>) -> Self {
-        let mut map = Self {
-            nodes: HashMap::new(),
+    #[tracing::instrument(skip(nodes), fields(nodes = ?SkipDebug(&nodes)))]
+    fn new(nodes: Vec<Node>) -> Result<Self> {
+        let ids = nodes.iter().map(|node| node.id).collect::<Interner<_>>();
+        let index = |id: u32| {
+            ids.get(&id)
+                .ok_or_else(|| Error::CouldNotInspectionForId(id_to_letters(id)))
         };
 
-        for node in nodes {
-            map.nodes.insert(node.id, node);
+        let mut neighbours = vec![(0, 0); ids.len()];
+        for node in &nodes {
+            neighbours[index(node.id)? as usize] = (index(node.left)?, index(node.right)?);
         }
 
-        map
+        Ok(Self { ids, neighbours })
     }
 
-    #[tracing::instrument]
-    fn from_str(input: &str) -> Result<Self> {
-        let mut nodes = Vec::new();
-
-        for line in input.lines() {
-            nodes.push(Node::from_str(line)?);
-        }
+    #[tracing::instrument(skip(lines), fields(lines = ?SkipDebug(lines)))]
+    fn from_lines(lines: &[&str]) -> Result<Self> {
+        let nodes = lines
+            .iter()
+            .map(|line| Node::from_str(line))
+            .collect::<Result<Vec<_>>>()?;
 
-        Ok(Self::new(nodes))
+        Self::new(nodes)
     }
 
-    #[tracing::instrument]
-    fn get_node(&self, id: u32) -> Result<&Node> {
-        self.nodes
+    #[tracing::instrument(skip(self), fields(map = ?SkipDebug(self)))]
+    fn index(&self, id: u32) -> Result<u32> {
+        self.ids
             .get(&id)
             .ok_or_else(|| Error::CouldNotInspectionForId(id_to_letters(id)))
     }
-}
 
-#[tracing::instrument]
-pub fn process(input: &str) -> Result<u32> {
-    let mut lines = input.lines().map(|l| l.trim());
-
-    let instructions = lines.next().ok_or_else(|| Error::NoInstructionsFound)?;
+    #[tracing::instrument(skip(self), fields(map = ?SkipDebug(self)))]
+    fn next(&self, index: u32, direction: char) -> Result<u32> {
+        let (left, right) = self.neighbours[index as usize];
 
-    lines.next();
+        match direction {
+            'L' => Ok(left),
+            'R' => Ok(right),
+            _ => Err(Error::UnexpectedInstruction(direction.to_string())),
+        }
+    }
+}
 
-    let remaining = lines.collect::<Vec<_>>().join("\n");
+#[tracing::instrument]
+pub fn process(input: &str) -> Result<Answer> {
+    let input = normalize(input);
+    let instructions = instructions::parse(&input)?;
+    let input = HeaderAndBody::parse(&input)?;
 
-    let map = Map::from_str(&remaining)?;
+    let map = Map::from_lines(&input.body_lines().collect::<Vec<_>>())?;
 
-    let mut steps = 0;
-    let mut current_node = map.get_node(letters_to_id("AAA")?)?;
+    let mut steps: u32 = 0;
+    let mut current_node = map.index(letters_to_id("AAA")?)?;
+    let end = map.index(ZZZ_ID)?;
 
     loop {
         for direction in instructions.chars() {
-            if current_node.id == ZZZ_ID {
-                return Ok(steps);
+            if current_node == end {
+                return Ok(Answer::from(steps));
             }
 
-            match direction {
-                'L' => current_node = map.get_node(current_node.left)?,
-                'R' => current_node = map.get_node(current_node.right)?,
-                _ => return Err(Error::UnexpectedInstruction(direction.to_string())),
-            }
+            current_node = map.next(current_node, direction)?;
 
             steps += 1;
         }
@@ -154,6 +163,15 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn it_should_reject_letters_outside_alphabet() -> miette::Result<()> {
+        assert!(matches!(
+            letters_to_id("AbC"),
+            Err(Error::InvalidLetterInId(_))
+        ));
+        Ok(())
+    }
+
     #[test]
     fn it_should_decode_the_id_correctly() -> miette::Result<()> {
         let input = 0x00414243;
@@ -186,7 +204,7 @@ mod tests {
         GGG = (GGG, GGG)
         ZZZ = (ZZZ, ZZZ)";
 
-        assert_eq!(2, process(input)?);
+        assert_eq!(Answer::UInt(2), process(input)?);
         Ok(())
     }
 
@@ -198,7 +216,7 @@ mod tests {
         BBB = (AAA, ZZZ)
         ZZZ = (ZZZ, ZZZ)";
 
-        assert_eq!(6, process(input)?);
+        assert_eq!(Answer::UInt(6), process(input)?);
         Ok(())
     }
 }
@@ -1,8 +1,15 @@
-use gcd::*;
 use rayon::prelude::*;
-use std::collections::HashMap;
 
-use crate::{error::Error, prelude::*};
+use aoc_core::{alphabet::NodeLetter, Answer, Footprint, SkipDebug};
+use aoc_input::{normalize, HeaderAndBody};
+use aoc_intern::Interner;
+
+use crate::{
+    cycle::{self, Cycle},
+    error::Error,
+    instructions, nodes,
+    prelude::*,
+};
 
 const Z: u32 = 0x0000005A;
 const A: u32 = 0x00000041;
@@ -15,8 +22,10 @@ fn letters_to_id(letters: &str) -> Result<u32> {
 
     let mut id: u32 = 0;
 
-    for (i, letter) in letters.chars().rev().enumerate() {
-        id |= (letter as u32) << (i * 8);
+    for (i, byte) in letters.bytes().rev().enumerate() {
+        let letter = NodeLetter::from_byte(byte)
+            .ok_or_else(|| Error::InvalidLetterInId(letters.to_string()))?;
+        id |= (letter.byte() as u32) << (i * 8);
     }
 
     Ok(id)
@@ -37,6 +46,11 @@ fn id_to_letters(id: u32) -> String {
     letters
 }
 
+#[tracing::instrument]
+fn id_ends_with_a(id: u32) -> bool {
+    id & 0x000000FF == A
+}
+
 #[tracing::instrument]
 fn id_ends_with_z(id: u32) -> bool {
     id & 0x000000FF == Z
@@ -57,17 +71,7 @@ impl Node {
 
     #[tracing::instrument]
     fn from_str(input: &str) -> Result<Self> {
-        let id = input
-            .get(0..=2)
-            .ok_or_else(|| Error::CouldNotFindIdForInstruction(input.to_string()))?;
-
-        let left = input
-            .get(7..=9)
-            .ok_or_else(|| Error::CouldNotFindLeftInstruction(input.to_string()))?;
-
-        let right = input
-            .get(12..=14)
-            .ok_or_else(|| Error::CouldNotFindRightInstruction(input.to_string()))?;
+        let (id, left, right) = nodes::parse_line(input)?;
 
         Ok(Self::new(
             letters_to_id(id)?,
@@ -75,83 +79,114 @@ impl Node {
             letters_to_id(right)?,
         ))
     }
-
-    #[tracing::instrument]
-    fn ends_with_a(&self) -> bool {
-        self.id & 0x000000FF == A
-    }
-
-    #[tracing::instrument]
-    fn ends_with_z(&self) -> bool {
-        self.id & 0x000000FF == Z
-    }
 }
 
+/// Nodes are numbered in the order they're listed, so a node's neighbours
+/// are a lookup into a `Vec` rather than a hash of its id.
 #[derive(Debug, Clone, PartialEq, Eq)]
 struct Map {
-    nodes: HashMap<u32, Node>,
+    ids: Interner<u32>,
+    neighbours: Vec<(u32, u32)>,
+}
+
+impl Footprint for Map {
+    fn footprint(&self) -> usize {
+        self.neighbours.len()
+    }
 }
 
 impl Map {
-    #[tracing::instrument]
-    fn new(nodes: Vec<Node>) -> Self {
-        let mut map = Self {
-            nodes: HashMap::new(),
+    #[tracing::instrument(skip(nodes), fields(nodes = ?SkipDebug(&nodes)))]
+    fn new(nodes: Vec<Node>) -> Result<Self> {
+        let ids = nodes.iter().map(|node| node.id).collect::<Interner<_>>();
+        let index = |id: u32| {
+            ids.get(&id)
+                .ok_or_else(|| Error::CouldNotInspectionForId(id_to_letters(id)))
         };
 
-        for node in nodes {
-            map.nodes.insert(node.id, node);
+        let mut neighbours = vec![(0, 0); ids.len()];
+        for node in &nodes {
+            neighbours[index(node.id)? as usize] = (index(node.left)?, index(node.right)?);
         }
 
-        map
+        Ok(Self { ids, neighbours })
     }
 
-    #[tracing::instrument]
-    fn from_str(input: &str) -> Result<Self> {
-        let mut nodes = Vec::new();
-
-        for line in input.lines() {
-            nodes.push(Node::from_str(line)?);
-        }
+    #[tracing::instrument(skip(lines), fields(lines = ?SkipDebug(lines)))]
+    fn from_lines(lines: &[&str]) -> Result<Self> {
+        let nodes = lines
+            .iter()
+            .map(|line| Node::from_str(line))
+            .collect::<Result<Vec<_>>>()?;
 
-        Ok(Self::new(nodes))
+        Self::new(nodes)
     }
 
-    #[tracing::instrument]
-    fn get_node(&self, id: u32) -> Result<&Node> {
-        self.nodes
+    /// The index of the node labelled `id`, for finding a start by name.
+    #[cfg(test)]
+    #[tracing::instrument(skip(self), fields(map = ?SkipDebug(self)))]
+    fn index(&self, id: u32) -> Result<u32> {
+        self.ids
             .get(&id)
             .ok_or_else(|| Error::CouldNotInspectionForId(id_to_letters(id)))
     }
 
-    #[tracing::instrument]
-    fn get_starting_nodes(&self) -> Vec<&Node> {
-        self.nodes
-            .values()
-            .filter(|n| n.ends_with_a())
-            .collect::<Vec<_>>()
+    /// The id of the node at `index`. Indices only come from this map, so
+    /// there's always one.
+    #[tracing::instrument(skip(self), fields(map = ?SkipDebug(self)))]
+    fn label(&self, index: u32) -> u32 {
+        self.ids.label(index).copied().unwrap_or_default()
+    }
+
+    #[tracing::instrument(skip(self), fields(map = ?SkipDebug(self)))]
+    fn next(&self, index: u32, direction: char) -> Result<u32> {
+        let (left, right) = self.neighbours[index as usize];
+
+        match direction {
+            'L' => Ok(left),
+            'R' => Ok(right),
+            _ => Err(Error::UnexpectedInstruction(direction.to_string())),
+        }
+    }
+
+    /// Sorted by id, so the ghosts come out in the same order on every run
+    /// however the map's nodes happen to be listed.
+    #[tracing::instrument(skip(self), fields(map = ?SkipDebug(self)))]
+    fn get_starting_nodes(&self) -> Vec<u32> {
+        let mut nodes = self
+            .ids
+            .iter()
+            .filter(|(_, id)| id_ends_with_a(**id))
+            .map(|(index, _)| index)
+            .collect::<Vec<_>>();
+
+        nodes.sort_by_key(|index| self.label(*index));
+
+        nodes
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
-struct Input {
-    input: String,
+/// The instructions, borrowed from the puzzle input so every starting node
+/// can walk its own copy without reallocating them.
+#[derive(Clone, Copy, PartialEq, Eq)]
+struct Input<'a> {
+    input: &'a str,
     cursor: usize,
 }
 
-impl Input {
-    #[tracing::instrument]
-    fn new(input: &str) -> Self {
-        Self {
-            input: input.to_string(),
-            cursor: 0,
-        }
+impl std::fmt::Debug for Input<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Input")
+            .field("len", &self.input.len())
+            .field("cursor", &self.cursor)
+            .finish()
     }
+}
 
+impl<'a> Input<'a> {
     #[tracing::instrument]
-    fn skip(&mut self, n: usize) {
-        self.cursor += n;
-        self.cursor %= self.input.len();
+    fn new(input: &'a str) -> Self {
+        Self { input, cursor: 0 }
     }
 
     #[tracing::instrument]
@@ -168,76 +203,53 @@ impl Input {
     }
 }
 
-#[tracing::instrument]
-fn get_next_node(map: &Map, node: u32, mut input: Input) -> Result<(u32, Input)> {
-    let node = map.get_node(node)?;
-
+#[tracing::instrument(skip(map), fields(map = ?SkipDebug(map)))]
+fn get_next_node<'a>(map: &Map, node: u32, mut input: Input<'a>) -> Result<(u32, Input<'a>)> {
     match input.next() {
-        Some('L') => Ok((map.get_node(node.left)?.id, input)),
-        Some('R') => Ok((map.get_node(node.right)?.id, input)),
-        Some(c) => Err(Error::UnexpectedInstruction(c.to_string())),
+        Some(direction) => Ok((map.next(node, direction)?, input)),
         None => Err(Error::UnexpectedEndOfInstructions),
     }
 }
 
-#[tracing::instrument]
-fn steps_to_next_ending_in_z(map: &Map, node: u32, mut input: Input) -> Result<u64> {
-    let mut steps = 0;
-    let mut current_node = node;
-
-    loop {
-        if id_ends_with_z(current_node) {
-            return Ok(steps);
-        }
-
-        let (next_node, next_input) = get_next_node(map, current_node, input)?;
-
-        steps += 1;
-
-        current_node = next_node;
-        input = next_input;
-    }
+/// Where the walk from `node` loops. A state is the node and the cursor into
+/// the instructions, as both decide where the walk goes next.
+#[tracing::instrument(skip(map), fields(map = ?SkipDebug(map)))]
+fn find_cycle(map: &Map, node: u32, input: Input) -> Result<Cycle> {
+    Cycle::detect(
+        (node, input.cursor),
+        |(node, cursor)| {
+            let (node, input) = get_next_node(map, node, Input { cursor, ..input })?;
+            Ok((node, input.cursor))
+        },
+        |(node, _)| id_ends_with_z(map.label(*node)),
+    )
 }
 
-#[tracing::instrument]
-fn lcm(numbers: &[u64]) -> u64 {
-    let mut result = numbers[0];
-
-    for &number in numbers.iter().skip(1) {
-        result = result * number / result.gcd(number);
-    }
-
-    result
+/// Each starting node's cycle, in starting node order. The work is split
+/// across threads but collected back in order.
+#[tracing::instrument(skip(map), fields(map = ?SkipDebug(map)))]
+fn find_cycles(map: &Map, input: &Input) -> Result<Vec<Cycle>> {
+    map.get_starting_nodes()
+        .par_iter()
+        .map(|node| find_cycle(map, *node, *input))
+        .collect::<Result<Vec<_>>>()
 }
 
 #[tracing::instrument]
-pub fn process(input: &str) -> Result<u64> {
-    let mut lines = input.lines().map(|l| l.trim());
+pub fn process(input: &str) -> Result<Answer> {
+    let input = normalize(input);
+    let instructions = instructions::parse(&input)?;
+    let input = HeaderAndBody::parse(&input)?;
 
-    let instructions = lines.next().ok_or_else(|| Error::NoInstructionsFound)?;
+    let map = Map::from_lines(&input.body_lines().collect::<Vec<_>>())?;
 
     let input = Input::new(instructions);
 
-    lines.next();
-
-    let remaining = lines.collect::<Vec<_>>().join("\n");
-
-    let map = Map::from_str(&remaining)?;
-
-    let current_nodes = map
-        .get_starting_nodes()
-        .iter()
-        .map(|n| n.id)
-        .collect::<Vec<_>>();
+    let cycles = find_cycles(&map, &input)?;
 
-    let distances_to_next_z = current_nodes
-        .par_iter()
-        .map(|n| steps_to_next_ending_in_z(&map, *n, input.clone()))
-        .collect::<Result<Vec<_>>>()?;
-
-    let lcm: u64 = lcm(&distances_to_next_z);
-
-    Ok(lcm)
+    cycle::first_common_end(&cycles)
+        .ok_or(Error::GhostsNeverMeet)
+        .map(Answer::from)
 }
 
 #[cfg(test)]
@@ -255,6 +267,15 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn it_should_reject_letters_outside_alphabet() -> miette::Result<()> {
+        assert!(matches!(
+            letters_to_id("AbC"),
+            Err(Error::InvalidLetterInId(_))
+        ));
+        Ok(())
+    }
+
     #[test]
     fn it_should_decode_the_id_correctly() -> miette::Result<()> {
         let input = 0x00414243;
@@ -276,7 +297,7 @@ mod tests {
     }
 
     #[test]
-    fn it_should_find_distance_to_next_end() -> miette::Result<()> {
+    fn it_should_find_each_ghosts_cycle() -> miette::Result<()> {
         let input = "LR
 
         11A = (11B, XXX)
@@ -288,27 +309,61 @@ mod tests {
         22Z = (22B, 22B)
         XXX = (XXX, XXX)";
 
-        let mut lines = input.lines().map(|l| l.trim());
-
-        let instructions = lines.next().ok_or_else(|| Error::NoInstructionsFound)?;
+        let input = HeaderAndBody::parse(input)?;
 
-        let instructions = Input::new(instructions);
+        let map = Map::from_lines(&input.body_lines().collect::<Vec<_>>())?;
 
-        lines.next();
+        let instructions = Input::new(input.header);
 
-        let remaining = lines.collect::<Vec<_>>().join("\n");
+        let cycle = find_cycle(&map, map.index(letters_to_id("11A")?)?, instructions)?;
+        assert_eq!((1, 2, vec![2]), (cycle.start, cycle.len, cycle.offsets));
 
-        let map = Map::from_str(&remaining)?;
+        let cycle = find_cycle(&map, map.index(letters_to_id("22A")?)?, instructions)?;
+        assert_eq!((1, 6, vec![3, 6]), (cycle.start, cycle.len, cycle.offsets));
 
-        assert_eq!(
-            2,
-            steps_to_next_ending_in_z(&map, letters_to_id("11A")?, instructions.clone())?
-        );
-        assert_eq!(
-            3,
-            steps_to_next_ending_in_z(&map, letters_to_id("22A")?, instructions.clone())?
-        );
+        Ok(())
+    }
 
+    #[test]
+    fn it_should_order_starting_nodes_the_same_every_time() -> miette::Result<()> {
+        let lines = [
+            "33A = (33Z, 33Z)",
+            "11A = (11B, XXX)",
+            "11B = (XXX, 11Z)",
+            "11Z = (11B, XXX)",
+            "33Z = (33A, 33A)",
+            "22A = (22B, XXX)",
+            "22B = (22C, 22C)",
+            "22C = (22Z, 22Z)",
+            "22Z = (22B, 22B)",
+            "XXX = (XXX, XXX)",
+        ];
+        let instructions = Input::new("LR");
+
+        let expected = vec![
+            letters_to_id("11A")?,
+            letters_to_id("22A")?,
+            letters_to_id("33A")?,
+        ];
+
+        for rotation in 0..lines.len() {
+            let mut lines = lines.to_vec();
+            lines.rotate_left(rotation);
+            let map = Map::from_lines(&lines)?;
+
+            let starting = map
+                .get_starting_nodes()
+                .into_iter()
+                .map(|node| map.label(node))
+                .collect::<Vec<_>>();
+
+            assert_eq!(expected, starting);
+            let first_ends = find_cycles(&map, &instructions)?
+                .iter()
+                .map(|cycle| (0..).find(|step| cycle.is_end(*step)))
+                .collect::<Vec<_>>();
+            assert_eq!(vec![Some(2), Some(3), Some(1)], first_ends);
+        }
         Ok(())
     }
 
@@ -325,7 +380,7 @@ mod tests {
         22Z = (22B, 22B)
         XXX = (XXX, XXX)";
 
-        assert_eq!(6, process(input)?);
+        assert_eq!(Answer::UInt(6), process(input)?);
         Ok(())
     }
 }
@@ -1,3 +1,5 @@
+use std::sync::OnceLock;
+
 use day_09::*;
 
 fn main() {
@@ -6,17 +8,73 @@ fn main() {
 }
 
 #[divan::bench]
-fn part1() {
-    part1::process(divan::black_box(include_str!(
-        "../input1.txt",
-    )))
-    .unwrap();
+fn part1(bencher: divan::Bencher) {
+    let input = aoc_core::puzzle_input!("input1.txt").unwrap();
+    bencher.bench(|| part1::process(divan::black_box(&input)).unwrap());
+}
+
+#[divan::bench]
+fn part2(bencher: divan::Bencher) {
+    let input = aoc_core::puzzle_input!("input2.txt").unwrap();
+    bencher.bench(|| part2::process(divan::black_box(&input)).unwrap());
+}
+
+#[divan::bench]
+fn part1_opt(bencher: divan::Bencher) {
+    let input = aoc_core::puzzle_input!("input1.txt").unwrap();
+    bencher.bench(|| part1_opt::process(divan::black_box(&input)).unwrap());
 }
 
 #[divan::bench]
-fn part2() {
-    part2::process(divan::black_box(include_str!(
-        "../input2.txt",
-    )))
-    .unwrap();
-}
\ No newline at end of file
+fn part2_opt(bencher: divan::Bencher) {
+    let input = aoc_core::puzzle_input!("input2.txt").unwrap();
+    bencher.bench(|| part2_opt::process(divan::black_box(&input)).unwrap());
+}
+
+#[divan::bench]
+fn part1_par(bencher: divan::Bencher) {
+    let input = aoc_core::puzzle_input!("input1.txt").unwrap();
+    bencher.bench(|| part1_par::process(divan::black_box(&input)).unwrap());
+}
+
+#[divan::bench]
+fn part2_par(bencher: divan::Bencher) {
+    let input = aoc_core::puzzle_input!("input2.txt").unwrap();
+    bencher.bench(|| part2_par::process(divan::black_box(&input)).unwrap());
+}
+
+/// One million histories, generated once and shared by the synthetic benches.
+fn million_lines() -> &'static str {
+    static INPUT: OnceLock<String> = OnceLock::new();
+    INPUT.get_or_init(|| synthetic::synthetic_input(1_000_000))
+}
+
+#[divan::bench(sample_count = 10)]
+fn part1_million_lines() {
+    part1::process(divan::black_box(million_lines())).unwrap();
+}
+
+#[divan::bench(sample_count = 10)]
+fn part1_opt_million_lines() {
+    part1_opt::process(divan::black_box(million_lines())).unwrap();
+}
+
+#[divan::bench(sample_count = 10)]
+fn part1_par_million_lines() {
+    part1_par::process(divan::black_box(million_lines())).unwrap();
+}
+
+#[divan::bench(sample_count = 10)]
+fn part2_million_lines() {
+    part2::process(divan::black_box(million_lines())).unwrap();
+}
+
+#[divan::bench(sample_count = 10)]
+fn part2_opt_million_lines() {
+    part2_opt::process(divan::black_box(million_lines())).unwrap();
+}
+
+#[divan::bench(sample_count = 10)]
+fn part2_par_million_lines() {
+    part2_par::process(divan::black_box(million_lines())).unwrap();
+}
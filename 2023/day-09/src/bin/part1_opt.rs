@@ -0,0 +1,10 @@
+use day_09::part1_opt::process;
+use miette::Context;
+
+#[tracing::instrument]
+fn main() -> miette::Result<()> {
+    let file = aoc_core::puzzle_input!("input1.txt")?;
+    let result = process(&file).context("process part 1")?;
+    println!("{}", result);
+    Ok(())
+}
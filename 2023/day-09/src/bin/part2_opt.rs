@@ -0,0 +1,10 @@
+use day_09::part2_opt::process;
+use miette::Context;
+
+#[tracing::instrument]
+fn main() -> miette::Result<()> {
+    let file = aoc_core::puzzle_input!("input2.txt")?;
+    let result = process(&file).context("process part 2")?;
+    println!("{}", result);
+    Ok(())
+}
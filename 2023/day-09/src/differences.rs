@@ -0,0 +1,276 @@
+use crate::{error::Error, prelude::*};
+
+/// Every value and difference. Differences of differences grow quickly, so
+/// even a modest input can outgrow an i32. `checked-math` widens them again
+/// and checks every step, so an overflow is an error rather than a wrap.
+#[cfg(not(feature = "checked-math"))]
+pub type Value = i64;
+#[cfg(feature = "checked-math")]
+pub type Value = i128;
+
+#[tracing::instrument]
+pub fn add(a: Value, b: Value) -> Result<Value> {
+    if cfg!(feature = "checked-math") {
+        a.checked_add(b).ok_or(Error::ValueOverflow)
+    } else {
+        Ok(a + b)
+    }
+}
+
+#[tracing::instrument]
+pub fn sub(a: Value, b: Value) -> Result<Value> {
+    if cfg!(feature = "checked-math") {
+        a.checked_sub(b).ok_or(Error::ValueOverflow)
+    } else {
+        Ok(a - b)
+    }
+}
+
+#[tracing::instrument(skip(values))]
+pub fn sum(values: impl IntoIterator<Item = Value>) -> Result<Value> {
+    values.into_iter().try_fold(0, add)
+}
+
+/// Which end of a sequence to extrapolate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    Forward,
+    Backward,
+}
+
+/// Every row of finite differences for a sequence, down to the first row of zeros.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Pyramid {
+    pub rows: Vec<Vec<Value>>,
+}
+
+#[tracing::instrument]
+pub fn parse_values(line: &str) -> Result<Vec<Value>> {
+    line.split_whitespace()
+        .map(|n| n.parse::<Value>().map_err(Error::CouldNotParseNumber))
+        .collect()
+}
+
+/// Like [`parse_values`], but into `values` so one buffer serves every line.
+#[tracing::instrument(skip(values))]
+pub fn parse_values_into(line: &str, values: &mut Vec<Value>) -> Result<()> {
+    values.clear();
+
+    for n in line.split_whitespace() {
+        values.push(n.parse::<Value>().map_err(Error::CouldNotParseNumber)?);
+    }
+
+    Ok(())
+}
+
+#[tracing::instrument]
+fn differences(values: &[Value]) -> Result<Vec<Value>> {
+    values
+        .windows(2)
+        .map(|pair| sub(pair[1], pair[0]))
+        .collect()
+}
+
+impl Pyramid {
+    #[tracing::instrument]
+    pub fn new(values: &[Value]) -> Result<Self> {
+        let mut rows = vec![values.to_vec()];
+
+        while let Some(bottom) = rows.last().filter(|row| row.iter().any(|n| *n != 0)) {
+            rows.push(differences(bottom)?);
+        }
+
+        Ok(Self { rows })
+    }
+
+    /// The value each row gains on the right, from the top row down. Every
+    /// row needs a last value, the row of zeros included, so a history too
+    /// short to reach zeros is an error rather than a guess.
+    #[tracing::instrument(skip(self))]
+    pub fn forward(&self) -> Result<Vec<Value>> {
+        let mut extrapolated = vec![0; self.rows.len()];
+
+        for (i, row) in self.rows.iter().enumerate().rev() {
+            let last = row.last().ok_or(Error::CouldNotGetLastValueOfRow(i))?;
+            extrapolated[i] = add(*last, extrapolated.get(i + 1).copied().unwrap_or_default())?;
+        }
+
+        Ok(extrapolated)
+    }
+
+    /// The value each row gains on the left, from the top row down.
+    #[tracing::instrument(skip(self))]
+    pub fn backward(&self) -> Result<Vec<Value>> {
+        let mut extrapolated = vec![0; self.rows.len()];
+
+        for (i, row) in self.rows.iter().enumerate().rev() {
+            let first = row.first().ok_or(Error::CouldNotGetFirstValueOfRow(i))?;
+            extrapolated[i] = sub(*first, extrapolated.get(i + 1).copied().unwrap_or_default())?;
+        }
+
+        Ok(extrapolated)
+    }
+}
+
+/// The same extrapolation as [`Pyramid`], without keeping its rows. Each row
+/// of differences is folded over the one above it, so `values` ends up
+/// holding differences rather than the sequence.
+#[tracing::instrument]
+pub fn extrapolate_in_place(values: &mut [Value], direction: Direction) -> Result<Value> {
+    let mut extrapolated = 0;
+    let mut len = values.len();
+    let mut depth = 0;
+
+    loop {
+        let row = &mut values[..len];
+
+        let edge = match direction {
+            Direction::Forward => row.last().ok_or(Error::CouldNotGetLastValueOfRow(depth))?,
+            Direction::Backward => row
+                .first()
+                .ok_or(Error::CouldNotGetFirstValueOfRow(depth))?,
+        };
+
+        if row.iter().all(|n| *n == 0) {
+            return Ok(extrapolated);
+        }
+
+        // Going backwards each row's first value is subtracted from what the
+        // row below gains, so the signs alternate down the pyramid.
+        extrapolated = match direction {
+            Direction::Backward if depth % 2 == 1 => sub(extrapolated, *edge)?,
+            _ => add(extrapolated, *edge)?,
+        };
+
+        for i in 1..len {
+            row[i - 1] = sub(row[i], row[i - 1])?;
+        }
+
+        len -= 1;
+        depth += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn it_should_calculate_differences() -> miette::Result<()> {
+        assert_eq!(vec![3, 3, 3, 3, 3], differences(&[0, 3, 6, 9, 12, 15])?);
+        assert_eq!(
+            vec![3, 3, 5, 9, 15],
+            differences(&[10, 13, 16, 21, 30, 45])?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_build_rows_down_to_zeros() -> miette::Result<()> {
+        let pyramid = Pyramid::new(&parse_values("0 3 6 9 12 15")?)?;
+
+        assert_eq!(
+            vec![
+                vec![0, 3, 6, 9, 12, 15],
+                vec![3, 3, 3, 3, 3],
+                vec![0, 0, 0, 0]
+            ],
+            pyramid.rows
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_extrapolate_both_ways() -> miette::Result<()> {
+        let pyramid = Pyramid::new(&parse_values("10 13 16 21 30 45")?)?;
+
+        assert_eq!(vec![68, 23, 8, 2, 0], pyramid.forward()?);
+        assert_eq!(vec![5, 5, -2, 2, 0], pyramid.backward()?);
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_extrapolate_in_place_like_the_pyramid() -> miette::Result<()> {
+        for line in [
+            "0 3 6 9 12 15",
+            "1 3 6 10 15 21",
+            "10 13 16 21 30 45",
+            "5 -3 -15 -31",
+        ] {
+            let pyramid = Pyramid::new(&parse_values(line)?)?;
+            let mut values = vec![];
+
+            parse_values_into(line, &mut values)?;
+            assert_eq!(
+                pyramid.forward()?[0],
+                extrapolate_in_place(&mut values, Direction::Forward)?
+            );
+
+            parse_values_into(line, &mut values)?;
+            assert_eq!(
+                pyramid.backward()?[0],
+                extrapolate_in_place(&mut values, Direction::Backward)?
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_refuse_histories_that_never_reach_zeros() -> miette::Result<()> {
+        let pyramid = Pyramid::new(&parse_values("1")?)?;
+
+        assert!(matches!(
+            pyramid.forward(),
+            Err(Error::CouldNotGetLastValueOfRow(1))
+        ));
+        assert!(matches!(
+            pyramid.backward(),
+            Err(Error::CouldNotGetFirstValueOfRow(1))
+        ));
+        assert!(matches!(
+            extrapolate_in_place(&mut [1], Direction::Forward),
+            Err(Error::CouldNotGetLastValueOfRow(1))
+        ));
+        assert!(matches!(
+            extrapolate_in_place(&mut [1], Direction::Backward),
+            Err(Error::CouldNotGetFirstValueOfRow(1))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_hold_differences_wider_than_i32() -> miette::Result<()> {
+        let line = "2147483647 -2147483647 -2147483647 2147483647";
+        let pyramid = Pyramid::new(&parse_values(line)?)?;
+
+        assert_eq!(-4294967294, pyramid.rows[1][0]);
+        assert!(i32::try_from(pyramid.rows[1][0]).is_err());
+        assert_eq!(10737418235, pyramid.forward()?[0]);
+        assert_eq!(10737418235, pyramid.backward()?[0]);
+
+        let mut values = parse_values(line)?;
+        assert_eq!(
+            10737418235,
+            extrapolate_in_place(&mut values, Direction::Forward)?
+        );
+        Ok(())
+    }
+
+    #[cfg(feature = "checked-math")]
+    #[test]
+    fn it_should_report_overflow_instead_of_wrapping() -> miette::Result<()> {
+        let line = format!("{} 0 {}", -i128::MAX, i128::MAX);
+
+        assert!(matches!(
+            Pyramid::new(&parse_values(&line)?)?.forward(),
+            Err(Error::ValueOverflow)
+        ));
+        assert!(matches!(
+            extrapolate_in_place(&mut parse_values(&line)?, Direction::Forward),
+            Err(Error::ValueOverflow)
+        ));
+        assert!(matches!(sum([i128::MAX, 1]), Err(Error::ValueOverflow)));
+        Ok(())
+    }
+}
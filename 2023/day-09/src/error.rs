@@ -7,10 +7,41 @@ pub enum Error {
     IoError(#[from] std::io::Error),
     #[error("Could not parse number {0}")]
     CouldNotParseNumber(#[from] std::num::ParseIntError),
-    #[error("Could not get bottom row of values")]
-    CouldNotGetBottomRowOfValues,
     #[error("Could not get last value of row {0}")]
     CouldNotGetLastValueOfRow(usize),
     #[error("Could not get first value of row {0}")]
     CouldNotGetFirstValueOfRow(usize),
-}
\ No newline at end of file
+    #[error("A value overflowed while extrapolating")]
+    ValueOverflow,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{part1, part2};
+
+    #[test]
+    fn it_should_reach_could_not_parse_number() -> miette::Result<()> {
+        assert!(matches!(part1::process("0 x 6"), Err(Error::CouldNotParseNumber(_))));
+        assert!(matches!(part2::process("0 x 6"), Err(Error::CouldNotParseNumber(_))));
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_reach_could_not_get_last_value_of_row() -> miette::Result<()> {
+        assert!(matches!(
+            part1::process("1"),
+            Err(Error::CouldNotGetLastValueOfRow(1))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_reach_could_not_get_first_value_of_row() -> miette::Result<()> {
+        assert!(matches!(
+            part2::process("1"),
+            Err(Error::CouldNotGetFirstValueOfRow(1))
+        ));
+        Ok(())
+    }
+}
@@ -0,0 +1,105 @@
+use aoc_viz::{Canvas, Color};
+
+use crate::{
+    differences::{parse_values, Pyramid},
+    prelude::*,
+};
+
+/// The classic difference pyramid for every non-blank line, with the value
+/// extrapolated backwards (part 2) in blue and forwards (part 1) in green.
+/// Lines are numbered as they appear in `input`, blanks included.
+#[tracing::instrument(skip(input))]
+pub fn render(input: &str, ansi: bool) -> Result<String> {
+    let mut pyramids = vec![];
+
+    for (i, line) in input.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let pyramid = Pyramid::new(&parse_values(line)?)?;
+        pyramids.push(render_pyramid(i + 1, &pyramid, ansi)?);
+    }
+
+    Ok(pyramids.join("\n"))
+}
+
+#[tracing::instrument(skip(pyramid))]
+fn render_pyramid(number: usize, pyramid: &Pyramid, ansi: bool) -> Result<String> {
+    let forward = pyramid.forward()?;
+    let backward = pyramid.backward()?;
+
+    let header = format!(
+        "Line {}: previous {}, next {}",
+        number, backward[0], forward[0]
+    );
+
+    // Even cell widths keep each difference centred between the two values above it.
+    let widest = pyramid
+        .rows
+        .iter()
+        .flatten()
+        .chain(&forward)
+        .chain(&backward)
+        .map(|n| n.to_string().len())
+        .max()
+        .unwrap_or(1);
+    let cell = (widest + 2) & !1;
+    let columns = pyramid.rows[0].len() + 2;
+
+    let mut canvas = Canvas::new((columns * cell).max(header.len()), pyramid.rows.len() + 1);
+    canvas.text(0, 0, &header, Color::Default);
+
+    for (depth, row) in pyramid.rows.iter().enumerate() {
+        let y = depth + 1;
+        let indent = depth * cell / 2;
+        let zeros = row.iter().all(|n| *n == 0);
+
+        let cells = std::iter::once((backward[depth], Color::Blue))
+            .chain(row.iter().map(|n| {
+                let color = if zeros { Color::Grey } else { Color::Default };
+                (*n, color)
+            }))
+            .chain(std::iter::once((forward[depth], Color::Green)));
+
+        for (x, (value, color)) in cells.enumerate() {
+            let text = format!("{:>width$}", value, width = cell);
+            canvas.text(indent + x * cell, y, &text, color);
+        }
+    }
+
+    Ok(canvas.render(ansi))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn it_should_render_pyramid() -> miette::Result<()> {
+        let output = render("10 13 16 21 30 45", false)?;
+
+        assert_eq!(
+            "Line 1: previous 5, next 68
+   5  10  13  16  21  30  45  68
+     5   3   3   5   9  15  23
+      -2   0   2   4   6   8
+         2   2   2   2   2
+           0   0   0   0
+",
+            output
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_number_lines_from_input() -> miette::Result<()> {
+        let output = render("\n0 3 6 9 12 15\n\n1 3 6 10 15 21", true)?;
+
+        assert!(output.starts_with("Line 2: previous -3, next 18"));
+        assert!(output.contains("\nLine 4: previous 0, next 28"));
+        assert!(output.contains("\x1b[32m  18"));
+        Ok(())
+    }
+}
@@ -1,5 +1,15 @@
 pub mod error;
 pub mod prelude;
 
+pub mod differences;
+pub mod explain;
+pub mod synthetic;
+
 pub mod part1;
-pub mod part2;
\ No newline at end of file
+pub mod part2;
+pub mod part1_opt;
+pub mod part2_opt;
+pub mod part1_par;
+pub mod part2_par;
+
+aoc_core::solution!(Day09, 2023, 9, part1::process, part2::process);
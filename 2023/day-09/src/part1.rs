@@ -1,69 +1,25 @@
-use crate::{error::Error, prelude::*};
+use aoc_core::Answer;
 
-#[tracing::instrument]
-fn calculate_differences(values: &[i32]) -> Vec<i32> {
-    let mut differences = Vec::new();
-    for i in 0..values.len() - 1 {
-        differences.push(values[i + 1] - values[i]);
-    }
-    differences
-}
+use crate::{
+    differences::{self, parse_values, Pyramid, Value},
+    prelude::*,
+};
 
+/// The value that would come after the last in `values`.
 #[tracing::instrument]
-fn extrapolate_value(input: &[i32]) -> Result<i32> {
-    let mut values = vec![input.to_vec()];
-
-    loop {
-        let bottom = values
-            .last()
-            .ok_or_else(|| Error::CouldNotGetBottomRowOfValues)?;
-
-        if bottom.iter().all(|n| *n == 0) {
-            break;
-        }
-
-        values.push(calculate_differences(bottom));
-    }
-
-    for row_index in (0..values.len() - 1).rev() {
-        let row_last_value = values[row_index]
-            .last()
-            .ok_or_else(|| Error::CouldNotGetLastValueOfRow(row_index))?;
-
-        let row_below_last_value = values[row_index + 1]
-            .last()
-            .ok_or_else(|| Error::CouldNotGetLastValueOfRow(row_index + 1))?;
-
-        let next_value = row_last_value + row_below_last_value;
-
-        values[row_index].push(next_value);
-    }
-
-    Ok(values[0][values[0].len() - 1])
+fn extrapolate_value(values: &[Value]) -> Result<Value> {
+    Ok(Pyramid::new(values)?.forward()?[0])
 }
 
-#[tracing::instrument]
-pub fn process(input: &str) -> Result<i32> {
-    let input = input
-        .lines()
-        .map(|line| {
-            line.split_whitespace()
-                .map(|n| {
-                    n.parse::<i32>()
-                        .map_err(Error::CouldNotParseNumber)
-                })  
-                .collect::<Result<Vec<_>>>()
-        })
-        .collect::<Result<Vec<_>>>()?;
-
+#[tracing::instrument(skip(input))]
+pub fn process(input: &str) -> Result<Answer> {
     let extrapolations = input
-        .iter()
-        .map(|row| extrapolate_value(row))
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| extrapolate_value(&parse_values(line)?))
         .collect::<Result<Vec<_>>>()?;
 
-    let sum = extrapolations.iter().sum();
-
-    Ok(sum)
+    differences::sum(extrapolations).map(Answer::from)
 }
 
 #[cfg(test)]
@@ -71,20 +27,6 @@ mod tests {
     use super::*;
     use pretty_assertions::assert_eq;
 
-    #[test]
-    fn it_should_calcualte_differences() -> miette::Result<()> {
-        assert_eq!(
-            vec![3, 3, 3, 3, 3],
-            calculate_differences(&[0, 3, 6, 9, 12, 15])
-        );
-
-        assert_eq!(
-            vec![3, 3, 5, 9, 15],
-            calculate_differences(&[10, 13, 16, 21, 30, 45])
-        );
-        Ok(())
-    }
-
     #[test]
     fn it_should_extrapolate_values() -> miette::Result<()> {
         assert_eq!(18, extrapolate_value(&[0, 3, 6, 9, 12, 15])?);
@@ -97,7 +39,15 @@ mod tests {
         let input = "0 3 6 9 12 15
         1 3 6 10 15 21
         10 13 16 21 30 45";
-        assert_eq!(114, process(input)?);
+        assert_eq!(Answer::Int(114), process(input)?);
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_extrapolate_past_i32() -> miette::Result<()> {
+        let answer = process("2147483645 2147483646 2147483647")?;
+
+        assert_eq!(Answer::Int(2147483648), answer);
         Ok(())
     }
 }
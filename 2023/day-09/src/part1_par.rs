@@ -0,0 +1,37 @@
+use rayon::prelude::*;
+
+use aoc_core::Answer;
+
+use crate::{
+    differences::{self, parse_values, Pyramid},
+    prelude::*,
+};
+
+#[tracing::instrument(skip(input))]
+pub fn process(input: &str) -> Result<Answer> {
+    let extrapolations = input
+        .par_lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            let pyramid = Pyramid::new(&parse_values(line)?)?;
+            Ok(pyramid.forward()?[0])
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    differences::sum(extrapolations).map(Answer::from)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_process() -> miette::Result<()> {
+        let input = "0 3 6 9 12 15
+        1 3 6 10 15 21
+        10 13 16 21 30 45";
+        assert_eq!(Answer::Int(114), process(input)?);
+        Ok(())
+    }
+}
@@ -0,0 +1,40 @@
+use aoc_core::Answer;
+
+use crate::{
+    differences::{add, extrapolate_in_place, parse_values_into, Direction},
+    prelude::*,
+};
+
+/// Folds each line's differences into one reused buffer rather than building
+/// a row per difference, so a line costs no allocations after the first.
+#[tracing::instrument(skip(input))]
+pub fn process(input: &str) -> Result<Answer> {
+    let mut values = vec![];
+
+    input
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .try_fold(0, |total, line| {
+            parse_values_into(line, &mut values)?;
+            add(
+                total,
+                extrapolate_in_place(&mut values, Direction::Backward)?,
+            )
+        })
+        .map(Answer::from)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_process() -> miette::Result<()> {
+        let input = "0 3 6 9 12 15
+        1 3 6 10 15 21
+        10 13 16 21 30 45";
+        assert_eq!(Answer::Int(2), process(input)?);
+        Ok(())
+    }
+}
@@ -0,0 +1,47 @@
+/// A deterministic day 9 input of `lines` histories, each 21 values of a
+/// quadratic, for benchmarking at sizes far beyond the real input.
+#[tracing::instrument]
+pub fn synthetic_input(lines: usize) -> String {
+    let mut input = String::new();
+
+    for i in 0..lines as i32 {
+        let (a, b, c) = (i % 17 - 8, i % 7 - 3, i % 5 - 2);
+
+        let values = (0..21)
+            .map(|x| (a + b * x + c * x * x).to_string())
+            .collect::<Vec<_>>();
+
+        input.push_str(&values.join(" "));
+        input.push('\n');
+    }
+
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{part1, part1_opt, part1_par, part2, part2_opt, part2_par};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn it_should_generate_histories() -> miette::Result<()> {
+        let input = synthetic_input(3);
+
+        assert_eq!(3, input.lines().count());
+        assert!(input.lines().all(|line| line.split(' ').count() == 21));
+        assert!(input.starts_with("-8 -13 -22 "));
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_match_serial_sums() -> miette::Result<()> {
+        let input = synthetic_input(10_000);
+
+        assert_eq!(part1::process(&input)?, part1_par::process(&input)?);
+        assert_eq!(part1::process(&input)?, part1_opt::process(&input)?);
+        assert_eq!(part2::process(&input)?, part2_par::process(&input)?);
+        assert_eq!(part2::process(&input)?, part2_opt::process(&input)?);
+        Ok(())
+    }
+}
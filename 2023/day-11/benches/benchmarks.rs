@@ -6,27 +6,21 @@ fn main() {
 }
 
 #[divan::bench]
-fn part1() {
-    part1::process(divan::black_box(include_str!(
-        "../input1.txt",
-    )))
-    .unwrap();
+fn part1(bencher: divan::Bencher) {
+    let input = aoc_core::puzzle_input!("input1.txt").unwrap();
+    bencher.bench(|| part1::process(divan::black_box(&input)).unwrap());
 }
 
 #[divan::bench]
-fn part2() {
-    part2::process(divan::black_box(include_str!(
-        "../input2.txt",
-    )))
-    .unwrap();
+fn part2(bencher: divan::Bencher) {
+    let input = aoc_core::puzzle_input!("input2.txt").unwrap();
+    bencher.bench(|| part2::process(divan::black_box(&input)).unwrap());
 }
 
 #[divan::bench]
-fn part1_opt() {
-    part1_opt::process(divan::black_box(include_str!(
-        "../input1.txt",
-    )))
-    .unwrap();
+fn part1_opt(bencher: divan::Bencher) {
+    let input = aoc_core::puzzle_input!("input1.txt").unwrap();
+    bencher.bench(|| part1_opt::process(divan::black_box(&input)).unwrap());
 }
 
 // #[divan::bench]
@@ -35,4 +29,32 @@ fn part1_opt() {
 //         "../input2.txt",
 //     )))
 //     .unwrap();
-// }
\ No newline at end of file
+// }
+
+#[divan::bench]
+fn part1_parse(bencher: divan::Bencher) {
+    let input = aoc_core::puzzle_input!("input1.txt").unwrap();
+    bencher.bench(|| part1::parse(divan::black_box(&input)).unwrap());
+}
+
+// solve fills the map's distance cache, so each run gets a freshly parsed map
+#[divan::bench]
+fn part1_solve(bencher: divan::Bencher) {
+    let input = aoc_core::puzzle_input!("input1.txt").unwrap();
+    bencher
+        .with_inputs(|| aoc_core::preload(&input, part1::parse).unwrap())
+        .bench_values(|map| part1::solve(map).unwrap());
+}
+
+#[divan::bench]
+fn part2_parse(bencher: divan::Bencher) {
+    let input = aoc_core::puzzle_input!("input2.txt").unwrap();
+    bencher.bench(|| part2::parse(divan::black_box(&input)).unwrap());
+}
+
+#[divan::bench]
+fn part2_solve(bencher: divan::Bencher) {
+    let input = aoc_core::puzzle_input!("input2.txt").unwrap();
+    let map = aoc_core::preload(&input, part2::parse).unwrap();
+    bencher.bench(|| part2::solve(divan::black_box(&map)).unwrap());
+}
@@ -0,0 +1,190 @@
+//! The image expanding one empty column or row at a time, for `--animate`.
+
+use std::{io::Write, time::Duration};
+
+use aoc_core::{Grid, Layout, PointSet};
+use aoc_geometry::Point2;
+use aoc_viz::{Animation, Canvas, Color};
+
+use crate::prelude::*;
+
+/// How long each frame stays up when played on a terminal.
+pub const FRAME_DELAY: Duration = Duration::from_millis(150);
+
+/// Empty lines are drawn at most this many cells wide, whatever the factor,
+/// so part 2's million still fits on screen.
+const MAX_DRAWN_FACTOR: u64 = 4;
+
+/// The image with its galaxies and which columns and rows are empty, in the
+/// order they get expanded: columns left to right, then rows top to bottom.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Image {
+    width: usize,
+    height: usize,
+    galaxies: PointSet,
+    empty_columns: Vec<usize>,
+    empty_rows: Vec<usize>,
+}
+
+impl Image {
+    #[tracing::instrument(skip(input))]
+    fn new(input: &str) -> Result<Self> {
+        let mut galaxies = PointSet::new();
+        let grid = Grid::parse_with(input, Layout::Trimmed, |position, cell| {
+            if cell == '#' {
+                galaxies.insert(position);
+            }
+            Some(cell)
+        })?;
+
+        let empty_columns = (0..grid.width())
+            .filter(|x| galaxies.count_in_col(*x as i64) == 0)
+            .collect();
+        let empty_rows = (0..grid.height())
+            .filter(|y| galaxies.count_in_row(*y as i64) == 0)
+            .collect();
+
+        Ok(Self {
+            width: grid.width(),
+            height: grid.height(),
+            galaxies,
+            empty_columns,
+            empty_rows,
+        })
+    }
+
+    /// Widths of every column and heights of every row once the first
+    /// `expanded` empty lines are `drawn` cells wide.
+    #[tracing::instrument(skip(self))]
+    fn sizes(&self, expanded: usize, drawn: usize) -> (Vec<usize>, Vec<usize>) {
+        let mut columns = vec![1; self.width];
+        let mut rows = vec![1; self.height];
+
+        for x in self.empty_columns.iter().take(expanded) {
+            columns[*x] = drawn;
+        }
+        for y in self
+            .empty_rows
+            .iter()
+            .take(expanded.saturating_sub(self.empty_columns.len()))
+        {
+            rows[*y] = drawn;
+        }
+
+        (columns, rows)
+    }
+
+    /// Galaxies in yellow and expanded space in grey, with a line of progress
+    /// under them. Every frame is drawn as big as the fully expanded image so
+    /// the frames line up.
+    #[tracing::instrument(skip(self))]
+    fn frame(&self, expanded: usize, factor: u64) -> Canvas {
+        let drawn = factor.clamp(1, MAX_DRAWN_FACTOR) as usize;
+        let lines = self.empty_columns.len() + self.empty_rows.len();
+        let (columns, rows) = self.sizes(expanded, drawn);
+        let (widest, tallest) = self.sizes(lines, drawn);
+        let status = format!("{}/{} empty lines expanded x{}", expanded, lines, factor);
+
+        let mut canvas = Canvas::new(
+            widest.iter().sum::<usize>().max(status.chars().count()),
+            tallest.iter().sum::<usize>() + 1,
+        );
+
+        let mut top = 0;
+        for (y, height) in rows.iter().enumerate() {
+            let mut left = 0;
+            for (x, width) in columns.iter().enumerate() {
+                let cell = if self.galaxies.contains(Point2::new(x as i64, y as i64)) {
+                    ('#', Color::Yellow)
+                } else if *width > 1 || *height > 1 {
+                    ('.', Color::Grey)
+                } else {
+                    ('.', Color::Default)
+                };
+                canvas.grid(left, top, *width, *height, |_, _| cell);
+                left += width;
+            }
+            top += height;
+        }
+
+        canvas.text(0, canvas.height() - 1, &status, Color::Default);
+
+        canvas
+    }
+}
+
+/// Plays the unexpanded image, then one frame per empty column and row as it
+/// grows to `factor`, returning how many frames were shown.
+#[tracing::instrument(skip(input, out))]
+pub fn play(
+    input: &str,
+    factor: u64,
+    out: impl Write,
+    delay: Duration,
+    ansi: bool,
+) -> Result<usize> {
+    let image = Image::new(input)?;
+    let lines = image.empty_columns.len() + image.empty_rows.len();
+    let mut animation = Animation::new(out, delay, ansi);
+
+    for expanded in 0..=lines {
+        animation.step(&image.frame(expanded, factor))?;
+    }
+
+    Ok(animation.frames())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    const EXAMPLE: &str = include_str!("../example1.txt");
+
+    #[test]
+    fn it_should_show_a_frame_per_empty_line() -> miette::Result<()> {
+        let mut output = Vec::new();
+        let frames = play(EXAMPLE, 2, &mut output, Duration::ZERO, false)?;
+
+        assert_eq!(6, frames);
+
+        let output = String::from_utf8_lossy(&output);
+        let last = output.split("\n\n").last().unwrap_or_default();
+        assert_eq!(
+            "....#........
+.........#...
+#............
+.............
+.............
+........#....
+.#...........
+............#
+.............
+.............
+.........#...
+#....#.......
+5/5 empty lines expanded x2
+",
+            last
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_cap_how_wide_empty_lines_are_drawn() -> miette::Result<()> {
+        let image = Image::new(EXAMPLE)?;
+
+        let small = image.frame(5, 10);
+        let huge = image.frame(5, 1_000_000);
+
+        assert_eq!(
+            small.render(false),
+            huge.render(false).replace("1000000", "10")
+        );
+        assert_eq!(
+            Some(10 + 3 * 3),
+            small.render(false).lines().next().map(str::len)
+        );
+        Ok(())
+    }
+}
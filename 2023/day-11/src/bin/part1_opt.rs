@@ -1,10 +1,27 @@
-use day_11::part1::process;
+use aoc_core::Answer;
+use clap::Parser;
+use day_11::{
+    cli::Args,
+    part1::process,
+    part2::{process_with_factor, total_distance_between},
+};
 use miette::Context;
 
 #[tracing::instrument]
 fn main() -> miette::Result<()> {
-    let file = include_str!("../../input1.txt");
-    let result = process(file).context("process part 1")?;
+    let args = Args::parse();
+    let file = args.read_input("input1.txt")?;
+    args.animate(&file, args.expansion.unwrap_or(2))?;
+    let result = match (&args.galaxies, args.expansion) {
+        (Some(ids), factor) => Answer::from(
+            total_distance_between(&file, ids, factor.unwrap_or(2)).context("process part 1")?,
+        ),
+        (None, Some(factor)) => {
+            Answer::from(process_with_factor(&file, factor).context("process part 1")?)
+        }
+        (None, None) => process(&file).context("process part 1")?,
+    };
+    args.check(&file, args.expansion.unwrap_or(2))?;
     println!("{}", result);
     Ok(())
-}
\ No newline at end of file
+}
@@ -1,10 +1,27 @@
-use day_11::part2::process;
+use aoc_core::Answer;
+use clap::Parser;
+use day_11::{
+    cli::Args,
+    part2::{process, process_with_factor, total_distance_between, PART_2_EXPANSION_FACTOR},
+};
 use miette::Context;
 
 #[tracing::instrument]
 fn main() -> miette::Result<()> {
-    let file = include_str!("../../input2.txt");
-    let result = process(file).context("process part 2")?;
+    let args = Args::parse();
+    let file = args.read_input("input2.txt")?;
+    args.animate(&file, args.expansion.unwrap_or(PART_2_EXPANSION_FACTOR))?;
+    let result = match (&args.galaxies, args.expansion) {
+        (Some(ids), factor) => Answer::from(
+            total_distance_between(&file, ids, factor.unwrap_or(PART_2_EXPANSION_FACTOR))
+                .context("process part 2")?,
+        ),
+        (None, Some(factor)) => {
+            Answer::from(process_with_factor(&file, factor).context("process part 2")?)
+        }
+        (None, None) => process(&file).context("process part 2")?,
+    };
+    args.check(&file, args.expansion.unwrap_or(PART_2_EXPANSION_FACTOR))?;
     println!("{}", result);
     Ok(())
 }
@@ -0,0 +1,132 @@
+use std::{io::IsTerminal, path::PathBuf, time::Duration};
+
+use clap::Parser;
+
+use crate::{animate, error::Error, prelude::*, verify};
+
+const VERIFY_SAMPLES: usize = 100;
+const VERIFY_SEED: u64 = 11;
+
+#[derive(Parser, Debug)]
+#[command(about = "Sum of shortest paths between galaxies")]
+pub struct Args {
+    /// Replace every empty row and column with N of them (2 for part 1, 1000000 for part 2)
+    #[arg(long)]
+    pub expansion: Option<u64>,
+    /// Read the puzzle input from this file instead of the crate's own
+    #[arg(long)]
+    pub input: Option<PathBuf>,
+    /// Also check sampled galaxy pairs against a slow shortest path search
+    #[arg(long)]
+    pub verify: bool,
+    /// Only sum distances between these galaxies, numbered from 1 in reading order
+    #[arg(long, value_delimiter = ',')]
+    pub galaxies: Option<Vec<u16>>,
+    /// Show the image expanding one empty row or column at a time first
+    #[arg(long)]
+    pub animate: bool,
+}
+
+impl Args {
+    /// The `--input` file, or else `file` beside this crate's manifest.
+    #[tracing::instrument]
+    pub fn read_input(&self, file: &str) -> Result<String> {
+        match &self.input {
+            Some(path) => Ok(std::fs::read_to_string(path)?),
+            None => Ok(aoc_core::inputs::read(env!("CARGO_MANIFEST_DIR"), file)?),
+        }
+    }
+
+    /// With `--animate`, plays the image expanding to `factor` on stdout,
+    /// redrawing in place when stdout is a terminal.
+    #[tracing::instrument(skip(input))]
+    pub fn animate(&self, input: &str, factor: u64) -> Result<()> {
+        if !self.animate {
+            return Ok(());
+        }
+
+        let stdout = std::io::stdout();
+        let ansi = stdout.is_terminal();
+        let delay = if ansi {
+            animate::FRAME_DELAY
+        } else {
+            Duration::ZERO
+        };
+
+        animate::play(input, factor, stdout.lock(), delay, ansi)?;
+        Ok(())
+    }
+
+    /// With `--verify`, prints every sampled pair where the expansion formula
+    /// and the search disagree at `factor`, failing if there are any.
+    #[tracing::instrument(skip(input))]
+    pub fn check(&self, input: &str, factor: u64) -> Result<()> {
+        if !self.verify {
+            return Ok(());
+        }
+
+        let mismatches = verify::verify(input, factor, VERIFY_SAMPLES, VERIFY_SEED)?;
+
+        for m in &mismatches {
+            eprintln!(
+                "galaxies {} and {}: search found {}, formula gave {}",
+                m.a, m.b, m.searched, m.formula
+            );
+        }
+
+        match mismatches.len() {
+            0 => Ok(()),
+            count => Err(Error::VerifyMismatch { count }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn it_should_parse_expansion_and_input() -> miette::Result<()> {
+        let args = Args::try_parse_from(["part2", "--expansion", "100", "--input", "example1.txt"])
+            .map_err(|e| miette::miette!("{}", e))?;
+        assert_eq!(Some(100), args.expansion);
+        assert_eq!(Some(PathBuf::from("example1.txt")), args.input);
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_default_to_the_crates_own_input() -> miette::Result<()> {
+        let args = Args::try_parse_from(["part1"]).map_err(|e| miette::miette!("{}", e))?;
+        assert_eq!(None, args.expansion);
+        assert!(!args.verify);
+        assert!(!args.animate);
+        assert_eq!(None, args.galaxies);
+        assert_eq!(
+            include_str!("../example1.txt"),
+            args.read_input("example1.txt")?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_parse_a_list_of_galaxies() -> miette::Result<()> {
+        let args = Args::try_parse_from(["part1", "--galaxies", "1,5,9"])
+            .map_err(|e| miette::miette!("{}", e))?;
+        assert_eq!(Some(vec![1, 5, 9]), args.galaxies);
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_read_input_from_file() -> miette::Result<()> {
+        let args = Args::try_parse_from([
+            "part2",
+            "--input",
+            concat!(env!("CARGO_MANIFEST_DIR"), "/example1.txt"),
+        ])
+        .map_err(|e| miette::miette!("{}", e))?;
+        let input = args.read_input("input1.txt")?;
+        assert_eq!(8410, crate::part2::process_with_factor(&input, 100)?);
+        Ok(())
+    }
+}
@@ -7,4 +7,27 @@ pub enum Error {
     IoError(#[from] std::io::Error),
     #[error("Could not parse number {0}")]
     CouldNotParseNumber(#[from] std::num::ParseIntError),
-}
\ No newline at end of file
+    #[error(transparent)]
+    #[diagnostic(transparent)]
+    Grid(#[from] aoc_core::grid::Error),
+    #[error(transparent)]
+    #[diagnostic(transparent)]
+    Input(#[from] aoc_core::inputs::Error),
+    #[error("({x}, {y}) is outside the {width}x{height} image")]
+    OutOfBounds {
+        x: usize,
+        y: usize,
+        width: usize,
+        height: usize,
+    },
+    #[error("There is already a galaxy at ({x}, {y})")]
+    GalaxyAlreadyAt { x: usize, y: usize },
+    #[error("There is no galaxy at ({x}, {y})")]
+    NoGalaxyAt { x: usize, y: usize },
+    #[error("No galaxy {id}, galaxies are numbered 1 to {count}")]
+    NoSuchGalaxy { id: u16, count: usize },
+    #[error("Search found {searched} galaxies but the formula placed {formula}")]
+    GalaxyCountMismatch { searched: usize, formula: usize },
+    #[error("{count} sampled galaxy pairs disagree with the shortest path search")]
+    VerifyMismatch { count: usize },
+}
@@ -0,0 +1,323 @@
+use aoc_core::{Grid, Layout, PointSet};
+use aoc_geometry::Point2;
+
+use crate::{error::Error, prelude::*};
+
+/// Galaxy counts along one axis and the sum of expanded distances between
+/// every pair of galaxies along it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Axis {
+    counts: Vec<u64>,
+    total: u64,
+}
+
+impl Axis {
+    #[tracing::instrument]
+    fn new(len: usize) -> Self {
+        Self {
+            counts: vec![0; len],
+            total: 0,
+        }
+    }
+
+    /// Where each index ends up once every empty index before it has grown to `factor`.
+    #[tracing::instrument(skip(self))]
+    fn expanded(&self, factor: u64) -> Vec<u64> {
+        let mut position = 0;
+
+        self.counts
+            .iter()
+            .map(|count| {
+                let current = position;
+                position += if *count == 0 { factor } else { 1 };
+                current
+            })
+            .collect()
+    }
+
+    /// Full recompute with running prefix sums, used when an index becomes
+    /// empty or non-empty and so shifts everything after it.
+    #[tracing::instrument(skip(self))]
+    fn recount(&mut self, factor: u64) {
+        let mut galaxies_before = 0;
+        let mut positions_before = 0;
+        self.total = 0;
+
+        for (count, position) in self.counts.iter().zip(self.expanded(factor)) {
+            self.total += count * (position * galaxies_before - positions_before);
+            galaxies_before += count;
+            positions_before += count * position;
+        }
+    }
+
+    /// Sum of distances from `index` to every galaxy on this axis.
+    #[tracing::instrument(skip(self))]
+    fn distance_to(&self, index: usize, factor: u64) -> u64 {
+        let expanded = self.expanded(factor);
+
+        self.counts
+            .iter()
+            .zip(&expanded)
+            .map(|(count, position)| count * position.abs_diff(expanded[index]))
+            .sum()
+    }
+
+    #[tracing::instrument(skip(self))]
+    fn add(&mut self, index: usize, factor: u64) {
+        if self.counts[index] == 0 {
+            self.counts[index] = 1;
+            self.recount(factor);
+        } else {
+            self.total += self.distance_to(index, factor);
+            self.counts[index] += 1;
+        }
+    }
+
+    #[tracing::instrument(skip(self))]
+    fn remove(&mut self, index: usize, factor: u64) {
+        self.counts[index] -= 1;
+
+        if self.counts[index] == 0 {
+            self.recount(factor);
+        } else {
+            self.total -= self.distance_to(index, factor);
+        }
+    }
+}
+
+/// The image as the puzzle draws it: its size and where the galaxies are,
+/// before any expansion.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    pub width: usize,
+    pub height: usize,
+    pub galaxies: PointSet,
+}
+
+impl Image {
+    #[tracing::instrument(skip(input))]
+    pub fn parse(input: &str) -> Result<Self> {
+        let mut galaxies = PointSet::new();
+        let image = Grid::parse_with(input, Layout::Trimmed, |position, cell| {
+            if cell == '#' {
+                galaxies.insert(position);
+            }
+            Some(cell)
+        })?;
+
+        Ok(Self {
+            width: image.width(),
+            height: image.height(),
+            galaxies,
+        })
+    }
+}
+
+/// Day 11's image kept as per-row and per-column galaxy counts, so galaxies
+/// can be added or removed in O(width + height) without redoing every pair.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GalaxyField {
+    width: usize,
+    height: usize,
+    factor: u64,
+    galaxies: PointSet,
+    columns: Axis,
+    rows: Axis,
+}
+
+impl GalaxyField {
+    /// Every empty row and column is replaced by `factor` empty rows or columns.
+    #[tracing::instrument(skip(input))]
+    pub fn new(input: &str, factor: u64) -> Result<Self> {
+        Ok(Self::expand(Image::parse(input)?, factor))
+    }
+
+    /// `image` with every empty row and column replaced by `factor` of them.
+    #[tracing::instrument(skip(image))]
+    pub fn expand(image: Image, factor: u64) -> Self {
+        let Image {
+            width,
+            height,
+            galaxies,
+        } = image;
+
+        let mut columns = Axis::new(width);
+        let mut rows = Axis::new(height);
+
+        for (x, count) in columns.counts.iter_mut().enumerate() {
+            *count = galaxies.count_in_col(x as i64) as u64;
+        }
+        for (y, count) in rows.counts.iter_mut().enumerate() {
+            *count = galaxies.count_in_row(y as i64) as u64;
+        }
+
+        columns.recount(factor);
+        rows.recount(factor);
+
+        Self {
+            width,
+            height,
+            factor,
+            galaxies,
+            columns,
+            rows,
+        }
+    }
+
+    /// Sum of the shortest paths between every pair of galaxies.
+    #[tracing::instrument(skip(self))]
+    pub fn total(&self) -> u64 {
+        self.columns.total + self.rows.total
+    }
+
+    #[tracing::instrument(skip(self))]
+    fn check_bounds(&self, (x, y): (usize, usize)) -> Result<()> {
+        if x >= self.width || y >= self.height {
+            return Err(Error::OutOfBounds {
+                x,
+                y,
+                width: self.width,
+                height: self.height,
+            });
+        }
+
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub fn add(&mut self, pos: (usize, usize)) -> Result<()> {
+        self.check_bounds(pos)?;
+
+        let point = Point2::new(pos.0 as i64, pos.1 as i64);
+        if !self.galaxies.insert(point) {
+            return Err(Error::GalaxyAlreadyAt { x: pos.0, y: pos.1 });
+        }
+
+        self.columns.add(pos.0, self.factor);
+        self.rows.add(pos.1, self.factor);
+
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub fn remove(&mut self, pos: (usize, usize)) -> Result<()> {
+        self.check_bounds(pos)?;
+
+        let point = Point2::new(pos.0 as i64, pos.1 as i64);
+        if !self.galaxies.remove(point) {
+            return Err(Error::NoGalaxyAt { x: pos.0, y: pos.1 });
+        }
+
+        self.columns.remove(pos.0, self.factor);
+        self.rows.remove(pos.1, self.factor);
+
+        Ok(())
+    }
+
+    /// The unexpanded image, in the same format as the puzzle input.
+    #[tracing::instrument(skip(self))]
+    pub fn render(&self) -> String {
+        let mut s = String::new();
+        for y in 0..self.height {
+            for x in 0..self.width {
+                s.push(if self.galaxies.contains(Point2::new(x as i64, y as i64)) {
+                    '#'
+                } else {
+                    '.'
+                });
+            }
+            s.push('\n');
+        }
+        s
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::part2::process_with_factor;
+    use pretty_assertions::assert_eq;
+
+    const EXAMPLE: &str = include_str!("../example1.txt");
+
+    fn assert_matches_full_recompute(field: &GalaxyField) -> miette::Result<()> {
+        assert_eq!(
+            process_with_factor(&field.render(), field.factor)?,
+            field.total()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_total_example() -> miette::Result<()> {
+        assert_eq!(374, GalaxyField::new(EXAMPLE, 2)?.total());
+        assert_eq!(8410, GalaxyField::new(EXAMPLE, 100)?.total());
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_add_to_occupied_row_and_column() -> miette::Result<()> {
+        let mut field = GalaxyField::new(EXAMPLE, 10)?;
+
+        field.add((0, 0))?;
+        assert_matches_full_recompute(&field)?;
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_add_to_empty_row_and_column() -> miette::Result<()> {
+        let mut field = GalaxyField::new(EXAMPLE, 10)?;
+
+        field.add((2, 3))?;
+        assert_matches_full_recompute(&field)?;
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_match_full_recompute_while_emptying() -> miette::Result<()> {
+        let mut field = GalaxyField::new(EXAMPLE, 1_000_000)?;
+        let galaxies = field.galaxies.iter_sorted().collect::<Vec<_>>();
+
+        for galaxy in galaxies {
+            field.remove((galaxy.x as usize, galaxy.y as usize))?;
+            assert_matches_full_recompute(&field)?;
+        }
+
+        assert_eq!(0, field.total());
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_return_to_original_total() -> miette::Result<()> {
+        let mut field = GalaxyField::new(EXAMPLE, 2)?;
+
+        field.add((5, 7))?;
+        field.add((5, 8))?;
+        field.remove((5, 7))?;
+        field.remove((5, 8))?;
+
+        assert_eq!(374, field.total());
+        assert_eq!(GalaxyField::new(EXAMPLE, 2)?, field);
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_reject_bad_positions() -> miette::Result<()> {
+        let mut field = GalaxyField::new(EXAMPLE, 2)?;
+
+        assert!(matches!(
+            field.add((3, 0)),
+            Err(Error::GalaxyAlreadyAt { x: 3, y: 0 })
+        ));
+        assert!(matches!(
+            field.remove((0, 0)),
+            Err(Error::NoGalaxyAt { x: 0, y: 0 })
+        ));
+        assert!(matches!(
+            field.add((10, 0)),
+            Err(Error::OutOfBounds { x: 10, y: 0, .. })
+        ));
+        assert_eq!(374, field.total());
+        Ok(())
+    }
+}
@@ -1,7 +1,41 @@
+pub mod animate;
+pub mod cli;
 pub mod error;
+pub mod galaxy_field;
 pub mod prelude;
+pub mod verify;
 
 pub mod part1;
 pub mod part2;
 pub mod part1_opt;
 pub mod part2_opt;
+
+/// Day 11 behind the shared `aoc_core::Solution` interface. The image is
+/// parsed once, then expanded by each part's factor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Day11;
+
+impl aoc_core::Solution for Day11 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 11;
+
+    type Parsed<'a> = galaxy_field::Image;
+
+    fn parse(input: &str) -> aoc_core::solution::Result<galaxy_field::Image> {
+        Ok(galaxy_field::Image::parse(input)?)
+    }
+
+    fn part1(image: &galaxy_field::Image) -> aoc_core::solution::Result<aoc_core::Answer> {
+        let factor = part1_opt::PART_1_EXPANSION_FACTOR;
+        Ok(galaxy_field::GalaxyField::expand(image.clone(), factor)
+            .total()
+            .into())
+    }
+
+    fn part2(image: &galaxy_field::Image) -> aoc_core::solution::Result<aoc_core::Answer> {
+        let factor = part2::PART_2_EXPANSION_FACTOR;
+        Ok(galaxy_field::GalaxyField::expand(image.clone(), factor)
+            .total()
+            .into())
+    }
+}
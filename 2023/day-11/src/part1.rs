@@ -1,91 +1,47 @@
 use std::collections::HashMap;
 
+use aoc_core::{Answer, Footprint, Grid, SkipDebug};
+
 use crate::prelude::*;
 
+/// The image with every empty row and column doubled. `Grid`'s `Debug` only
+/// shows the dimensions, as the image is tens of thousands of cells.
 #[derive(Debug, Clone, PartialEq, Eq)]
 struct Input {
-    chars: Vec<char>,
-    width: usize,
-    height: usize,
+    image: Grid<char>,
 }
 
 impl Input {
     #[tracing::instrument]
-    fn new(input: &str) -> Self {
-        let lines = input.lines().map(|l| l.trim()).collect::<Vec<_>>();
-        let width = lines[0].len();
+    fn new(input: &str) -> Result<Self> {
+        let image = input.parse::<Grid<char>>()?;
 
-        // Expand "empty" rows to two "empty" rows
-        let rows = lines
-            .iter()
-            .flat_map(|row| {
-                if row.chars().all(|c| c == '.') {
-                    vec![row, row]
-                } else {
-                    vec![row]
-                }
-            })
+        let empty_cols = image
+            .columns()
+            .map(|mut col| col.all(|c| *c == '.'))
             .collect::<Vec<_>>();
 
-        let height = rows.len();
-
-        let mut cols = vec![];
-
-        for x in 0..width {
-            let mut col = vec![];
-            for y in 0..height {
-                col.push(rows[y].chars().nth(x).unwrap());
-            }
-            cols.push(col);
-        }
-
-        let cols = cols
-            .iter()
-            .flat_map(|col| {
-                if col.iter().all(|&c| c == '.') {
-                    vec![col.clone(), col.clone()]
+        // Expand "empty" rows and columns to two "empty" ones
+        let rows = image
+            .rows()
+            .flat_map(|row| {
+                let row = row
+                    .iter()
+                    .zip(&empty_cols)
+                    .flat_map(|(c, empty)| if *empty { vec![*c, *c] } else { vec![*c] })
+                    .collect::<Vec<_>>();
+
+                if row.iter().all(|c| *c == '.') {
+                    vec![row.clone(), row]
                 } else {
-                    vec![col.clone()]
+                    vec![row]
                 }
             })
             .collect::<Vec<_>>();
 
-        let width = cols.len();
-
-        let mut chars = vec![];
-
-        for y in 0..height {
-            for x in 0..width {
-                chars.push(cols[x][y]);
-            }
-        }
-
-        Self {
-            chars,
-            width,
-            height,
-        }
-    }
-
-    #[tracing::instrument]
-    fn to_string(&self) -> String {
-        let mut s = String::new();
-        for y in 0..self.height {
-            for x in 0..self.width {
-                s.push(self.chars[y * self.width + x]);
-            }
-            s.push('\n');
-        }
-        s
-    }
-
-    #[tracing::instrument]
-    fn get(&self, x: usize, y: usize) -> Option<char> {
-        if x >= self.width || y >= self.height {
-            None
-        } else {
-            Some(self.chars[y * self.width + x])
-        }
+        Ok(Self {
+            image: Grid::from_rows(rows)?,
+        })
     }
 }
 
@@ -135,11 +91,17 @@ fn galaxy_distance_hash_id(galaxy_a: u16, galaxy_b: u16) -> u32 {
 }
 
 #[derive(Debug, Clone, PartialEq)]
-struct GalaxyMap {
+pub struct GalaxyMap {
     galaxies: HashMap<u16, Galaxy>,
     galaxy_distances: HashMap<u32, u32>,
 }
 
+impl Footprint for GalaxyMap {
+    fn footprint(&self) -> usize {
+        self.galaxies.len() + self.galaxy_distances.len()
+    }
+}
+
 impl GalaxyMap {
     #[tracing::instrument]
     fn new() -> Self {
@@ -155,24 +117,22 @@ impl GalaxyMap {
 
         let mut id = 1;
 
-        for y in 0..input.height {
-            for x in 0..input.width {
-                if input.get(x, y) == Some('#') {
-                    map.add(Galaxy::new(id, x as f32, y as f32));
-                    id += 1;
-                }
+        for (position, cell) in input.image.cells() {
+            if *cell == '#' {
+                map.add(Galaxy::new(id, position.x as f32, position.y as f32));
+                id += 1;
             }
         }
 
         map
     }
 
-    #[tracing::instrument]
+    #[tracing::instrument(skip(self), fields(galaxies = ?SkipDebug(self)))]
     fn add(&mut self, galaxy: Galaxy) {
         self.galaxies.insert(galaxy.id, galaxy);
     }
 
-    #[tracing::instrument]
+    #[tracing::instrument(skip(self), fields(galaxies = ?SkipDebug(self)))]
     fn distance(&mut self, a: u16, b: u16) -> u32 {
         let key = galaxy_distance_hash_id(a, b);
         if let Some(distance) = self.galaxy_distances.get(&key) {
@@ -184,28 +144,29 @@ impl GalaxyMap {
         }
     }
 
-    #[tracing::instrument]
+    #[tracing::instrument(skip(self), fields(galaxies = ?SkipDebug(self)))]
     fn galaxy_ids(&self) -> Vec<u16> {
         self.galaxies.keys().copied().collect::<Vec<_>>()
     }
 }
 
-#[tracing::instrument]
-pub fn process(input: &str) -> Result<u32> {
-    let input = Input::new(input);
-
-    let mut map = GalaxyMap::from_input(&input);
+/// The galaxies, parsed apart from `solve` so the two can be benchmarked separately.
+#[tracing::instrument(skip(input))]
+pub fn parse(input: &str) -> Result<GalaxyMap> {
+    Ok(GalaxyMap::from_input(&Input::new(input)?))
+}
 
-    let mut galaxy_ids = map.galaxy_ids();
+/// Takes the map by value as `distance` caches into it.
+#[tracing::instrument(skip(map), fields(galaxies = ?SkipDebug(&map)))]
+pub fn solve(mut map: GalaxyMap) -> Result<u32> {
+    let galaxy_ids = map.galaxy_ids();
 
     let mut total_distance = 0;
-    let mut x = 0;
 
     for a in 0..galaxy_ids.len() {
         for b in a + 1..galaxy_ids.len() {
             let distance = map.distance(galaxy_ids[a], galaxy_ids[b]);
             total_distance += distance;
-            x += 1;
 
             // println!(
             //     "distance from {} to {}: {}",
@@ -220,9 +181,15 @@ pub fn process(input: &str) -> Result<u32> {
     Ok(total_distance)
 }
 
+#[tracing::instrument]
+pub fn process(input: &str) -> Result<Answer> {
+    solve(parse(input)?).map(Answer::from)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_geometry::Point2;
     use pretty_assertions::assert_eq;
 
     #[test]
@@ -238,37 +205,39 @@ mod tests {
             ..........
             .......#..
             #...#.....",
-        );
-
-        assert_eq!(input.width, 13);
-        assert_eq!(input.height, 12);
-
-        assert_eq!(input.get(0, 0), Some('.'));
-        assert_eq!(input.get(1, 0), Some('.'));
-        assert_eq!(input.get(2, 0), Some('.'));
-        assert_eq!(input.get(3, 0), Some('.'));
-        assert_eq!(input.get(4, 0), Some('#'));
-        assert_eq!(input.get(5, 0), Some('.'));
-        assert_eq!(input.get(6, 0), Some('.'));
-        assert_eq!(input.get(7, 0), Some('.'));
-        assert_eq!(input.get(8, 0), Some('.'));
-        assert_eq!(input.get(9, 0), Some('.'));
-        assert_eq!(input.get(10, 0), Some('.'));
-        assert_eq!(input.get(11, 0), Some('.'));
-        assert_eq!(input.get(12, 0), Some('.'));
-
-        assert_eq!(input.get(0, 0), Some('.'));
-        assert_eq!(input.get(0, 1), Some('.'));
-        assert_eq!(input.get(0, 2), Some('#'));
-        assert_eq!(input.get(0, 3), Some('.'));
-        assert_eq!(input.get(0, 4), Some('.'));
-        assert_eq!(input.get(0, 5), Some('.'));
-        assert_eq!(input.get(0, 6), Some('.'));
-        assert_eq!(input.get(0, 7), Some('.'));
-        assert_eq!(input.get(0, 8), Some('.'));
-        assert_eq!(input.get(0, 9), Some('.'));
-        assert_eq!(input.get(0, 10), Some('.'));
-        assert_eq!(input.get(0, 11), Some('#'));
+        )?;
+
+        let get = |x, y| input.image.get(Point2::new(x, y)).copied();
+
+        assert_eq!(input.image.width(), 13);
+        assert_eq!(input.image.height(), 12);
+
+        assert_eq!(get(0, 0), Some('.'));
+        assert_eq!(get(1, 0), Some('.'));
+        assert_eq!(get(2, 0), Some('.'));
+        assert_eq!(get(3, 0), Some('.'));
+        assert_eq!(get(4, 0), Some('#'));
+        assert_eq!(get(5, 0), Some('.'));
+        assert_eq!(get(6, 0), Some('.'));
+        assert_eq!(get(7, 0), Some('.'));
+        assert_eq!(get(8, 0), Some('.'));
+        assert_eq!(get(9, 0), Some('.'));
+        assert_eq!(get(10, 0), Some('.'));
+        assert_eq!(get(11, 0), Some('.'));
+        assert_eq!(get(12, 0), Some('.'));
+
+        assert_eq!(get(0, 0), Some('.'));
+        assert_eq!(get(0, 1), Some('.'));
+        assert_eq!(get(0, 2), Some('#'));
+        assert_eq!(get(0, 3), Some('.'));
+        assert_eq!(get(0, 4), Some('.'));
+        assert_eq!(get(0, 5), Some('.'));
+        assert_eq!(get(0, 6), Some('.'));
+        assert_eq!(get(0, 7), Some('.'));
+        assert_eq!(get(0, 8), Some('.'));
+        assert_eq!(get(0, 9), Some('.'));
+        assert_eq!(get(0, 10), Some('.'));
+        assert_eq!(get(0, 11), Some('#'));
 
         Ok(())
     }
@@ -286,7 +255,7 @@ mod tests {
             ..........
             .......#..
             #...#.....",
-        );
+        )?;
 
         let map = GalaxyMap::from_input(&input);
 
@@ -335,7 +304,7 @@ mod tests {
             ..........
             .......#..
             #...#.....",
-        );
+        )?;
 
         let mut map = GalaxyMap::from_input(&input);
 
@@ -374,7 +343,7 @@ mod tests {
         ..........
         .......#..
         #...#.....";
-        assert_eq!(374, process(input)?);
+        assert_eq!(Answer::UInt(374), process(input)?);
         Ok(())
     }
 }
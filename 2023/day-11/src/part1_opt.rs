@@ -1,93 +1,55 @@
 use std::collections::HashMap;
 
-use crate::prelude::*;
-use rayon::prelude::*;
-
-#[derive(Debug, Clone, PartialEq)]
-struct Line {
-    start_x: f32,
-    start_y: f32,
-    end_x: f32,
-    end_y: f32,
-}
+use aoc_core::{Answer, Footprint, Grid, SkipDebug};
+use aoc_geometry::Point2;
 
-#[derive(Debug, Clone, PartialEq)]
-struct Rect {
-    x: f32,
-    y: f32,
-    w: f32,
-    h: f32,
-}
+use crate::{error::Error, prelude::*};
+use rayon::prelude::*;
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Clone, PartialEq, Eq)]
 struct Input {
-    chars: Vec<char>,
-    width: usize,
-    height: usize,
+    image: Grid<char>,
     empty_rows: Vec<usize>,
     empty_cols: Vec<usize>,
 }
 
+impl std::fmt::Debug for Input {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Input")
+            .field("image", &self.image)
+            .field("empty_rows", &self.empty_rows.len())
+            .field("empty_cols", &self.empty_cols.len())
+            .finish()
+    }
+}
+
 impl Input {
     #[tracing::instrument]
-    fn new(input: &str) -> Self {
-        let lines = input.lines().map(|l| l.trim()).collect::<Vec<_>>();
+    fn new(input: &str) -> Result<Self> {
+        let image = input.parse::<Grid<char>>()?;
 
-        let width = lines[0].len();
-        let height = lines.len();
-
-        let chars = lines.iter().flat_map(|l| l.chars()).collect::<Vec<_>>();
-
-        let empty_rows = (0..height)
-            .filter(|y| {
-                for x in 0..width {
-                    if chars[*y * width + x] == '#' {
-                        return false;
-                    }
-                }
-                true
-            })
+        let empty_rows = image
+            .rows()
+            .enumerate()
+            .filter_map(|(y, row)| (!row.contains(&'#')).then_some(y))
             .collect::<Vec<_>>();
 
-        let empty_cols = (0..width)
-            .filter(|x| {
-                for y in 0..height {
-                    if chars[y * width + *x] == '#' {
-                        return false;
-                    }
-                }
-                true
-            })
+        let empty_cols = image
+            .columns()
+            .enumerate()
+            .filter_map(|(x, mut col)| col.all(|c| *c != '#').then_some(x))
             .collect::<Vec<_>>();
 
-        Self {
-            chars,
-            width,
-            height,
+        Ok(Self {
+            image,
             empty_rows,
             empty_cols,
-        }
-    }
-
-    #[tracing::instrument]
-    fn to_string(&self) -> String {
-        let mut s = String::new();
-        for y in 0..self.height {
-            for x in 0..self.width {
-                s.push(self.chars[y * self.width + x]);
-            }
-            s.push('\n');
-        }
-        s
+        })
     }
 
     #[tracing::instrument]
     fn get(&self, x: usize, y: usize) -> Option<char> {
-        if x >= self.width || y >= self.height {
-            None
-        } else {
-            Some(self.chars[y * self.width + x])
-        }
+        self.image.get(Point2::new(x as i64, y as i64)).copied()
     }
 
     #[tracing::instrument]
@@ -101,33 +63,39 @@ impl Input {
     }
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 struct Galaxy {
     id: u16,
-    x: f32,
-    y: f32,
+    position: Point2,
 }
 
 impl Galaxy {
     #[tracing::instrument]
-    fn new(id: u16, x: f32, y: f32) -> Self {
-        Self { id, x, y }
+    fn new(id: u16, x: u64, y: u64) -> Self {
+        Self {
+            id,
+            position: Point2::new(x as i64, y as i64),
+        }
     }
 
     #[tracing::instrument]
-    fn distance(&self, other: &Self) -> f32 {
-        let dx = other.x - self.x;
-        let dy = other.y - self.y;
-
-        dx.abs() + dy.abs()
+    fn distance(&self, other: &Self) -> u64 {
+        self.position.manhattan(other.position)
     }
 }
 
+/// The galaxies at their expanded positions, shared by part 1 and part 2.
 #[derive(Debug, Clone, PartialEq)]
-struct GalaxyMap {
+pub struct GalaxyMap {
     galaxies: HashMap<u16, Galaxy>,
 }
 
+impl Footprint for GalaxyMap {
+    fn footprint(&self) -> usize {
+        self.galaxies.len()
+    }
+}
+
 impl GalaxyMap {
     #[tracing::instrument]
     fn new() -> Self {
@@ -136,29 +104,28 @@ impl GalaxyMap {
         }
     }
 
+    /// Every empty row and column is replaced by `factor` empty rows or columns.
     #[tracing::instrument]
-    fn from_input(input: &Input) -> Self {
+    fn from_input(input: &Input, factor: u64) -> Self {
         let mut map = Self::new();
 
         let mut id = 1;
 
+        let expansion = factor.saturating_sub(1);
+
         let mut y_offset = 0;
 
-        for y in 0..input.height {
+        for y in 0..input.image.height() {
             if input.is_row_empty(y) {
-                y_offset += 1;
+                y_offset += expansion;
             }
             let mut x_offset = 0;
-            for x in 0..input.width {
+            for x in 0..input.image.width() {
                 if input.is_col_empty(x) {
-                    x_offset += 1;
+                    x_offset += expansion;
                 }
                 if input.get(x, y) == Some('#') {
-                    map.add(Galaxy::new(
-                        id,
-                        (x + x_offset) as f32,
-                        (y + y_offset) as f32,
-                    ));
+                    map.add(Galaxy::new(id, x as u64 + x_offset, y as u64 + y_offset));
                     id += 1;
                 }
             }
@@ -167,28 +134,75 @@ impl GalaxyMap {
         map
     }
 
-    #[tracing::instrument]
+    #[tracing::instrument(skip(self), fields(galaxies = ?SkipDebug(self)))]
     fn add(&mut self, galaxy: Galaxy) {
         self.galaxies.insert(galaxy.id, galaxy);
     }
 
-    #[tracing::instrument]
-    fn distance(&self, a: u16, b: u16) -> u32 {
-        self.galaxies[&a].distance(&self.galaxies[&b]) as u32
+    #[tracing::instrument(skip(self), fields(galaxies = ?SkipDebug(self)))]
+    fn distance(&self, a: u16, b: u16) -> u64 {
+        self.galaxies[&a].distance(&self.galaxies[&b])
     }
 
-    #[tracing::instrument]
+    #[tracing::instrument(skip(self), fields(galaxies = ?SkipDebug(self)))]
     fn galaxy_ids(&self) -> Vec<u16> {
         self.galaxies.keys().copied().collect::<Vec<_>>()
     }
+
+    /// Sum of the distances between every pair of the galaxies in `ids`,
+    /// numbered from 1 in reading order. Repeated ids only count once.
+    #[tracing::instrument(skip(self), fields(galaxies = ?SkipDebug(self)))]
+    pub fn total_distance_between(&self, ids: &[u16]) -> Result<u64> {
+        let mut ids = ids.to_vec();
+        ids.sort_unstable();
+        ids.dedup();
+
+        if let Some(id) = ids.iter().find(|id| !self.galaxies.contains_key(id)) {
+            return Err(Error::NoSuchGalaxy {
+                id: *id,
+                count: self.galaxies.len(),
+            });
+        }
+
+        Ok(ids
+            .iter()
+            .enumerate()
+            .flat_map(|(i, a)| ids[i + 1..].iter().map(move |b| (*a, *b)))
+            .map(|(a, b)| self.distance(a, b))
+            .sum())
+    }
+
+    /// Each galaxy's expanded position, in reading order.
+    #[tracing::instrument(skip(self), fields(galaxies = ?SkipDebug(self)))]
+    pub fn positions(&self) -> Vec<Point2> {
+        let mut galaxies = self.galaxies.values().collect::<Vec<_>>();
+        galaxies.sort_by_key(|galaxy| galaxy.id);
+
+        galaxies.iter().map(|galaxy| galaxy.position).collect()
+    }
 }
 
+pub const PART_1_EXPANSION_FACTOR: u64 = 2;
+
 #[tracing::instrument]
-pub fn process(input: &str) -> Result<u32> {
-    let input = Input::new(input);
+pub fn process(input: &str) -> Result<Answer> {
+    process_with_expansion(input, PART_1_EXPANSION_FACTOR).map(Answer::from)
+}
 
-    let map = GalaxyMap::from_input(&input);
+/// Sum of galaxy distances with each empty row and column replaced by `factor`
+/// of them, so part 1 is a factor of 2 and part 2 a factor of a million.
+#[tracing::instrument]
+pub fn process_with_expansion(input: &str, factor: u64) -> Result<u64> {
+    solve(&parse_with_expansion(input, factor)?)
+}
 
+#[tracing::instrument(skip(input))]
+pub fn parse_with_expansion(input: &str, factor: u64) -> Result<GalaxyMap> {
+    Ok(GalaxyMap::from_input(&Input::new(input)?, factor))
+}
+
+#[tracing::instrument(skip(map), fields(galaxies = ?SkipDebug(map)))]
+pub fn solve(map: &GalaxyMap) -> Result<u64> {
     let galaxy_ids = map.galaxy_ids();
 
     let mut galaxys_to_compute: Vec<(u16, u16)> = Vec::new();
@@ -202,7 +216,7 @@ pub fn process(input: &str) -> Result<u32> {
     let total_distance = galaxys_to_compute
         .par_iter()
         .map(|(a, b)| map.distance(*a, *b))
-        .sum::<u32>();
+        .sum::<u64>();
 
     Ok(total_distance)
 }
@@ -225,38 +239,38 @@ mod tests {
             ..........
             .......#..
             #...#.....",
-        );
+        )?;
 
-        let map = GalaxyMap::from_input(&input);
+        let map = GalaxyMap::from_input(&input, 2);
 
         assert_eq!(map.galaxies.len(), 9);
 
-        assert_eq!(map.galaxies[&1].x, 4.0);
-        assert_eq!(map.galaxies[&1].y, 0.0);
+        assert_eq!(map.galaxies[&1].position.x, 4);
+        assert_eq!(map.galaxies[&1].position.y, 0);
 
-        assert_eq!(map.galaxies[&2].x, 9.0);
-        assert_eq!(map.galaxies[&2].y, 1.0);
+        assert_eq!(map.galaxies[&2].position.x, 9);
+        assert_eq!(map.galaxies[&2].position.y, 1);
 
-        assert_eq!(map.galaxies[&3].x, 0.0);
-        assert_eq!(map.galaxies[&3].y, 2.0);
+        assert_eq!(map.galaxies[&3].position.x, 0);
+        assert_eq!(map.galaxies[&3].position.y, 2);
 
-        assert_eq!(map.galaxies[&4].x, 8.0);
-        assert_eq!(map.galaxies[&4].y, 5.0);
+        assert_eq!(map.galaxies[&4].position.x, 8);
+        assert_eq!(map.galaxies[&4].position.y, 5);
 
-        assert_eq!(map.galaxies[&5].x, 1.0);
-        assert_eq!(map.galaxies[&5].y, 6.0);
+        assert_eq!(map.galaxies[&5].position.x, 1);
+        assert_eq!(map.galaxies[&5].position.y, 6);
 
-        assert_eq!(map.galaxies[&6].x, 12.0);
-        assert_eq!(map.galaxies[&6].y, 7.0);
+        assert_eq!(map.galaxies[&6].position.x, 12);
+        assert_eq!(map.galaxies[&6].position.y, 7);
 
-        assert_eq!(map.galaxies[&7].x, 9.0);
-        assert_eq!(map.galaxies[&7].y, 10.0);
+        assert_eq!(map.galaxies[&7].position.x, 9);
+        assert_eq!(map.galaxies[&7].position.y, 10);
 
-        assert_eq!(map.galaxies[&8].x, 0.0);
-        assert_eq!(map.galaxies[&8].y, 11.0);
+        assert_eq!(map.galaxies[&8].position.x, 0);
+        assert_eq!(map.galaxies[&8].position.y, 11);
 
-        assert_eq!(map.galaxies[&9].x, 5.0);
-        assert_eq!(map.galaxies[&9].y, 11.0);
+        assert_eq!(map.galaxies[&9].position.x, 5);
+        assert_eq!(map.galaxies[&9].position.y, 11);
 
         Ok(())
     }
@@ -274,9 +288,9 @@ mod tests {
             ..........
             .......#..
             #...#.....",
-        );
+        )?;
 
-        let mut map = GalaxyMap::from_input(&input);
+        let map = GalaxyMap::from_input(&input, 2);
 
         // ....1........
         // .........2...
@@ -313,14 +327,28 @@ mod tests {
         ..........
         .......#..
         #...#.....";
-        assert_eq!(374, process(input)?);
+        assert_eq!(Answer::UInt(374), process(input)?);
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_expand_by_any_factor() -> miette::Result<()> {
+        let input = include_str!("../example1.txt");
+
+        assert_eq!(374, process_with_expansion(input, 2)?);
+        assert_eq!(1030, process_with_expansion(input, 10)?);
+        // Too large for an f32 to hold exactly
+        assert_eq!(82000210, process_with_expansion(input, 1_000_000)?);
         Ok(())
     }
 
     #[test]
     fn it_should_get_right_output() -> miette::Result<()> {
-        let input = include_str!("../input1.txt");
-        assert_eq!(9565386, process(input)?);
+        let input = &aoc_core::puzzle_input!("input1.txt")?;
+        assert_eq!(
+            aoc_core::manifest::answer(2023, 11, 1)?,
+            process(input)?.to_string()
+        );
         Ok(())
     }
 }
@@ -1,334 +1,153 @@
-use std::collections::HashMap;
+use aoc_core::{Answer, SkipDebug};
+use aoc_geometry::Point2;
 
-use crate::prelude::*;
-use rayon::prelude::*;
+use crate::{
+    part1_opt::{self, GalaxyMap},
+    prelude::*,
+};
 
-#[derive(Debug, Clone, PartialEq)]
-struct Line {
-    start_x: f64,
-    start_y: f64,
-    end_x: f64,
-    end_y: f64,
-}
-
-#[derive(Debug, Clone, PartialEq)]
-struct Rect {
-    x: f64,
-    y: f64,
-    w: f64,
-    h: f64,
-}
-
-#[derive(Debug, Clone, PartialEq, Eq)]
-struct Input {
-    chars: Vec<char>,
-    width: usize,
-    height: usize,
-    empty_rows: Vec<usize>,
-    empty_cols: Vec<usize>,
+/// Where each galaxy ends up after expansion, in reading order. These are the
+/// positions `process_with_factor` takes Manhattan distances between.
+#[tracing::instrument]
+pub fn expanded_positions(input: &str, factor: u64) -> Result<Vec<Point2>> {
+    Ok(part1_opt::parse_with_expansion(input, factor)?.positions())
 }
 
-impl Input {
-    #[tracing::instrument]
-    fn new(input: &str) -> Self {
-        let lines = input.lines().map(|l| l.trim()).collect::<Vec<_>>();
-
-        let width = lines[0].len();
-        let height = lines.len();
-
-        let chars = lines.iter().flat_map(|l| l.chars()).collect::<Vec<_>>();
-
-        let empty_rows = (0..height)
-            .filter(|y| {
-                for x in 0..width {
-                    if chars[*y * width + x] == '#' {
-                        return false;
-                    }
-                }
-                true
-            })
-            .collect::<Vec<_>>();
-
-        let empty_cols = (0..width)
-            .filter(|x| {
-                for y in 0..height {
-                    if chars[y * width + *x] == '#' {
-                        return false;
-                    }
-                }
-                true
-            })
-            .collect::<Vec<_>>();
-
-        Self {
-            chars,
-            width,
-            height,
-            empty_rows,
-            empty_cols,
-        }
-    }
-
-    #[tracing::instrument]
-    fn to_string(&self) -> String {
-        let mut s = String::new();
-        for y in 0..self.height {
-            for x in 0..self.width {
-                s.push(self.chars[y * self.width + x]);
-            }
-            s.push('\n');
-        }
-        s
-    }
-
-    #[tracing::instrument]
-    fn get(&self, x: usize, y: usize) -> Option<char> {
-        if x >= self.width || y >= self.height {
-            None
-        } else {
-            Some(self.chars[y * self.width + x])
-        }
-    }
-
-    #[tracing::instrument]
-    fn is_row_empty(&self, y: usize) -> bool {
-        self.empty_rows.contains(&y)
-    }
-
-    #[tracing::instrument]
-    fn is_col_empty(&self, x: usize) -> bool {
-        self.empty_cols.contains(&x)
-    }
-}
+pub const PART_2_EXPANSION_FACTOR: u64 = 1_000_000;
 
-#[derive(Debug, Clone, PartialEq)]
-struct Galaxy {
-    id: u16,
-    x: f64,
-    y: f64,
+#[tracing::instrument]
+pub fn process(input: &str) -> Result<Answer> {
+    process_with_factor(input, PART_2_EXPANSION_FACTOR).map(Answer::from)
 }
 
-impl Galaxy {
-    #[tracing::instrument]
-    fn new(id: u16, x: f64, y: f64) -> Self {
-        Self { id, x, y }
-    }
-
-    #[tracing::instrument]
-    fn distance(&self, other: &Self) -> f64 {
-        let dx = other.x - self.x;
-        let dy = other.y - self.y;
-
-        dx.abs() + dy.abs()
-    }
+/// Sum of galaxy distances with each empty row and column expanded `factor` times,
+/// so a factor of 2 gives the part 1 answer.
+#[tracing::instrument]
+pub fn process_with_factor(input: &str, factor: u64) -> Result<u64> {
+    part1_opt::process_with_expansion(input, factor)
 }
 
+/// Sum of distances between just the galaxies in `ids`, numbered from 1 in
+/// reading order, at any expansion `factor`. Every id gives the full total.
 #[tracing::instrument]
-fn galaxy_distance_hash_id(galaxy_a: u16, galaxy_b: u16) -> u64 {
-    let min = galaxy_a.min(galaxy_b);
-    let max = galaxy_a.max(galaxy_b);
-
-    (min as u64) << 16 | (max as u64)
+pub fn total_distance_between(input: &str, ids: &[u16], factor: u64) -> Result<u64> {
+    parse_with_factor(input, factor)?.total_distance_between(ids)
 }
 
-#[derive(Debug, Clone, PartialEq)]
-struct GalaxyMap {
-    galaxies: HashMap<u16, Galaxy>,
+/// The galaxies after part 2's expansion, parsed apart from `solve` so the two
+/// can be benchmarked separately.
+#[tracing::instrument(skip(input))]
+pub fn parse(input: &str) -> Result<GalaxyMap> {
+    parse_with_factor(input, PART_2_EXPANSION_FACTOR)
 }
 
-impl GalaxyMap {
-    #[tracing::instrument]
-    fn new() -> Self {
-        Self {
-            galaxies: HashMap::new(),
-        }
-    }
-
-    #[tracing::instrument]
-    fn from_input(input: &Input) -> Self {
-        let mut map = Self::new();
-
-        let mut id = 1;
-
-        let mut y_offset = 0;
-
-        for y in 0..input.height {
-            if input.is_row_empty(y) {
-                y_offset += 999_999;
-            }
-            let mut x_offset = 0;
-            for x in 0..input.width {
-                if input.is_col_empty(x) {
-                    x_offset += 999_999;
-                }
-                if input.get(x, y) == Some('#') {
-                    map.add(Galaxy::new(
-                        id,
-                        (x + x_offset) as f64,
-                        (y + y_offset) as f64,
-                    ));
-                    id += 1;
-                }
-            }
-        }
-
-        map
-    }
-
-    #[tracing::instrument]
-    fn add(&mut self, galaxy: Galaxy) {
-        self.galaxies.insert(galaxy.id, galaxy);
-    }
-
-    #[tracing::instrument]
-    fn distance(&self, a: u16, b: u16) -> u64 {
-        self.galaxies[&a].distance(&self.galaxies[&b]) as u64
-    }
-
-    #[tracing::instrument]
-    fn galaxy_ids(&self) -> Vec<u16> {
-        self.galaxies.keys().copied().collect::<Vec<_>>()
-    }
+#[tracing::instrument(skip(input))]
+pub fn parse_with_factor(input: &str, factor: u64) -> Result<GalaxyMap> {
+    part1_opt::parse_with_expansion(input, factor)
 }
 
-#[tracing::instrument]
-pub fn process(input: &str) -> Result<u64> {
-    let input = Input::new(input);
-
-    let map = GalaxyMap::from_input(&input);
-
-    let galaxy_ids = map.galaxy_ids();
-
-    let mut galaxys_to_compute: Vec<(u16, u16)> = Vec::new();
-
-    for a in 0..galaxy_ids.len() {
-        for b in a + 1..galaxy_ids.len() {
-            galaxys_to_compute.push((galaxy_ids[a], galaxy_ids[b]));
-        }
-    }
-
-    let total_distance = galaxys_to_compute
-        .par_iter()
-        .map(|(a, b)| map.distance(*a, *b))
-        .sum::<u64>();
-
-    Ok(total_distance)
+#[tracing::instrument(skip(map), fields(galaxies = ?SkipDebug(map)))]
+pub fn solve(map: &GalaxyMap) -> Result<u64> {
+    part1_opt::solve(map)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::error::Error;
     use pretty_assertions::assert_eq;
 
-    // #[test]
-    // fn it_should_find_galaxies() -> miette::Result<()> {
-    //     let input = Input::new(
-    //         "...#......
-    //         .......#..
-    //         #.........
-    //         ..........
-    //         ......#...
-    //         .#........
-    //         .........#
-    //         ..........
-    //         .......#..
-    //         #...#.....",
-    //     );
-
-    //     let map = GalaxyMap::from_input(&input);
-
-    //     assert_eq!(map.galaxies.len(), 9);
-
-    //     assert_eq!(map.galaxies[&1].x, 4.0);
-    //     assert_eq!(map.galaxies[&1].y, 0.0);
-
-    //     assert_eq!(map.galaxies[&2].x, 9.0);
-    //     assert_eq!(map.galaxies[&2].y, 1.0);
-
-    //     assert_eq!(map.galaxies[&3].x, 0.0);
-    //     assert_eq!(map.galaxies[&3].y, 2.0);
-
-    //     assert_eq!(map.galaxies[&4].x, 8.0);
-    //     assert_eq!(map.galaxies[&4].y, 5.0);
-
-    //     assert_eq!(map.galaxies[&5].x, 1.0);
-    //     assert_eq!(map.galaxies[&5].y, 6.0);
-
-    //     assert_eq!(map.galaxies[&6].x, 12.0);
-    //     assert_eq!(map.galaxies[&6].y, 7.0);
-
-    //     assert_eq!(map.galaxies[&7].x, 9.0);
-    //     assert_eq!(map.galaxies[&7].y, 10.0);
-
-    //     assert_eq!(map.galaxies[&8].x, 0.0);
-    //     assert_eq!(map.galaxies[&8].y, 11.0);
-
-    //     assert_eq!(map.galaxies[&9].x, 5.0);
-    //     assert_eq!(map.galaxies[&9].y, 11.0);
-
-    //     Ok(())
-    // }
-
-    // #[test]
-    // fn it_should_calculate_distances() -> miette::Result<()> {
-    //     let input = Input::new(
-    //         "...#......
-    //         .......#..
-    //         #.........
-    //         ..........
-    //         ......#...
-    //         .#........
-    //         .........#
-    //         ..........
-    //         .......#..
-    //         #...#.....",
-    //     );
-
-    //     let mut map = GalaxyMap::from_input(&input);
-
-    //     // ....1........
-    //     // .........2...
-    //     // 3............
-    //     // .............
-    //     // .............
-    //     // ........4....
-    //     // .5...........
-    //     // .##.........6
-    //     // ..##.........
-    //     // ...##........
-    //     // ....##...7...
-    //     // 8....9.......
+    #[test]
+    fn it_should_expand_by_factor() -> miette::Result<()> {
+        let input = "...#......
+        .......#..
+        #.........
+        ..........
+        ......#...
+        .#........
+        .........#
+        ..........
+        .......#..
+        #...#.....";
+        assert_eq!(374, process_with_factor(input, 2)?);
+        assert_eq!(1030, process_with_factor(input, 10)?);
+        assert_eq!(8410, process_with_factor(input, 100)?);
+        Ok(())
+    }
 
-    //     assert_eq!(map.distance(5, 9), 9);
-    //     assert_eq!(map.distance(9, 5), 9);
+    #[test]
+    fn it_should_match_part1_with_factor_of_two() -> miette::Result<()> {
+        let input = &aoc_core::puzzle_input!("input1.txt")?;
+        assert_eq!(
+            crate::part1::process(input)?,
+            Answer::from(process_with_factor(input, 2)?)
+        );
+        Ok(())
+    }
 
-    //     assert_eq!(map.distance(1, 7), 15);
-    //     assert_eq!(map.distance(3, 6), 17);
-    //     assert_eq!(map.distance(8, 9), 5);
+    #[test]
+    fn it_should_total_every_galaxy_like_process() -> miette::Result<()> {
+        let input = include_str!("../example1.txt");
+        let ids = (1..=9).collect::<Vec<_>>();
+
+        assert_eq!(374, total_distance_between(input, &ids, 2)?);
+        assert_eq!(
+            process(input)?,
+            Answer::from(total_distance_between(
+                input,
+                &ids,
+                PART_2_EXPANSION_FACTOR
+            )?)
+        );
+        assert_eq!(0, total_distance_between(input, &[4], 2)?);
+        assert_eq!(9, total_distance_between(input, &[5, 9, 5], 2)?);
+        Ok(())
+    }
 
-    //     Ok(())
-    // }
+    #[test]
+    fn it_should_compose_subsets() -> miette::Result<()> {
+        let input = include_str!("../example1.txt");
+        let total = |ids: &[u16]| total_distance_between(input, ids, 10);
+        let (a, b, c) = ([1, 2, 3], [4, 5], [6, 7, 8, 9]);
+
+        let pairs = (1..=9)
+            .flat_map(|x| (x + 1..=9).map(move |y| [x, y]))
+            .map(|pair| total(&pair))
+            .sum::<Result<u64>>()?;
+        assert_eq!(total(&[a.as_slice(), &b, &c].concat())?, pairs);
+
+        // Inclusion-exclusion over disjoint sets and their pairwise unions
+        assert_eq!(
+            total(&[a.as_slice(), &b, &c].concat())? + total(&a)? + total(&b)? + total(&c)?,
+            total(&[a.as_slice(), &b].concat())?
+                + total(&[a.as_slice(), &c].concat())?
+                + total(&[b.as_slice(), &c].concat())?
+        );
+        Ok(())
+    }
 
-    // #[test]
-    // fn test_process() -> miette::Result<()> {
-    //     let input = "...#......
-    //     .......#..
-    //     #.........
-    //     ..........
-    //     ......#...
-    //     .#........
-    //     .........#
-    //     ..........
-    //     .......#..
-    //     #...#.....";
-    //     assert_eq!(8410, process(input)?);
-    //     Ok(())
-    // }
+    #[test]
+    fn it_should_reject_unknown_galaxies() -> miette::Result<()> {
+        let input = include_str!("../example1.txt");
+
+        assert!(matches!(
+            total_distance_between(input, &[1, 10], 2),
+            Err(Error::NoSuchGalaxy { id: 10, count: 9 })
+        ));
+        assert!(matches!(
+            total_distance_between(input, &[0], 2),
+            Err(Error::NoSuchGalaxy { id: 0, .. })
+        ));
+        Ok(())
+    }
 
     #[test]
     fn it_should_get_right_output() -> miette::Result<()> {
-        let input = include_str!("../input2.txt");
-        assert_eq!(true, process(input)? > 3288356729);
+        let input = &aoc_core::puzzle_input!("input2.txt")?;
+        assert_eq!(
+            aoc_core::manifest::answer(2023, 11, 2)?,
+            process(input)?.to_string()
+        );
         Ok(())
     }
 }
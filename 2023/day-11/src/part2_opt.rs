@@ -1,16 +1,14 @@
-use crate::prelude::*;
+use aoc_core::Answer;
 
 #[tracing::instrument]
 pub fn process(
     _input: &str,
-) -> miette::Result<String> {
+) -> miette::Result<Answer> {
     todo!("day 01 - part 1");
 }
 
 #[cfg(test)]
 mod tests {
-    use super::*;
-
     // #[test]
     // fn test_process() -> miette::Result<()> {
     //     todo!("haven't built test yet");
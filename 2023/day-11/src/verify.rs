@@ -0,0 +1,155 @@
+//! A slow, independent check of the expansion maths: shortest paths found by
+//! searching the expanded grid, compared against the Manhattan distances
+//! between `part2::expanded_positions` for a sample of galaxy pairs.
+
+use std::{cmp::Reverse, collections::BinaryHeap};
+
+use aoc_core::{Grid, Layout, PointSet};
+use aoc_geometry::Point2;
+use aoc_testing::XorShift;
+
+use crate::{error::Error, part2::expanded_positions, prelude::*};
+
+/// The unexpanded image with its galaxies, which also say which rows and
+/// columns are empty.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Image {
+    grid: Grid<char>,
+    galaxies: PointSet,
+}
+
+impl Image {
+    #[tracing::instrument(skip(input))]
+    fn new(input: &str) -> Result<Self> {
+        let mut galaxies = PointSet::new();
+        let grid = Grid::parse_with(input, Layout::Trimmed, |position, cell| {
+            if cell == '#' {
+                galaxies.insert(position);
+            }
+            Some(cell)
+        })?;
+
+        Ok(Self { grid, galaxies })
+    }
+
+    /// Length of the shortest path between two cells once every empty row and
+    /// column is `factor` wide. Stepping into an empty line walks all of its
+    /// copies at once, so this is a breadth-first search over the expanded grid
+    /// without building it.
+    #[tracing::instrument(skip(self))]
+    fn shortest_path(&self, from: Point2, to: Point2, factor: u64) -> u64 {
+        let width = self.grid.width();
+        let index = |point: Point2| point.y as usize * width + point.x as usize;
+        let mut distances = vec![u64::MAX; width * self.grid.height()];
+        let mut queue = BinaryHeap::new();
+
+        distances[index(from)] = 0;
+        queue.push(Reverse((0, from)));
+
+        while let Some(Reverse((distance, position))) = queue.pop() {
+            if position == to {
+                return distance;
+            }
+
+            if distance > distances[index(position)] {
+                continue;
+            }
+
+            for next_position in self.grid.neighbours4(position) {
+                let empty = if next_position.x != position.x {
+                    self.galaxies.count_in_col(next_position.x) == 0
+                } else {
+                    self.galaxies.count_in_row(next_position.y) == 0
+                };
+                let next = distance + if empty { factor } else { 1 };
+
+                if next < distances[index(next_position)] {
+                    distances[index(next_position)] = next;
+                    queue.push(Reverse((next, next_position)));
+                }
+            }
+        }
+
+        u64::MAX
+    }
+}
+
+/// A sampled pair whose searched distance and formula distance disagree.
+/// Galaxies are numbered from 1 in reading order, as in the puzzle text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mismatch {
+    pub a: usize,
+    pub b: usize,
+    pub searched: u64,
+    pub formula: u64,
+}
+
+/// Compares `samples` random galaxy pairs, returning every disagreement.
+#[tracing::instrument(skip(input))]
+pub fn verify(input: &str, factor: u64, samples: usize, seed: u64) -> Result<Vec<Mismatch>> {
+    let image = Image::new(input)?;
+    let galaxies = image.galaxies.iter_sorted().collect::<Vec<_>>();
+    let positions = expanded_positions(input, factor)?;
+
+    if positions.len() != galaxies.len() {
+        return Err(Error::GalaxyCountMismatch {
+            searched: galaxies.len(),
+            formula: positions.len(),
+        });
+    }
+
+    if galaxies.len() < 2 {
+        return Ok(Vec::new());
+    }
+
+    let mut rng = XorShift::new(seed);
+    let mut mismatches = Vec::new();
+
+    for _ in 0..samples {
+        let a = rng.next_u64() as usize % galaxies.len();
+        let b = (a + 1 + rng.next_u64() as usize % (galaxies.len() - 1)) % galaxies.len();
+
+        let searched = image.shortest_path(galaxies[a], galaxies[b], factor);
+        let formula = positions[a].manhattan(positions[b]);
+
+        if searched != formula {
+            mismatches.push(Mismatch {
+                a: a + 1,
+                b: b + 1,
+                searched,
+                formula,
+            });
+        }
+    }
+
+    Ok(mismatches)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    const EXAMPLE: &str = include_str!("../example1.txt");
+
+    #[test]
+    fn it_should_search_puzzle_text_distances() -> miette::Result<()> {
+        let image = Image::new(EXAMPLE)?;
+        let galaxies = image.galaxies.iter_sorted().collect::<Vec<_>>();
+        let galaxy = |n: usize| galaxies[n - 1];
+
+        assert_eq!(9, image.shortest_path(galaxy(5), galaxy(9), 2));
+        assert_eq!(15, image.shortest_path(galaxy(1), galaxy(7), 2));
+        assert_eq!(17, image.shortest_path(galaxy(3), galaxy(6), 2));
+        assert_eq!(5, image.shortest_path(galaxy(8), galaxy(9), 2));
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_agree_with_formula() -> miette::Result<()> {
+        for factor in [1, 2, 10, 100, 1_000_000] {
+            assert_eq!(Vec::<Mismatch>::new(), verify(EXAMPLE, factor, 50, 11)?);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,2 @@
+//! Home of the cross-crate tests in `tests/`, which call every day's `process`
+//! functions directly rather than through the runner's registry.
@@ -0,0 +1,104 @@
+//! Where one part's input is a special case of the other's, every variant of
+//! both parts must agree on it, so the parts can't drift apart semantically.
+
+use std::sync::OnceLock;
+
+use pretty_assertions::assert_eq;
+use proptest::prelude::*;
+
+const DAY_05_EXAMPLE: &str = include_str!("../../day-05/example1.txt");
+
+/// Day 5's real input, read once. It may not be there until it's fetched.
+fn day_05_input() -> &'static str {
+    static INPUT: OnceLock<String> = OnceLock::new();
+    INPUT.get_or_init(|| {
+        aoc_core::inputs::read(
+            concat!(env!("CARGO_MANIFEST_DIR"), "/../day-05"),
+            "input1.txt",
+        )
+        .unwrap_or_else(|e| panic!("{:?}", miette::Report::new(e)))
+    })
+}
+
+/// Day 5's almanac with its seeds line swapped for `seeds`.
+fn with_seeds(almanac: &str, seeds: &str) -> String {
+    let (_, maps) = almanac.split_once('\n').unwrap_or((almanac, ""));
+    format!("seeds: {}\n{}", seeds, maps)
+}
+
+/// The numbers on day 5's seeds line.
+fn seed_numbers(almanac: &str) -> Vec<u64> {
+    almanac
+        .lines()
+        .next()
+        .and_then(|line| line.strip_prefix("seeds:"))
+        .unwrap_or_default()
+        .split_whitespace()
+        .map(|n| n.parse().expect("seeds should be numbers"))
+        .collect()
+}
+
+/// Every seed part 2 reads from the almanac, as `start..start + length`.
+fn seed_ranges(almanac: &str) -> Vec<std::ops::Range<u64>> {
+    seed_numbers(almanac)
+        .chunks(2)
+        .map(|pair| pair[0]..pair[0] + pair[1])
+        .collect()
+}
+
+/// Part 1's location for `seed`, after checking part 2's variants give the
+/// same for the width-1 range `[seed, seed]`.
+fn day_05_location(almanac: &str, seed: u64) -> miette::Result<aoc_core::Answer> {
+    let location = day_05::part1::process(&with_seeds(almanac, &seed.to_string()))?;
+
+    let range = with_seeds(almanac, &format!("{} 1", seed));
+    assert_eq!(
+        location,
+        day_05::part2::process(&range)?,
+        "part2 seed {}",
+        seed
+    );
+    assert_eq!(
+        location,
+        day_05::part2_opt::process(&range)?,
+        "part2_opt seed {}",
+        seed
+    );
+
+    Ok(location)
+}
+
+#[test]
+fn day_05_parts_agree_on_example_seeds() -> miette::Result<()> {
+    let seeds = seed_numbers(DAY_05_EXAMPLE)
+        .into_iter()
+        .chain(seed_ranges(DAY_05_EXAMPLE).into_iter().flatten());
+
+    for seed in seeds {
+        day_05_location(DAY_05_EXAMPLE, seed)?;
+    }
+    Ok(())
+}
+
+fn real_seed() -> impl Strategy<Value = u64> {
+    prop::sample::select(seed_ranges(day_05_input())).prop_flat_map(|range| range)
+}
+
+proptest! {
+    #[test]
+    fn day_05_parts_agree_on_real_seeds(seed in real_seed()) {
+        day_05_location(day_05_input(), seed).map_err(|e| TestCaseError::fail(e.to_string()))?;
+    }
+    #[test]
+    fn day_05_brute_force_agrees_on_real_seed_ranges(
+        (start, len) in real_seed().prop_flat_map(|start| (Just(start), 1u64..2_000))
+    ) {
+        let almanac = with_seeds(day_05_input(), &format!("{} {}", start, len));
+        let brute = day_05::part2_bruteforce::process(&almanac)
+            .map_err(|e| TestCaseError::fail(e.to_string()))?;
+        let opt = day_05::part2_opt::process(&almanac)
+            .map_err(|e| TestCaseError::fail(e.to_string()))?;
+
+        prop_assert_eq!(brute, opt, "seeds {} {}", start, len);
+    }
+}
@@ -0,0 +1,64 @@
+//! Every registered variant's `process` against its day's example answers,
+//! so moving a day onto the shared crates can't quietly change an answer.
+
+use aoc_core::Answer;
+use pretty_assertions::assert_eq;
+
+/// One test per example, asserting `expected` from each listed variant. It is
+/// made into an `Answer`, so a text answer is written as a string.
+macro_rules! example {
+    ($test:ident, $file:literal, $expected:expr, $day:ident::{$($variant:ident),+ $(,)?}) => {
+        #[test]
+        fn $test() -> miette::Result<()> {
+            let input = include_str!($file);
+            $(
+                assert_eq!(
+                    Answer::from($expected),
+                    $day::$variant::process(input)?,
+                    "{}",
+                    stringify!($day::$variant)
+                );
+            )+
+            Ok(())
+        }
+    };
+}
+
+example!(day_01_part1, "../../day-01/example1.txt", 142, day_01::{part1, part1_opt});
+example!(day_01_part2, "../../day-01/example2.txt", 281, day_01::{part2, part2_opt});
+
+example!(day_02_part1, "../../day-02/example1.txt", 8, day_02::{part1, part1_opt, part1_opt2, part1_implicit});
+example!(day_02_part2, "../../day-02/example1.txt", 2286, day_02::{part2, part2_opt, part2_opt2});
+
+example!(day_03_part1, "../../day-03/example1.txt", 4361, day_03::{part1, part1_opt});
+example!(day_03_part2, "../../day-03/example1.txt", 467835, day_03::{part2});
+
+example!(day_04_part1, "../../day-04/example1.txt", 13, day_04::{part1, part1_opt});
+example!(day_04_part2, "../../day-04/example1.txt", 30, day_04::{part2, part2_opt});
+
+example!(day_05_part1, "../../day-05/example1.txt", 35, day_05::{part1});
+example!(day_05_part2, "../../day-05/example1.txt", 46, day_05::{part2, part2_opt, part2_bruteforce});
+
+example!(day_06_part1, "../../day-06/example1.txt", 288, day_06::{part1, part1_opt});
+example!(day_06_part2, "../../day-06/example1.txt", 71503, day_06::{part2, part2_opt});
+
+example!(day_07_part1, "../../day-07/example1.txt", 6440, day_07::{part1, part1_opt, part1_opt2});
+example!(day_07_part2, "../../day-07/example1.txt", 5905, day_07::{part2});
+
+example!(day_08_part1, "../../day-08/example1.txt", 6, day_08::{part1});
+example!(day_08_part2, "../../day-08/example2.txt", 6, day_08::{part2});
+
+example!(day_09_part1, "../../day-09/example1.txt", 114, day_09::{part1, part1_opt, part1_par});
+example!(day_09_part2, "../../day-09/example1.txt", 2, day_09::{part2, part2_opt, part2_par});
+
+example!(day_11_part1, "../../day-11/example1.txt", 374, day_11::{part1, part1_opt});
+example!(day_11_part2, "../../day-11/example1.txt", 82000210, day_11::{part2});
+
+#[test]
+fn day_11_expansion_factors() -> miette::Result<()> {
+    let input = include_str!("../../day-11/example1.txt");
+
+    assert_eq!(1030, day_11::part2::process_with_factor(input, 10)?);
+    assert_eq!(8410, day_11::part2::process_with_factor(input, 100)?);
+    Ok(())
+}
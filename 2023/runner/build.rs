@@ -0,0 +1,311 @@
+//! Generates the solutions index that `registry::days` returns from the
+//! `[package.metadata.aoc]` table of every workspace member, so adding a day
+//! is a matter of declaring it in its own `Cargo.toml` and depending on it
+//! here. Anything inconsistent becomes a `compile_error!` in the index.
+
+use std::{
+    collections::BTreeMap,
+    fmt::Write as _,
+    path::{Path, PathBuf},
+};
+
+/// One workspace member's claim to a puzzle day.
+struct Solution {
+    package: String,
+    dir: PathBuf,
+    meta: toml::Table,
+}
+
+impl Solution {
+    fn crate_ident(&self) -> String {
+        self.package.replace('-', "_")
+    }
+
+    fn number(&self, key: &str) -> Option<i64> {
+        self.meta.get(key).and_then(|v| v.as_integer())
+    }
+
+    fn path(&self, key: &str) -> Option<&str> {
+        self.meta.get(key).and_then(|v| v.as_str())
+    }
+
+    fn variants(&self) -> Result<String, String> {
+        let names = self
+            .meta
+            .get("variants")
+            .and_then(|v| v.as_array())
+            .ok_or_else(|| format!("{} declares no variants", self.package))?;
+
+        self.variant_list(names)
+    }
+
+    /// Variants too slow for every sweep, only run when asked for by name or
+    /// with `--strategy brute`. Optional, unlike `variants`.
+    fn brute_force(&self) -> Result<String, String> {
+        match self.meta.get("brute_force").and_then(|v| v.as_array()) {
+            Some(names) => self.variant_list(names),
+            None => Ok(String::new()),
+        }
+    }
+
+    /// Variants to time and verify in place of a part's reference, for
+    /// references that can't run on the real input. Each has to be one of
+    /// `variants`. Optional, like `brute_force`.
+    fn fast(&self) -> Result<String, String> {
+        let Some(names) = self.meta.get("fast").and_then(|v| v.as_array()) else {
+            return Ok(String::new());
+        };
+        let variants = self
+            .meta
+            .get("variants")
+            .and_then(|v| v.as_array())
+            .cloned()
+            .unwrap_or_default();
+
+        names
+            .iter()
+            .map(|name| match name.as_str() {
+                Some(name) if variants.iter().any(|v| v.as_str() == Some(name)) => {
+                    Ok(format!("{:?},", name))
+                }
+                Some(name) => Err(format!(
+                    "{} names {} as fast but it isn't one of its variants",
+                    self.package, name
+                )),
+                None => Err(format!(
+                    "{} has a fast variant that isn't a string",
+                    self.package
+                )),
+            })
+            .collect::<Result<Vec<_>, String>>()
+            .map(|names| names.join(" "))
+    }
+
+    fn variant_list(&self, names: &[toml::Value]) -> Result<String, String> {
+        names
+            .iter()
+            .map(|name| {
+                let name = name
+                    .as_str()
+                    .ok_or_else(|| format!("{} has a variant that isn't a string", self.package))?;
+                let part = name
+                    .strip_prefix("part")
+                    .and_then(|rest| rest.chars().next())
+                    .and_then(|c| c.to_digit(10))
+                    .ok_or_else(|| {
+                        format!("{} variant {} doesn't start with partN", self.package, name)
+                    })?;
+
+                Ok(format!(
+                    "variant!({}, {:?}, {}::{}::process),",
+                    part,
+                    name,
+                    self.crate_ident(),
+                    name
+                ))
+            })
+            .collect::<Result<Vec<_>, String>>()
+            .map(|lines| lines.join("\n"))
+    }
+
+    fn examples(&self) -> Result<String, String> {
+        let examples = self
+            .meta
+            .get("examples")
+            .and_then(|v| v.as_array())
+            .ok_or_else(|| format!("{} declares no examples", self.package))?;
+
+        examples
+            .iter()
+            .map(|example| {
+                let field = |key: &str| example.get(key);
+                let (Some(part), Some(file), Some(expected)) = (
+                    field("part").and_then(|v| v.as_integer()),
+                    field("file").and_then(|v| v.as_str()),
+                    field("expected").and_then(|v| v.as_str()),
+                ) else {
+                    return Err(format!(
+                        "{} has an example without part, file and expected",
+                        self.package
+                    ));
+                };
+
+                let path = self.dir.join(file);
+                if !path.is_file() {
+                    return Err(format!("{} example {} does not exist", self.package, file));
+                }
+
+                Ok(format!(
+                    "example!({}, {:?}, {:?}),",
+                    part,
+                    path.display().to_string(),
+                    expected
+                ))
+            })
+            .collect::<Result<Vec<_>, String>>()
+            .map(|lines| lines.join("\n"))
+    }
+
+    fn day(&self, year: i64, day: i64) -> Result<String, String> {
+        let ident = self.crate_ident();
+
+        let solution = self.path("solution").ok_or_else(|| {
+            format!(
+                "{} names no aoc_core::Solution type in [package.metadata.aoc]",
+                self.package
+            )
+        })?;
+
+        let expansion = match self.path("expansion") {
+            Some(path) => format!(
+                "Some(|input, factor| Ok(Answer::from({}::{}(input, factor)?)))",
+                ident, path
+            ),
+            None => "None".to_string(),
+        };
+        let attempts = match self.path("attempts") {
+            Some(path) => format!("{}::{}", ident, path),
+            None => "&[]".to_string(),
+        };
+        let renderer = |key: &str| match self.path(key) {
+            Some(path) => format!("Some(|input, ansi| Ok({}::{}(input, ansi)?))", ident, path),
+            None => "None".to_string(),
+        };
+
+        Ok(format!(
+            "Day {{
+                year: {},
+                day: {},
+                solution: <{}::{} as aoc_core::Solution>::solve,
+                variants: vec![{}],
+                brute_force: vec![{}],
+                fast: vec![{}],
+                examples: vec![{}],
+                expansion: {},
+                viz: {},
+                explain: {},
+                attempts: {},
+            }},",
+            year,
+            day,
+            ident,
+            solution,
+            self.variants()?,
+            self.brute_force()?,
+            self.fast()?,
+            self.examples()?,
+            expansion,
+            renderer("viz"),
+            renderer("explain"),
+            attempts,
+        ))
+    }
+}
+
+fn read_toml(path: &Path) -> toml::Table {
+    let source = std::fs::read_to_string(path)
+        .unwrap_or_else(|e| panic!("could not read {}: {}", path.display(), e));
+
+    source
+        .parse::<toml::Table>()
+        .unwrap_or_else(|e| panic!("could not parse {}: {}", path.display(), e))
+}
+
+/// Every workspace member beside `runner` that carries `[package.metadata.aoc]`,
+/// with `day-*` directories included either way so a missing table is reported.
+fn solutions(root: &Path) -> Vec<Solution> {
+    let mut dirs = std::fs::read_dir(root)
+        .expect("workspace root should be readable")
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| path.join("Cargo.toml").is_file())
+        // daily-template's manifest is only valid TOML once it's filled in
+        .filter(|path| !path.join("cargo-generate.toml").is_file())
+        .collect::<Vec<_>>();
+    dirs.sort();
+
+    dirs.into_iter()
+        .filter_map(|dir| {
+            let manifest_path = dir.join("Cargo.toml");
+            println!("cargo:rerun-if-changed={}", manifest_path.display());
+
+            let manifest = read_toml(&manifest_path);
+            let package = manifest.get("package")?.as_table()?;
+            let meta = package
+                .get("metadata")
+                .and_then(|m| m.get("aoc"))
+                .and_then(|aoc| aoc.as_table())
+                .cloned();
+
+            let is_day = dir
+                .file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| name.starts_with("day-"));
+
+            if meta.is_none() && !is_day {
+                return None;
+            }
+
+            Some(Solution {
+                package: package.get("name")?.as_str()?.to_string(),
+                dir,
+                meta: meta.unwrap_or_default(),
+            })
+        })
+        .collect()
+}
+
+fn index(root: &Path, dependencies: &toml::Table) -> Result<String, String> {
+    let mut claimed = BTreeMap::<(i64, i64), Solution>::new();
+
+    for solution in solutions(root) {
+        let (Some(year), Some(day)) = (solution.number("year"), solution.number("day")) else {
+            return Err(format!(
+                "{} has no [package.metadata.aoc] year and day",
+                solution.package
+            ));
+        };
+
+        if !dependencies.contains_key(&solution.package) {
+            return Err(format!(
+                "{} solves {} day {} but is not a dependency of runner",
+                solution.package, year, day
+            ));
+        }
+
+        if let Some(existing) = claimed.get(&(year, day)) {
+            return Err(format!(
+                "{} and {} both claim {} day {}",
+                existing.package, solution.package, year, day
+            ));
+        }
+
+        claimed.insert((year, day), solution);
+    }
+
+    let mut days = String::new();
+    for ((year, day), solution) in &claimed {
+        writeln!(days, "{}", solution.day(*year, *day)?).expect("writing to a String");
+    }
+
+    Ok(format!("vec![{}]", days))
+}
+
+fn main() {
+    let manifest_dir = PathBuf::from(std::env::var("CARGO_MANIFEST_DIR").expect("set by cargo"));
+    let root = manifest_dir.join("..");
+    println!("cargo:rerun-if-changed={}", root.display());
+
+    let runner_manifest = read_toml(&manifest_dir.join("Cargo.toml"));
+    let dependencies = runner_manifest
+        .get("dependencies")
+        .and_then(|d| d.as_table())
+        .cloned()
+        .unwrap_or_default();
+
+    let generated = index(&root, &dependencies)
+        .unwrap_or_else(|reason| format!("compile_error!({:?})", reason));
+
+    let out = PathBuf::from(std::env::var("OUT_DIR").expect("set by cargo")).join("days.rs");
+    std::fs::write(out, generated).expect("OUT_DIR should be writable");
+}
@@ -0,0 +1,335 @@
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+use aoc_core::Answer;
+use serde::Deserialize;
+
+use crate::{error::Error, prelude::*, workspace};
+
+/// Label given to a bare `--expected` value on the command line.
+pub const DEFAULT_LABEL: &str = "expected";
+
+/// Every answer accepted for one part, keyed by a label naming the convention
+/// it follows (e.g. `inclusive` and `exclusive` when counting ways to win).
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct Accepted {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub accepted: BTreeMap<String, String>,
+    /// The variant that produced the answer when it was last recorded.
+    #[serde(default)]
+    pub variant: Option<String>,
+    /// The commit it was recorded at.
+    #[serde(default)]
+    pub commit: Option<String>,
+}
+
+impl Accepted {
+    /// Builds the set from `--expected` values, each either `label=answer` or a bare answer.
+    #[tracing::instrument]
+    pub fn from_args(year: u16, day: u8, part: u8, values: &[String]) -> Self {
+        let accepted = values
+            .iter()
+            .map(|value| match value.split_once('=') {
+                Some((label, answer)) => (label.to_string(), answer.to_string()),
+                None => (DEFAULT_LABEL.to_string(), value.to_string()),
+            })
+            .collect();
+
+        Self {
+            year,
+            day,
+            part,
+            accepted,
+            variant: None,
+            commit: None,
+        }
+    }
+
+    /// The label of the accepted answer equal to `actual`, if any.
+    #[tracing::instrument]
+    pub fn matching(&self, actual: &Answer) -> Option<&str> {
+        self.accepted
+            .iter()
+            .find(|(_, answer)| Answer::parse(answer) == *actual)
+            .map(|(label, _)| label.as_str())
+    }
+
+    /// A note for when the part's default variant is no longer the one the
+    /// answer was recorded from, e.g. after an optimised rewrite became the default.
+    #[tracing::instrument]
+    pub fn provenance_warning(&self, default_variant: &str) -> Option<String> {
+        let recorded = self.variant.as_deref()?;
+
+        (recorded != default_variant).then(|| {
+            format!(
+                "{} day {} part {} was recorded from {} at {}, but the default variant is now {}",
+                self.year,
+                self.day,
+                self.part,
+                recorded,
+                self.commit.as_deref().unwrap_or("an unknown commit"),
+                default_variant
+            )
+        })
+    }
+
+    /// The answer on its own when there's only one, otherwise each with its label.
+    #[tracing::instrument]
+    pub fn describe(&self) -> String {
+        match self.accepted.values().collect::<Vec<_>>()[..] {
+            [answer] => answer.clone(),
+            _ => self
+                .accepted
+                .iter()
+                .map(|(label, answer)| format!("{} ({})", answer, label))
+                .collect::<Vec<_>>()
+                .join(" or "),
+        }
+    }
+}
+
+/// The answers manifest, `answers.toml` at the workspace root.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+pub struct Answers {
+    #[serde(default)]
+    pub answers: Vec<Accepted>,
+}
+
+impl Answers {
+    #[tracing::instrument]
+    pub fn path() -> PathBuf {
+        workspace::root().join("answers.toml")
+    }
+
+    #[tracing::instrument(skip(source))]
+    pub fn parse(source: &str) -> Result<Self> {
+        toml::from_str(source).map_err(|e| Error::InvalidAnswers {
+            reason: e.message().to_string(),
+        })
+    }
+
+    #[tracing::instrument]
+    pub fn load() -> Result<Self> {
+        Self::load_from(&Self::path())
+    }
+
+    #[tracing::instrument]
+    pub fn load_from(path: &Path) -> Result<Self> {
+        Self::parse(&std::fs::read_to_string(path)?)
+    }
+
+    /// Sets `variant` and `commit` on one part's entry in `source`, keeping the
+    /// rest of the file (comments included) as it is.
+    #[tracing::instrument(skip(source))]
+    pub fn with_provenance(
+        source: &str,
+        (year, day, part): (u16, u8, u8),
+        variant: &str,
+        commit: &str,
+    ) -> Result<String> {
+        let mut document =
+            source
+                .parse::<toml_edit::DocumentMut>()
+                .map_err(|e| Error::InvalidAnswers {
+                    reason: e.message().to_string(),
+                })?;
+
+        let is_part = |table: &toml_edit::Table| {
+            let number = |key: &str| table.get(key).and_then(|v| v.as_integer());
+            number("year") == Some(year.into())
+                && number("day") == Some(day.into())
+                && number("part") == Some(part.into())
+        };
+
+        let entry = document
+            .get_mut("answers")
+            .and_then(|answers| answers.as_array_of_tables_mut())
+            .and_then(|answers| answers.iter_mut().find(|table| is_part(table)))
+            .ok_or(Error::NoAcceptedAnswers { year, day, part })?;
+
+        entry["variant"] = toml_edit::value(variant);
+        entry["commit"] = toml_edit::value(commit);
+
+        Ok(document.to_string())
+    }
+
+    /// Records which variant produced a part's answer, and at which commit, in answers.toml.
+    #[tracing::instrument]
+    pub fn record_provenance(year: u16, day: u8, part: u8, variant: &str) -> Result<String> {
+        let commit = head_commit()?;
+        let source = std::fs::read_to_string(Self::path())?;
+        let updated = Self::with_provenance(&source, (year, day, part), variant, &commit)?;
+
+        std::fs::write(Self::path(), updated)?;
+
+        Ok(commit)
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub fn find(&self, year: u16, day: u8, part: u8) -> Result<&Accepted> {
+        self.answers
+            .iter()
+            .find(|a| a.year == year && a.day == day && a.part == part)
+            .filter(|a| !a.accepted.is_empty())
+            .ok_or(Error::NoAcceptedAnswers { year, day, part })
+    }
+}
+
+/// Short hash of the checked out commit.
+#[tracing::instrument]
+fn head_commit() -> Result<String> {
+    let output = Command::new("git")
+        .args(["rev-parse", "--short", "HEAD"])
+        .current_dir(workspace::root())
+        .output()?;
+
+    if !output.status.success() {
+        return Err(Error::NoCommit {
+            reason: String::from_utf8_lossy(&output.stderr).trim().to_string(),
+        });
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    const MANIFEST: &str = "[[answers]]
+year = 2023
+day = 6
+part = 1
+accepted = { inclusive = \"288\", exclusive = \"284\" }
+
+[[answers]]
+year = 2023
+day = 9
+part = 2
+accepted = { submitted = \"2\" }
+";
+
+    #[test]
+    fn it_should_report_matching_label() -> miette::Result<()> {
+        let answers = Answers::parse(MANIFEST)?;
+        let day_6 = answers.find(2023, 6, 1)?;
+
+        assert_eq!(Some("inclusive"), day_6.matching(&Answer::from(288u64)));
+        assert_eq!(Some("exclusive"), day_6.matching(&Answer::from(284i64)));
+        assert_eq!(None, day_6.matching(&Answer::from(287u32)));
+        assert_eq!(None, day_6.matching(&Answer::from("two hundred")));
+        assert_eq!("284 (exclusive) or 288 (inclusive)", day_6.describe());
+        assert_eq!("2", answers.find(2023, 9, 2)?.describe());
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_reject_missing_parts() -> miette::Result<()> {
+        let answers = Answers::parse(MANIFEST)?;
+
+        assert!(matches!(
+            answers.find(2023, 6, 2),
+            Err(Error::NoAcceptedAnswers {
+                year: 2023,
+                day: 6,
+                part: 2
+            })
+        ));
+        assert!(matches!(
+            Answers::parse("[[answers]]\nday = 1"),
+            Err(Error::InvalidAnswers { .. })
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_label_command_line_answers() -> miette::Result<()> {
+        let accepted = Accepted::from_args(
+            2023,
+            6,
+            1,
+            &["288".to_string(), "exclusive=284".to_string()],
+        );
+
+        assert_eq!(
+            Some(DEFAULT_LABEL),
+            accepted.matching(&Answer::from(288u32))
+        );
+        assert_eq!(Some("exclusive"), accepted.matching(&Answer::from(284u32)));
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_warn_when_default_variant_changes() -> miette::Result<()> {
+        let answers = Answers::parse(
+            "[[answers]]
+year = 2023
+day = 5
+part = 2
+accepted = { submitted = \"46\" }
+variant = \"part2\"
+commit = \"abc1234\"
+",
+        )?;
+        let day_5 = answers.find(2023, 5, 2)?;
+
+        assert_eq!(None, day_5.provenance_warning("part2"));
+        assert_eq!(
+            Some(
+                "2023 day 5 part 2 was recorded from part2 at abc1234, but the default variant is now part2_opt"
+                    .to_string()
+            ),
+            day_5.provenance_warning("part2_opt")
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_not_warn_without_provenance() -> miette::Result<()> {
+        let answers = Answers::parse(MANIFEST)?;
+
+        assert_eq!(
+            None,
+            answers.find(2023, 6, 1)?.provenance_warning("part1_opt")
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_record_provenance_in_place() -> miette::Result<()> {
+        let source = format!("# Accepted answers\n\n{}", MANIFEST);
+        let updated = Answers::with_provenance(&source, (2023, 9, 2), "part2", "abc1234")?;
+
+        assert!(updated.starts_with("# Accepted answers\n"));
+
+        let answers = Answers::parse(&updated)?;
+        let day_9 = answers.find(2023, 9, 2)?;
+        assert_eq!(Some("part2".to_string()), day_9.variant);
+        assert_eq!(Some("abc1234".to_string()), day_9.commit);
+        assert_eq!(None, answers.find(2023, 6, 1)?.variant);
+
+        assert!(matches!(
+            Answers::with_provenance(&source, (2023, 9, 1), "part1", "abc1234"),
+            Err(Error::NoAcceptedAnswers { part: 1, .. })
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_have_every_registered_part() -> miette::Result<()> {
+        let answers = Answers::load()?;
+
+        for day in crate::registry::days() {
+            for part in [1, 2] {
+                answers.find(day.year, day.day, part)?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,195 @@
+use std::{
+    path::Path,
+    time::{Duration, Instant},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{error::Error, prelude::*};
+
+/// One variant's timings over its stored input, in nanoseconds so they
+/// survive a round trip through a baseline file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Timing {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub variant: String,
+    pub fastest_ns: u64,
+    pub median_ns: u64,
+}
+
+impl Timing {
+    /// Runs `solve` `runs` times (at least once) and keeps the fastest and
+    /// median run.
+    #[tracing::instrument(skip(solve))]
+    pub fn measure(
+        (year, day, part): (u16, u8, u8),
+        variant: &str,
+        runs: usize,
+        mut solve: impl FnMut(),
+    ) -> Self {
+        let mut times = (0..runs.max(1))
+            .map(|_| {
+                let start = Instant::now();
+                solve();
+                start.elapsed()
+            })
+            .collect::<Vec<_>>();
+        times.sort();
+
+        Self {
+            year,
+            day,
+            part,
+            variant: variant.to_string(),
+            fastest_ns: times[0].as_nanos() as u64,
+            median_ns: times[times.len() / 2].as_nanos() as u64,
+        }
+    }
+
+    #[tracing::instrument]
+    pub fn fastest(&self) -> Duration {
+        Duration::from_nanos(self.fastest_ns)
+    }
+
+    #[tracing::instrument]
+    pub fn median(&self) -> Duration {
+        Duration::from_nanos(self.median_ns)
+    }
+
+    #[tracing::instrument]
+    fn is_same_solution(&self, other: &Timing) -> bool {
+        (self.year, self.day, self.part, self.variant.as_str())
+            == (other.year, other.day, other.part, other.variant.as_str())
+    }
+
+    /// How many times faster this is than the part's reference variant
+    /// (`part1` or `part2`) in `timings`, by median.
+    #[tracing::instrument(skip(timings))]
+    pub fn speedup(&self, timings: &[Timing]) -> Option<f64> {
+        let reference = format!("part{}", self.part);
+
+        timings
+            .iter()
+            .find(|t| {
+                (t.year, t.day, t.part) == (self.year, self.day, self.part)
+                    && t.variant == reference
+            })
+            .map(|reference| reference.median_ns as f64 / self.median_ns.max(1) as f64)
+    }
+}
+
+/// Timings saved by an earlier `bench --save`, to compare a later run against.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Baseline {
+    #[serde(default)]
+    pub timing: Vec<Timing>,
+}
+
+impl Baseline {
+    #[tracing::instrument(skip(source))]
+    pub fn parse(source: &str) -> Result<Self> {
+        toml::from_str(source).map_err(|e| Error::InvalidBaseline {
+            reason: e.message().to_string(),
+        })
+    }
+
+    #[tracing::instrument]
+    pub fn load(path: &Path) -> Result<Self> {
+        Self::parse(&std::fs::read_to_string(path)?)
+    }
+
+    #[tracing::instrument]
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let source = toml::to_string(self).map_err(|e| Error::InvalidBaseline {
+            reason: e.to_string(),
+        })?;
+
+        Ok(std::fs::write(path, source)?)
+    }
+
+    /// How much slower `timing` is than its baseline median, as a fraction
+    /// (0.25 for 25% slower), when that's more than `tolerance`.
+    #[tracing::instrument(skip(self))]
+    pub fn regression(&self, timing: &Timing, tolerance: f64) -> Option<f64> {
+        let baseline = self.timing.iter().find(|t| t.is_same_solution(timing))?;
+        let slowdown = timing.median_ns as f64 / baseline.median_ns.max(1) as f64 - 1.0;
+
+        (slowdown > tolerance).then_some(slowdown)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn timing(day: u8, variant: &str, median_ns: u64) -> Timing {
+        Timing {
+            year: 2023,
+            day,
+            part: 1,
+            variant: variant.to_string(),
+            fastest_ns: median_ns / 2,
+            median_ns,
+        }
+    }
+
+    #[test]
+    fn it_should_measure_every_run() -> miette::Result<()> {
+        let mut calls = 0;
+        let timing = Timing::measure((2023, 1, 1), "part1", 5, || calls += 1);
+
+        assert_eq!(5, calls);
+        assert!(timing.fastest() <= timing.median());
+
+        Timing::measure((2023, 1, 1), "part1", 0, || calls += 1);
+        assert_eq!(6, calls);
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_compare_variants_with_their_reference() -> miette::Result<()> {
+        let timings = vec![
+            timing(7, "part1", 8_000),
+            timing(7, "part1_opt", 2_000),
+            timing(9, "part1_par", 1_000),
+        ];
+
+        assert_eq!(Some(1.0), timings[0].speedup(&timings));
+        assert_eq!(Some(4.0), timings[1].speedup(&timings));
+        assert_eq!(None, timings[2].speedup(&timings));
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_flag_regressions_past_tolerance() -> miette::Result<()> {
+        let baseline = Baseline {
+            timing: vec![timing(7, "part1", 1_000), timing(7, "part1_opt", 1_000)],
+        };
+
+        assert_eq!(None, baseline.regression(&timing(7, "part1", 1_100), 0.2));
+        assert_eq!(
+            Some(0.5),
+            baseline.regression(&timing(7, "part1_opt", 1_500), 0.2)
+        );
+        assert_eq!(None, baseline.regression(&timing(8, "part1", 9_000), 0.2));
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_round_trip_through_toml() -> miette::Result<()> {
+        let baseline = Baseline {
+            timing: vec![timing(7, "part1", 1_000), timing(11, "part1", 250)],
+        };
+        let source = toml::to_string(&baseline).map_err(|e| miette::miette!("{}", e))?;
+
+        assert_eq!(baseline, Baseline::parse(&source)?);
+        assert!(matches!(
+            Baseline::parse("timing = 3"),
+            Err(Error::InvalidBaseline { .. })
+        ));
+        Ok(())
+    }
+}
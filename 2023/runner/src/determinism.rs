@@ -0,0 +1,165 @@
+use crate::{error::Error, prelude::*, registry::Day};
+
+/// Name given to the explain output's check, alongside the variant names.
+pub const EXPLAIN: &str = "explain";
+
+/// One output produced twice from the same input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Check {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub name: String,
+    pub first: String,
+    pub second: String,
+}
+
+impl Check {
+    #[tracing::instrument]
+    pub fn deterministic(&self) -> bool {
+        self.first == self.second
+    }
+}
+
+/// Runs `f` on a rayon pool of `threads` threads, or the global pool when `None`,
+/// so every `par_iter` inside a solution uses that many workers.
+#[tracing::instrument(skip(f))]
+fn with_threads<T: Send>(threads: Option<usize>, f: impl FnOnce() -> T + Send) -> Result<T> {
+    match threads {
+        None => Ok(f()),
+        Some(threads) => Ok(rayon::ThreadPoolBuilder::new()
+            .num_threads(threads)
+            .build()
+            .map_err(|e| Error::ThreadPool {
+                reason: e.to_string(),
+            })?
+            .install(f)),
+    }
+}
+
+/// Solves every variant of `day` twice, and renders its explain output twice
+/// per part. Errors count as output, so a solution that only sometimes fails
+/// is reported too.
+#[tracing::instrument(skip(day), fields(day = day.day))]
+pub fn audit_day(day: &Day, threads: [Option<usize>; 2]) -> Result<Vec<Check>> {
+    let mut checks = vec![];
+    let mut parts = day.variants.iter().map(|v| v.part).collect::<Vec<_>>();
+    parts.sort();
+    parts.dedup();
+
+    for part in parts {
+        let input = day.read_input(part)?;
+
+        for variant in day.variants.iter().filter(|v| v.part == part) {
+            let [first, second] = threads.map(|threads| {
+                with_threads(threads, || {
+                    (variant.solve)(&input)
+                        .map(|answer| answer.to_submit_string())
+                        .unwrap_or_else(|report| format!("error: {}", report))
+                })
+            });
+
+            checks.push(Check {
+                year: day.year,
+                day: day.day,
+                part,
+                name: variant.name.to_string(),
+                first: first?,
+                second: second?,
+            });
+        }
+
+        if let Some(explain) = day.explain {
+            let [first, second] = threads.map(|threads| {
+                with_threads(threads, || {
+                    explain(&input, false).unwrap_or_else(|report| format!("error: {}", report))
+                })
+            });
+
+            checks.push(Check {
+                year: day.year,
+                day: day.day,
+                part,
+                name: EXPLAIN.to_string(),
+                first: first?,
+                second: second?,
+            });
+        }
+    }
+
+    Ok(checks)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicU64, Ordering};
+
+    use aoc_core::Answer;
+
+    use super::*;
+    use crate::registry::Variant;
+    use pretty_assertions::assert_eq;
+
+    static CALLS: AtomicU64 = AtomicU64::new(0);
+
+    fn day(variants: Vec<Variant>) -> Day {
+        Day {
+            year: 2023,
+            day: 9,
+            solution: |input, _| Ok(Answer::from(input.len() as u64)),
+            variants,
+            brute_force: vec![],
+            fast: vec![],
+            examples: vec![],
+            expansion: None,
+            viz: None,
+            explain: Some(|input, _| Ok(input.lines().count().to_string())),
+            attempts: &[],
+        }
+    }
+
+    #[test]
+    fn it_should_flag_answers_that_change_between_runs() -> miette::Result<()> {
+        let checks = audit_day(
+            &day(vec![
+                Variant {
+                    part: 1,
+                    name: "part1",
+                    solve: |input| Ok(Answer::from(input.len() as u64)),
+                },
+                Variant {
+                    part: 1,
+                    name: "part1_racy",
+                    solve: |_| Ok(Answer::from(CALLS.fetch_add(1, Ordering::SeqCst))),
+                },
+            ]),
+            [None, None],
+        )?;
+
+        assert_eq!(
+            vec!["part1", "part1_racy", EXPLAIN],
+            checks.iter().map(|c| c.name.as_str()).collect::<Vec<_>>()
+        );
+        assert!(checks[0].deterministic());
+        assert!(!checks[1].deterministic());
+        assert!(checks[2].deterministic());
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_run_on_requested_thread_counts() -> miette::Result<()> {
+        let checks = audit_day(
+            &day(vec![Variant {
+                part: 2,
+                name: "part2",
+                solve: |_| Ok(Answer::from(rayon::current_num_threads() as u64)),
+            }]),
+            [Some(1), Some(3)],
+        )?;
+
+        assert_eq!("1", checks[0].first);
+        assert_eq!("3", checks[0].second);
+        assert!(!checks[0].deterministic());
+        Ok(())
+    }
+}
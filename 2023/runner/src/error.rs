@@ -0,0 +1,84 @@
+use miette::Diagnostic;
+use thiserror::Error;
+
+#[derive(Error, Diagnostic, Debug)]
+pub enum Error {
+    #[error(transparent)]
+    #[diagnostic(code(aoc::io_error))]
+    IoError(#[from] std::io::Error),
+    #[error("No solution registered for {year} day {day}")]
+    UnknownDay { year: u16, day: u8 },
+    #[error("Could not parse selector {selector}: {reason}")]
+    InvalidSelector { selector: String, reason: String },
+    #[error("No registered days match {selector}")]
+    NothingSelected { selector: String },
+    #[error("No variant {variant} registered for {year} day {day}")]
+    UnknownVariant { year: u16, day: u8, variant: String },
+    #[error("{year} day {day} does not take an expansion factor")]
+    ExpansionNotSupported { year: u16, day: u8 },
+    #[error("{year} day {day} has no brute force variant for the selected parts")]
+    BruteForceNotSupported { year: u16, day: u8 },
+    #[error("{year} day {day} has no visualisation")]
+    VizNotSupported { year: u16, day: u8 },
+    #[error("{year} day {day} has no explain renderer")]
+    ExplainNotSupported { year: u16, day: u8 },
+    #[error("{path} has no line {line}")]
+    NoSuchLine { path: String, line: usize },
+    #[error("{year} day {day} {variant} answered {actual} but expected {expected}")]
+    WrongAnswer {
+        year: u16,
+        day: u8,
+        variant: String,
+        expected: String,
+        actual: String,
+    },
+    #[error("{count} of {total} verified parts failed")]
+    #[diagnostic(code(aoc::verify_failed))]
+    VerifyFailed {
+        count: usize,
+        total: usize,
+        #[related]
+        failures: Vec<crate::verify::PartFailure>,
+    },
+    #[error("No accepted answers for {year} day {day} part {part}, add them to answers.toml or pass --expected")]
+    NoAcceptedAnswers { year: u16, day: u8, part: u8 },
+    #[error("Could not parse answers.toml: {reason}")]
+    InvalidAnswers { reason: String },
+    #[error("Could not read or write the benchmark baseline: {reason}")]
+    InvalidBaseline { reason: String },
+    #[error("Could not find the current commit: {reason}")]
+    NoCommit { reason: String },
+    #[error("No cached input for {year} day {day} and fetching is offline")]
+    OfflineCacheCold { year: u16, day: u8 },
+    #[error("Set {var} to your adventofcode.com session cookie to fetch inputs")]
+    MissingSession { var: String },
+    #[error("Could not fetch {url}: {reason}")]
+    FetchFailed { url: String, reason: String },
+    #[error("Could not build a rayon thread pool: {reason}")]
+    ThreadPool { reason: String },
+    #[error("{count} outputs changed between runs")]
+    Nondeterministic { count: usize },
+    #[error("{count} smoke inputs gave the wrong answer")]
+    SmokeFailed { count: usize },
+    #[error("{count} solutions ran more than {percent}% slower than the baseline")]
+    BenchRegressed { count: usize, percent: u32 },
+    #[error("Could not read input {path}")]
+    CouldNotReadInput { path: String },
+    #[error("No scratch solution at {path}, expected a .rs file")]
+    ScratchNotFound { path: String },
+    #[error("Scratch solution {path} failed to build or run")]
+    ScratchFailed { path: String },
+    #[error("There is no puzzle for {year} day {day}, days run from 1 to 25")]
+    NoSuchPuzzle { year: u16, day: u8 },
+    #[error("{path} already exists")]
+    DayExists { path: String },
+    #[error("Could not update the runner's Cargo.toml: {reason}")]
+    InvalidRunnerManifest { reason: String },
+    #[error("Could not read run log line {line:?}")]
+    InvalidRunLog { line: String },
+    #[error("Could not start tracing: {reason}")]
+    Telemetry { reason: String },
+    #[error("{directive:?} is not a log filter: {reason}")]
+    #[diagnostic(help("Use a level like `info`, or `target=level` like `day_05=trace`"))]
+    InvalidLogFilter { directive: String, reason: String },
+}
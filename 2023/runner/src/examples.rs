@@ -0,0 +1,66 @@
+use aoc_core::Answer;
+
+use crate::registry::{days, Day, Example, Variant};
+
+/// Outcome of running one variant against one of its day's examples.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExampleResult {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub variant: &'static str,
+    pub expected: Answer,
+    pub actual: std::result::Result<Answer, String>,
+}
+
+impl ExampleResult {
+    #[tracing::instrument]
+    pub fn passed(&self) -> bool {
+        self.actual.as_ref() == Ok(&self.expected)
+    }
+}
+
+#[tracing::instrument(skip(variant), fields(variant = variant.name))]
+fn solve_example(day: &Day, variant: &Variant, example: &Example) -> ExampleResult {
+    ExampleResult {
+        year: day.year,
+        day: day.day,
+        part: example.part,
+        variant: variant.name,
+        expected: Answer::parse(example.expected),
+        actual: (variant.solve)(example.input).map_err(|report| report.to_string()),
+    }
+}
+
+/// Runs every variant of a day against the examples for its part.
+#[tracing::instrument(skip(day), fields(day = day.day))]
+pub fn solve_examples(day: &Day) -> Vec<ExampleResult> {
+    day.examples
+        .iter()
+        .flat_map(|example| {
+            day.variants
+                .iter()
+                .filter(move |variant| variant.part == example.part)
+                .map(move |variant| solve_example(day, variant, example))
+        })
+        .collect()
+}
+
+/// Every registered day against its examples, with no printing or exit codes
+/// so callers outside the CLI can present the results however they like.
+#[tracing::instrument]
+pub fn solve_all_examples() -> Vec<ExampleResult> {
+    days().iter().flat_map(solve_examples).collect()
+}
+
+/// Each part's default variant against its smoke input only. Much quicker than
+/// every variant against every example, for a first check after a change that
+/// touches all the days.
+#[tracing::instrument(skip(day), fields(day = day.day))]
+pub fn smoke(day: &Day) -> Vec<ExampleResult> {
+    [1, 2]
+        .into_iter()
+        .filter_map(|part| Some((day.default_variant(part)?, day.smoke_input(part)?)))
+        .map(|(variant, example)| solve_example(day, variant, example))
+        .collect()
+}
@@ -0,0 +1,321 @@
+use std::{
+    fmt::{self, Write as _},
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Mutex,
+    },
+    time::{Instant, SystemTime, UNIX_EPOCH},
+};
+
+use tracing::{
+    field::{Field, Visit},
+    span::{Attributes, Id},
+    subscriber::Interest,
+    Event, Metadata, Subscriber,
+};
+use tracing_subscriber::{
+    layer::{Context, SubscriberExt},
+    registry::LookupSpan,
+    Layer,
+};
+
+use crate::{input::InputSource, prelude::*, workspace};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Failure {
+    Error { report: String },
+    WrongAnswer { expected: String, actual: String },
+}
+
+/// Everything needed to start debugging a failed run without re-running it by hand.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FailureBundle {
+    pub year: u16,
+    pub day: u8,
+    pub variant: String,
+    pub input: InputSource,
+    pub input_hash: String,
+    pub failure: Failure,
+    pub explain: Option<String>,
+    pub trace: String,
+}
+
+impl FailureBundle {
+    #[tracing::instrument(skip(self))]
+    fn dir_name(&self) -> String {
+        let millis = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_millis())
+            .unwrap_or(0);
+
+        format!(
+            "{}-day{:02}-{}-{}",
+            self.year, self.day, self.variant, millis
+        )
+    }
+
+    #[tracing::instrument(skip(self))]
+    fn summary(&self) -> String {
+        let mut summary = format!(
+            "year: {}\nday: {}\nvariant: {}\ninput: {}\ninput hash: {}\n",
+            self.year, self.day, self.variant, self.input, self.input_hash
+        );
+
+        match &self.failure {
+            Failure::Error { .. } => summary.push_str("failure: error (see error.txt)\n"),
+            Failure::WrongAnswer { expected, actual } => summary.push_str(&format!(
+                "failure: wrong answer\nexpected: {}\nactual: {}\n",
+                expected, actual
+            )),
+        }
+
+        if self.explain.is_none() {
+            summary.push_str("explain: not available for this day\n");
+        }
+
+        summary
+    }
+
+    /// Writes the bundle into a new directory under `root` and returns its path.
+    #[tracing::instrument(skip(self))]
+    pub fn write(&self, root: &Path) -> Result<PathBuf> {
+        let dir = root.join(self.dir_name());
+        std::fs::create_dir_all(&dir)?;
+
+        std::fs::write(dir.join("summary.txt"), self.summary())?;
+
+        if let Failure::Error { report } = &self.failure {
+            std::fs::write(dir.join("error.txt"), report)?;
+        }
+
+        if let Some(explain) = &self.explain {
+            std::fs::write(dir.join("explain.txt"), explain)?;
+        }
+
+        std::fs::write(dir.join("trace.log"), &self.trace)?;
+
+        Ok(dir)
+    }
+}
+
+/// `target/aoc-failures`, honouring `CARGO_TARGET_DIR` when it is set.
+#[tracing::instrument]
+pub fn failures_dir() -> PathBuf {
+    workspace::target_dir().join("aoc-failures")
+}
+
+/// Spans and events recorded per run before the recorder switches itself off,
+/// so a solve with millions of spans only pays a counter check for the rest.
+const MAX_TRACE_LINES: usize = 10_000;
+
+/// When a span opened and what it was called with, kept until it closes.
+struct Opened {
+    at: Instant,
+    fields: String,
+}
+
+/// Writes `name=value` pairs the way the fmt subscriber does.
+#[derive(Default)]
+struct Fields(String);
+
+impl Visit for Fields {
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        if !self.0.is_empty() {
+            self.0.push(' ');
+        }
+        let _ = write!(self.0, "{}={:?}", field.name(), value);
+    }
+}
+
+/// Records a line per closed span and per event, indented by nesting, until
+/// `MAX_TRACE_LINES` have started. After that it reports itself disabled, so
+/// no more spans are built at all.
+#[derive(Debug, Clone, Default)]
+struct TraceRecorder {
+    lines: Arc<Mutex<Vec<String>>>,
+    started: Arc<AtomicUsize>,
+}
+
+impl TraceRecorder {
+    // Not instrumented: the recorder asks this while deciding about spans
+    fn is_full(&self) -> bool {
+        self.started.load(Ordering::Relaxed) >= MAX_TRACE_LINES
+    }
+
+    fn push(&self, line: String) {
+        if let Ok(mut lines) = self.lines.lock() {
+            lines.push(line);
+        }
+    }
+}
+
+impl<S> Layer<S> for TraceRecorder
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn register_callsite(&self, _: &'static Metadata<'static>) -> Interest {
+        Interest::sometimes()
+    }
+
+    fn enabled(&self, _: &Metadata<'_>, _: Context<'_, S>) -> bool {
+        !self.is_full()
+    }
+
+    fn on_new_span(&self, attributes: &Attributes<'_>, id: &Id, context: Context<'_, S>) {
+        self.started.fetch_add(1, Ordering::Relaxed);
+
+        let mut fields = Fields::default();
+        attributes.record(&mut fields);
+
+        if let Some(span) = context.span(id) {
+            span.extensions_mut().insert(Opened {
+                at: Instant::now(),
+                fields: fields.0,
+            });
+        }
+    }
+
+    fn on_event(&self, event: &Event<'_>, context: Context<'_, S>) {
+        self.started.fetch_add(1, Ordering::Relaxed);
+
+        let mut fields = Fields::default();
+        event.record(&mut fields);
+        let depth = context.event_scope(event).map_or(0, |scope| scope.count());
+
+        self.push(format!(
+            "{}{} {}: {}",
+            "  ".repeat(depth),
+            event.metadata().level(),
+            event.metadata().target(),
+            fields.0
+        ));
+    }
+
+    fn on_close(&self, id: Id, context: Context<'_, S>) {
+        let Some(span) = context.span(&id) else {
+            return;
+        };
+        let Some(opened) = span.extensions_mut().remove::<Opened>() else {
+            return;
+        };
+        let depth = span.scope().count() - 1;
+
+        self.push(format!(
+            "{}{}::{}{{{}}} closed after {:?}",
+            "  ".repeat(depth),
+            span.metadata().target(),
+            span.name(),
+            opened.fields,
+            opened.at.elapsed()
+        ));
+    }
+}
+
+/// Runs `f` once with its spans and events on this thread recorded, keeping
+/// the first `MAX_TRACE_LINES` of them so a failure can be bundled without
+/// running it again. Work rayon moves to other threads isn't captured, and
+/// nothing is when `--trace`, `--log` or `--flame` already installed a
+/// subscriber, since that one is reporting the run.
+#[tracing::instrument(skip(f))]
+pub fn capture_trace<T>(f: impl FnOnce() -> T) -> (T, String) {
+    if tracing::dispatcher::has_been_set() {
+        return (
+            f(),
+            "not captured: the run was traced with --trace, --log or --flame\n".to_string(),
+        );
+    }
+
+    record_trace(f)
+}
+
+#[tracing::instrument(skip(f))]
+fn record_trace<T>(f: impl FnOnce() -> T) -> (T, String) {
+    let recorder = TraceRecorder::default();
+    let subscriber = tracing_subscriber::registry().with(recorder.clone());
+
+    let result = tracing::subscriber::with_default(subscriber, f);
+
+    let mut trace = recorder
+        .lines
+        .lock()
+        .map(|lines| lines.iter().map(|line| format!("{}\n", line)).collect())
+        .unwrap_or_else(|_| String::new());
+
+    if recorder.is_full() {
+        trace.push_str(&format!(
+            "... trace stopped after {} spans and events\n",
+            MAX_TRACE_LINES
+        ));
+    }
+
+    (result, trace)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{error::Error, input::content_hash};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn it_should_capture_spans() -> miette::Result<()> {
+        #[tracing::instrument]
+        fn traced(value: u32) -> u32 {
+            value + 1
+        }
+
+        let (result, trace) = record_trace(|| traced(41));
+        assert_eq!(42, result);
+        assert!(trace.contains("traced{value=41} closed after"));
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_stop_recording_when_full() -> miette::Result<()> {
+        #[tracing::instrument]
+        fn traced(value: usize) -> usize {
+            value
+        }
+
+        let (sum, trace) = record_trace(|| (0..MAX_TRACE_LINES * 3).map(traced).sum::<usize>());
+
+        assert_eq!((0..MAX_TRACE_LINES * 3).sum::<usize>(), sum);
+        assert_eq!(MAX_TRACE_LINES + 1, trace.lines().count());
+        assert!(trace.ends_with("... trace stopped after 10000 spans and events\n"));
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_write_bundle() -> miette::Result<()> {
+        let root = std::env::temp_dir().join(format!("aoc-failures-test-{}", std::process::id()));
+
+        let bundle = FailureBundle {
+            year: 2023,
+            day: 9,
+            variant: "part1".to_string(),
+            input: InputSource::File(PathBuf::from("day-09/input1.txt")),
+            input_hash: content_hash("1"),
+            failure: Failure::WrongAnswer {
+                expected: "114".to_string(),
+                actual: "113".to_string(),
+            },
+            explain: None,
+            trace: "trace".to_string(),
+        };
+
+        let dir = bundle.write(&root)?;
+        let summary = std::fs::read_to_string(dir.join("summary.txt")).map_err(Error::from)?;
+
+        assert!(summary.contains("expected: 114\nactual: 113"));
+        assert!(summary.contains(&format!("input hash: {}", content_hash("1"))));
+        assert_eq!(
+            "trace",
+            std::fs::read_to_string(dir.join("trace.log")).map_err(Error::from)?
+        );
+        assert!(!dir.join("error.txt").exists());
+
+        std::fs::remove_dir_all(&root).map_err(Error::from)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,390 @@
+use std::{
+    io::Write,
+    path::{Path, PathBuf},
+    process::{Command, Stdio},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use crate::{error::Error, prelude::*, workspace};
+
+/// Environment variable holding the adventofcode.com `session` cookie.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+/// Inputs never change once released, so even a conditional request more often
+/// than this is wasted load on the AoC servers.
+pub const MIN_REFETCH_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+const USER_AGENT: &str = "github.com/GeekyAubergine/advent-of-code runner";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Request {
+    pub url: String,
+    pub session: String,
+    pub etag: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Response {
+    pub status: u16,
+    pub etag: Option<String>,
+    pub body: String,
+}
+
+pub trait HttpClient {
+    fn get(&self, request: &Request) -> Result<Response>;
+}
+
+/// Shells out to `curl`. The cookie goes through stdin so the session never
+/// shows up in the process list.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CurlClient;
+
+impl HttpClient for CurlClient {
+    #[tracing::instrument(skip(self, request), fields(url = %request.url))]
+    fn get(&self, request: &Request) -> Result<Response> {
+        let mut command = Command::new("curl");
+        command
+            .args(["--silent", "--show-error", "--include", "--config", "-"])
+            .args(["--user-agent", USER_AGENT]);
+
+        if let Some(etag) = &request.etag {
+            command
+                .arg("--header")
+                .arg(format!("If-None-Match: {}", etag));
+        }
+
+        let mut child = command
+            .arg(&request.url)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()?;
+
+        if let Some(mut stdin) = child.stdin.take() {
+            writeln!(stdin, "cookie = \"session={}\"", request.session)?;
+        }
+
+        let output = child.wait_with_output()?;
+        if !output.status.success() {
+            return Err(Error::FetchFailed {
+                url: request.url.clone(),
+                reason: String::from_utf8_lossy(&output.stderr).trim().to_string(),
+            });
+        }
+
+        parse_response(&request.url, &String::from_utf8_lossy(&output.stdout))
+    }
+}
+
+/// Splits `curl --include` output into the status, `ETag` header and body.
+#[tracing::instrument(skip(raw))]
+fn parse_response(url: &str, raw: &str) -> Result<Response> {
+    let (head, body) = raw
+        .split_once("\r\n\r\n")
+        .or_else(|| raw.split_once("\n\n"))
+        .unwrap_or((raw, ""));
+    let mut lines = head.lines();
+
+    let status = lines
+        .next()
+        .and_then(|line| line.split_whitespace().nth(1))
+        .and_then(|code| code.parse::<u16>().ok())
+        .ok_or_else(|| Error::FetchFailed {
+            url: url.to_string(),
+            reason: "response has no status line".to_string(),
+        })?;
+
+    let etag = lines
+        .filter_map(|line| line.split_once(':'))
+        .find(|(name, _)| name.trim().eq_ignore_ascii_case("etag"))
+        .map(|(_, value)| value.trim().to_string());
+
+    Ok(Response {
+        status,
+        etag,
+        body: body.to_string(),
+    })
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct CacheEntry {
+    body: String,
+    etag: Option<String>,
+    fetched: u64,
+}
+
+#[tracing::instrument]
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+/// Downloads puzzle inputs through a cache on disk. Cached inputs are reused
+/// without asking the server until `min_interval` has passed, then revalidated
+/// with their `ETag`.
+#[derive(Debug, Clone)]
+pub struct Fetcher<C> {
+    pub client: C,
+    pub cache_dir: PathBuf,
+    pub session: Option<String>,
+    pub offline: bool,
+    pub min_interval: Duration,
+}
+
+impl<C: HttpClient> Fetcher<C> {
+    #[tracing::instrument(skip(client, session))]
+    pub fn new(client: C, cache_dir: &Path, session: Option<String>) -> Self {
+        Self {
+            client,
+            cache_dir: cache_dir.to_path_buf(),
+            session,
+            offline: false,
+            min_interval: MIN_REFETCH_INTERVAL,
+        }
+    }
+
+    #[tracing::instrument(skip(self))]
+    fn cache_path(&self, year: u16, day: u8, extension: &str) -> PathBuf {
+        self.cache_dir
+            .join(format!("{}-day{:02}.{}", year, day, extension))
+    }
+
+    #[tracing::instrument(skip(self))]
+    fn read_cache(&self, year: u16, day: u8) -> Option<CacheEntry> {
+        let body = std::fs::read_to_string(self.cache_path(year, day, "txt")).ok()?;
+        let meta = std::fs::read_to_string(self.cache_path(year, day, "meta")).unwrap_or_default();
+
+        let field = |name: &str| {
+            meta.lines()
+                .find_map(|line| line.strip_prefix(name)?.strip_prefix('='))
+                .map(|value| value.to_string())
+        };
+
+        Some(CacheEntry {
+            body,
+            etag: field("etag"),
+            fetched: field("fetched").and_then(|s| s.parse().ok()).unwrap_or(0),
+        })
+    }
+
+    #[tracing::instrument(skip(self, entry))]
+    fn write_cache(&self, year: u16, day: u8, entry: &CacheEntry) -> Result<()> {
+        std::fs::create_dir_all(&self.cache_dir)?;
+        std::fs::write(self.cache_path(year, day, "txt"), &entry.body)?;
+
+        let mut meta = format!("fetched={}\n", entry.fetched);
+        if let Some(etag) = &entry.etag {
+            meta.push_str(&format!("etag={}\n", etag));
+        }
+        std::fs::write(self.cache_path(year, day, "meta"), meta)?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub fn fetch_input(&self, year: u16, day: u8) -> Result<String> {
+        let cached = self.read_cache(year, day);
+
+        if self.offline {
+            return cached
+                .map(|entry| entry.body)
+                .ok_or(Error::OfflineCacheCold { year, day });
+        }
+
+        if let Some(entry) = &cached {
+            if now().saturating_sub(entry.fetched) < self.min_interval.as_secs() {
+                return Ok(entry.body.clone());
+            }
+        }
+
+        let session = self.session.clone().ok_or(Error::MissingSession {
+            var: SESSION_VAR.to_string(),
+        })?;
+
+        let request = Request {
+            url: format!("https://adventofcode.com/{}/day/{}/input", year, day),
+            session,
+            etag: cached.as_ref().and_then(|entry| entry.etag.clone()),
+        };
+        let response = self.client.get(&request)?;
+
+        let entry = match (response.status, cached) {
+            (304, Some(entry)) => CacheEntry {
+                fetched: now(),
+                ..entry
+            },
+            (200, _) => CacheEntry {
+                body: response.body,
+                etag: response.etag,
+                fetched: now(),
+            },
+            (status, _) => {
+                return Err(Error::FetchFailed {
+                    url: request.url,
+                    reason: format!("server answered {}", status),
+                })
+            }
+        };
+
+        self.write_cache(year, day, &entry)?;
+        Ok(entry.body)
+    }
+}
+
+/// `target/aoc-inputs`, honouring `CARGO_TARGET_DIR` when it is set.
+#[tracing::instrument]
+pub fn inputs_dir() -> PathBuf {
+    workspace::target_dir().join("aoc-inputs")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    /// Answers with queued responses and remembers every request it was sent.
+    #[derive(Debug, Default)]
+    struct MockClient {
+        responses: RefCell<Vec<Response>>,
+        requests: RefCell<Vec<Request>>,
+    }
+
+    impl MockClient {
+        fn with(responses: Vec<Response>) -> Self {
+            Self {
+                responses: RefCell::new(responses),
+                requests: RefCell::default(),
+            }
+        }
+    }
+
+    impl HttpClient for &MockClient {
+        fn get(&self, request: &Request) -> Result<Response> {
+            self.requests.borrow_mut().push(request.clone());
+            Ok(self.responses.borrow_mut().remove(0))
+        }
+    }
+
+    fn ok(body: &str, etag: &str) -> Response {
+        Response {
+            status: 200,
+            etag: Some(etag.to_string()),
+            body: body.to_string(),
+        }
+    }
+
+    fn fetcher<'a>(client: &'a MockClient, name: &str) -> Fetcher<&'a MockClient> {
+        let dir =
+            std::env::temp_dir().join(format!("aoc-fetch-test-{}-{}", std::process::id(), name));
+        std::fs::remove_dir_all(&dir).ok();
+        Fetcher::new(client, &dir, Some("abc".to_string()))
+    }
+
+    #[test]
+    fn it_should_reuse_fresh_cache_without_requesting() -> miette::Result<()> {
+        let client = MockClient::with(vec![ok("1 2 3\n", "\"v1\"")]);
+        let fetcher = fetcher(&client, "fresh");
+
+        assert_eq!("1 2 3\n", fetcher.fetch_input(2023, 9)?);
+        assert_eq!("1 2 3\n", fetcher.fetch_input(2023, 9)?);
+
+        let requests = client.requests.borrow();
+        assert_eq!(1, requests.len());
+        assert_eq!("https://adventofcode.com/2023/day/9/input", requests[0].url);
+        assert_eq!(None, requests[0].etag);
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_revalidate_stale_cache_with_etag() -> miette::Result<()> {
+        let client = MockClient::with(vec![
+            ok("1 2 3\n", "\"v1\""),
+            Response {
+                status: 304,
+                etag: None,
+                body: String::new(),
+            },
+        ]);
+        let mut fetcher = fetcher(&client, "stale");
+        fetcher.min_interval = Duration::ZERO;
+
+        fetcher.fetch_input(2023, 9)?;
+        assert_eq!("1 2 3\n", fetcher.fetch_input(2023, 9)?);
+        assert_eq!(Some("\"v1\"".to_string()), client.requests.borrow()[1].etag);
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_only_use_cache_when_offline() -> miette::Result<()> {
+        let client = MockClient::with(vec![ok("7\n", "\"v1\"")]);
+        let mut fetcher = fetcher(&client, "offline");
+        fetcher.offline = true;
+
+        assert!(matches!(
+            fetcher.fetch_input(2023, 6),
+            Err(Error::OfflineCacheCold { year: 2023, day: 6 })
+        ));
+
+        fetcher.offline = false;
+        fetcher.fetch_input(2023, 6)?;
+        fetcher.offline = true;
+        fetcher.min_interval = Duration::ZERO;
+
+        assert_eq!("7\n", fetcher.fetch_input(2023, 6)?);
+        assert_eq!(1, client.requests.borrow().len());
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_not_cache_failed_responses() -> miette::Result<()> {
+        let client = MockClient::with(vec![Response {
+            status: 400,
+            etag: None,
+            body: "Please log in".to_string(),
+        }]);
+        let fetcher = fetcher(&client, "failed");
+
+        assert!(matches!(
+            fetcher.fetch_input(2023, 1),
+            Err(Error::FetchFailed { .. })
+        ));
+        assert_eq!(None, fetcher.read_cache(2023, 1));
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_require_session_to_fetch() -> miette::Result<()> {
+        let client = MockClient::default();
+        let mut fetcher = fetcher(&client, "session");
+        fetcher.session = None;
+
+        assert!(matches!(
+            fetcher.fetch_input(2023, 1),
+            Err(Error::MissingSession { .. })
+        ));
+        assert!(client.requests.borrow().is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_parse_curl_output() -> miette::Result<()> {
+        let response = parse_response(
+            "url",
+            "HTTP/2 200\r\ncontent-type: text/plain\r\nETag: \"abc\"\r\n\r\n1 2\n3 4\n",
+        )?;
+
+        assert_eq!(
+            Response {
+                status: 200,
+                etag: Some("\"abc\"".to_string()),
+                body: "1 2\n3 4\n".to_string(),
+            },
+            response
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,145 @@
+//! Where a solution's input comes from. Everything that reads one goes
+//! through `InputSource::read`, so a new origin only needs adding here.
+
+use std::{fmt::Display, io::Read, path::PathBuf, sync::OnceLock};
+
+use crate::{
+    error::Error,
+    fetch::{self, CurlClient, Fetcher},
+    prelude::*,
+};
+
+/// Stdin can only be read once, so every part asking for it shares this.
+static STDIN: OnceLock<String> = OnceLock::new();
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    /// A file on disk, like a day's `input1.txt`.
+    File(PathBuf),
+    /// Everything piped in, until it closes.
+    Stdin,
+    /// Text already in memory, like a worked example.
+    Inline(String),
+    /// The puzzle input from adventofcode.com, through the fetch cache.
+    Url { year: u16, day: u8 },
+    /// Only what the fetch cache already holds, never the network.
+    Cached { year: u16, day: u8 },
+}
+
+impl InputSource {
+    /// `-` for stdin, `fetch` or `cache` for the day's puzzle input, and
+    /// anything else a path.
+    #[tracing::instrument]
+    pub fn from_arg(arg: &str, year: u16, day: u8) -> Self {
+        match arg {
+            "-" => InputSource::Stdin,
+            "fetch" => InputSource::Url { year, day },
+            "cache" => InputSource::Cached { year, day },
+            path => InputSource::File(PathBuf::from(path)),
+        }
+    }
+
+    #[tracing::instrument]
+    pub fn read(&self) -> Result<String> {
+        match self {
+            InputSource::File(path) => {
+                std::fs::read_to_string(path).map_err(|_| Error::CouldNotReadInput {
+                    path: path.display().to_string(),
+                })
+            }
+            InputSource::Stdin => {
+                if let Some(text) = STDIN.get() {
+                    return Ok(text.clone());
+                }
+
+                let mut text = String::new();
+                std::io::stdin().read_to_string(&mut text)?;
+                Ok(STDIN.get_or_init(|| text).clone())
+            }
+            InputSource::Inline(text) => Ok(text.clone()),
+            InputSource::Url { year, day } => fetcher(false).fetch_input(*year, *day),
+            InputSource::Cached { year, day } => fetcher(true).fetch_input(*year, *day),
+        }
+    }
+}
+
+impl Display for InputSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputSource::File(path) => write!(f, "{}", path.display()),
+            InputSource::Stdin => write!(f, "stdin"),
+            InputSource::Inline(_) => write!(f, "inline"),
+            InputSource::Url { year, day } => {
+                write!(f, "https://adventofcode.com/{}/day/{}/input", year, day)
+            }
+            InputSource::Cached { year, day } => write!(f, "cached {} day {}", year, day),
+        }
+    }
+}
+
+#[tracing::instrument]
+fn fetcher(offline: bool) -> Fetcher<CurlClient> {
+    let session = std::env::var(fetch::SESSION_VAR).ok();
+    let mut fetcher = Fetcher::new(CurlClient, &fetch::inputs_dir(), session);
+    fetcher.offline = offline;
+    fetcher
+}
+
+/// FNV-1a, so the hash is stable across runs and Rust versions. It depends
+/// only on the text, so the same input keys the same entry wherever it came
+/// from.
+#[tracing::instrument(skip(input))]
+pub fn content_hash(input: &str) -> String {
+    let hash = input.bytes().fold(0xcbf29ce484222325u64, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+    });
+
+    format!("{:016x}", hash)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn it_should_hash_input_stably() -> miette::Result<()> {
+        assert_eq!("cbf29ce484222325", content_hash(""));
+        assert_eq!("af63dc4c8601ec8c", content_hash("a"));
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_pick_a_source_from_an_argument() -> miette::Result<()> {
+        assert_eq!(InputSource::Stdin, InputSource::from_arg("-", 2023, 9));
+        assert_eq!(
+            InputSource::Url { year: 2023, day: 9 },
+            InputSource::from_arg("fetch", 2023, 9)
+        );
+        assert_eq!(
+            InputSource::Cached { year: 2023, day: 9 },
+            InputSource::from_arg("cache", 2023, 9)
+        );
+        assert_eq!(
+            InputSource::File(PathBuf::from("day-09/input1.txt")),
+            InputSource::from_arg("day-09/input1.txt", 2023, 9)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_read_files_and_inline_text() -> miette::Result<()> {
+        let path = std::env::temp_dir().join(format!("aoc-input-test-{}", std::process::id()));
+        std::fs::write(&path, "0 3 6\n").map_err(Error::from)?;
+
+        assert_eq!("0 3 6\n", InputSource::File(path.clone()).read()?);
+        assert_eq!("1 2\n", InputSource::Inline("1 2\n".to_string()).read()?);
+
+        std::fs::remove_file(&path).map_err(Error::from)?;
+        assert!(matches!(
+            InputSource::File(path).read(),
+            Err(Error::CouldNotReadInput { .. })
+        ));
+        Ok(())
+    }
+}
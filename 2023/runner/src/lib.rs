@@ -0,0 +1,22 @@
+pub mod answers;
+pub mod benchmark;
+pub mod determinism;
+pub mod error;
+pub mod examples;
+pub mod failure;
+pub mod fetch;
+pub mod input;
+pub mod prelude;
+
+pub mod registry;
+pub mod scaffold;
+pub mod scratch;
+pub mod selector;
+pub mod serve;
+pub mod stats;
+pub mod summary;
+pub mod telemetry;
+pub mod verify;
+pub mod workspace;
+
+pub use examples::{solve_all_examples, ExampleResult};
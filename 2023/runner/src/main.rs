@@ -0,0 +1,1074 @@
+use std::{
+    io::IsTerminal,
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
+
+use aoc_core::Answer;
+use aoc_ui::{Align, Backend, Progress, Status, Summary, Table};
+use clap::{Parser, Subcommand, ValueEnum};
+use miette::Context;
+use runner::{
+    answers::{Accepted, Answers},
+    benchmark::{Baseline, Timing},
+    determinism,
+    error::Error,
+    examples,
+    failure::{self, Failure, FailureBundle},
+    input::{self, InputSource},
+    registry::{self, Day, Variant},
+    scaffold::Scaffold,
+    scratch::Scratch,
+    selector::Selector,
+    serve,
+    stats::{self, RunLog, RunRecord},
+    summary::YearSummary,
+    telemetry, verify, workspace,
+};
+
+#[derive(Parser, Debug)]
+#[command(about = "Run Advent of Code solutions against their stored inputs")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+    /// Print how long each instrumented span took to stderr as it closes
+    #[arg(long, global = true)]
+    trace: bool,
+    /// Write every span to this file as folded stacks for a flamegraph
+    #[arg(long, global = true)]
+    flame: Option<PathBuf>,
+    /// Print events to stderr, filtered like RUST_LOG (`info`, `day_05=trace`).
+    /// Repeatable. Tracing stays off without it, `--trace` or `--flame`
+    #[arg(long, global = true)]
+    log: Vec<String>,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Run the selected days' solutions
+    Run {
+        /// `YEAR`, or days like `2023:5`, `2023:1-10` or `2023:5,7,11`
+        selection: Selector,
+        /// A single day of the selected year, as in `2023 5`
+        day: Option<u8>,
+        #[arg(long, value_enum, default_value_t = PartSelection::Both)]
+        part: PartSelection,
+        /// Run a specific variant (e.g. part1_opt) instead of the default ones
+        #[arg(long)]
+        variant: Option<String>,
+        /// Print only the answer, exactly as it should be submitted
+        #[arg(long)]
+        submit_format: bool,
+        /// Custom expansion factor for days that take one (day 11)
+        #[arg(long, conflicts_with = "variant")]
+        expansion: Option<u64>,
+        /// Where to read the input: one of the day's named inputs like `example2`
+        /// (see `inputs`), a path, `-` for stdin, or `fetch` or `cache` for the
+        /// puzzle input. Each part's stored input when not given
+        #[arg(long)]
+        input: Option<String>,
+        /// How to solve: the usual variants, or the slow brute force ones to
+        /// cross-check them (day 5)
+        #[arg(long, value_enum, default_value_t = Strategy::Default, conflicts_with_all = ["variant", "expansion"])]
+        strategy: Strategy,
+    },
+    /// Run one part of the selected days and check it against its accepted
+    /// answers, reporting every part that fails rather than stopping at the first
+    Verify {
+        /// `YEAR`, or days like `2023:5`, `2023:1-10` or `2023:5,7,11`
+        selection: Selector,
+        /// A single day of the selected year, as in `2023 5`
+        day: Option<u8>,
+        /// Only check this part, rather than both
+        #[arg(long)]
+        part: Option<u8>,
+        /// An accepted answer as submitted, optionally `label=answer`. Repeat to
+        /// accept several; answers.toml is used when none are given
+        #[arg(long, requires = "part")]
+        expected: Vec<String>,
+        /// Check a specific variant (e.g. part1_opt) instead of the default one,
+        /// which is the part's fast variant when its reference is too slow
+        #[arg(long, requires = "part")]
+        variant: Option<String>,
+        /// Store the variant and current commit against the answer in answers.toml
+        #[arg(long, conflicts_with = "expected")]
+        record: bool,
+        /// Read accepted answers from this manifest instead of answers.toml
+        #[arg(long, conflicts_with_all = ["expected", "record"])]
+        answers: Option<PathBuf>,
+    },
+    /// Draw the selected days' inputs in the terminal, skipping days without a
+    /// visualisation when more than one is selected
+    Viz {
+        /// `YEAR`, or days like `2023:5`, `2023:1-10` or `2023:5,7,11`
+        selection: Selector,
+        /// A single day of the selected year, as in `2023 5`
+        day: Option<u8>,
+        /// Which part's input to draw
+        #[arg(long, default_value_t = 1)]
+        part: u8,
+        /// Draw this input instead, as for `run --input`
+        #[arg(long)]
+        input: Option<String>,
+        /// Plain characters only, even when writing to a terminal
+        #[arg(long)]
+        no_color: bool,
+    },
+    /// Time the selected days' solutions over several runs
+    Bench {
+        /// `YEAR`, or days like `2023:5`, `2023:1-10` or `2023:5,7,11`
+        selection: Selector,
+        /// A single day of the selected year, as in `2023 5`
+        day: Option<u8>,
+        #[arg(long, value_enum, default_value_t = PartSelection::Both)]
+        part: PartSelection,
+        /// Run a specific variant (e.g. part1_opt) instead of the default ones
+        #[arg(long)]
+        variant: Option<String>,
+        /// How many times to run each solution
+        #[arg(long, default_value_t = 10)]
+        runs: usize,
+        /// Time every variant of the selected parts, to compare each against
+        /// its reference `part1`/`part2`
+        #[arg(long, conflicts_with = "variant")]
+        all_variants: bool,
+        /// Write the timings to this file for a later `--baseline`
+        #[arg(long)]
+        save: Option<PathBuf>,
+        /// Compare with timings saved by `--save`, failing on regressions
+        #[arg(long)]
+        baseline: Option<PathBuf>,
+        /// How much slower than the baseline counts as a regression, in percent
+        #[arg(long, default_value_t = 20)]
+        tolerance: u32,
+        /// Fault each input into memory and give every variant a few untimed
+        /// runs first, so none of them pays first-touch costs the others don't
+        #[arg(long)]
+        stable: bool,
+    },
+    /// Time every default solution of a year, as `bench --stable` does, and
+    /// report the total against the one second budget, the slowest three and
+    /// a bar per day
+    Summary {
+        year: u16,
+        /// How many times to run each solution
+        #[arg(long, default_value_t = 10)]
+        runs: usize,
+        /// Print the summary as JSON instead
+        #[arg(long)]
+        json: bool,
+    },
+    /// Show a day's intermediate working for its stored input
+    Explain {
+        year: u16,
+        day: u8,
+        /// Which part's input to explain
+        #[arg(long, default_value_t = 1)]
+        part: u8,
+        /// Only explain this input line (1-based). Repeat for several
+        #[arg(long)]
+        line: Vec<usize>,
+        /// Plain characters only, even when writing to a terminal
+        #[arg(long)]
+        no_color: bool,
+    },
+    /// List the named inputs each selected day has for `--input`
+    Inputs {
+        /// `YEAR`, or days like `2023-05`, `2023:1-10` or `2023:5,7,11`
+        selection: Selector,
+        /// A single day of the selected year, as in `2023 5`
+        day: Option<u8>,
+    },
+    /// List the variants each selected day has tried, what they took and
+    /// what became of them
+    Attempts {
+        /// `YEAR`, or days like `2023-05`, `2023:1-10` or `2023:5,7,11`
+        selection: Selector,
+        /// A single day of the selected year, as in `2023 5`
+        day: Option<u8>,
+    },
+    /// Download a day's input, reusing the cached copy where possible
+    Fetch {
+        year: u16,
+        day: u8,
+        /// Never contact the server, failing if the input isn't cached
+        #[arg(long)]
+        offline: bool,
+        /// Write the input here instead of printing it
+        #[arg(long)]
+        output: Option<PathBuf>,
+    },
+    /// Run every solution twice and report any output that changes between runs
+    AuditDeterminism {
+        year: u16,
+        /// Only audit this day
+        #[arg(long)]
+        day: Option<u8>,
+        /// Rayon thread counts for the first and second run
+        #[arg(long, num_args = 2, value_names = ["FIRST", "SECOND"])]
+        threads: Vec<usize>,
+    },
+    /// Run every day's default solutions against their built-in smoke inputs
+    Smoke {
+        /// Only run days from this year
+        #[arg(long)]
+        year: Option<u16>,
+    },
+    /// Build and run a single-file solution from scratch/ against an input
+    Scratch {
+        /// File defining `pub fn process(input: &str) -> miette::Result<impl Display>`
+        file: PathBuf,
+        /// Input file to run against
+        #[arg(long, required_unless_present = "day")]
+        input: Option<PathBuf>,
+        /// Use a registered day's stored input instead of --input
+        #[arg(long, conflicts_with = "input")]
+        day: Option<u8>,
+        #[arg(long, default_value_t = 2023)]
+        year: u16,
+        /// Which part's stored input to use with --day
+        #[arg(long, default_value_t = 1)]
+        part: u8,
+    },
+    /// Generate a crate for a new day from daily-template and add it to the runner
+    New {
+        #[arg(long)]
+        year: u16,
+        #[arg(long)]
+        day: u8,
+    },
+    /// Answer JSON-RPC 2.0 requests on stdin, one per line, for editor
+    /// integrations. Methods: run, verify, bench_quick, fetch and shutdown
+    Serve,
+    /// Look at the runs logged while AOC_TELEMETRY=1 was set
+    Telemetry {
+        #[command(subcommand)]
+        command: TelemetryCommand,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+enum TelemetryCommand {
+    /// Chart runs, failures and median time for every logged variant
+    Summary,
+}
+
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+enum PartSelection {
+    #[value(name = "1")]
+    One,
+    #[value(name = "2")]
+    Two,
+    Both,
+}
+
+impl PartSelection {
+    fn parts(&self) -> Vec<u8> {
+        match self {
+            PartSelection::One => vec![1],
+            PartSelection::Two => vec![2],
+            PartSelection::Both => vec![1, 2],
+        }
+    }
+}
+
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+enum Strategy {
+    Default,
+    Brute,
+}
+
+/// `--input` for the day, one of its named inputs before anything else, or
+/// the part's stored input when it's not given.
+#[tracing::instrument]
+fn input_source(day: &Day, part: u8, arg: Option<&str>) -> InputSource {
+    match arg {
+        Some(arg) => day
+            .named_input(arg)
+            .unwrap_or_else(|| InputSource::from_arg(arg, day.year, day.day)),
+        None => day.input_source(part),
+    }
+}
+
+#[tracing::instrument]
+fn select(selection: Selector, day: Option<u8>) -> miette::Result<Vec<Day>> {
+    Ok(selection.with_day(day)?.resolve(registry::days())?)
+}
+
+/// The named variant for every selected part, or each part's default one.
+#[tracing::instrument]
+fn variants(day: &Day, parts: &[u8], variant: Option<&str>) -> miette::Result<Vec<Variant>> {
+    Ok(match variant {
+        Some(name) => vec![*day.variant(name)?],
+        None => parts
+            .iter()
+            .filter_map(|part| day.default_variant(*part).copied())
+            .collect(),
+    })
+}
+
+/// Every variant of the selected parts, reference ones first.
+#[tracing::instrument]
+fn all_variants_of(day: &Day, parts: &[u8]) -> Vec<Variant> {
+    let mut variants = day
+        .variants
+        .iter()
+        .filter(|v| parts.contains(&v.part))
+        .copied()
+        .collect::<Vec<_>>();
+    variants.sort_by_key(|v| (v.part, v.name != format!("part{}", v.part)));
+    variants
+}
+
+#[tracing::instrument]
+fn print_answer(day: &Day, name: &str, answer: &Answer, elapsed: Duration, submit_format: bool) {
+    if submit_format {
+        println!("{}", answer.to_submit_string());
+    } else {
+        println!(
+            "{} day {:02} {:<10} {:>20}  ({:?})",
+            day.year,
+            day.day,
+            name,
+            answer.to_pretty_string(),
+            elapsed
+        );
+    }
+}
+
+/// Writes the failing run's input, trace and explanation to a failure bundle,
+/// printing where it went.
+#[tracing::instrument(skip(input, trace))]
+fn report_failure(
+    day: &Day,
+    variant: &Variant,
+    source: &InputSource,
+    input: &str,
+    trace: String,
+    failure: Failure,
+) {
+    let explain = day.explain.and_then(|explain| explain(input, false).ok());
+
+    let bundle = FailureBundle {
+        year: day.year,
+        day: day.day,
+        variant: variant.name.to_string(),
+        input: source.clone(),
+        input_hash: input::content_hash(input),
+        failure,
+        explain,
+        trace,
+    };
+
+    match bundle.write(&failure::failures_dir()) {
+        Ok(path) => eprintln!("failure bundle written to {}", path.display()),
+        Err(e) => eprintln!("could not write failure bundle: {}", e),
+    }
+}
+
+/// Appends the run to the local run log when AOC_TELEMETRY=1 opts in. Failing
+/// to write it only warns, so it never changes how a run turns out.
+#[tracing::instrument]
+fn log_run(day: &Day, variant: &Variant, elapsed: Duration, success: bool) {
+    let Some(log) = RunLog::opted_in() else {
+        return;
+    };
+
+    let record = RunRecord {
+        year: day.year,
+        day: day.day,
+        variant: variant.name.to_string(),
+        duration_ns: elapsed.as_nanos() as u64,
+        success,
+    };
+
+    if let Err(e) = log.append(&record) {
+        eprintln!("could not write run log: {}", e);
+    }
+}
+
+/// Reads the input and solves it once, with the trace captured along the way
+/// in case the answer turns out to be wrong.
+#[tracing::instrument]
+fn solve_variant(
+    day: &Day,
+    variant: &Variant,
+    source: &InputSource,
+) -> miette::Result<(String, Answer, Duration, String)> {
+    let input = source.read()?;
+
+    let start = Instant::now();
+    let (result, trace) = failure::capture_trace(|| (variant.solve)(&input));
+    let elapsed = start.elapsed();
+
+    log_run(day, variant, elapsed, result.is_ok());
+
+    match result {
+        Ok(answer) => Ok((input, answer, elapsed, trace)),
+        Err(report) => {
+            report_failure(
+                day,
+                variant,
+                source,
+                &input,
+                trace,
+                Failure::Error {
+                    report: format!("{:?}", report),
+                },
+            );
+            Err(report.context(format!("{} day {:02} {}", day.year, day.day, variant.name)))
+        }
+    }
+}
+
+#[tracing::instrument]
+fn run_variant(
+    day: &Day,
+    variant: &Variant,
+    source: &InputSource,
+    submit_format: bool,
+) -> miette::Result<()> {
+    let (_, answer, elapsed, _) = solve_variant(day, variant, source)?;
+
+    print_answer(day, variant.name, &answer, elapsed, submit_format);
+
+    Ok(())
+}
+
+#[tracing::instrument]
+fn verify_variant(day: &Day, variant: &Variant, accepted: &Accepted) -> miette::Result<()> {
+    let source = day.input_source(variant.part);
+    let (input, answer, elapsed, trace) = solve_variant(day, variant, &source)?;
+    let actual = answer.to_submit_string();
+
+    let Some(label) = accepted.matching(&answer) else {
+        report_failure(
+            day,
+            variant,
+            &source,
+            &input,
+            trace,
+            Failure::WrongAnswer {
+                expected: accepted.describe(),
+                actual: actual.clone(),
+            },
+        );
+        return Err(Error::WrongAnswer {
+            year: day.year,
+            day: day.day,
+            variant: variant.name.to_string(),
+            expected: accepted.describe(),
+            actual,
+        }
+        .into());
+    };
+
+    print_answer(day, variant.name, &answer, elapsed, false);
+    println!(
+        "{}",
+        Backend::stdout(false).paint(
+            Status::Ok.color(),
+            &format!("matched accepted answer {}", label)
+        )
+    );
+
+    Ok(())
+}
+
+#[tracing::instrument]
+fn run_expansion(
+    day: &Day,
+    source: &InputSource,
+    part: u8,
+    factor: u64,
+    submit_format: bool,
+) -> miette::Result<()> {
+    let solve = day.expansion()?;
+    let input = source.read()?;
+    let name = format!("part{} x{}", part, factor);
+
+    let start = Instant::now();
+    let answer = solve(&input, factor)
+        .with_context(|| format!("{} day {:02} {}", day.year, day.day, name))?;
+    let elapsed = start.elapsed();
+
+    print_answer(day, &name, &answer, elapsed, submit_format);
+
+    Ok(())
+}
+
+#[tracing::instrument]
+fn verify(
+    day: &Day,
+    part: u8,
+    expected: &[String],
+    variant: Option<&str>,
+    record: bool,
+    answers: Option<&Path>,
+) -> miette::Result<()> {
+    let variant = match variant {
+        Some(name) => *day.variant(name)?,
+        None => *day
+            .fast_variant(part)
+            .ok_or_else(|| Error::UnknownVariant {
+                year: day.year,
+                day: day.day,
+                variant: format!("part{}", part),
+            })?,
+    };
+    let accepted = if expected.is_empty() {
+        let answers = match answers {
+            Some(path) => Answers::load_from(path)?,
+            None => Answers::load()?,
+        };
+        answers.find(day.year, day.day, part)?.clone()
+    } else {
+        Accepted::from_args(day.year, day.day, part, expected)
+    };
+    verify_variant(day, &variant, &accepted)?;
+
+    if let Some(warning) = day
+        .fast_variant(part)
+        .and_then(|default| accepted.provenance_warning(default.name))
+    {
+        let warn = Backend::stderr(false).paint(Status::Warn.color(), "warning");
+        eprintln!("{}: {}", warn, warning);
+    }
+
+    if record {
+        let commit = Answers::record_provenance(day.year, day.day, part, variant.name)?;
+        println!("recorded {} at {}", variant.name, commit);
+    }
+
+    Ok(())
+}
+
+/// Untimed runs each variant gets on top of the first under `bench --stable`.
+const WARM_UP_RUNS: usize = 3;
+
+/// `runs` timed runs of a variant on its stored input, after one untimed run
+/// to catch errors and, when `stable`, a prefaulted input and a few more.
+#[tracing::instrument]
+fn time_variant(day: &Day, variant: &Variant, runs: usize, stable: bool) -> miette::Result<Timing> {
+    let source = day.input_source(variant.part);
+    let (input, _, _, _) = solve_variant(day, variant, &source)?;
+
+    if stable {
+        aoc_core::prefault(&input);
+        for _ in 0..WARM_UP_RUNS {
+            let _ = std::hint::black_box((variant.solve)(&input));
+        }
+    }
+
+    Ok(Timing::measure(
+        (day.year, day.day, variant.part),
+        variant.name,
+        runs,
+        || {
+            let _ = std::hint::black_box((variant.solve)(&input));
+        },
+    ))
+}
+
+/// Fastest and median of `runs` timed runs per variant, after one untimed run
+/// to warm caches and catch errors, with its speedup over the part's reference
+/// variant and, given a baseline, how it compares with that.
+#[tracing::instrument(skip(solutions))]
+fn bench(
+    solutions: &[(&Day, Vec<Variant>)],
+    runs: usize,
+    save: Option<&Path>,
+    baseline: Option<&Path>,
+    tolerance: u32,
+    stable: bool,
+) -> miette::Result<()> {
+    let baseline = baseline.map(Baseline::load).transpose()?;
+    let tolerance_fraction = tolerance as f64 / 100.0;
+
+    let mut timings = vec![];
+    for (day, variants) in solutions {
+        for variant in variants {
+            timings.push(time_variant(day, variant, runs, stable)?);
+        }
+    }
+
+    let mut headers = vec!["day", "variant", "fastest", "median", "speedup"];
+    if baseline.is_some() {
+        headers.push("vs baseline");
+    }
+    let mut table = Table::new(&headers);
+    for column in 2..headers.len() {
+        table = table.align(column, Align::Right);
+    }
+
+    let mut regressions = 0;
+    for timing in &timings {
+        let mut row = vec![
+            format!("{} day {:02}", timing.year, timing.day),
+            timing.variant.clone(),
+            format!("{:?}", timing.fastest()),
+            format!("{:?}", timing.median()),
+            timing
+                .speedup(&timings)
+                .map_or("-".to_string(), |speedup| format!("{:.2}x", speedup)),
+        ];
+
+        if let Some(baseline) = &baseline {
+            row.push(match baseline.regression(timing, tolerance_fraction) {
+                Some(slowdown) => {
+                    regressions += 1;
+                    format!("{:.0}% slower", slowdown * 100.0)
+                }
+                None => "ok".to_string(),
+            });
+        }
+
+        table.row(row);
+    }
+
+    print!("{}", table.render());
+
+    if let Some(path) = save {
+        Baseline { timing: timings }.save(path)?;
+        println!("Saved timings to {}", path.display());
+    }
+
+    if regressions > 0 {
+        return Err(Error::BenchRegressed {
+            count: regressions,
+            percent: tolerance,
+        }
+        .into());
+    }
+
+    Ok(())
+}
+
+/// Every day's parts timed from a warm start with their fast variants, one
+/// day at a time with a progress bar, then summarised for the year.
+#[tracing::instrument]
+fn summary(year: u16, runs: usize, json: bool) -> miette::Result<()> {
+    let bar = Backend::stderr(false);
+    let days = registry::days()
+        .into_iter()
+        .filter(|day| day.year == year)
+        .collect::<Vec<_>>();
+
+    if days.is_empty() {
+        return Err(Error::NothingSelected {
+            selector: year.to_string(),
+        }
+        .into());
+    }
+
+    let mut progress = Progress::new(days.len());
+    let mut timings = vec![];
+    for day in &days {
+        progress.draw(bar, &format!("day {:02}", day.day));
+        for variant in [1, 2].iter().filter_map(|part| day.fast_variant(*part)) {
+            timings.push(time_variant(day, variant, runs, true)?);
+        }
+        progress.advance();
+        progress.clear(bar);
+    }
+
+    let summary = YearSummary { year, timings };
+    if json {
+        println!("{}", summary.to_json());
+    } else {
+        print!("{}", summary.render());
+    }
+
+    Ok(())
+}
+
+#[tracing::instrument]
+fn viz(day: &Day, source: &InputSource, no_color: bool) -> miette::Result<()> {
+    let render = day.viz()?;
+    let input = source.read()?;
+    let ansi = !no_color && std::io::stdout().is_terminal();
+
+    print!("{}", render(&input, ansi)?);
+
+    Ok(())
+}
+
+/// Unselected lines are blanked rather than removed so the explanation keeps
+/// the input's line numbers.
+#[tracing::instrument]
+fn explain(day: &Day, part: u8, lines: &[usize], no_color: bool) -> miette::Result<()> {
+    let render = day.explain()?;
+    let input = day.read_input(part)?;
+    let ansi = !no_color && std::io::stdout().is_terminal();
+
+    let line_count = input.lines().count();
+    if let Some(line) = lines.iter().find(|line| **line == 0 || **line > line_count) {
+        return Err(Error::NoSuchLine {
+            path: day.input_path(part).display().to_string(),
+            line: *line,
+        }
+        .into());
+    }
+
+    let input = if lines.is_empty() {
+        input
+    } else {
+        input
+            .lines()
+            .enumerate()
+            .map(|(i, text)| if lines.contains(&(i + 1)) { text } else { "" })
+            .collect::<Vec<_>>()
+            .join("\n")
+    };
+
+    print!("{}", render(&input, ansi)?);
+
+    Ok(())
+}
+
+#[tracing::instrument]
+fn inputs(days: &[Day]) {
+    for day in days {
+        let names = day
+            .inputs()
+            .into_iter()
+            .map(|(name, _)| name)
+            .collect::<Vec<_>>();
+
+        if names.is_empty() {
+            println!("{} day {:02}: no inputs", day.year, day.day);
+        } else {
+            println!("{} day {:02}: {}", day.year, day.day, names.join(", "));
+        }
+    }
+}
+
+#[tracing::instrument]
+fn attempts(days: &[Day]) {
+    for day in days {
+        println!("{} day {:02}", day.year, day.day);
+
+        if day.attempts.is_empty() {
+            println!("  no attempts recorded");
+            continue;
+        }
+
+        let mut table =
+            Table::new(&["variant", "date", "median", "outcome"]).align(2, Align::Right);
+        for attempt in day.attempts {
+            table.row(vec![
+                attempt.name.to_string(),
+                attempt.date.to_string(),
+                attempt
+                    .timing
+                    .map_or("-".to_string(), |timing| format!("{:?}", timing)),
+                attempt.outcome.to_string(),
+            ]);
+        }
+
+        print!("{}", table.render());
+    }
+}
+
+#[tracing::instrument]
+fn fetch(year: u16, day: u8, offline: bool, output: Option<PathBuf>) -> miette::Result<()> {
+    let source = if offline {
+        InputSource::Cached { year, day }
+    } else {
+        InputSource::Url { year, day }
+    };
+    let input = source.read()?;
+
+    match output {
+        Some(path) => std::fs::write(path, input).map_err(Error::from)?,
+        None => print!("{}", input),
+    }
+
+    Ok(())
+}
+
+#[tracing::instrument]
+fn audit_determinism(year: u16, day: Option<u8>, threads: &[usize]) -> miette::Result<()> {
+    let threads = match threads {
+        [first, second] => [Some(*first), Some(*second)],
+        _ => [None, None],
+    };
+    let out = Backend::stdout(false);
+    let bar = Backend::stderr(false);
+    let mut summary = Summary::default();
+
+    let days = registry::days()
+        .into_iter()
+        .filter(|d| d.year == year && day.is_none_or(|day| d.day == day))
+        .collect::<Vec<_>>();
+    let mut progress = Progress::new(days.len());
+
+    for day in days {
+        progress.draw(bar, &format!("day {:02}", day.day));
+        let checks = determinism::audit_day(&day, threads)?;
+        progress.advance();
+        progress.clear(bar);
+
+        for check in checks {
+            let label = format!(
+                "{} day {:02} part {} {}",
+                check.year, check.day, check.part, check.name
+            );
+
+            if check.deterministic() {
+                summary.record(Status::Ok);
+                println!("{}", out.status(Status::Ok, &label, "ok"));
+                continue;
+            }
+
+            summary.record(Status::Fail);
+            let text = if check.first.contains('\n') || check.second.contains('\n') {
+                "output differs".to_string()
+            } else {
+                format!("{} then {}", check.first, check.second)
+            };
+            println!("{}", out.status(Status::Fail, &label, &text));
+        }
+    }
+
+    println!("{}", summary.render(out));
+
+    if !summary.passed() {
+        return Err(Error::Nondeterministic {
+            count: summary.fail,
+        }
+        .into());
+    }
+
+    Ok(())
+}
+
+#[tracing::instrument]
+fn smoke(year: Option<u16>) -> miette::Result<()> {
+    let out = Backend::stdout(false);
+    let mut summary = Summary::default();
+    let start = Instant::now();
+
+    for day in registry::days()
+        .iter()
+        .filter(|d| year.is_none_or(|year| d.year == year))
+    {
+        for result in examples::smoke(day) {
+            let label = format!(
+                "{} day {:02} part {} {}",
+                result.year, result.day, result.part, result.variant
+            );
+
+            if result.passed() {
+                summary.record(Status::Ok);
+                println!("{}", out.status(Status::Ok, &label, "ok"));
+                continue;
+            }
+
+            summary.record(Status::Fail);
+            let text = match &result.actual {
+                Ok(actual) => format!("expected {}, got {}", result.expected, actual),
+                Err(report) => report.clone(),
+            };
+            println!("{}", out.status(Status::Fail, &label, &text));
+        }
+    }
+
+    println!("{} in {:?}", summary.render(out), start.elapsed());
+
+    if !summary.passed() {
+        return Err(Error::SmokeFailed {
+            count: summary.fail,
+        }
+        .into());
+    }
+
+    Ok(())
+}
+
+#[tracing::instrument]
+fn scratch(
+    file: &PathBuf,
+    input: Option<PathBuf>,
+    day: Option<(u16, u8, u8)>,
+) -> miette::Result<()> {
+    let input = match (input, day) {
+        (Some(path), _) => path,
+        (None, Some((year, day, part))) => registry::find_day(year, day)?.input_path(part),
+        (None, None) => unreachable!("clap requires --input or --day"),
+    };
+
+    Scratch::from_path(file)?.run(&input)?;
+
+    Ok(())
+}
+
+#[tracing::instrument]
+fn new_day(year: u16, day: u8) -> miette::Result<()> {
+    let dir = Scaffold::new(year, day)?.write(&workspace::root())?;
+
+    println!("created {}", dir.display());
+    println!("add its worked examples to [package.metadata.aoc] in its Cargo.toml");
+
+    Ok(())
+}
+
+#[tracing::instrument]
+fn telemetry_summary() -> miette::Result<()> {
+    let records = RunLog::new(&RunLog::default_path()).read()?;
+
+    if records.is_empty() {
+        println!(
+            "no runs logged, set {}=1 to start logging",
+            stats::STATS_VAR
+        );
+    } else {
+        print!("{}", stats::render_summary(&records));
+    }
+
+    Ok(())
+}
+
+fn main() -> miette::Result<()> {
+    let cli = Cli::parse();
+    let _telemetry = telemetry::install(cli.trace, &cli.log, cli.flame.as_deref())?;
+
+    match cli.command {
+        Command::Run {
+            selection,
+            day,
+            part,
+            variant,
+            submit_format,
+            expansion,
+            input,
+            strategy,
+        } => {
+            for day in select(selection, day)? {
+                if let Some(factor) = expansion {
+                    for part in part.parts() {
+                        let source = input_source(&day, part, input.as_deref());
+                        run_expansion(&day, &source, part, factor, submit_format)?;
+                    }
+                    continue;
+                }
+
+                let variants = match strategy {
+                    Strategy::Default => variants(&day, &part.parts(), variant.as_deref())?,
+                    Strategy::Brute => day.brute_force(&part.parts())?,
+                };
+                for variant in variants {
+                    let source = input_source(&day, variant.part, input.as_deref());
+                    run_variant(&day, &variant, &source, submit_format)?;
+                }
+            }
+        }
+        Command::Verify {
+            selection,
+            day,
+            part,
+            expected,
+            variant,
+            record,
+            answers,
+        } => {
+            let parts = part.map_or(vec![1, 2], |part| vec![part]);
+            let targets = select(selection, day)?
+                .into_iter()
+                .flat_map(|day| parts.iter().map(move |part| (day.clone(), *part)))
+                .collect::<Vec<_>>();
+
+            verify::verify_all(&targets, |day, part| {
+                verify(
+                    day,
+                    part,
+                    &expected,
+                    variant.as_deref(),
+                    record,
+                    answers.as_deref(),
+                )
+            })?;
+        }
+        Command::Viz {
+            selection,
+            day,
+            part,
+            input,
+            no_color,
+        } => {
+            let days = select(selection, day)?;
+            let single = days.len() == 1;
+
+            for day in days.iter().filter(|day| single || day.viz.is_some()) {
+                let source = input_source(day, part, input.as_deref());
+                viz(day, &source, no_color)?;
+            }
+        }
+        Command::Bench {
+            selection,
+            day,
+            part,
+            variant,
+            runs,
+            all_variants,
+            save,
+            baseline,
+            tolerance,
+            stable,
+        } => {
+            let days = select(selection, day)?;
+            let solutions = days
+                .iter()
+                .map(|day| {
+                    let variants = if all_variants {
+                        all_variants_of(day, &part.parts())
+                    } else {
+                        variants(day, &part.parts(), variant.as_deref())?
+                    };
+                    Ok((day, variants))
+                })
+                .collect::<miette::Result<Vec<_>>>()?;
+
+            bench(
+                &solutions,
+                runs,
+                save.as_deref(),
+                baseline.as_deref(),
+                tolerance,
+                stable,
+            )?
+        }
+        Command::Summary { year, runs, json } => summary(year, runs, json)?,
+        Command::Explain {
+            year,
+            day,
+            part,
+            line,
+            no_color,
+        } => explain(&registry::find_day(year, day)?, part, &line, no_color)?,
+        Command::Inputs { selection, day } => inputs(&select(selection, day)?),
+        Command::Attempts { selection, day } => attempts(&select(selection, day)?),
+        Command::Fetch {
+            year,
+            day,
+            offline,
+            output,
+        } => fetch(year, day, offline, output)?,
+        Command::AuditDeterminism { year, day, threads } => audit_determinism(year, day, &threads)?,
+        Command::Smoke { year } => smoke(year)?,
+        Command::Scratch {
+            file,
+            input,
+            day,
+            year,
+            part,
+        } => scratch(&file, input, day.map(|day| (year, day, part)))?,
+        Command::New { year, day } => new_day(year, day)?,
+        Command::Serve => serve::serve(std::io::stdin().lock(), std::io::stdout().lock())?,
+        Command::Telemetry { command } => match command {
+            TelemetryCommand::Summary => telemetry_summary()?,
+        },
+    }
+
+    Ok(())
+}
@@ -0,0 +1,375 @@
+use std::path::PathBuf;
+
+use aoc_core::{Answer, Attempt};
+
+use crate::{error::Error, input::InputSource, prelude::*};
+
+pub type SolveFn = fn(&str) -> miette::Result<Answer>;
+
+/// Parses the input and solves one part through the day's `aoc_core::Solution`.
+pub type SolutionFn = fn(&str, u8) -> miette::Result<Answer>;
+
+/// Solves with a custom galaxy expansion factor instead of the part's fixed one.
+pub type ExpansionFn = fn(&str, u64) -> miette::Result<Answer>;
+
+/// Renders a terminal visualisation of the input, with ANSI colours when the flag is set.
+pub type VizFn = fn(&str, bool) -> miette::Result<String>;
+
+/// Shows the intermediate working for each input line, with ANSI colours when the flag is set.
+pub type ExplainFn = fn(&str, bool) -> miette::Result<String>;
+
+#[derive(Debug, Clone, Copy)]
+pub struct Variant {
+    pub part: u8,
+    pub name: &'static str,
+    pub solve: SolveFn,
+}
+
+/// A worked example from the puzzle text, with the answer in submit format.
+#[derive(Debug, Clone, Copy)]
+pub struct Example {
+    pub part: u8,
+    pub input: &'static str,
+    pub expected: &'static str,
+}
+
+#[derive(Debug, Clone)]
+pub struct Day {
+    pub year: u16,
+    pub day: u8,
+    pub solution: SolutionFn,
+    pub variants: Vec<Variant>,
+    /// Slow cross-checks, kept out of `variants` so sweeps over every variant
+    /// don't wait on them.
+    pub brute_force: Vec<Variant>,
+    /// Variants named to stand in for a part's reference when the whole
+    /// year is timed or verified, because the reference can't finish on
+    /// the real input.
+    pub fast: Vec<&'static str>,
+    pub examples: Vec<Example>,
+    pub expansion: Option<ExpansionFn>,
+    pub viz: Option<VizFn>,
+    pub explain: Option<ExplainFn>,
+    /// The day's changelog of variants tried, oldest first.
+    pub attempts: &'static [Attempt],
+}
+
+impl Day {
+    #[tracing::instrument]
+    pub fn crate_dir(&self) -> PathBuf {
+        PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("..")
+            .join(format!("day-{:02}", self.day))
+    }
+
+    #[tracing::instrument]
+    pub fn input_path(&self, part: u8) -> PathBuf {
+        self.crate_dir().join(format!("input{}.txt", part))
+    }
+
+    #[tracing::instrument]
+    pub fn input_source(&self, part: u8) -> InputSource {
+        InputSource::File(self.input_path(part))
+    }
+
+    #[tracing::instrument]
+    pub fn read_input(&self, part: u8) -> Result<String> {
+        self.input_source(part).read()
+    }
+
+    /// Every `.txt` beside the day's `Cargo.toml`, named by its stem like
+    /// `example2` or `input1`, in name order. Real inputs may not be in a
+    /// fresh clone, so this is whatever is there now.
+    #[tracing::instrument]
+    pub fn inputs(&self) -> Vec<(String, PathBuf)> {
+        let mut inputs = std::fs::read_dir(self.crate_dir())
+            .into_iter()
+            .flatten()
+            .filter_map(|entry| Some(entry.ok()?.path()))
+            .filter(|path| path.extension().is_some_and(|extension| extension == "txt"))
+            .filter_map(|path| Some((path.file_stem()?.to_str()?.to_string(), path)))
+            .collect::<Vec<_>>();
+
+        inputs.sort();
+        inputs
+    }
+
+    /// The input named `name` among `inputs`, as `--input example2` picks it.
+    #[tracing::instrument]
+    pub fn named_input(&self, name: &str) -> Option<InputSource> {
+        self.inputs()
+            .into_iter()
+            .find(|(input, _)| input == name)
+            .map(|(_, path)| InputSource::File(path))
+    }
+
+    /// The plain `partN` module is the reference implementation for a part.
+    #[tracing::instrument]
+    pub fn default_variant(&self, part: u8) -> Option<&Variant> {
+        let name = format!("part{}", part);
+        self.variants.iter().find(|v| v.name == name)
+    }
+
+    /// The variant to time or verify a part with: the one named in `fast`
+    /// for it if there is one, otherwise the reference.
+    #[tracing::instrument]
+    pub fn fast_variant(&self, part: u8) -> Option<&Variant> {
+        self.variants
+            .iter()
+            .find(|v| v.part == part && self.fast.contains(&v.name))
+            .or_else(|| self.default_variant(part))
+    }
+
+    /// The tiny input `runner smoke` uses for a part: its first worked example.
+    #[tracing::instrument]
+    pub fn smoke_input(&self, part: u8) -> Option<&Example> {
+        self.examples.iter().find(|e| e.part == part)
+    }
+
+    #[tracing::instrument]
+    pub fn expansion(&self) -> Result<ExpansionFn> {
+        self.expansion.ok_or(Error::ExpansionNotSupported {
+            year: self.year,
+            day: self.day,
+        })
+    }
+
+    /// The brute force variants of `parts`, erroring when there are none.
+    #[tracing::instrument]
+    pub fn brute_force(&self, parts: &[u8]) -> Result<Vec<Variant>> {
+        let variants = self
+            .brute_force
+            .iter()
+            .filter(|v| parts.contains(&v.part))
+            .copied()
+            .collect::<Vec<_>>();
+
+        if variants.is_empty() {
+            return Err(Error::BruteForceNotSupported {
+                year: self.year,
+                day: self.day,
+            });
+        }
+
+        Ok(variants)
+    }
+
+    #[tracing::instrument]
+    pub fn viz(&self) -> Result<VizFn> {
+        self.viz.ok_or(Error::VizNotSupported {
+            year: self.year,
+            day: self.day,
+        })
+    }
+
+    #[tracing::instrument]
+    pub fn explain(&self) -> Result<ExplainFn> {
+        self.explain.ok_or(Error::ExplainNotSupported {
+            year: self.year,
+            day: self.day,
+        })
+    }
+
+    #[tracing::instrument]
+    pub fn variant(&self, name: &str) -> Result<&Variant> {
+        self.variants
+            .iter()
+            .chain(&self.brute_force)
+            .find(|v| v.name == name)
+            .ok_or_else(|| Error::UnknownVariant {
+                year: self.year,
+                day: self.day,
+                variant: name.to_string(),
+            })
+    }
+}
+
+macro_rules! example {
+    ($part:expr, $path:literal, $expected:literal) => {
+        Example {
+            part: $part,
+            input: include_str!($path),
+            expected: $expected,
+        }
+    };
+}
+
+macro_rules! variant {
+    ($part:expr, $name:literal, $process:path) => {
+        Variant {
+            part: $part,
+            name: $name,
+            solve: |input| $process(input).map_err(Into::into),
+        }
+    };
+}
+
+/// Every solved day in (year, day) order, generated by `build.rs` from the
+/// `[package.metadata.aoc]` table in each day crate's `Cargo.toml`.
+#[tracing::instrument]
+pub fn days() -> Vec<Day> {
+    include!(concat!(env!("OUT_DIR"), "/days.rs"))
+}
+
+#[tracing::instrument]
+pub fn find_day(year: u16, day: u8) -> Result<Day> {
+    days()
+        .into_iter()
+        .find(|d| d.year == year && d.day == day)
+        .ok_or(Error::UnknownDay { year, day })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn it_should_find_registered_day() -> miette::Result<()> {
+        let day = find_day(2023, 11)?;
+        assert_eq!(11, day.day);
+        assert_eq!(
+            "part1",
+            day.default_variant(1).map(|v| v.name).unwrap_or("")
+        );
+        assert_eq!(
+            "part2",
+            day.default_variant(2).map(|v| v.name).unwrap_or("")
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_have_smoke_input_for_every_part() -> miette::Result<()> {
+        for day in days() {
+            for part in [1, 2] {
+                assert!(
+                    day.smoke_input(part).is_some(),
+                    "day {} part {} has no smoke input",
+                    day.day,
+                    part
+                );
+            }
+        }
+        assert_eq!(
+            Some("374"),
+            find_day(2023, 11)?.smoke_input(1).map(|e| e.expected)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_index_each_day_once_in_order() -> miette::Result<()> {
+        let keys = days()
+            .iter()
+            .map(|day| (day.year, day.day))
+            .collect::<Vec<_>>();
+
+        let mut sorted = keys.clone();
+        sorted.sort();
+        sorted.dedup();
+
+        assert_eq!(sorted, keys);
+        assert_eq!(Some(&(2023, 1)), keys.first());
+        assert!(find_day(2023, 2)?.viz.is_some());
+        assert!(find_day(2023, 9)?.explain.is_some());
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_name_every_input_beside_the_manifest() -> miette::Result<()> {
+        let day = find_day(2023, 8)?;
+        let names = day
+            .inputs()
+            .into_iter()
+            .map(|(name, _)| name)
+            .collect::<Vec<_>>();
+
+        assert!(names.contains(&"example1".to_string()));
+        assert!(names.contains(&"example2".to_string()));
+        assert_eq!(
+            Some(InputSource::File(day.crate_dir().join("example2.txt"))),
+            day.named_input("example2")
+        );
+        assert_eq!(None, day.named_input("example9"));
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_error_for_unknown_day() -> miette::Result<()> {
+        assert!(matches!(
+            find_day(2023, 10),
+            Err(Error::UnknownDay {
+                year: 2023,
+                day: 10
+            })
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_solve_with_expansion_factor() -> miette::Result<()> {
+        let day = find_day(2023, 11)?;
+        let input = "...#......
+        .......#..
+        #.........
+        ..........
+        ......#...
+        .#........
+        .........#
+        ..........
+        .......#..
+        #...#.....";
+        assert_eq!(Answer::UInt(1030), (day.expansion()?)(input, 10)?);
+        assert!(matches!(
+            find_day(2023, 9)?.expansion(),
+            Err(Error::ExpansionNotSupported { year: 2023, day: 9 })
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_solve_through_variant() -> miette::Result<()> {
+        let day = find_day(2023, 9)?;
+        let variant = day.variant("part1")?;
+        let answer = (variant.solve)("0 3 6 9 12 15\n1 3 6 10 15 21\n10 13 16 21 30 45")?;
+        assert_eq!(Answer::Int(114), answer);
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_keep_brute_force_out_of_the_variants() -> miette::Result<()> {
+        let day = find_day(2023, 5)?;
+        let input = include_str!("../../day-05/example1.txt");
+
+        assert!(day.variants.iter().all(|v| v.name != "part2_bruteforce"));
+        assert_eq!("part2_bruteforce", day.variant("part2_bruteforce")?.name);
+        assert_eq!(
+            vec!["part2_bruteforce"],
+            day.brute_force(&[1, 2])?
+                .iter()
+                .map(|v| v.name)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(Answer::UInt(46), (day.brute_force(&[2])?[0].solve)(input)?);
+        assert!(matches!(
+            day.brute_force(&[1]),
+            Err(Error::BruteForceNotSupported { year: 2023, day: 5 })
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_stand_fast_variants_in_for_references() -> miette::Result<()> {
+        let day = find_day(2023, 5)?;
+
+        assert_eq!(Some("part1"), day.fast_variant(1).map(|v| v.name));
+        assert_eq!(Some("part2_opt"), day.fast_variant(2).map(|v| v.name));
+        assert_eq!(Some("part2"), day.default_variant(2).map(|v| v.name));
+        assert_eq!(
+            Some("part2"),
+            find_day(2023, 9)?.fast_variant(2).map(|v| v.name)
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,182 @@
+use std::path::{Path, PathBuf};
+
+use crate::{error::Error, prelude::*};
+
+/// The year the workspace started with. Its days keep the short `day-NN`
+/// names; other years get `day-YYYY-NN` so they don't collide.
+pub const FIRST_YEAR: u16 = 2023;
+
+macro_rules! template {
+    ($($path:literal),* $(,)?) => {
+        [$(($path, include_str!(concat!("../../daily-template/", $path)))),*]
+    };
+}
+
+/// `daily-template`, baked in so `runner new` works from any directory, keyed
+/// by each file's path in the new day's crate.
+const TEMPLATE: [(&str, &str); 11] = template!(
+    "Cargo.toml",
+    "src/lib.rs",
+    "src/error.rs",
+    "src/prelude.rs",
+    "src/part1.rs",
+    "src/part2.rs",
+    "src/bin/part1.rs",
+    "src/bin/part2.rs",
+    "benches/benchmarks.rs",
+    "input1.txt",
+    "input2.txt",
+);
+
+/// A new day's crate, generated from `daily-template` and added to the runner.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Scaffold {
+    pub year: u16,
+    pub day: u8,
+}
+
+impl Scaffold {
+    #[tracing::instrument]
+    pub fn new(year: u16, day: u8) -> Result<Self> {
+        if !(1..=25).contains(&day) {
+            return Err(Error::NoSuchPuzzle { year, day });
+        }
+
+        Ok(Self { year, day })
+    }
+
+    #[tracing::instrument]
+    pub fn package(&self) -> String {
+        if self.year == FIRST_YEAR {
+            format!("day-{:02}", self.day)
+        } else {
+            format!("day-{}-{:02}", self.year, self.day)
+        }
+    }
+
+    #[tracing::instrument]
+    fn render(&self, template: &str) -> String {
+        let package = self.package();
+
+        template
+            .replace("{{project-name}}", &package)
+            .replace("{{crate_name}}", &package.replace('-', "_"))
+            .replace("{{year}}", &self.year.to_string())
+            .replace("{{day}}", &self.day.to_string())
+            .replace("{{solution}}", &format!("Day{:02}", self.day))
+    }
+
+    /// Writes the crate into `root` and makes it a dependency of the runner,
+    /// returning the crate's directory. Never overwrites an existing day.
+    #[tracing::instrument]
+    pub fn write(&self, root: &Path) -> Result<PathBuf> {
+        let dir = root.join(self.package());
+        if dir.exists() {
+            return Err(Error::DayExists {
+                path: dir.display().to_string(),
+            });
+        }
+
+        let runner_manifest = root.join("runner").join("Cargo.toml");
+        let runner = self.with_dependency(&std::fs::read_to_string(&runner_manifest)?)?;
+
+        for (path, template) in TEMPLATE {
+            let path = dir.join(path);
+            if let Some(parent) = path.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+            std::fs::write(path, self.render(template))?;
+        }
+
+        std::fs::write(runner_manifest, runner)?;
+
+        Ok(dir)
+    }
+
+    /// `source`, the runner's manifest, with this day added to its dependencies.
+    #[tracing::instrument(skip(source))]
+    fn with_dependency(&self, source: &str) -> Result<String> {
+        let mut document =
+            source
+                .parse::<toml_edit::DocumentMut>()
+                .map_err(|e| Error::InvalidRunnerManifest {
+                    reason: e.message().to_string(),
+                })?;
+
+        let dependencies = document
+            .get_mut("dependencies")
+            .and_then(|d| d.as_table_mut())
+            .ok_or_else(|| Error::InvalidRunnerManifest {
+                reason: "no [dependencies] table".to_string(),
+            })?;
+
+        let mut path = toml_edit::InlineTable::new();
+        path.insert("path", format!("../{}", self.package()).into());
+        dependencies.insert(&self.package(), toml_edit::value(path));
+
+        Ok(document.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn it_should_name_days_by_year() -> miette::Result<()> {
+        assert_eq!("day-12", Scaffold::new(2023, 12)?.package());
+        assert_eq!("day-2024-01", Scaffold::new(2024, 1)?.package());
+        assert!(Scaffold::new(2024, 26).is_err());
+        assert!(Scaffold::new(2024, 0).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_fill_in_the_template() -> miette::Result<()> {
+        let scaffold = Scaffold::new(2024, 3)?;
+
+        assert_eq!(
+            "aoc_core::solution!(Day03, 2024, 3, part1::process, part2::process);",
+            scaffold
+                .render(TEMPLATE[1].1)
+                .lines()
+                .last()
+                .unwrap_or_default()
+        );
+        assert!(scaffold
+            .render(TEMPLATE[0].1)
+            .contains("name = \"day-2024-03\""));
+        assert!(TEMPLATE
+            .iter()
+            .all(|(_, template)| !scaffold.render(template).contains("{{")));
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_write_the_crate_and_depend_on_it() -> miette::Result<()> {
+        let root = std::env::temp_dir().join(format!("runner-scaffold-{}", std::process::id()));
+        std::fs::create_dir_all(root.join("runner")).map_err(Error::from)?;
+        std::fs::write(
+            root.join("runner/Cargo.toml"),
+            "[dependencies]\nday-01 = { path = \"../day-01\" }\n\n[build-dependencies]\n",
+        )
+        .map_err(Error::from)?;
+
+        let dir = Scaffold::new(2024, 1)?.write(&root)?;
+
+        assert!(dir.join("src/part2.rs").is_file());
+        assert!(dir.join("benches/benchmarks.rs").is_file());
+        assert_eq!(
+            "[dependencies]\nday-01 = { path = \"../day-01\" }\nday-2024-01 = { path = \"../day-2024-01\" }\n\n[build-dependencies]\n",
+            std::fs::read_to_string(root.join("runner/Cargo.toml")).map_err(Error::from)?
+        );
+        assert!(matches!(
+            Scaffold::new(2024, 1)?.write(&root),
+            Err(Error::DayExists { .. })
+        ));
+
+        std::fs::remove_dir_all(&root).map_err(Error::from)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,213 @@
+use std::{
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+use crate::{error::Error, prelude::*, workspace};
+
+/// Third-party crates a scratch solution can use, on top of every shared `aoc-*` crate.
+/// Versions come from the workspace so a promoted solution builds the same way.
+const SCRATCH_DEPENDENCIES: [&str; 10] = [
+    "glam",
+    "itertools",
+    "miette",
+    "nom",
+    "petgraph",
+    "rayon",
+    "regex",
+    "thiserror",
+    "tracing",
+    "gcd",
+];
+
+const MAIN_RS: &str = r#"#[path = SOURCE]
+mod solution;
+
+fn main() -> miette::Result<()> {
+    let path = std::env::args()
+        .nth(1)
+        .ok_or_else(|| miette::miette!("missing input path"))?;
+    let input = std::fs::read_to_string(&path).map_err(|e| miette::miette!("{}: {}", path, e))?;
+    println!("{}", solution::process(&input)?);
+    Ok(())
+}
+"#;
+
+/// A single-file solution exposing `pub fn process(input: &str) -> miette::Result<T>`
+/// where `T: Display`, the same shape as a day crate's `partN.rs`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Scratch {
+    pub name: String,
+    pub source: PathBuf,
+}
+
+impl Scratch {
+    #[tracing::instrument]
+    pub fn from_path(path: &Path) -> Result<Self> {
+        let source = path.canonicalize().map_err(|_| Error::ScratchNotFound {
+            path: path.display().to_string(),
+        })?;
+
+        let name = source
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .filter(|_| source.extension().is_some_and(|ext| ext == "rs"))
+            .map(|stem| format!("scratch-{}", stem.replace('_', "-")))
+            .ok_or_else(|| Error::ScratchNotFound {
+                path: path.display().to_string(),
+            })?;
+
+        Ok(Self { name, source })
+    }
+
+    /// A standalone package (its own `[workspace]`) so it can live under `target`.
+    #[tracing::instrument(skip(workspace_manifest))]
+    fn manifest(&self, workspace_manifest: &str, root: &Path) -> String {
+        let mut manifest = format!(
+            "[package]\nname = \"{}\"\nversion = \"0.0.0\"\nedition = \"2021\"\n\n[workspace]\n\n[dependencies]\n",
+            self.name
+        );
+
+        for line in workspace_dependencies(workspace_manifest) {
+            let name = line.split('=').next().unwrap_or("").trim();
+
+            if !name.starts_with("aoc-") && !SCRATCH_DEPENDENCIES.contains(&name) {
+                continue;
+            }
+
+            let line = line.replace("path = \"", &format!("path = \"{}/", root.display()));
+            manifest.push_str(&line);
+            manifest.push('\n');
+        }
+
+        manifest
+    }
+
+    #[tracing::instrument]
+    fn main_rs(&self) -> String {
+        MAIN_RS.replace(
+            "SOURCE",
+            &format!("{:?}", self.source.display().to_string()),
+        )
+    }
+
+    /// Writes the wrapper package into `dir`, reusing the workspace lockfile so
+    /// dependency versions match, and returns its manifest path.
+    #[tracing::instrument]
+    pub fn prepare(&self, dir: &Path) -> Result<PathBuf> {
+        let root = workspace::root();
+        let workspace_manifest = std::fs::read_to_string(root.join("Cargo.toml"))?;
+
+        std::fs::create_dir_all(dir.join("src"))?;
+
+        let manifest_path = dir.join("Cargo.toml");
+        std::fs::write(&manifest_path, self.manifest(&workspace_manifest, &root))?;
+        std::fs::write(dir.join("src").join("main.rs"), self.main_rs())?;
+
+        let lock = root.join("Cargo.lock");
+        if lock.exists() && !dir.join("Cargo.lock").exists() {
+            std::fs::copy(lock, dir.join("Cargo.lock"))?;
+        }
+
+        Ok(manifest_path)
+    }
+
+    /// Builds and runs the solution in release mode, streaming its output.
+    /// Every scratch package shares one target directory so dependencies build once.
+    #[tracing::instrument]
+    pub fn run(&self, input: &Path) -> Result<()> {
+        let scratch_root = workspace::target_dir().join("aoc-scratch");
+        let manifest_path = self.prepare(&scratch_root.join(&self.name))?;
+
+        let status = Command::new(std::env::var("CARGO").unwrap_or_else(|_| "cargo".to_string()))
+            .args(["run", "--release", "--quiet", "--manifest-path"])
+            .arg(&manifest_path)
+            .arg("--")
+            .arg(input)
+            .env("CARGO_TARGET_DIR", scratch_root.join("target"))
+            .status()?;
+
+        if !status.success() {
+            return Err(Error::ScratchFailed {
+                path: self.source.display().to_string(),
+            });
+        }
+
+        Ok(())
+    }
+}
+
+/// Lines of the `[workspace.dependencies]` table, one dependency per line.
+#[tracing::instrument(skip(manifest))]
+fn workspace_dependencies(manifest: &str) -> impl Iterator<Item = &str> {
+    manifest
+        .lines()
+        .skip_while(|line| line.trim() != "[workspace.dependencies]")
+        .skip(1)
+        .take_while(|line| !line.trim_start().starts_with('['))
+        .filter(|line| line.contains('='))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MANIFEST: &str = "[workspace]
+members = [\"day-*\"]
+
+[workspace.dependencies]
+itertools = \"0.12.0\"
+divan = \"0.1.3\"
+miette = { version = \"5.10\", features = [\"fancy\"] }
+aoc-core = { path = \"aoc-core\" }
+
+[profile.flamegraph]
+inherits = \"release\"";
+
+    fn scratch() -> Scratch {
+        Scratch {
+            name: "scratch-day-12".to_string(),
+            source: PathBuf::from("/aoc/scratch/day_12.rs"),
+        }
+    }
+
+    #[test]
+    fn it_should_only_depend_on_shared_crates() -> miette::Result<()> {
+        let manifest = scratch().manifest(MANIFEST, Path::new("/aoc"));
+
+        assert!(manifest.starts_with("[package]\nname = \"scratch-day-12\""));
+        assert!(manifest.contains("\n[workspace]\n"));
+        assert!(manifest.contains("itertools = \"0.12.0\"\n"));
+        assert!(manifest.contains("miette = { version = \"5.10\", features = [\"fancy\"] }\n"));
+        assert!(manifest.contains("aoc-core = { path = \"/aoc/aoc-core\" }\n"));
+        assert!(!manifest.contains("divan"));
+        assert!(!manifest.contains("flamegraph"));
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_wrap_solution_module() -> miette::Result<()> {
+        let main = scratch().main_rs();
+
+        assert!(main.starts_with("#[path = \"/aoc/scratch/day_12.rs\"]\nmod solution;"));
+        assert!(main.contains("solution::process(&input)?"));
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_reject_missing_or_non_rust_files() -> miette::Result<()> {
+        assert!(matches!(
+            Scratch::from_path(Path::new("scratch/does_not_exist.rs")),
+            Err(Error::ScratchNotFound { .. })
+        ));
+        assert!(matches!(
+            Scratch::from_path(
+                Path::new(env!("CARGO_MANIFEST_DIR"))
+                    .join("Cargo.toml")
+                    .as_path()
+            ),
+            Err(Error::ScratchNotFound { .. })
+        ));
+        Ok(())
+    }
+}
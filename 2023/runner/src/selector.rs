@@ -0,0 +1,289 @@
+use std::{fmt, str::FromStr};
+
+use crate::{error::Error, prelude::*, registry::Day};
+
+/// One comma-separated item of a selector's day list.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DaySpan {
+    One(u8),
+    Range(u8, u8),
+}
+
+impl DaySpan {
+    #[tracing::instrument]
+    fn contains(&self, day: u8) -> bool {
+        match self {
+            DaySpan::One(one) => *one == day,
+            DaySpan::Range(first, last) => (*first..=*last).contains(&day),
+        }
+    }
+}
+
+impl fmt::Display for DaySpan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DaySpan::One(day) => write!(f, "{}", day),
+            DaySpan::Range(first, last) => write!(f, "{}-{}", first, last),
+        }
+    }
+}
+
+/// Which days a command runs over: `2023` for every registered day of a year,
+/// `2023:5` (or `2023-05`, as the day's directories name it) for one, or comma
+/// lists of days and inclusive ranges such as `2023:1-10` and `2023:5,7,11`.
+/// Days named on their own must be registered; ranges just skip the ones that
+/// aren't.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Selector {
+    pub year: u16,
+    days: Option<Vec<DaySpan>>,
+}
+
+impl Selector {
+    #[tracing::instrument]
+    pub fn day(year: u16, day: u8) -> Self {
+        Self {
+            year,
+            days: Some(vec![DaySpan::One(day)]),
+        }
+    }
+
+    /// Applies the older `YEAR DAY` form, where the day follows as its own
+    /// argument.
+    #[tracing::instrument]
+    pub fn with_day(self, day: Option<u8>) -> Result<Self> {
+        match (day, &self.days) {
+            (None, _) => Ok(self),
+            (Some(day), None) => {
+                check_day(&self.to_string(), day)?;
+                Ok(Self::day(self.year, day))
+            }
+            (Some(day), Some(_)) => Err(Error::InvalidSelector {
+                selector: format!("{} {}", self, day),
+                reason: "days are already selected after the colon".to_string(),
+            }),
+        }
+    }
+
+    #[tracing::instrument]
+    pub fn contains(&self, year: u16, day: u8) -> bool {
+        year == self.year
+            && self
+                .days
+                .as_ref()
+                .is_none_or(|spans| spans.iter().any(|span| span.contains(day)))
+    }
+
+    /// The selected days out of `days`, in registry order.
+    #[tracing::instrument(skip(days))]
+    pub fn resolve(&self, days: Vec<Day>) -> Result<Vec<Day>> {
+        for span in self.days.iter().flatten() {
+            if let DaySpan::One(day) = span {
+                if !days.iter().any(|d| d.year == self.year && d.day == *day) {
+                    return Err(Error::UnknownDay {
+                        year: self.year,
+                        day: *day,
+                    });
+                }
+            }
+        }
+
+        let selected = days
+            .into_iter()
+            .filter(|d| self.contains(d.year, d.day))
+            .collect::<Vec<_>>();
+
+        if selected.is_empty() {
+            return Err(Error::NothingSelected {
+                selector: self.to_string(),
+            });
+        }
+
+        Ok(selected)
+    }
+}
+
+#[tracing::instrument]
+fn check_day(selector: &str, day: u8) -> Result<()> {
+    if (1..=25).contains(&day) {
+        return Ok(());
+    }
+
+    Err(Error::InvalidSelector {
+        selector: selector.to_string(),
+        reason: format!("day {} is not between 1 and 25", day),
+    })
+}
+
+#[tracing::instrument]
+fn parse_span(selector: &str, span: &str) -> Result<DaySpan> {
+    let day = |text: &str| -> Result<u8> {
+        let day = text
+            .trim()
+            .parse::<u8>()
+            .map_err(|_| Error::InvalidSelector {
+                selector: selector.to_string(),
+                reason: format!("{:?} is not a day", text),
+            })?;
+        check_day(selector, day)?;
+        Ok(day)
+    };
+
+    match span.split_once('-') {
+        None => Ok(DaySpan::One(day(span)?)),
+        Some((first, last)) => {
+            let (first, last) = (day(first)?, day(last)?);
+
+            if first > last {
+                return Err(Error::InvalidSelector {
+                    selector: selector.to_string(),
+                    reason: format!("range {}-{} runs backwards", first, last),
+                });
+            }
+
+            Ok(DaySpan::Range(first, last))
+        }
+    }
+}
+
+impl FromStr for Selector {
+    type Err = Error;
+
+    fn from_str(selector: &str) -> Result<Self> {
+        let separated = selector
+            .split_once(':')
+            .or_else(|| selector.split_once('-'));
+
+        let (year, days) = match separated {
+            Some((year, days)) => (year, Some(days)),
+            None => (selector, None),
+        };
+
+        let year = year
+            .trim()
+            .parse::<u16>()
+            .map_err(|_| Error::InvalidSelector {
+                selector: selector.to_string(),
+                reason: format!("{:?} is not a year", year),
+            })?;
+
+        let days = days
+            .map(|days| {
+                days.split(',')
+                    .map(|span| parse_span(selector, span))
+                    .collect::<Result<Vec<_>>>()
+            })
+            .transpose()?;
+
+        Ok(Self { year, days })
+    }
+}
+
+impl fmt::Display for Selector {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.year)?;
+
+        if let Some(spans) = &self.days {
+            let spans = spans.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+            write!(f, ":{}", spans.join(","))?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry::days;
+    use pretty_assertions::assert_eq;
+
+    fn selected(selector: &str) -> Result<Vec<u8>> {
+        Ok(selector
+            .parse::<Selector>()?
+            .resolve(days())?
+            .iter()
+            .map(|d| d.day)
+            .collect())
+    }
+
+    #[test]
+    fn it_should_parse_ranges_and_lists() -> miette::Result<()> {
+        assert_eq!(
+            Selector {
+                year: 2023,
+                days: Some(vec![DaySpan::Range(1, 3), DaySpan::One(7)]),
+            },
+            "2023:1-3,7".parse::<Selector>()?
+        );
+        assert_eq!(
+            Selector {
+                year: 2023,
+                days: None
+            },
+            "2023".parse::<Selector>()?
+        );
+        assert_eq!(Selector::day(2023, 5), "2023-05".parse::<Selector>()?);
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_round_trip_through_display() -> miette::Result<()> {
+        for selector in ["2023", "2023:5", "2023:1-10", "2023:5,7,11", "2022:1-3,9"] {
+            assert_eq!(selector, selector.parse::<Selector>()?.to_string());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_reject_malformed_selectors() -> miette::Result<()> {
+        for selector in [
+            "", "twenty:1", "2023:", "2023:0", "2023:26", "2023:5-3", "2023:1-", "2023-", "2023-00",
+        ] {
+            assert!(
+                matches!(
+                    selector.parse::<Selector>(),
+                    Err(Error::InvalidSelector { .. })
+                ),
+                "{:?} should be rejected",
+                selector
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_resolve_registered_days() -> miette::Result<()> {
+        assert_eq!(vec![1, 2, 3, 4, 5, 6, 7, 8, 9], selected("2023:1-10")?);
+        assert_eq!(vec![5, 7, 11], selected("2023:11,5,7")?);
+        assert_eq!(10, selected("2023")?.len());
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_reject_unregistered_days() -> miette::Result<()> {
+        assert!(matches!(
+            selected("2023:9,10"),
+            Err(Error::UnknownDay {
+                year: 2023,
+                day: 10
+            })
+        ));
+        assert!(matches!(
+            selected("2019:1-25"),
+            Err(Error::NothingSelected { .. })
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_take_day_as_separate_argument() -> miette::Result<()> {
+        let selector = "2023".parse::<Selector>()?.with_day(Some(9))?;
+        assert_eq!(Selector::day(2023, 9), selector);
+        assert!(matches!(
+            "2023:9".parse::<Selector>()?.with_day(Some(9)),
+            Err(Error::InvalidSelector { .. })
+        ));
+        Ok(())
+    }
+}
@@ -0,0 +1,404 @@
+//! `runner serve`: JSON-RPC 2.0 over stdio, one request or response per line,
+//! so an editor plugin can run, verify and time solutions without parsing the
+//! runner's human-readable output.
+
+use std::{
+    any::Any,
+    io::{BufRead, Write},
+    panic,
+    time::Instant,
+};
+
+use aoc_core::Answer;
+use serde::{de::DeserializeOwned, Deserialize};
+use serde_json::{json, Value};
+
+use crate::{
+    answers::Answers,
+    benchmark::Timing,
+    error::Error,
+    input::InputSource,
+    prelude::*,
+    registry::{self, Day, Variant},
+};
+
+pub const PARSE_ERROR: i64 = -32700;
+pub const INVALID_REQUEST: i64 = -32600;
+pub const METHOD_NOT_FOUND: i64 = -32601;
+pub const INVALID_PARAMS: i64 = -32602;
+/// The runner's own errors: unknown days, unreadable inputs, failed solutions.
+pub const SERVER_ERROR: i64 = -32000;
+
+/// Timed runs for `bench_quick` when the request doesn't say.
+const QUICK_BENCH_RUNS: usize = 3;
+
+#[derive(Debug, Deserialize)]
+struct Request {
+    #[serde(default)]
+    jsonrpc: String,
+    /// Missing for notifications, which get no response.
+    #[serde(default)]
+    id: Option<Value>,
+    method: String,
+    #[serde(default)]
+    params: Value,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct RpcError {
+    code: i64,
+    message: String,
+}
+
+impl RpcError {
+    fn new(code: i64, message: impl ToString) -> Self {
+        Self {
+            code,
+            message: message.to_string(),
+        }
+    }
+}
+
+impl From<Error> for RpcError {
+    fn from(error: Error) -> Self {
+        Self::new(SERVER_ERROR, error)
+    }
+}
+
+impl From<miette::Report> for RpcError {
+    fn from(report: miette::Report) -> Self {
+        Self::new(SERVER_ERROR, report)
+    }
+}
+
+type RpcResult = std::result::Result<Value, RpcError>;
+
+/// One solution: a part's reference variant, or the named one.
+#[derive(Debug, Deserialize)]
+struct Target {
+    year: u16,
+    day: u8,
+    part: u8,
+    #[serde(default)]
+    variant: Option<String>,
+}
+
+impl Target {
+    #[tracing::instrument]
+    fn resolve(&self) -> Result<(Day, Variant)> {
+        let day = registry::find_day(self.year, self.day)?;
+        let variant = match &self.variant {
+            Some(name) => *day.variant(name)?,
+            None => *day
+                .default_variant(self.part)
+                .ok_or_else(|| Error::UnknownVariant {
+                    year: self.year,
+                    day: self.day,
+                    variant: format!("part{}", self.part),
+                })?,
+        };
+
+        Ok((day, variant))
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct RunParams {
+    #[serde(flatten)]
+    target: Target,
+    /// Text to solve instead of the stored input, like an unsaved buffer.
+    #[serde(default)]
+    input: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct BenchParams {
+    #[serde(flatten)]
+    target: Target,
+    #[serde(default)]
+    runs: Option<usize>,
+}
+
+#[derive(Debug, Deserialize)]
+struct FetchParams {
+    year: u16,
+    day: u8,
+    #[serde(default)]
+    offline: bool,
+}
+
+#[tracing::instrument(skip(params))]
+fn params<T: DeserializeOwned>(params: Value) -> std::result::Result<T, RpcError> {
+    serde_json::from_value(params).map_err(|e| RpcError::new(INVALID_PARAMS, e))
+}
+
+/// Runs `variant` on `input`, turning a panic into an error response so one
+/// broken solution doesn't take the server down with it.
+#[tracing::instrument(skip(input))]
+fn solve(variant: &Variant, input: &str) -> std::result::Result<Answer, RpcError> {
+    match panic::catch_unwind(|| (variant.solve)(input)) {
+        Ok(answer) => Ok(answer?),
+        Err(payload) => Err(RpcError::new(
+            SERVER_ERROR,
+            format!("{} panicked: {}", variant.name, panic_message(&*payload)),
+        )),
+    }
+}
+
+#[tracing::instrument(skip(payload))]
+fn panic_message(payload: &(dyn Any + Send)) -> &str {
+    match payload.downcast_ref::<&str>() {
+        Some(message) => message,
+        None => payload
+            .downcast_ref::<String>()
+            .map(String::as_str)
+            .unwrap_or("no message"),
+    }
+}
+
+/// `{ year, day, part, variant?, input? }` to `{ variant, answer, elapsed_ns }`.
+#[tracing::instrument(skip(params), fields(target = ?params.target))]
+fn run(params: RunParams) -> RpcResult {
+    let (day, variant) = params.target.resolve()?;
+    let source = match params.input {
+        Some(text) => InputSource::Inline(text),
+        None => day.input_source(variant.part),
+    };
+    let input = source.read()?;
+
+    let start = Instant::now();
+    let answer = solve(&variant, &input)?;
+    let elapsed = start.elapsed();
+
+    Ok(json!({
+        "variant": variant.name,
+        "answer": answer.to_submit_string(),
+        "elapsed_ns": elapsed.as_nanos() as u64,
+    }))
+}
+
+/// `{ year, day, part, variant? }` to `{ variant, answer, passed, matched }`,
+/// checked against answers.toml. A wrong answer is a result, not an error.
+#[tracing::instrument]
+fn verify(target: Target) -> RpcResult {
+    let (day, variant) = target.resolve()?;
+    let input = day.read_input(variant.part)?;
+    let answers = Answers::load()?;
+    let accepted = answers.find(day.year, day.day, variant.part)?;
+
+    let answer = solve(&variant, &input)?;
+    let matched = accepted.matching(&answer);
+
+    Ok(json!({
+        "variant": variant.name,
+        "answer": answer.to_submit_string(),
+        "passed": matched.is_some(),
+        "matched": matched,
+    }))
+}
+
+/// `{ year, day, part, variant?, runs? }` to `{ variant, fastest_ns, median_ns }`
+/// over a few runs, after one untimed run to catch errors.
+#[tracing::instrument]
+fn bench_quick(params: BenchParams) -> RpcResult {
+    let (day, variant) = params.target.resolve()?;
+    let input = day.read_input(variant.part)?;
+    solve(&variant, &input)?;
+
+    let timing = Timing::measure(
+        (day.year, day.day, variant.part),
+        variant.name,
+        params.runs.unwrap_or(QUICK_BENCH_RUNS),
+        || {
+            let _ = std::hint::black_box((variant.solve)(&input));
+        },
+    );
+
+    Ok(json!({
+        "variant": variant.name,
+        "fastest_ns": timing.fastest_ns,
+        "median_ns": timing.median_ns,
+    }))
+}
+
+/// `{ year, day, offline? }` to `{ input }`, through the same cache as `runner fetch`.
+#[tracing::instrument]
+fn fetch_input(params: FetchParams) -> RpcResult {
+    let (year, day) = (params.year, params.day);
+    let source = if params.offline {
+        InputSource::Cached { year, day }
+    } else {
+        InputSource::Url { year, day }
+    };
+
+    Ok(json!({ "input": source.read()? }))
+}
+
+#[tracing::instrument(skip(request))]
+fn dispatch(request: Request) -> RpcResult {
+    if request.jsonrpc != "2.0" {
+        return Err(RpcError::new(INVALID_REQUEST, "jsonrpc must be \"2.0\""));
+    }
+
+    match request.method.as_str() {
+        "run" => run(params(request.params)?),
+        "verify" => verify(params(request.params)?),
+        "bench_quick" => bench_quick(params(request.params)?),
+        "fetch" => fetch_input(params(request.params)?),
+        "shutdown" => Ok(Value::Null),
+        method => Err(RpcError::new(
+            METHOD_NOT_FOUND,
+            format!("no method {}", method),
+        )),
+    }
+}
+
+/// The response to one line, if it needs one, and whether to stop serving.
+#[tracing::instrument]
+fn respond(line: &str) -> (Option<Value>, bool) {
+    let request = match serde_json::from_str::<Request>(line) {
+        Ok(request) => request,
+        Err(e) => {
+            let code = if e.is_data() {
+                INVALID_REQUEST
+            } else {
+                PARSE_ERROR
+            };
+            let error = RpcError::new(code, e);
+            return (Some(response(Value::Null, Err(error))), false);
+        }
+    };
+
+    let id = request.id.clone();
+    let shutdown = request.method == "shutdown";
+    let result = dispatch(request);
+
+    (id.map(|id| response(id, result)), shutdown)
+}
+
+#[tracing::instrument]
+fn response(id: Value, result: RpcResult) -> Value {
+    match result {
+        Ok(result) => json!({ "jsonrpc": "2.0", "id": id, "result": result }),
+        Err(error) => json!({
+            "jsonrpc": "2.0",
+            "id": id,
+            "error": { "code": error.code, "message": error.message },
+        }),
+    }
+}
+
+/// Answers requests from `input` until it closes or a `shutdown` request
+/// arrives, flushing each response so the client never waits on a buffer.
+#[tracing::instrument(skip(input, output))]
+pub fn serve(input: impl BufRead, mut output: impl Write) -> Result<()> {
+    for line in input.lines() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let (response, shutdown) = respond(&line);
+        if let Some(response) = response {
+            writeln!(output, "{}", response)?;
+            output.flush()?;
+        }
+
+        if shutdown {
+            break;
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn serve_lines(lines: &[&str]) -> Result<Vec<Value>> {
+        let mut output = vec![];
+        serve(lines.join("\n").as_bytes(), &mut output)?;
+
+        Ok(String::from_utf8_lossy(&output)
+            .lines()
+            .map(|line| serde_json::from_str(line).expect("responses should be JSON"))
+            .collect())
+    }
+
+    #[test]
+    fn it_should_report_protocol_errors_with_their_codes() -> miette::Result<()> {
+        let responses = serve_lines(&[
+            "{ not json",
+            r#"{"jsonrpc": "2.0", "id": 1}"#,
+            r#"{"jsonrpc": "1.0", "id": 2, "method": "run"}"#,
+            r#"{"jsonrpc": "2.0", "id": 3, "method": "solve"}"#,
+            r#"{"jsonrpc": "2.0", "id": 4, "method": "run", "params": {"year": 2023}}"#,
+            r#"{"jsonrpc": "2.0", "id": 5, "method": "run", "params": {"year": 2023, "day": 25, "part": 1}}"#,
+        ])?;
+
+        assert_eq!(
+            vec![
+                (Value::Null, PARSE_ERROR),
+                (Value::Null, INVALID_REQUEST),
+                (json!(2), INVALID_REQUEST),
+                (json!(3), METHOD_NOT_FOUND),
+                (json!(4), INVALID_PARAMS),
+                (json!(5), SERVER_ERROR),
+            ],
+            responses
+                .iter()
+                .map(|r| (r["id"].clone(), r["error"]["code"].as_i64().unwrap_or(0)))
+                .collect::<Vec<_>>()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_run_against_inline_input() -> miette::Result<()> {
+        let responses = serve_lines(&[
+            r#"{"jsonrpc": "2.0", "id": 1, "method": "run", "params": {"year": 2023, "day": 9, "part": 1, "input": "0 3 6 9 12 15"}}"#,
+        ])?;
+
+        assert_eq!(json!("18"), responses[0]["result"]["answer"]);
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_report_a_panicking_solution_as_an_error() -> miette::Result<()> {
+        let variant = Variant {
+            part: 1,
+            name: "part1_broken",
+            solve: |_| panic!("ran off the grid"),
+        };
+
+        assert_eq!(
+            Err(RpcError::new(
+                SERVER_ERROR,
+                "part1_broken panicked: ran off the grid"
+            )),
+            solve(&variant, "")
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_skip_notifications_and_stop_on_shutdown() -> miette::Result<()> {
+        let responses = serve_lines(&[
+            r#"{"jsonrpc": "2.0", "method": "shutdown"}"#,
+            r#"{"jsonrpc": "2.0", "id": 1, "method": "shutdown"}"#,
+        ])?;
+        assert_eq!(Vec::<Value>::new(), responses);
+
+        let responses = serve_lines(&[
+            r#"{"jsonrpc": "2.0", "id": 1, "method": "shutdown"}"#,
+            r#"{"jsonrpc": "2.0", "id": 2, "method": "shutdown"}"#,
+        ])?;
+        assert_eq!(
+            vec![json!({ "jsonrpc": "2.0", "id": 1, "result": null })],
+            responses
+        );
+        Ok(())
+    }
+}
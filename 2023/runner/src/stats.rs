@@ -0,0 +1,266 @@
+use std::{
+    collections::BTreeMap,
+    io::Write,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use aoc_ui::{Align, Table};
+
+use crate::{error::Error, prelude::*, workspace};
+
+/// Set to `1` to have every solve appended to the local run log.
+pub const STATS_VAR: &str = "AOC_TELEMETRY";
+
+/// Widest bar in the summary chart, for the slowest variant.
+const CHART_WIDTH: usize = 30;
+
+/// One solve, with nothing about the input or who ran it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RunRecord {
+    pub year: u16,
+    pub day: u8,
+    pub variant: String,
+    pub duration_ns: u64,
+    pub success: bool,
+}
+
+impl RunRecord {
+    #[tracing::instrument]
+    fn to_line(&self) -> String {
+        format!(
+            "{}\t{}\t{}\t{}\t{}\n",
+            self.year,
+            self.day,
+            self.variant,
+            self.duration_ns,
+            if self.success { "ok" } else { "failed" }
+        )
+    }
+
+    #[tracing::instrument]
+    fn parse(line: &str) -> Result<Self> {
+        let invalid = || Error::InvalidRunLog {
+            line: line.to_string(),
+        };
+
+        let [year, day, variant, duration_ns, outcome] = line
+            .split('\t')
+            .collect::<Vec<_>>()
+            .try_into()
+            .map_err(|_| invalid())?;
+
+        Ok(Self {
+            year: year.parse().map_err(|_| invalid())?,
+            day: day.parse().map_err(|_| invalid())?,
+            variant: variant.to_string(),
+            duration_ns: duration_ns.parse().map_err(|_| invalid())?,
+            success: match outcome {
+                "ok" => true,
+                "failed" => false,
+                _ => return Err(invalid()),
+            },
+        })
+    }
+}
+
+/// Append-only, tab-separated log of runs, kept under `target` so it never
+/// leaves the machine.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RunLog {
+    path: PathBuf,
+}
+
+impl RunLog {
+    #[tracing::instrument]
+    pub fn new(path: &Path) -> Self {
+        Self {
+            path: path.to_path_buf(),
+        }
+    }
+
+    /// `target/aoc-telemetry.log`, when `AOC_TELEMETRY=1` opts in.
+    #[tracing::instrument]
+    pub fn opted_in() -> Option<Self> {
+        std::env::var(STATS_VAR)
+            .is_ok_and(|value| value == "1")
+            .then(Self::default_path)
+            .map(|path| Self::new(&path))
+    }
+
+    #[tracing::instrument]
+    pub fn default_path() -> PathBuf {
+        workspace::target_dir().join("aoc-telemetry.log")
+    }
+
+    #[tracing::instrument(skip(self))]
+    pub fn append(&self, record: &RunRecord) -> Result<()> {
+        if let Some(dir) = self.path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+
+        let mut file = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        file.write_all(record.to_line().as_bytes())?;
+
+        Ok(())
+    }
+
+    /// Every record so far, or none if nothing has been logged yet.
+    #[tracing::instrument(skip(self))]
+    pub fn read(&self) -> Result<Vec<RunRecord>> {
+        match std::fs::read_to_string(&self.path) {
+            Ok(source) => source
+                .lines()
+                .filter(|line| !line.is_empty())
+                .map(RunRecord::parse)
+                .collect(),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(vec![]),
+            Err(e) => Err(e.into()),
+        }
+    }
+}
+
+/// Runs of one variant, folded together.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct VariantStats {
+    pub runs: usize,
+    pub failed: usize,
+    durations_ns: Vec<u64>,
+}
+
+impl VariantStats {
+    #[tracing::instrument(skip(self))]
+    pub fn median(&self) -> Duration {
+        let mut durations = self.durations_ns.clone();
+        durations.sort();
+
+        Duration::from_nanos(durations.get(durations.len() / 2).copied().unwrap_or(0))
+    }
+}
+
+/// Stats for every variant in the log, by year, day and variant name.
+#[tracing::instrument(skip(records))]
+pub fn aggregate(records: &[RunRecord]) -> BTreeMap<(u16, u8, String), VariantStats> {
+    let mut stats = BTreeMap::<_, VariantStats>::new();
+
+    for record in records {
+        let entry = stats
+            .entry((record.year, record.day, record.variant.clone()))
+            .or_default();
+        entry.runs += 1;
+        if record.success {
+            entry.durations_ns.push(record.duration_ns);
+        } else {
+            entry.failed += 1;
+        }
+    }
+
+    stats
+}
+
+/// A table of runs, failures and median time per variant, with a bar scaled
+/// so the slowest median fills the chart.
+#[tracing::instrument(skip(records))]
+pub fn render_summary(records: &[RunRecord]) -> String {
+    let stats = aggregate(records);
+    let slowest = stats
+        .values()
+        .map(|s| s.median().as_nanos())
+        .max()
+        .unwrap_or(0)
+        .max(1);
+
+    let mut table = Table::new(&["day", "variant", "runs", "failed", "median", "chart"])
+        .align(2, Align::Right)
+        .align(3, Align::Right)
+        .align(4, Align::Right);
+
+    for ((year, day, variant), stats) in &stats {
+        let median = stats.median();
+        let bar = (median.as_nanos() * CHART_WIDTH as u128).div_ceil(slowest) as usize;
+
+        table.row(vec![
+            format!("{} day {:02}", year, day),
+            variant.clone(),
+            stats.runs.to_string(),
+            stats.failed.to_string(),
+            format!("{:?}", median),
+            "#".repeat(bar),
+        ]);
+    }
+
+    format!(
+        "{}{} runs of {} variants\n",
+        table.render(),
+        records.len(),
+        stats.len()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn record(day: u8, variant: &str, duration_ns: u64, success: bool) -> RunRecord {
+        RunRecord {
+            year: 2023,
+            day,
+            variant: variant.to_string(),
+            duration_ns,
+            success,
+        }
+    }
+
+    #[test]
+    fn it_should_round_trip_records_through_the_log() -> miette::Result<()> {
+        let path = std::env::temp_dir().join(format!("runner-stats-{}.log", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        let log = RunLog::new(&path);
+
+        assert_eq!(Vec::<RunRecord>::new(), log.read()?);
+
+        let records = vec![
+            record(6, "part1", 1_200, true),
+            record(6, "part2", 0, false),
+        ];
+        for r in &records {
+            log.append(r)?;
+        }
+        assert_eq!(records, log.read()?);
+
+        std::fs::remove_file(&path).map_err(Error::from)?;
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_reject_malformed_lines() -> miette::Result<()> {
+        assert!(RunRecord::parse("2023\t6\tpart1\tfast\tok").is_err());
+        assert!(RunRecord::parse("2023\t6\tpart1\t10").is_err());
+        assert!(RunRecord::parse("2023\t6\tpart1\t10\tmaybe").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_summarise_each_variant() -> miette::Result<()> {
+        let records = vec![
+            record(6, "part1", 1_000, true),
+            record(6, "part1", 3_000, true),
+            record(6, "part1", 0, false),
+            record(9, "part2", 500, true),
+        ];
+
+        assert_eq!(
+            "day          variant  runs  failed  median  chart
+2023 day 06  part1       3       1     3µs  ##############################
+2023 day 09  part2       1       0   500ns  #####
+4 runs of 2 variants
+",
+            render_summary(&records)
+        );
+        Ok(())
+    }
+}
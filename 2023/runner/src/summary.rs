@@ -0,0 +1,236 @@
+use std::{collections::BTreeMap, time::Duration};
+
+use aoc_ui::{Align, Table};
+use serde::Serialize;
+
+use crate::benchmark::Timing;
+
+/// The community challenge: every part of a year, run one after another, in
+/// under a second.
+pub const BUDGET: Duration = Duration::from_secs(1);
+
+/// Solutions named as the slowest in the summary.
+const SLOWEST: usize = 3;
+
+/// Widest bar in the per-day chart, for the slowest day.
+const CHART_WIDTH: usize = 30;
+
+/// A year's default solutions timed as `bench --stable` times them, added up
+/// to see how the whole year does against the one second budget.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct YearSummary {
+    pub year: u16,
+    pub timings: Vec<Timing>,
+}
+
+/// One day's parts added together.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DayTotal {
+    pub day: u8,
+    pub total_ns: u64,
+}
+
+/// Everything the summary reports, in the shape `--json` prints it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+struct Report<'a> {
+    year: u16,
+    total_ns: u64,
+    budget_ns: u64,
+    within_budget: bool,
+    slowest: Vec<&'a Timing>,
+    days: Vec<DayTotal>,
+    timings: &'a [Timing],
+}
+
+impl YearSummary {
+    /// Every part's median, as if they ran back to back.
+    #[tracing::instrument(skip(self), fields(year = self.year))]
+    pub fn total(&self) -> Duration {
+        Duration::from_nanos(self.timings.iter().map(|t| t.median_ns).sum())
+    }
+
+    #[tracing::instrument(skip(self), fields(year = self.year))]
+    pub fn within_budget(&self) -> bool {
+        self.total() < BUDGET
+    }
+
+    /// The `count` slowest solutions by median, slowest first.
+    #[tracing::instrument(skip(self), fields(year = self.year))]
+    pub fn slowest(&self, count: usize) -> Vec<&Timing> {
+        let mut timings = self.timings.iter().collect::<Vec<_>>();
+        timings.sort_by_key(|t| std::cmp::Reverse(t.median_ns));
+        timings.truncate(count);
+        timings
+    }
+
+    /// Each day's parts added together, in day order.
+    #[tracing::instrument(skip(self), fields(year = self.year))]
+    pub fn days(&self) -> Vec<DayTotal> {
+        let mut totals = BTreeMap::<u8, u64>::new();
+        for timing in &self.timings {
+            *totals.entry(timing.day).or_default() += timing.median_ns;
+        }
+
+        totals
+            .into_iter()
+            .map(|(day, total_ns)| DayTotal { day, total_ns })
+            .collect()
+    }
+
+    #[tracing::instrument(skip(self), fields(year = self.year))]
+    fn report(&self) -> Report<'_> {
+        Report {
+            year: self.year,
+            total_ns: self.total().as_nanos() as u64,
+            budget_ns: BUDGET.as_nanos() as u64,
+            within_budget: self.within_budget(),
+            slowest: self.slowest(SLOWEST),
+            days: self.days(),
+            timings: &self.timings,
+        }
+    }
+
+    #[tracing::instrument(skip(self), fields(year = self.year))]
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(&self.report()).expect("summaries should serialise")
+    }
+
+    /// The total against the budget, the slowest solutions, then a bar per
+    /// day scaled so the slowest day fills the chart.
+    #[tracing::instrument(skip(self), fields(year = self.year))]
+    pub fn render(&self) -> String {
+        let report = self.report();
+        let verdict = if report.within_budget {
+            "within"
+        } else {
+            "over"
+        };
+        let mut output = format!(
+            "{}: {} solutions in {:?}, {} the {:?} budget\n\n",
+            self.year,
+            self.timings.len(),
+            self.total(),
+            verdict,
+            BUDGET
+        );
+
+        let mut slowest = Table::new(&["slowest", "variant", "median"]).align(2, Align::Right);
+        for timing in &report.slowest {
+            slowest.row(vec![
+                format!("{} day {:02}", timing.year, timing.day),
+                timing.variant.clone(),
+                format!("{:?}", timing.median()),
+            ]);
+        }
+        output.push_str(&slowest.render());
+        output.push('\n');
+
+        let longest = report
+            .days
+            .iter()
+            .map(|day| day.total_ns)
+            .max()
+            .unwrap_or(0)
+            .max(1);
+        let mut days = Table::new(&["day", "total", "chart"]).align(1, Align::Right);
+        for day in &report.days {
+            let bar = (day.total_ns as u128 * CHART_WIDTH as u128).div_ceil(longest as u128);
+            days.row(vec![
+                format!("{} day {:02}", self.year, day.day),
+                format!("{:?}", Duration::from_nanos(day.total_ns)),
+                "#".repeat(bar as usize),
+            ]);
+        }
+        output.push_str(&days.render());
+
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn timing(day: u8, part: u8, median_ns: u64) -> Timing {
+        Timing {
+            year: 2023,
+            day,
+            part,
+            variant: format!("part{}", part),
+            fastest_ns: median_ns,
+            median_ns,
+        }
+    }
+
+    fn summary() -> YearSummary {
+        YearSummary {
+            year: 2023,
+            timings: vec![
+                timing(1, 1, 1_000),
+                timing(1, 2, 2_000),
+                timing(5, 1, 500),
+                timing(5, 2, 6_000),
+                timing(9, 1, 3_000),
+            ],
+        }
+    }
+
+    #[test]
+    fn it_should_total_every_part_against_the_budget() -> miette::Result<()> {
+        let summary = summary();
+
+        assert_eq!(Duration::from_nanos(12_500), summary.total());
+        assert!(summary.within_budget());
+        assert_eq!(
+            vec![(5, 2), (9, 1), (1, 2)],
+            summary
+                .slowest(3)
+                .iter()
+                .map(|t| (t.day, t.part))
+                .collect::<Vec<_>>()
+        );
+
+        let slow = YearSummary {
+            year: 2023,
+            timings: vec![timing(1, 1, 600_000_000), timing(2, 1, 400_000_000)],
+        };
+        assert!(!slow.within_budget());
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_render_a_bar_per_day() -> miette::Result<()> {
+        assert_eq!(
+            "2023: 5 solutions in 12.5µs, within the 1s budget
+
+slowest      variant  median
+2023 day 05  part2       6µs
+2023 day 09  part1       3µs
+2023 day 01  part2       2µs
+
+day          total  chart
+2023 day 01    3µs  ##############
+2023 day 05  6.5µs  ##############################
+2023 day 09    3µs  ##############
+",
+            summary().render()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_report_the_same_as_json() -> miette::Result<()> {
+        let json = serde_json::from_str::<serde_json::Value>(&summary().to_json())
+            .map_err(|e| miette::miette!("{}", e))?;
+
+        assert_eq!(12_500, json["total_ns"]);
+        assert_eq!(1_000_000_000, json["budget_ns"]);
+        assert_eq!(true, json["within_budget"]);
+        assert_eq!("part2", json["slowest"][0]["variant"]);
+        assert_eq!(5, json["days"][1]["day"]);
+        assert_eq!(6_500, json["days"][1]["total_ns"]);
+        assert_eq!(5, json["timings"].as_array().map_or(0, Vec::len));
+        Ok(())
+    }
+}
@@ -0,0 +1,141 @@
+use std::{fs::File, io::BufWriter, path::Path};
+
+use tracing_flame::{FlameLayer, FlushGuard};
+use tracing_subscriber::{
+    filter::Directive, fmt::format::FmtSpan, layer::SubscriberExt, util::SubscriberInitExt,
+    EnvFilter,
+};
+
+use crate::{error::Error, prelude::*};
+
+/// Keeps the flamegraph file open until the run is over; dropping it flushes
+/// whatever spans are still buffered.
+#[derive(Default)]
+pub struct Telemetry {
+    _flame: Option<FlushGuard<BufWriter<File>>>,
+}
+
+/// `--log` values as one filter, each a level like `info` or a directive like
+/// `day_05=trace` as `RUST_LOG` would take it. A value can also hold several,
+/// separated by commas. `None` when there are none, so nothing is filtered.
+#[tracing::instrument]
+pub fn filter(log: &[String]) -> Result<Option<EnvFilter>> {
+    let directives = log
+        .iter()
+        .flat_map(|value| value.split(','))
+        .map(str::trim)
+        .filter(|directive| !directive.is_empty())
+        .map(|directive| {
+            directive
+                .parse::<Directive>()
+                .map_err(|e| Error::InvalidLogFilter {
+                    directive: directive.to_string(),
+                    reason: e.to_string(),
+                })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    if directives.is_empty() {
+        return Ok(None);
+    }
+
+    Ok(Some(
+        directives
+            .into_iter()
+            .fold(EnvFilter::default(), EnvFilter::add_directive),
+    ))
+}
+
+/// Installs the global subscriber for the solutions' `#[tracing::instrument]`
+/// spans and events. With `log`, events its filter lets through print to
+/// stderr, and only the spans it lets through are timed or flamegraphed. With
+/// `timing`, every span prints how long it was busy and idle to stderr as it
+/// closes. With `flame`, spans are also written there as folded stacks, ready
+/// for `inferno-flamegraph`. Installs nothing when none are asked for, so
+/// untraced runs pay nothing for the instrumentation.
+#[tracing::instrument]
+pub fn install(timing: bool, log: &[String], flame: Option<&Path>) -> Result<Telemetry> {
+    let filter = filter(log)?;
+
+    if !timing && filter.is_none() && flame.is_none() {
+        return Ok(Telemetry::default());
+    }
+
+    let span_events = if timing {
+        FmtSpan::CLOSE
+    } else {
+        FmtSpan::NONE
+    };
+    let output = (timing || filter.is_some()).then(|| {
+        tracing_subscriber::fmt::layer()
+            .with_writer(std::io::stderr)
+            .with_span_events(span_events)
+            .with_target(filter.is_some())
+    });
+
+    let (flame, guard) = match flame {
+        Some(path) => {
+            let (layer, guard) = FlameLayer::with_file(path).map_err(|e| Error::Telemetry {
+                reason: e.to_string(),
+            })?;
+            (Some(layer), Some(guard))
+        }
+        None => (None, None),
+    };
+
+    tracing_subscriber::registry()
+        .with(output)
+        .with(flame)
+        .with(filter)
+        .try_init()
+        .map_err(|e| Error::Telemetry {
+            reason: e.to_string(),
+        })?;
+
+    Ok(Telemetry { _flame: guard })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn log(values: &[&str]) -> Vec<String> {
+        values.iter().map(|value| value.to_string()).collect()
+    }
+
+    #[test]
+    fn it_should_leave_runs_unfiltered_without_log() -> miette::Result<()> {
+        assert!(filter(&[])?.is_none());
+        assert!(filter(&log(&["", " , "]))?.is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_combine_every_log_directive() -> miette::Result<()> {
+        let filter = filter(&log(&["info", "day_05=trace,runner=off"]))?
+            .expect("expected a filter")
+            .to_string();
+
+        for directive in ["info", "day_05=trace", "runner=off"] {
+            assert!(
+                filter.contains(directive),
+                "{:?} in {:?}",
+                directive,
+                filter
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_reject_unknown_levels() -> miette::Result<()> {
+        let Err(Error::InvalidLogFilter { directive, .. }) = filter(&log(&["info", "day_05=loud"]))
+        else {
+            panic!("expected loud not to be a level");
+        };
+
+        assert_eq!("day_05=loud", directive);
+        Ok(())
+    }
+}
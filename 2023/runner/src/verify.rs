@@ -0,0 +1,131 @@
+use miette::Diagnostic;
+use thiserror::Error;
+
+use crate::{error::Error, prelude::*, registry::Day};
+
+/// One part that didn't verify, with the chain of errors behind it on one line.
+#[derive(Error, Diagnostic, Debug, Clone, PartialEq, Eq)]
+#[error("{year} day {day:02} part {part}: {reason}")]
+#[diagnostic(code(aoc::verify_failure))]
+pub struct PartFailure {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub reason: String,
+}
+
+impl PartFailure {
+    #[tracing::instrument]
+    pub fn new(day: &Day, part: u8, report: &miette::Report) -> Self {
+        Self {
+            year: day.year,
+            day: day.day,
+            part,
+            reason: report
+                .chain()
+                .map(|e| e.to_string())
+                .collect::<Vec<_>>()
+                .join(": "),
+        }
+    }
+}
+
+/// Runs `verify` on every `(day, part)`, carrying on past failures so one run
+/// shows everything that's wrong with a year, then reports them together.
+#[tracing::instrument(skip(targets, verify))]
+pub fn verify_all(
+    targets: &[(Day, u8)],
+    mut verify: impl FnMut(&Day, u8) -> miette::Result<()>,
+) -> Result<()> {
+    let failures = targets
+        .iter()
+        .filter_map(|(day, part)| {
+            verify(day, *part)
+                .err()
+                .map(|report| PartFailure::new(day, *part, &report))
+        })
+        .collect::<Vec<_>>();
+
+    if !failures.is_empty() {
+        return Err(Error::VerifyFailed {
+            count: failures.len(),
+            total: targets.len(),
+            failures,
+        });
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{answers::Answers, registry};
+    use pretty_assertions::assert_eq;
+
+    /// Day 6 part 1's example answer is 288, and day 9 part 2 has no entry.
+    const WRONG_MANIFEST: &str = "[[answers]]
+year = 2023
+day = 6
+part = 1
+accepted = { submitted = \"289\" }
+
+[[answers]]
+year = 2023
+day = 9
+part = 1
+accepted = { submitted = \"114\" }
+";
+
+    #[test]
+    fn it_should_report_every_wrong_manifest_entry() -> miette::Result<()> {
+        let answers = Answers::parse(WRONG_MANIFEST)?;
+        let targets = [
+            (registry::find_day(2023, 6)?, 1),
+            (registry::find_day(2023, 9)?, 1),
+            (registry::find_day(2023, 9)?, 2),
+        ];
+
+        let result = verify_all(&targets, |day, part| {
+            let accepted = answers.find(day.year, day.day, part)?;
+            let example = day.smoke_input(part).map(|e| e.input).unwrap_or_default();
+            let actual = (day.solution)(example, part)?;
+
+            accepted.matching(&actual).ok_or(Error::WrongAnswer {
+                year: day.year,
+                day: day.day,
+                variant: format!("part{}", part),
+                expected: accepted.describe(),
+                actual: actual.to_submit_string(),
+            })?;
+            Ok(())
+        });
+
+        let Err(Error::VerifyFailed {
+            count,
+            total,
+            failures,
+        }) = result
+        else {
+            panic!("expected the failures to be gathered");
+        };
+
+        assert_eq!((2, 3), (count, total));
+        assert_eq!(
+            vec![
+                "2023 day 06 part 1: 2023 day 6 part1 answered 288 but expected 289",
+                "2023 day 09 part 2: No accepted answers for 2023 day 9 part 2, add them to answers.toml or pass --expected",
+            ],
+            failures.iter().map(|f| f.to_string()).collect::<Vec<_>>()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_pass_when_every_part_verifies() -> miette::Result<()> {
+        let targets = [(registry::find_day(2023, 9)?, 1)];
+
+        verify_all(&targets, |_, _| Ok(()))?;
+        Ok(())
+    }
+}
@@ -0,0 +1,17 @@
+use std::path::{Path, PathBuf};
+
+#[tracing::instrument]
+pub fn root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .unwrap_or(Path::new(".."))
+        .to_path_buf()
+}
+
+/// The workspace `target` directory, honouring `CARGO_TARGET_DIR` when it is set.
+#[tracing::instrument]
+pub fn target_dir() -> PathBuf {
+    std::env::var_os("CARGO_TARGET_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| root().join("target"))
+}
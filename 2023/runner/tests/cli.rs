@@ -0,0 +1,506 @@
+use std::{
+    io::Write,
+    process::{Command, Stdio},
+};
+
+use pretty_assertions::assert_eq;
+
+fn run(args: &[&str]) -> String {
+    let output = Command::new(env!("CARGO_BIN_EXE_runner"))
+        .args(args)
+        .output()
+        .expect("runner should start");
+
+    assert!(
+        output.status.success(),
+        "runner {:?} failed: {}",
+        args,
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    String::from_utf8_lossy(&output.stdout).to_string()
+}
+
+#[test]
+fn it_should_answer_part1_with_expansion_of_two() {
+    let expanded = run(&[
+        "run",
+        "2023",
+        "11",
+        "--part",
+        "1",
+        "--expansion",
+        "2",
+        "--submit-format",
+    ]);
+    let part1 = run(&["run", "2023", "11", "--part", "1", "--submit-format"]);
+    assert_eq!(part1, expanded);
+}
+
+#[test]
+fn it_should_answer_part2_with_expansion_of_one_million() {
+    let expanded = run(&[
+        "run",
+        "2023",
+        "11",
+        "--part",
+        "2",
+        "--expansion",
+        "1000000",
+        "--submit-format",
+    ]);
+    let part2 = run(&["run", "2023", "11", "--part", "2", "--submit-format"]);
+    assert_eq!(part2, expanded);
+}
+
+#[test]
+fn it_should_reject_expansion_for_other_days() {
+    let output = Command::new(env!("CARGO_BIN_EXE_runner"))
+        .args(["run", "2023", "9", "--expansion", "10"])
+        .output()
+        .expect("runner should start");
+
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("expansion factor"));
+}
+
+#[test]
+fn it_should_read_input_from_stdin() {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_runner"))
+        .args([
+            "run",
+            "2023",
+            "9",
+            "--part",
+            "1",
+            "--input",
+            "-",
+            "--submit-format",
+        ])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .expect("runner should start");
+
+    child
+        .stdin
+        .take()
+        .expect("stdin should be piped")
+        .write_all(b"0 3 6 9 12 15\n1 3 6 10 15 21\n10 13 16 21 30 45\n")
+        .expect("input should be written");
+    let output = child.wait_with_output().expect("runner should finish");
+
+    assert!(output.status.success());
+    assert_eq!("114\n", String::from_utf8_lossy(&output.stdout));
+}
+
+#[test]
+fn it_should_reject_brute_force_for_days_without_it() {
+    let output = Command::new(env!("CARGO_BIN_EXE_runner"))
+        .args(["run", "2023", "9", "--strategy", "brute"])
+        .output()
+        .expect("runner should start");
+
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("no brute force variant"));
+}
+
+#[test]
+fn it_should_draw_day_2_viz() {
+    let output = run(&["viz", "2023", "2", "--no-color"]);
+    assert!(output.starts_with("Game 1"));
+    assert!(!output.contains('\x1b'));
+    assert_eq!(100 * 3, output.lines().count());
+}
+
+#[test]
+fn it_should_draw_day_6_viz() {
+    let output = run(&["viz", "2023", "6", "--no-color"]);
+    assert!(output.starts_with("Race 1: time 41, record 249, wins"));
+    assert_eq!(4, output.matches("Race ").count());
+    assert!(!output.contains('\x1b'));
+}
+
+#[test]
+fn it_should_write_failure_bundle_for_wrong_answer() {
+    let target = std::env::temp_dir().join(format!("runner-cli-failures-{}", std::process::id()));
+
+    let output = Command::new(env!("CARGO_BIN_EXE_runner"))
+        .args(["verify", "2023", "9", "--part", "1", "--expected", "1"])
+        .env("CARGO_TARGET_DIR", &target)
+        .output()
+        .expect("runner should start");
+
+    assert!(!output.status.success());
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let path = stderr
+        .lines()
+        .find_map(|line| line.strip_prefix("failure bundle written to "))
+        .expect("runner should print the bundle path");
+
+    assert!(path.starts_with(target.join("aoc-failures").to_str().unwrap_or_default()));
+
+    let summary = std::fs::read_to_string(std::path::Path::new(path).join("summary.txt"))
+        .expect("bundle should have a summary");
+    assert!(summary.contains("variant: part1"));
+    assert!(summary.contains("expected: 1\n"));
+
+    let explain = std::fs::read_to_string(std::path::Path::new(path).join("explain.txt"))
+        .expect("day 9 bundles should be explained");
+    assert!(explain.starts_with("Line 1: previous "));
+
+    std::fs::remove_dir_all(&target).expect("bundle should be removable");
+}
+
+#[test]
+fn it_should_pass_verify_for_right_answer() {
+    let part1 = run(&["run", "2023", "9", "--part", "1", "--submit-format"]);
+    run(&[
+        "verify",
+        "2023",
+        "9",
+        "--part",
+        "1",
+        "--expected",
+        part1.trim(),
+    ]);
+}
+
+#[test]
+fn it_should_run_scratch_solution() {
+    let input = std::env::temp_dir().join(format!("runner-cli-scratch-{}.txt", std::process::id()));
+    std::fs::write(&input, "1 2 3\n4 5\n").expect("write scratch input");
+
+    let scratch = concat!(env!("CARGO_MANIFEST_DIR"), "/../scratch/example.rs");
+    let output = run(&[
+        "scratch",
+        scratch,
+        "--input",
+        input.to_str().unwrap_or_default(),
+    ]);
+
+    assert_eq!("15", output.trim());
+    std::fs::remove_file(&input).ok();
+}
+
+#[test]
+fn it_should_report_which_accepted_answer_matched() {
+    let part1 = run(&["run", "2023", "9", "--part", "1", "--submit-format"]);
+    let output = run(&[
+        "verify",
+        "2023",
+        "9",
+        "--part",
+        "1",
+        "--expected",
+        "exclusive=1",
+        "--expected",
+        &format!("inclusive={}", part1.trim()),
+    ]);
+
+    assert!(output.contains("matched accepted answer inclusive"));
+}
+
+#[test]
+fn it_should_verify_against_answers_manifest() {
+    let output = run(&["verify", "2023", "6", "--part", "1"]);
+
+    assert!(output.contains("matched accepted answer submitted"));
+}
+
+#[test]
+fn it_should_verify_day_5_part_2_with_its_fast_variant() {
+    let output = run(&["verify", "2023", "5", "--part", "2"]);
+
+    assert!(output.contains("2023 day 05 part2_opt"));
+    assert!(output.contains("matched accepted answer submitted"));
+}
+
+#[test]
+fn it_should_verify_every_part_of_a_range_of_days() {
+    let output = run(&["verify", "2023:6-7"]);
+
+    assert_eq!(4, output.matches("matched accepted answer").count());
+    assert!(output.contains("2023 day 07 part2"));
+}
+
+#[test]
+fn it_should_report_every_part_that_fails_to_verify() {
+    let dir = std::env::temp_dir().join(format!("runner-cli-verify-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).expect("create manifest dir");
+    let manifest = dir.join("answers.toml");
+    std::fs::write(
+        &manifest,
+        "[[answers]]\nyear = 2023\nday = 1\npart = 1\naccepted = { submitted = \"1\" }\n\n\
+         [[answers]]\nyear = 2023\nday = 1\npart = 2\naccepted = { submitted = \"2\" }\n\n\
+         [[answers]]\nyear = 2023\nday = 2\npart = 1\naccepted = { submitted = \"3\" }\n",
+    )
+    .expect("write manifest");
+
+    let output = Command::new(env!("CARGO_BIN_EXE_runner"))
+        .args(["verify", "2023:1-2", "--answers"])
+        .arg(&manifest)
+        .env("CARGO_TARGET_DIR", &dir)
+        .output()
+        .expect("runner should start");
+
+    assert_eq!(Some(1), output.status.code());
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("4 of 4 verified parts failed"));
+    for part in ["day 01 part 1", "day 01 part 2", "day 02 part 1"] {
+        assert!(stderr.contains(&format!("2023 {}: ", part)));
+    }
+    assert!(stderr.contains("2023 day 02 part 2: No accepted answers"));
+
+    std::fs::remove_dir_all(&dir).expect("manifest dir should be removable");
+}
+
+#[test]
+fn it_should_explain_selected_day_9_lines() {
+    let output = run(&["explain", "2023", "9", "--line", "3"]);
+
+    assert!(output.starts_with("Line 3: previous "));
+    assert_eq!(1, output.matches("Line ").count());
+}
+
+#[test]
+fn it_should_run_against_a_named_input() {
+    let output = run(&[
+        "run",
+        "2023",
+        "8",
+        "--part",
+        "2",
+        "--input",
+        "example2",
+        "--submit-format",
+    ]);
+    assert_eq!("6\n", output);
+
+    let inputs = run(&["inputs", "2023-08"]);
+    assert!(inputs.starts_with("2023 day 08: example1, example2"));
+}
+
+#[test]
+fn it_should_list_day_5_attempts() {
+    let output = run(&["attempts", "2023-05"]);
+
+    assert!(output.starts_with("2023 day 05\n"));
+    assert!(output.contains("replaced by part1:"));
+    assert!(output.contains("36.02µs"));
+}
+
+#[test]
+fn it_should_fail_cleanly_when_offline_cache_is_cold() {
+    let target = std::env::temp_dir().join(format!("runner-cli-fetch-{}", std::process::id()));
+
+    let output = Command::new(env!("CARGO_BIN_EXE_runner"))
+        .args(["fetch", "2023", "25", "--offline"])
+        .env("CARGO_TARGET_DIR", &target)
+        .output()
+        .expect("runner should start");
+
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("No cached input for 2023 day 25"));
+}
+
+#[test]
+fn it_should_audit_day_9_determinism_across_thread_counts() {
+    let output = run(&[
+        "audit-determinism",
+        "2023",
+        "--day",
+        "9",
+        "--threads",
+        "1",
+        "4",
+    ]);
+
+    assert!(output.contains("2023 day 09 part 1 part1: ok"));
+    assert!(output.contains("2023 day 09 part 2 explain: ok"));
+    assert!(!output.contains('\x1b'));
+    assert!(output.ends_with(" ok\n"));
+}
+
+#[test]
+fn it_should_smoke_every_day() {
+    let output = run(&["smoke", "--year", "2023"]);
+
+    assert!(output.contains("2023 day 01 part 1 part1: ok"));
+    assert!(output.contains("2023 day 11 part 2 part2: ok"));
+    assert!(output
+        .lines()
+        .last()
+        .is_some_and(|summary| summary.starts_with("20 ok in ")));
+}
+
+#[test]
+fn it_should_run_a_range_of_days() {
+    let ranged = run(&["run", "2023:5-7", "--part", "1", "--submit-format"]);
+    let single = ["5", "6", "7"]
+        .iter()
+        .map(|day| run(&["run", "2023", day, "--part", "1", "--submit-format"]))
+        .collect::<String>();
+
+    assert_eq!(single, ranged);
+}
+
+#[test]
+fn it_should_bench_a_list_of_days() {
+    let output = run(&["bench", "2023:6,9", "--part", "2", "--runs", "2"]);
+
+    assert!(output.starts_with("day "));
+    assert!(output.contains("2023 day 06  part2"));
+    assert!(output.contains("2023 day 09  part2"));
+    assert_eq!(3, output.lines().count());
+}
+
+#[test]
+fn it_should_bench_from_a_warm_start() {
+    let output = run(&["bench", "2023", "6", "--runs", "2", "--stable"]);
+
+    assert!(output.contains("2023 day 06  part1"));
+    assert!(output.contains("2023 day 06  part2"));
+}
+
+#[test]
+fn it_should_summarise_a_year_as_json() {
+    let output = run(&["summary", "2023", "--runs", "1", "--json"]);
+
+    assert!(output.contains("\"year\": 2023"));
+    assert!(output.contains("\"budget_ns\": 1000000000"));
+    assert!(output.contains("\"variant\": \"part2\""));
+    assert!(output.contains("\"variant\": \"part2_opt\""));
+    assert!(output.contains("\"day\": 11"));
+}
+
+#[test]
+fn it_should_time_spans_and_write_a_flamegraph_when_traced() {
+    let flame =
+        std::env::temp_dir().join(format!("runner-cli-flame-{}.folded", std::process::id()));
+    let output = Command::new(env!("CARGO_BIN_EXE_runner"))
+        .args(["run", "2023", "6", "--part", "1", "--trace", "--flame"])
+        .arg(&flame)
+        .output()
+        .expect("runner should start");
+
+    assert!(output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("time.busy"));
+    let folded = std::fs::read_to_string(&flame).expect("flamegraph should be written");
+    assert!(folded.contains("process"));
+
+    let _ = std::fs::remove_file(&flame);
+}
+
+#[test]
+fn it_should_only_time_spans_the_log_filter_lets_through() {
+    let stderr = |log: &str| {
+        let output = Command::new(env!("CARGO_BIN_EXE_runner"))
+            .args(["run", "2023", "6", "--part", "1", "--trace", "--log", log])
+            .output()
+            .expect("runner should start");
+        assert!(output.status.success(), "--log {} failed", log);
+        String::from_utf8_lossy(&output.stderr).to_string()
+    };
+
+    assert!(stderr("day_06=trace").contains("time.busy"));
+    assert!(!stderr("day_06=off").contains("time.busy"));
+
+    let output = Command::new(env!("CARGO_BIN_EXE_runner"))
+        .args(["run", "2023", "6", "--log", "day_06=loud"])
+        .output()
+        .expect("runner should start");
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("is not a log filter"));
+}
+
+#[test]
+fn it_should_only_log_runs_after_opting_in() {
+    let target = std::env::temp_dir().join(format!("runner-cli-telemetry-{}", std::process::id()));
+    let runner = |args: &[&str], opt_in: &str| {
+        let output = Command::new(env!("CARGO_BIN_EXE_runner"))
+            .args(args)
+            .env("CARGO_TARGET_DIR", &target)
+            .env("AOC_TELEMETRY", opt_in)
+            .output()
+            .expect("runner should start");
+        assert!(output.status.success());
+        String::from_utf8_lossy(&output.stdout).to_string()
+    };
+
+    runner(&["run", "2023", "6"], "0");
+    assert!(runner(&["telemetry", "summary"], "0").starts_with("no runs logged"));
+
+    runner(&["run", "2023", "6"], "1");
+    runner(&["run", "2023", "6", "--part", "1"], "1");
+    let summary = runner(&["telemetry", "summary"], "0");
+
+    assert!(summary.contains("2023 day 06  part1       2       0"));
+    assert!(summary.contains("2023 day 06  part2       1       0"));
+    assert!(summary.ends_with("3 runs of 2 variants\n"));
+
+    std::fs::remove_dir_all(&target).expect("run log should be removable");
+}
+
+#[test]
+fn it_should_compare_every_variant_with_its_reference_and_baseline() {
+    let baseline =
+        std::env::temp_dir().join(format!("runner-cli-bench-{}.toml", std::process::id()));
+    let baseline = baseline.to_str().expect("temp dir should be UTF-8");
+
+    let output = run(&[
+        "bench",
+        "2023:7",
+        "--part",
+        "1",
+        "--runs",
+        "2",
+        "--all-variants",
+        "--save",
+        baseline,
+    ]);
+    assert!(output.contains("2023 day 07  part1       "));
+    assert!(output.contains("1.00x"));
+    assert!(output.contains("part1_opt2"));
+
+    let output = run(&[
+        "bench",
+        "2023:7",
+        "--part",
+        "1",
+        "--runs",
+        "2",
+        "--baseline",
+        baseline,
+        "--tolerance",
+        "100000",
+    ]);
+    assert!(output
+        .lines()
+        .next()
+        .is_some_and(|header| header.ends_with("vs baseline")));
+    assert!(output.contains(" ok"));
+
+    std::fs::remove_file(baseline).expect("baseline should have been saved");
+}
+
+#[test]
+fn it_should_only_draw_days_with_viz_for_a_whole_year() {
+    let output = run(&["viz", "2023", "--no-color"]);
+
+    assert!(output.starts_with("Game 1"));
+    assert!(output.contains("Race 1: time 41"));
+}
+
+#[test]
+fn it_should_reject_malformed_selectors() {
+    let output = Command::new(env!("CARGO_BIN_EXE_runner"))
+        .args(["run", "2023:7-3"])
+        .output()
+        .expect("runner should start");
+
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("runs backwards"));
+}
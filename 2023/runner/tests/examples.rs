@@ -0,0 +1,59 @@
+use aoc_core::Answer;
+use runner::solve_all_examples;
+
+#[test]
+fn it_should_solve_every_example() {
+    let results = solve_all_examples();
+
+    assert!(!results.is_empty());
+
+    let failures = results
+        .iter()
+        .filter(|result| !result.passed())
+        .map(|result| {
+            format!(
+                "{} day {} {}: expected {}, got {:?}",
+                result.year, result.day, result.variant, result.expected, result.actual
+            )
+        })
+        .collect::<Vec<_>>();
+
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
+
+#[test]
+fn it_should_cover_both_parts_of_every_day() {
+    let results = solve_all_examples();
+
+    for day in runner::registry::days() {
+        for part in [1, 2] {
+            assert!(
+                results
+                    .iter()
+                    .any(|result| result.day == day.day && result.part == part),
+                "day {} part {} has no example",
+                day.day,
+                part
+            );
+        }
+    }
+}
+
+#[test]
+fn it_should_solve_every_example_through_its_solution() {
+    for day in runner::registry::days() {
+        for example in &day.examples {
+            let actual =
+                (day.solution)(example.input, example.part).map_err(|report| report.to_string());
+
+            assert_eq!(
+                Ok(Answer::parse(example.expected)),
+                actual,
+                "{} day {} part {}",
+                day.year,
+                day.day,
+                example.part
+            );
+        }
+    }
+}
@@ -0,0 +1,89 @@
+use std::{
+    io::{BufRead, BufReader, Write},
+    process::{Child, ChildStdin, ChildStdout, Command, Stdio},
+};
+
+use pretty_assertions::assert_eq;
+use serde_json::{json, Value};
+
+struct Server {
+    child: Child,
+    stdin: ChildStdin,
+    stdout: BufReader<ChildStdout>,
+    next_id: u64,
+}
+
+impl Server {
+    fn spawn(target: &std::path::Path) -> Self {
+        let mut child = Command::new(env!("CARGO_BIN_EXE_runner"))
+            .arg("serve")
+            .env("CARGO_TARGET_DIR", target)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .spawn()
+            .expect("runner should start");
+
+        let stdin = child.stdin.take().expect("stdin should be piped");
+        let stdout = BufReader::new(child.stdout.take().expect("stdout should be piped"));
+
+        Self {
+            child,
+            stdin,
+            stdout,
+            next_id: 1,
+        }
+    }
+
+    fn call(&mut self, method: &str, params: Value) -> Value {
+        let id = self.next_id;
+        self.next_id += 1;
+
+        let request = json!({ "jsonrpc": "2.0", "id": id, "method": method, "params": params });
+        writeln!(self.stdin, "{}", request).expect("request should be written");
+
+        let mut line = String::new();
+        self.stdout
+            .read_line(&mut line)
+            .expect("response should be read");
+        let response: Value = serde_json::from_str(&line).expect("response should be JSON");
+
+        assert_eq!(json!(id), response["id"]);
+        response
+    }
+}
+
+#[test]
+fn it_should_drive_solutions_over_stdio() {
+    let target = std::env::temp_dir().join(format!("runner-serve-{}", std::process::id()));
+    let mut server = Server::spawn(&target);
+
+    let run = server.call("run", json!({ "year": 2023, "day": 6, "part": 1 }));
+    assert_eq!(json!("part1"), run["result"]["variant"]);
+    assert!(run["result"]["elapsed_ns"].is_u64());
+
+    let verify = server.call(
+        "verify",
+        json!({ "year": 2023, "day": 6, "part": 1, "variant": "part1_opt" }),
+    );
+    assert_eq!(json!(true), verify["result"]["passed"]);
+    assert_eq!(json!("submitted"), verify["result"]["matched"]);
+    assert_eq!(run["result"]["answer"], verify["result"]["answer"]);
+
+    let bench = server.call(
+        "bench_quick",
+        json!({ "year": 2023, "day": 6, "part": 2, "runs": 2 }),
+    );
+    assert!(bench["result"]["fastest_ns"].as_u64() <= bench["result"]["median_ns"].as_u64());
+
+    let fetch = server.call("fetch", json!({ "year": 2023, "day": 25, "offline": true }));
+    assert!(fetch["error"]["message"]
+        .as_str()
+        .unwrap_or_default()
+        .contains("No cached input for 2023 day 25"));
+
+    let shutdown = server.call("shutdown", Value::Null);
+    assert_eq!(Value::Null, shutdown["result"]);
+    assert!(server.child.wait().expect("server should exit").success());
+
+    let _ = std::fs::remove_dir_all(&target);
+}
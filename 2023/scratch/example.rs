@@ -0,0 +1,22 @@
+//! Template for a single-file solution, run with
+//! `cargo run -p runner -- scratch scratch/example.rs --input <file>`.
+//! Once it works, move `process` into a day crate's `partN.rs`.
+
+use itertools::Itertools;
+
+#[tracing::instrument]
+pub fn process(input: &str) -> miette::Result<u64> {
+    input
+        .lines()
+        .map(|line| line.trim())
+        .filter(|line| !line.is_empty())
+        .map(|line| {
+            line.split_whitespace()
+                .map(|n| {
+                    n.parse::<u64>()
+                        .map_err(|e| miette::miette!("{}: {}", n, e))
+                })
+                .fold_ok(0, |sum, n| sum + n)
+        })
+        .sum()
+}
@@ -0,0 +1,316 @@
+//! Stub entries in each day's `attempts::ATTEMPTS` changelog for variant
+//! modules it doesn't mention yet, creating the module and registering it in
+//! the day's manifest for days that don't keep one.
+
+use std::{
+    path::Path,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use crate::{error::Error, prelude::*, workspace};
+
+const HEADER: &str = "use aoc_core::{Attempt, Outcome};
+
+pub const ATTEMPTS: &[Attempt] = &[
+";
+
+/// The stubs written for one day.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Scaffolded {
+    pub day: String,
+    pub names: Vec<String>,
+    /// Whether `attempts.rs` had to be created.
+    pub created: bool,
+}
+
+/// Today in UTC as `YYYY-MM-DD`, the format attempts are dated in.
+#[tracing::instrument]
+pub fn today() -> String {
+    let seconds = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs());
+
+    date_from_days((seconds / 86_400) as i64)
+}
+
+/// The civil date `days` after 1970-01-01, by Howard Hinnant's algorithm.
+#[tracing::instrument]
+fn date_from_days(days: i64) -> String {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let day_of_era = z.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+    let month = if shifted_month < 10 {
+        shifted_month + 3
+    } else {
+        shifted_month - 9
+    };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+    format!("{:04}-{:02}-{:02}", year, month, day)
+}
+
+/// The `partN...` modules a day's `lib.rs` declares, in declaration order.
+#[tracing::instrument(skip(lib))]
+pub fn variant_modules(lib: &str) -> Vec<String> {
+    lib.lines()
+        .filter_map(|line| {
+            let name = line
+                .trim()
+                .strip_prefix("pub mod ")
+                .or_else(|| line.trim().strip_prefix("mod "))?
+                .strip_suffix(';')?;
+            name.starts_with("part").then(|| name.to_string())
+        })
+        .collect()
+}
+
+/// The names an `attempts.rs` already has entries for.
+#[tracing::instrument(skip(source))]
+pub fn recorded(source: &str) -> Vec<String> {
+    source
+        .lines()
+        .filter_map(|line| {
+            let name = line.trim().strip_prefix("name: \"")?.strip_suffix("\",")?;
+            Some(name.to_string())
+        })
+        .collect()
+}
+
+/// A stub for `name`, kept until someone says otherwise.
+#[tracing::instrument]
+pub fn entry(name: &str, date: &str) -> String {
+    format!(
+        "    Attempt {{
+        name: {:?},
+        date: {:?},
+        timing: None,
+        outcome: Outcome::Kept,
+    }},
+",
+        name, date
+    )
+}
+
+/// `source` with `entries` added at the end of `ATTEMPTS`, or a new module
+/// holding only them when there's no source yet.
+#[tracing::instrument(skip(source, entries))]
+pub fn with_entries(source: Option<&str>, entries: &[String]) -> Result<String> {
+    let source = source.unwrap_or(HEADER);
+    let end = source.rfind("];").unwrap_or(source.len());
+    let (before, after) = source.split_at(end);
+
+    if !before.contains("ATTEMPTS") {
+        return Err(Error::NoAttemptsList);
+    }
+
+    let mut output = before.to_string();
+    output.extend(entries.iter().cloned());
+    if after.is_empty() {
+        output.push_str("];\n");
+    } else {
+        output.push_str(after);
+    }
+
+    Ok(output)
+}
+
+/// `manifest` pointing the runner at the day's `attempts::ATTEMPTS`.
+#[tracing::instrument(skip(manifest))]
+pub fn with_attempts_key(manifest: &str) -> Result<String> {
+    let mut document =
+        manifest
+            .parse::<toml_edit::DocumentMut>()
+            .map_err(|e| Error::InvalidManifest {
+                path: "Cargo.toml".to_string(),
+                reason: e.message().to_string(),
+            })?;
+
+    let aoc = document
+        .get_mut("package")
+        .and_then(|package| package.get_mut("metadata"))
+        .and_then(|metadata| metadata.get_mut("aoc"))
+        .and_then(|aoc| aoc.as_table_mut())
+        .ok_or_else(|| Error::InvalidManifest {
+            path: "Cargo.toml".to_string(),
+            reason: "no [package.metadata.aoc] table".to_string(),
+        })?;
+
+    if !aoc.contains_key("attempts") {
+        aoc["attempts"] = toml_edit::value("attempts::ATTEMPTS");
+    }
+
+    Ok(document.to_string())
+}
+
+/// `lib` declaring `pub mod attempts;` just before its first variant module.
+#[tracing::instrument(skip(lib))]
+fn with_attempts_module(lib: &str) -> String {
+    if variant_modules(lib).is_empty() || lib.contains("mod attempts;") {
+        return lib.to_string();
+    }
+
+    let first = lib
+        .lines()
+        .position(|line| line.starts_with("pub mod part"))
+        .unwrap_or(0);
+
+    let mut lines = lib.lines().map(str::to_string).collect::<Vec<_>>();
+    lines.insert(first, "pub mod attempts;".to_string());
+
+    lines.join("\n") + "\n"
+}
+
+/// Adds a stub dated `date` for every variant module without an attempt, in
+/// every day or just `day`. Nothing is written with `dry_run`.
+#[tracing::instrument]
+pub fn scaffold(
+    root: &Path,
+    day: Option<u8>,
+    date: &str,
+    dry_run: bool,
+) -> Result<Vec<Scaffolded>> {
+    let dirs = workspace::day_dirs(root)?
+        .into_iter()
+        .filter(|dir| day.is_none_or(|day| dir.ends_with(format!("day-{:02}", day))))
+        .collect::<Vec<_>>();
+
+    if let (Some(day), true) = (day, dirs.is_empty()) {
+        return Err(Error::NoSuchDay {
+            day: format!("day-{:02}", day),
+        });
+    }
+
+    let mut scaffolded = vec![];
+
+    for dir in dirs {
+        let lib_path = dir.join("src").join("lib.rs");
+        let attempts_path = dir.join("src").join("attempts.rs");
+        let manifest_path = dir.join("Cargo.toml");
+
+        let lib = std::fs::read_to_string(&lib_path)?;
+        let existing = std::fs::read_to_string(&attempts_path).ok();
+        let known = existing.as_deref().map(recorded).unwrap_or_default();
+
+        let names = variant_modules(&lib)
+            .into_iter()
+            .filter(|name| !known.contains(name))
+            .collect::<Vec<_>>();
+
+        if names.is_empty() {
+            continue;
+        }
+
+        if !dry_run {
+            let entries = names
+                .iter()
+                .map(|name| entry(name, date))
+                .collect::<Vec<_>>();
+            std::fs::write(&attempts_path, with_entries(existing.as_deref(), &entries)?)?;
+
+            if existing.is_none() {
+                std::fs::write(&lib_path, with_attempts_module(&lib))?;
+                let manifest = std::fs::read_to_string(&manifest_path)?;
+                std::fs::write(&manifest_path, with_attempts_key(&manifest)?)?;
+            }
+        }
+
+        scaffolded.push(Scaffolded {
+            day: dir
+                .file_name()
+                .and_then(|name| name.to_str())
+                .unwrap_or_default()
+                .to_string(),
+            names,
+            created: existing.is_none(),
+        });
+    }
+
+    Ok(scaffolded)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    const LIB: &str = "pub mod error;
+pub mod prelude;
+
+pub mod games;
+pub mod part1;
+pub mod part1_opt;
+pub mod part2;
+
+aoc_core::solution!(Day02, 2023, 2, part1::process, part2::process);
+";
+
+    #[test]
+    fn it_should_date_days_since_the_epoch() -> miette::Result<()> {
+        assert_eq!("1970-01-01", date_from_days(0));
+        assert_eq!("2023-12-01", date_from_days(19_692));
+        assert_eq!("2024-02-29", date_from_days(19_782));
+        assert_eq!(10, today().len());
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_only_list_variant_modules() -> miette::Result<()> {
+        assert_eq!(vec!["part1", "part1_opt", "part2"], variant_modules(LIB));
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_append_stubs_for_new_variants() -> miette::Result<()> {
+        let source = format!("{}{}];\n", HEADER, entry("part1", "2023-12-02"));
+        assert_eq!(vec!["part1"], recorded(&source));
+
+        let updated = with_entries(Some(&source), &[entry("part1_opt", "2023-12-03")])?;
+
+        assert_eq!(vec!["part1", "part1_opt"], recorded(&updated));
+        assert!(updated.ends_with("        outcome: Outcome::Kept,\n    },\n];\n"));
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_start_a_new_attempts_module() -> miette::Result<()> {
+        let created = with_entries(None, &[entry("part1", "2023-12-02")])?;
+
+        assert_eq!(
+            "use aoc_core::{Attempt, Outcome};
+
+pub const ATTEMPTS: &[Attempt] = &[
+    Attempt {
+        name: \"part1\",
+        date: \"2023-12-02\",
+        timing: None,
+        outcome: Outcome::Kept,
+    },
+];
+",
+            created
+        );
+        assert!(matches!(
+            with_entries(Some("pub fn unrelated() {}\n"), &[]),
+            Err(Error::NoAttemptsList)
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_register_the_module_with_the_runner() -> miette::Result<()> {
+        let lib = with_attempts_module(LIB);
+        assert!(lib.contains("pub mod games;\npub mod attempts;\npub mod part1;\n"));
+        assert_eq!(lib, with_attempts_module(&lib));
+
+        let manifest = with_attempts_key(
+            "[package]\nname = \"day-02\"\n\n[package.metadata.aoc]\nyear = 2023\n",
+        )?;
+        assert!(manifest.contains("attempts = \"attempts::ATTEMPTS\""));
+        Ok(())
+    }
+}
@@ -0,0 +1,323 @@
+//! Timing changes between a revision and now, from the divan output that
+//! `just bench` appends to `benchmarks/`. Each file is a history of runs, so
+//! the last time a bench appears is its current timing.
+
+use std::{collections::BTreeMap, path::Path, process::Command};
+
+use crate::{error::Error, prelude::*};
+
+/// Changes smaller than this, in percent, are noise between runs.
+pub const DEFAULT_TOLERANCE: f64 = 5.0;
+
+/// One bench's median, keyed by its group and path in divan's tree, like
+/// `("day_03", "part1_stress/500")`.
+pub type Medians = BTreeMap<(String, String), f64>;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Change {
+    pub group: String,
+    pub bench: String,
+    /// `None` for a bench that wasn't recorded at the revision.
+    pub before_ns: Option<f64>,
+    pub after_ns: f64,
+}
+
+impl Change {
+    /// Percent slower (positive) or faster (negative) than before.
+    #[tracing::instrument]
+    pub fn percent(&self) -> Option<f64> {
+        self.before_ns
+            .map(|before| (self.after_ns - before) / before.max(1.0) * 100.0)
+    }
+
+    #[tracing::instrument]
+    pub fn arrow(&self, tolerance: f64) -> &'static str {
+        match self.percent() {
+            None => "+",
+            Some(percent) if percent > tolerance => "↑",
+            Some(percent) if percent < -tolerance => "↓",
+            Some(_) => "~",
+        }
+    }
+}
+
+/// Nanoseconds in a divan time like `105 µs` or `7.205 ms`.
+#[tracing::instrument]
+pub fn parse_time(text: &str) -> Option<f64> {
+    let (value, unit) = text.trim().split_once(' ')?;
+    let scale = match unit.trim() {
+        "ns" => 1.0,
+        "µs" | "us" => 1e3,
+        "ms" => 1e6,
+        "s" => 1e9,
+        _ => return None,
+    };
+
+    Some(value.parse::<f64>().ok()? * scale)
+}
+
+/// Nanoseconds the way divan shows them.
+#[tracing::instrument]
+pub fn format_time(ns: f64) -> String {
+    let (value, unit) = match ns {
+        ns if ns >= 1e9 => (ns / 1e9, "s"),
+        ns if ns >= 1e6 => (ns / 1e6, "ms"),
+        ns if ns >= 1e3 => (ns / 1e3, "µs"),
+        ns => (ns, "ns"),
+    };
+
+    format!("{:.1} {}", value, unit)
+}
+
+/// Every bench's median in divan's output, the latest run winning.
+#[tracing::instrument(skip(output))]
+pub fn parse_medians(output: &str) -> Medians {
+    let mut medians = Medians::new();
+    let mut group = String::new();
+    let mut path: Vec<String> = vec![];
+
+    for line in output.lines() {
+        let tree = line
+            .chars()
+            .take_while(|c| matches!(c, '│' | '├' | '╰' | '─' | ' '))
+            .collect::<String>();
+        let rest = &line[tree.len()..];
+        let Some(name) = rest.split_whitespace().next() else {
+            continue;
+        };
+
+        if tree.is_empty() {
+            group = name.to_string();
+            path.clear();
+            continue;
+        }
+
+        // Each level of the tree is three characters wide.
+        let depth = tree.chars().count() / 3;
+        path.truncate(depth.saturating_sub(1));
+        path.push(name.to_string());
+
+        let median = rest.split('│').nth(2).and_then(parse_time);
+        if let Some(median) = median {
+            medians.insert((group.clone(), path.join("/")), median);
+        }
+    }
+
+    medians
+}
+
+/// Benches whose latest median is different now, in group and path order.
+#[tracing::instrument(skip(before, after))]
+pub fn changes(before: &Medians, after: &Medians) -> Vec<Change> {
+    after
+        .iter()
+        .filter(|(key, after)| before.get(key) != Some(after))
+        .map(|((group, bench), after)| Change {
+            group: group.clone(),
+            bench: bench.clone(),
+            before_ns: before.get(&(group.clone(), bench.clone())).copied(),
+            after_ns: *after,
+        })
+        .collect()
+}
+
+/// The block to paste into a commit message.
+#[tracing::instrument(skip(changes))]
+pub fn render(rev: &str, changes: &[Change], tolerance: f64) -> String {
+    let rows = changes
+        .iter()
+        .map(|change| {
+            let name = format!("{} {}", change.group.replace('_', " "), change.bench);
+            let before = change.before_ns.map_or("-".to_string(), format_time);
+            let delta = change
+                .percent()
+                .map_or("new".to_string(), |percent| format!("{:+.1}%", percent));
+
+            (
+                name,
+                before,
+                format_time(change.after_ns),
+                change.arrow(tolerance),
+                delta,
+            )
+        })
+        .collect::<Vec<_>>();
+
+    let name_width = rows.iter().map(|row| row.0.chars().count()).max();
+    let before_width = rows.iter().map(|row| row.1.chars().count()).max();
+    let after_width = rows.iter().map(|row| row.2.chars().count()).max();
+    let (name_width, before_width, after_width) = (
+        name_width.unwrap_or_default(),
+        before_width.unwrap_or_default(),
+        after_width.unwrap_or_default(),
+    );
+
+    let mut block = format!("Benchmarks since {}:\n", rev);
+    if rows.is_empty() {
+        block.push_str("  no timings changed\n");
+    }
+    for (name, before, after, arrow, delta) in &rows {
+        block.push_str(&format!(
+            "  {:<name_width$}  {:>before_width$} -> {:>after_width$}  {} {}\n",
+            name, before, after, arrow, delta,
+        ));
+    }
+
+    block
+}
+
+/// `path`, relative to the workspace root, as it was at `rev`. Empty when
+/// the file didn't exist yet.
+#[tracing::instrument]
+fn show_at(root: &Path, rev: &str, path: &str) -> Result<String> {
+    let output = Command::new("git")
+        .current_dir(root)
+        .args(["show", &format!("{}:./{}", rev, path)])
+        .output()?;
+
+    Ok(if output.status.success() {
+        String::from_utf8_lossy(&output.stdout).to_string()
+    } else {
+        String::new()
+    })
+}
+
+#[tracing::instrument]
+fn verify_rev(root: &Path, rev: &str) -> Result<()> {
+    let output = Command::new("git")
+        .current_dir(root)
+        .args(["rev-parse", "--verify", "--quiet"])
+        .arg(format!("{}^{{commit}}", rev))
+        .output()?;
+
+    if !output.status.success() {
+        return Err(Error::UnknownRevision {
+            rev: rev.to_string(),
+        });
+    }
+
+    Ok(())
+}
+
+/// Changes in every `benchmarks/*.txt` since `rev`. Files are compared one
+/// by one, so a bench run into `all.txt` counts even when the day's own file
+/// didn't change.
+#[tracing::instrument]
+pub fn since(root: &Path, rev: &str) -> Result<Vec<Change>> {
+    verify_rev(root, rev)?;
+
+    let mut files = std::fs::read_dir(root.join("benchmarks"))?
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "txt"))
+        .collect::<Vec<_>>();
+    files.sort();
+
+    let mut changed = BTreeMap::new();
+
+    for file in files {
+        let name = file
+            .file_name()
+            .and_then(|name| name.to_str())
+            .unwrap_or_default();
+
+        let before = parse_medians(&show_at(root, rev, &format!("benchmarks/{}", name))?);
+        let after = parse_medians(&std::fs::read_to_string(&file)?);
+
+        for change in changes(&before, &after) {
+            changed.insert((change.group.clone(), change.bench.clone()), change);
+        }
+    }
+
+    Ok(changed.into_values().collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    const OUTPUT: &str = "day_03                fastest       │ slowest       │ median        │ mean          │ samples │ iters
+├─ part1              7.205 ms      │ 7.633 ms      │ 7.277 ms      │ 7.301 ms      │ 100     │ 100
+├─ part1_opt_stress                 │               │               │               │         │
+│  ├─ 50              12.1 µs       │ 15.2 µs       │ 12.5 µs       │ 12.7 µs       │ 100     │ 100
+│  ╰─ 500             118.9 µs      │ 130.2 µs      │ 120 µs        │ 121.4 µs      │ 100     │ 100
+╰─ part2              6.165 ms      │ 6.865 ms      │ 6.277 ms      │ 6.301 ms      │ 100     │ 100
+
+day_03    fastest       │ slowest       │ median        │ mean          │ samples │ iters
+╰─ part1  5.1 ms        │ 5.3 ms        │ 5.2 ms        │ 5.2 ms        │ 100     │ 100
+";
+
+    fn key(group: &str, bench: &str) -> (String, String) {
+        (group.to_string(), bench.to_string())
+    }
+
+    #[test]
+    fn it_should_parse_divan_times() -> miette::Result<()> {
+        assert_eq!(Some(105_000.0), parse_time("105 µs"));
+        assert_eq!(Some(7_205_000.0), parse_time(" 7.205 ms   "));
+        assert_eq!(Some(25.08), parse_time("25.08 ns"));
+        assert_eq!(None, parse_time(""));
+        assert_eq!(None, parse_time("12 parsecs"));
+        assert_eq!("124.8 µs", format_time(124_800.0));
+        assert_eq!("7.3 ms", format_time(7_277_000.0));
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_keep_the_latest_median_of_every_bench() -> miette::Result<()> {
+        let medians = parse_medians(OUTPUT);
+
+        assert_eq!(
+            vec![
+                (key("day_03", "part1"), 5_200_000.0),
+                (key("day_03", "part1_opt_stress/50"), 12_500.0),
+                (key("day_03", "part1_opt_stress/500"), 120_000.0),
+                (key("day_03", "part2"), 6_277_000.0),
+            ],
+            medians.into_iter().collect::<Vec<_>>()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_only_list_benches_that_changed() -> miette::Result<()> {
+        let before = Medians::from([
+            (key("day_03", "part1"), 1_000.0),
+            (key("day_03", "part2"), 1_000.0),
+            (key("day_07", "part1"), 1_000.0),
+        ]);
+        let after = Medians::from([
+            (key("day_03", "part1"), 800.0),
+            (key("day_03", "part2"), 1_000.0),
+            (key("day_07", "part1"), 1_030.0),
+            (key("day_09", "part1"), 50.0),
+        ]);
+
+        let changes = changes(&before, &after);
+        let arrows = changes
+            .iter()
+            .map(|change| (change.bench.as_str(), change.arrow(DEFAULT_TOLERANCE)))
+            .collect::<Vec<_>>();
+
+        assert_eq!(vec![("part1", "↓"), ("part1", "~"), ("part1", "+")], arrows);
+        assert_eq!(
+            "Benchmarks since abc123:
+  day 03 part1  1.0 µs -> 800.0 ns  ↓ -20.0%
+  day 07 part1  1.0 µs ->   1.0 µs  ~ +3.0%
+  day 09 part1       - ->  50.0 ns  + new
+",
+            render("abc123", &changes, DEFAULT_TOLERANCE)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_say_when_nothing_changed() -> miette::Result<()> {
+        assert_eq!(
+            "Benchmarks since HEAD~3:\n  no timings changed\n",
+            render("HEAD~3", &[], DEFAULT_TOLERANCE)
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,437 @@
+use std::path::{Path, PathBuf};
+
+use crate::{error::Error, prelude::*, workspace};
+
+/// Every day in this workspace is from the 2023 event.
+pub const YEAR: u16 = 2023;
+
+/// A real-input test asserting a literal answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Hardcoded {
+    pub file: PathBuf,
+    /// 1-based line of the `assert_eq!`.
+    pub line: usize,
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Added,
+    AlreadyPresent,
+    /// answers.toml accepts something else, so the test is left alone.
+    Conflict {
+        accepted: Vec<String>,
+    },
+}
+
+impl Outcome {
+    #[tracing::instrument]
+    pub fn label(&self) -> String {
+        match self {
+            Outcome::Added => "added to answers.toml".to_string(),
+            Outcome::AlreadyPresent => "already in answers.toml".to_string(),
+            Outcome::Conflict { accepted } => {
+                format!("conflicts with answers.toml ({})", accepted.join(", "))
+            }
+        }
+    }
+}
+
+/// `3` for `day-03`.
+#[tracing::instrument]
+fn day_number(dir: &Path) -> Option<u8> {
+    dir.file_name()?
+        .to_str()?
+        .strip_prefix("day-")?
+        .parse()
+        .ok()
+}
+
+/// `1` for `part1_opt.rs`. Files that aren't a part (like `games.rs`) are skipped.
+#[tracing::instrument]
+fn part_number(file: &Path) -> Option<u8> {
+    let stem = file.file_stem()?.to_str()?.strip_prefix("part")?;
+    stem.get(..1)?.parse().ok()
+}
+
+/// The literal in `assert_eq!(<literal>, process(input)?);`, if that's the
+/// line. The literal may be wrapped as `Answer::UInt(<literal>)` or
+/// `Answer::Int(<literal>)`.
+#[tracing::instrument]
+fn literal_answer(line: &str) -> Option<&str> {
+    let answer = line
+        .trim()
+        .strip_prefix("assert_eq!(")?
+        .strip_suffix(", process(input)?);")?;
+    let answer = ["Answer::UInt(", "Answer::Int("]
+        .iter()
+        .find_map(|wrapper| answer.strip_prefix(wrapper)?.strip_suffix(')'))
+        .unwrap_or(answer);
+
+    let digits = answer.strip_prefix('-').unwrap_or(answer);
+    (!digits.is_empty() && digits.bytes().all(|b| b.is_ascii_digit())).then_some(answer)
+}
+
+/// `(line, answer)` for each literal answer asserted by a test that reads a
+/// real input file. Example tests use inline strings, so they never match.
+#[tracing::instrument(skip(source))]
+pub fn hardcoded_answers(source: &str) -> Vec<(usize, String)> {
+    let mut reads_real_input = false;
+    let mut found = vec![];
+
+    for (i, line) in source.lines().enumerate() {
+        let trimmed = line.trim_start();
+
+        if trimmed.starts_with("fn ") {
+            reads_real_input = false;
+        }
+
+        if trimmed.starts_with("//") {
+            continue;
+        }
+
+        if trimmed.contains("include_str!(\"../input") || trimmed.contains("puzzle_input!(\"input")
+        {
+            reads_real_input = true;
+        }
+
+        if let Some(answer) = literal_answer(line).filter(|_| reads_real_input) {
+            found.push((i + 1, answer.to_string()));
+        }
+    }
+
+    found
+}
+
+/// Adds an entry to `manifest` for every hardcoded answer it doesn't cover yet,
+/// keeping existing entries and comments as they are.
+#[tracing::instrument(skip(manifest))]
+pub fn seed(manifest: &str, found: &[Hardcoded]) -> Result<(String, Vec<Outcome>)> {
+    let mut document =
+        manifest
+            .parse::<toml_edit::DocumentMut>()
+            .map_err(|e| Error::InvalidAnswers {
+                reason: e.message().to_string(),
+            })?;
+
+    if document.get("answers").is_none() {
+        document["answers"] = toml_edit::Item::ArrayOfTables(Default::default());
+    }
+
+    let answers = document["answers"]
+        .as_array_of_tables_mut()
+        .ok_or_else(|| Error::InvalidAnswers {
+            reason: "answers is not an array of tables".to_string(),
+        })?;
+
+    let mut outcomes = vec![];
+
+    for hardcoded in found {
+        let existing = answers.iter().find(|table| {
+            let number = |key: &str| table.get(key).and_then(|v| v.as_integer());
+            number("year") == Some(YEAR.into())
+                && number("day") == Some(hardcoded.day.into())
+                && number("part") == Some(hardcoded.part.into())
+        });
+
+        let outcome = match existing {
+            Some(table) => {
+                let accepted = table
+                    .get("accepted")
+                    .and_then(|a| a.as_inline_table())
+                    .map(|a| {
+                        a.iter()
+                            .filter_map(|(_, v)| v.as_str().map(|s| s.to_string()))
+                            .collect::<Vec<_>>()
+                    })
+                    .unwrap_or_default();
+
+                if accepted.contains(&hardcoded.answer) {
+                    Outcome::AlreadyPresent
+                } else {
+                    Outcome::Conflict { accepted }
+                }
+            }
+            None => {
+                let mut accepted = toml_edit::InlineTable::new();
+                accepted.insert("submitted", hardcoded.answer.as_str().into());
+
+                let mut table = toml_edit::Table::new();
+                table["year"] = toml_edit::value(YEAR as i64);
+                table["day"] = toml_edit::value(hardcoded.day as i64);
+                table["part"] = toml_edit::value(hardcoded.part as i64);
+                table["accepted"] = toml_edit::value(accepted);
+                answers.push(table);
+
+                Outcome::Added
+            }
+        };
+
+        outcomes.push(outcome);
+    }
+
+    Ok((document.to_string(), outcomes))
+}
+
+/// Points the assertion on `line` at answers.toml instead of the literal,
+/// laid out the way rustfmt would.
+#[tracing::instrument(skip(source))]
+pub fn rewrite_test(source: &str, line: usize, day: u8, part: u8) -> String {
+    let mut output = source
+        .lines()
+        .enumerate()
+        .map(|(i, text)| {
+            if i + 1 != line {
+                return text.to_string();
+            }
+
+            let indent = &text[..text.len() - text.trim_start().len()];
+            [
+                format!("{}assert_eq!(", indent),
+                format!(
+                    "{}    aoc_core::manifest::answer({}, {}, {})?,",
+                    indent, YEAR, day, part
+                ),
+                format!("{}    process(input)?.to_string()", indent),
+                format!("{});", indent),
+            ]
+            .join("\n")
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    if source.ends_with('\n') {
+        output.push('\n');
+    }
+
+    output
+}
+
+/// Rewritten tests call into aoc-core, so the day needs it at least as a dev-dependency.
+#[tracing::instrument(skip(manifest))]
+pub fn with_aoc_core(manifest: &str) -> Result<String> {
+    let mut document =
+        manifest
+            .parse::<toml_edit::DocumentMut>()
+            .map_err(|e| Error::InvalidManifest {
+                path: "Cargo.toml".to_string(),
+                reason: e.message().to_string(),
+            })?;
+
+    let depends = ["dependencies", "dev-dependencies"].iter().any(|section| {
+        document
+            .get(section)
+            .and_then(|deps| deps.get("aoc-core"))
+            .is_some()
+    });
+
+    if !depends {
+        let mut dependency = toml_edit::InlineTable::new();
+        dependency.insert("workspace", true.into());
+        document["dev-dependencies"]["aoc-core"] = toml_edit::value(dependency);
+    }
+
+    Ok(document.to_string())
+}
+
+/// Every literal real-input answer in the days' part files.
+#[tracing::instrument]
+pub fn scan(root: &Path) -> Result<Vec<Hardcoded>> {
+    let mut found = vec![];
+
+    for dir in workspace::day_dirs(root)? {
+        let Some(day) = day_number(&dir) else {
+            continue;
+        };
+
+        for file in workspace::rust_files(&dir.join("src"))? {
+            // Binaries share the part names but only print answers.
+            if file.parent().is_some_and(|parent| parent.ends_with("bin")) {
+                continue;
+            }
+
+            let Some(part) = part_number(&file) else {
+                continue;
+            };
+
+            let source = std::fs::read_to_string(&file)?;
+
+            for (line, answer) in hardcoded_answers(&source) {
+                found.push(Hardcoded {
+                    file: file.clone(),
+                    line,
+                    day,
+                    part,
+                    answer,
+                });
+            }
+        }
+    }
+
+    Ok(found)
+}
+
+/// Seeds answers.toml from the hardcoded answers and, unless `dry_run`,
+/// rewrites every test whose answer the manifest now holds.
+#[tracing::instrument]
+pub fn run(root: &Path, dry_run: bool) -> Result<Vec<(Hardcoded, Outcome)>> {
+    let found = scan(root)?;
+
+    let manifest_path = root.join("answers.toml");
+    let (manifest, outcomes) = seed(&std::fs::read_to_string(&manifest_path)?, &found)?;
+
+    let results = found.into_iter().zip(outcomes).collect::<Vec<_>>();
+
+    if dry_run {
+        return Ok(results);
+    }
+
+    std::fs::write(&manifest_path, manifest)?;
+
+    let mut migrated_days = vec![];
+
+    // Bottom up, so rewriting a line never moves one still to be rewritten.
+    for (hardcoded, outcome) in results.iter().rev() {
+        if matches!(outcome, Outcome::Conflict { .. }) {
+            continue;
+        }
+
+        let source = std::fs::read_to_string(&hardcoded.file)?;
+        let rewritten = rewrite_test(&source, hardcoded.line, hardcoded.day, hardcoded.part);
+        std::fs::write(&hardcoded.file, rewritten)?;
+
+        if !migrated_days.contains(&hardcoded.day) {
+            migrated_days.push(hardcoded.day);
+        }
+    }
+
+    for day in migrated_days {
+        let cargo_toml = root.join(format!("day-{:02}", day)).join("Cargo.toml");
+        let updated = with_aoc_core(&std::fs::read_to_string(&cargo_toml)?)?;
+        std::fs::write(&cargo_toml, updated)?;
+    }
+
+    Ok(results)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    const SOURCE: &str = r#"#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_process() -> miette::Result<()> {
+        let input = "1 2 3";
+        assert_eq!(6, process(input)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_full() -> miette::Result<()> {
+        let input = include_str!("../input1.txt");
+        assert_eq!(528819, process(input)?);
+        Ok(())
+    }
+
+    // #[test]
+    // fn it_should_be_correct_for_real_data() -> miette::Result<()> {
+    //     let input = include_str!("../input2.txt");
+    //     assert_eq!(1, process(input)?);
+    // }
+}
+"#;
+
+    fn hardcoded(day: u8, part: u8, answer: &str) -> Hardcoded {
+        Hardcoded {
+            file: PathBuf::from("day-03/src/part1_opt.rs"),
+            line: 13,
+            day,
+            part,
+            answer: answer.to_string(),
+        }
+    }
+
+    #[test]
+    fn it_should_only_find_real_input_answers() -> miette::Result<()> {
+        assert_eq!(vec![(13, "528819".to_string())], hardcoded_answers(SOURCE));
+        assert_eq!(
+            vec![(13, "528819".to_string())],
+            hardcoded_answers(&SOURCE.replace(
+                "include_str!(\"../input1.txt\")",
+                "&aoc_core::puzzle_input!(\"input1.txt\")?"
+            ))
+        );
+        assert_eq!(
+            vec![(13, "528819".to_string())],
+            hardcoded_answers(&SOURCE.replace("528819,", "Answer::UInt(528819),"))
+        );
+        assert_eq!(
+            Some("-7"),
+            literal_answer("assert_eq!(Answer::Int(-7), process(input)?);")
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_name_days_and_parts() -> miette::Result<()> {
+        assert_eq!(Some(3), day_number(Path::new("/x/day-03")));
+        assert_eq!(Some(1), part_number(Path::new("src/part1_opt.rs")));
+        assert_eq!(Some(2), part_number(Path::new("src/part2.rs")));
+        assert_eq!(None, part_number(Path::new("src/games.rs")));
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_seed_missing_answers() -> miette::Result<()> {
+        let manifest = "# Accepted answers\n\n[[answers]]\nyear = 2023\nday = 3\npart = 1\naccepted = { submitted = \"528819\" }\n";
+
+        let (seeded, outcomes) = seed(
+            manifest,
+            &[
+                hardcoded(3, 1, "528819"),
+                hardcoded(3, 2, "42"),
+                hardcoded(3, 1, "1"),
+            ],
+        )?;
+
+        assert_eq!(
+            vec![
+                Outcome::AlreadyPresent,
+                Outcome::Added,
+                Outcome::Conflict {
+                    accepted: vec!["528819".to_string()]
+                }
+            ],
+            outcomes
+        );
+        assert!(seeded.starts_with("# Accepted answers\n"));
+        assert!(seeded.contains("part = 2\naccepted = { submitted = \"42\" }"));
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_rewrite_assertion() -> miette::Result<()> {
+        let rewritten = rewrite_test(SOURCE, 13, 3, 1);
+
+        assert!(rewritten.contains(
+            "        assert_eq!(\n            aoc_core::manifest::answer(2023, 3, 1)?,\n            process(input)?.to_string()\n        );\n        Ok(())"
+        ));
+        assert!(hardcoded_answers(&rewritten).is_empty());
+        assert_eq!(SOURCE.lines().count() + 3, rewritten.lines().count());
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_add_aoc_core_once() -> miette::Result<()> {
+        let manifest = "[dependencies]\nmiette = { workspace = true }\n\n[dev-dependencies]\ndivan = { workspace = true }\n";
+
+        let updated = with_aoc_core(manifest)?;
+        assert!(updated.contains("divan = { workspace = true }\naoc-core = { workspace = true }\n"));
+        assert_eq!(updated, with_aoc_core(&updated)?);
+        Ok(())
+    }
+}
@@ -0,0 +1,371 @@
+use std::path::{Path, PathBuf};
+
+use crate::{error_coverage::split_tests, prelude::*, workspace};
+
+/// Field types whose derived `Debug` output grows with the puzzle input.
+const CONTAINERS: [&str; 7] = [
+    "Vec<",
+    "String",
+    "HashMap<",
+    "HashSet<",
+    "BTreeMap<",
+    "BTreeSet<",
+    "VecDeque<",
+];
+
+/// A struct deriving `Debug` that holds at least one container.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DebugStruct {
+    pub name: String,
+    pub containers: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InstrumentedFn {
+    pub name: String,
+    pub impl_type: Option<String>,
+    pub skip_all: bool,
+    pub skipped: Vec<String>,
+    pub params: Vec<String>,
+}
+
+/// A large derived `Debug` that a `#[tracing::instrument]` span would format.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Finding {
+    pub file: PathBuf,
+    pub name: String,
+    pub containers: Vec<String>,
+    pub recorded_by: String,
+}
+
+#[tracing::instrument]
+fn is_container(ty: &str) -> bool {
+    CONTAINERS.iter().any(|container| ty.contains(container))
+}
+
+#[tracing::instrument]
+fn ident(text: &str) -> String {
+    text.chars()
+        .take_while(|c| c.is_alphanumeric() || *c == '_')
+        .collect()
+}
+
+#[tracing::instrument(skip(text))]
+fn mentions_word(text: &str, word: &str) -> bool {
+    text.match_indices(word).any(|(index, _)| {
+        let before = text[..index].chars().next_back();
+        let after = text[index + word.len()..].chars().next();
+        [before, after]
+            .iter()
+            .all(|c| c.is_none_or(|c| !(c.is_alphanumeric() || c == '_')))
+    })
+}
+
+/// Splits on commas that aren't nested inside brackets or generics.
+#[tracing::instrument]
+fn split_top_level(text: &str) -> Vec<String> {
+    let mut parts = vec![];
+    let mut depth = 0;
+    let mut current = String::new();
+
+    for c in text.chars() {
+        match c {
+            '<' | '(' | '[' => depth += 1,
+            '>' | ')' | ']' => depth -= 1,
+            ',' if depth == 0 => {
+                parts.push(current.trim().to_string());
+                current.clear();
+                continue;
+            }
+            _ => {}
+        }
+        current.push(c);
+    }
+
+    if !current.trim().is_empty() {
+        parts.push(current.trim().to_string());
+    }
+
+    parts
+}
+
+/// Structs with `#[derive(.., Debug, ..)]` and their container fields.
+#[tracing::instrument(skip(source))]
+pub fn derived_structs(source: &str) -> Vec<DebugStruct> {
+    let mut structs = vec![];
+    let mut derives_debug = false;
+    let mut lines = source.lines();
+
+    while let Some(line) = lines.next() {
+        let line = line.trim();
+
+        if line.starts_with("#[derive(") {
+            derives_debug |= mentions_word(line, "Debug");
+            continue;
+        }
+        if line.starts_with("#[") || line.starts_with("//") {
+            continue;
+        }
+
+        let declaration = line
+            .strip_prefix("pub ")
+            .or_else(|| line.strip_prefix("pub(crate) "))
+            .unwrap_or(line);
+
+        if let (true, Some(rest)) = (derives_debug, declaration.strip_prefix("struct ")) {
+            let name = ident(rest);
+            let containers = match rest.find('(') {
+                Some(index) if is_container(&rest[index..]) => vec!["0".to_string()],
+                Some(_) => vec![],
+                None if rest.ends_with('{') => lines
+                    .by_ref()
+                    .map(|field| field.trim())
+                    .take_while(|field| !field.starts_with('}'))
+                    .filter_map(|field| field.split_once(':'))
+                    .filter(|(_, ty)| is_container(ty))
+                    .map(|(field, _)| ident(field.trim_start_matches("pub ")))
+                    .collect(),
+                None => vec![],
+            };
+
+            if !containers.is_empty() {
+                structs.push(DebugStruct { name, containers });
+            }
+        }
+
+        derives_debug = false;
+    }
+
+    structs
+}
+
+/// Every `#[tracing::instrument]` function with the arguments it records.
+#[tracing::instrument(skip(source))]
+pub fn instrumented_fns(source: &str) -> Vec<InstrumentedFn> {
+    let lines = source.lines().collect::<Vec<_>>();
+    let mut fns = vec![];
+    let mut impl_type = None;
+
+    for (i, line) in lines.iter().enumerate() {
+        if line.starts_with("impl") {
+            let header = line.trim_end_matches('{').trim();
+            let target = header.rsplit(" for ").next().unwrap_or(header);
+            impl_type = target
+                .split(|c: char| !(c.is_alphanumeric() || c == '_'))
+                .rfind(|word| !word.is_empty() && *word != "impl")
+                .map(|word| word.to_string());
+            continue;
+        }
+        if line.starts_with('}') {
+            impl_type = None;
+            continue;
+        }
+
+        let attribute = line.trim();
+        if !attribute.starts_with("#[tracing::instrument") {
+            continue;
+        }
+
+        let skipped = attribute
+            .split_once("skip(")
+            .and_then(|(_, rest)| rest.split_once(')'))
+            .map(|(names, _)| split_top_level(names))
+            .unwrap_or_default();
+
+        let signature = lines[i + 1..]
+            .iter()
+            .skip_while(|line| line.trim().starts_with("#["))
+            .scan(false, |done, line| {
+                if *done {
+                    return None;
+                }
+                *done = line.contains('{') || line.contains(';');
+                Some(line.trim())
+            })
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        let Some((_, after_fn)) = signature.split_once("fn ") else {
+            continue;
+        };
+        let Some(open) = after_fn.find('(') else {
+            continue;
+        };
+
+        let mut depth = 0;
+        let close = after_fn[open..]
+            .char_indices()
+            .find(|(_, c)| {
+                match c {
+                    '(' => depth += 1,
+                    ')' => depth -= 1,
+                    _ => {}
+                }
+                depth == 0
+            })
+            .map(|(index, _)| open + index)
+            .unwrap_or(after_fn.len());
+
+        fns.push(InstrumentedFn {
+            name: ident(after_fn),
+            impl_type: impl_type.clone(),
+            skip_all: attribute.contains("skip_all"),
+            skipped,
+            params: split_top_level(&after_fn[open + 1..close]),
+        });
+    }
+
+    fns
+}
+
+impl InstrumentedFn {
+    /// Whether the span records an argument of type `name`, including `self`.
+    #[tracing::instrument]
+    pub fn records(&self, name: &str) -> bool {
+        if self.skip_all {
+            return false;
+        }
+
+        self.params.iter().any(|param| {
+            let param = param.trim_start_matches("mut ");
+            match param.split_once(':') {
+                Some((pattern, ty)) => {
+                    let pattern = pattern.trim().trim_start_matches("mut ");
+                    mentions_word(ty, name) && !self.skipped.iter().any(|s| s == pattern)
+                }
+                None => {
+                    mentions_word(param, "self")
+                        && self.impl_type.as_deref() == Some(name)
+                        && !self.skipped.iter().any(|s| s == "self")
+                }
+            }
+        })
+    }
+}
+
+/// Findings for one source file. Only types and functions in the same file are
+/// matched, which covers how the day crates keep their parser state private.
+#[tracing::instrument(skip(source))]
+pub fn lint_source(file: &Path, source: &str) -> Vec<Finding> {
+    let (code, _) = split_tests(source);
+    let fns = instrumented_fns(code);
+
+    derived_structs(code)
+        .into_iter()
+        .filter_map(|s| {
+            let recorded_by = fns.iter().find(|f| f.records(&s.name))?;
+            Some(Finding {
+                file: file.to_path_buf(),
+                name: s.name,
+                containers: s.containers,
+                recorded_by: recorded_by.name.clone(),
+            })
+        })
+        .collect()
+}
+
+#[tracing::instrument]
+pub fn report(root: &Path) -> Result<Vec<Finding>> {
+    let mut findings = vec![];
+
+    for day in workspace::day_dirs(root)? {
+        for path in workspace::rust_files(&day.join("src"))? {
+            let source = std::fs::read_to_string(&path)?;
+            let file = path.strip_prefix(root).unwrap_or(&path);
+            findings.extend(lint_source(file, &source));
+        }
+    }
+
+    Ok(findings)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    const SOURCE: &str = "#[derive(Debug, Clone, PartialEq, Eq)]
+struct Input {
+    lines: Vec<String>,
+    cursor: usize,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Race {
+    time: u64,
+}
+
+#[derive(Clone)]
+struct Grid {
+    cells: Vec<char>,
+}
+
+#[derive(Debug)]
+pub struct Hands(Vec<u8>);
+
+impl Input {
+    #[tracing::instrument]
+    fn next(&mut self) -> Option<&String> {
+        None
+    }
+}
+
+#[tracing::instrument(skip(hands))]
+fn score(hands: &Hands, race: Race) -> u64 {
+    0
+}
+";
+
+    #[test]
+    fn it_should_find_debug_structs_with_containers() -> miette::Result<()> {
+        assert_eq!(
+            vec![
+                DebugStruct {
+                    name: "Input".to_string(),
+                    containers: vec!["lines".to_string()],
+                },
+                DebugStruct {
+                    name: "Hands".to_string(),
+                    containers: vec!["0".to_string()],
+                },
+            ],
+            derived_structs(SOURCE)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_read_instrumented_signatures() -> miette::Result<()> {
+        let fns = instrumented_fns(SOURCE);
+
+        assert_eq!(2, fns.len());
+        assert_eq!(Some("Input".to_string()), fns[0].impl_type);
+        assert_eq!(vec!["&mut self".to_string()], fns[0].params);
+        assert_eq!(None, fns[1].impl_type);
+        assert_eq!(vec!["hands".to_string()], fns[1].skipped);
+        assert_eq!(
+            vec!["hands: &Hands".to_string(), "race: Race".to_string()],
+            fns[1].params
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_flag_recorded_structs_only() -> miette::Result<()> {
+        let findings = lint_source(Path::new("day-05/src/part1.rs"), SOURCE);
+
+        assert_eq!(1, findings.len());
+        assert_eq!("Input", findings[0].name);
+        assert_eq!("next", findings[0].recorded_by);
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_respect_skip_all() -> miette::Result<()> {
+        let fns = instrumented_fns(
+            "impl Input {\n    #[tracing::instrument(skip_all)]\n    fn next(&self) {}\n}",
+        );
+        assert!(!fns[0].records("Input"));
+        Ok(())
+    }
+}
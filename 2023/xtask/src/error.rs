@@ -0,0 +1,57 @@
+use miette::Diagnostic;
+use thiserror::Error;
+
+#[derive(Error, Diagnostic, Debug)]
+pub enum Error {
+    #[error(transparent)]
+    #[diagnostic(code(aoc::io_error))]
+    IoError(#[from] std::io::Error),
+    #[error("Could not find Error enum in {path}")]
+    CouldNotFindErrorEnum { path: String },
+    #[error("{count} error variants are not reached by any test")]
+    UnreachedErrorVariants { count: usize },
+    #[error("{count} structs with large Debug output are recorded by tracing spans")]
+    LargeDebugInSpans { count: usize },
+    #[error("{count} crate attributes enable nightly features without a stable fallback")]
+    NightlyFeatures { count: usize },
+    #[error("Could not read manifest {path}: {reason}")]
+    InvalidManifest { path: String, reason: String },
+    #[error("No crate named {day} in the workspace")]
+    NoSuchDay { day: String },
+    #[error("This workspace is {supported}, not {year}")]
+    UnsupportedYear { year: u16, supported: u16 },
+    #[error("{day} has no binary for {part}")]
+    #[diagnostic(help("Pick a part number or one of: {available}"))]
+    NoSuchBinary {
+        day: String,
+        part: String,
+        available: String,
+    },
+    #[error("{day} has no feature {feature}")]
+    #[diagnostic(help("Features {day} declares: {declared}"))]
+    UnknownFeature {
+        day: String,
+        feature: String,
+        declared: String,
+    },
+    #[error("{day} has no benchmarks")]
+    #[diagnostic(help("Add a [[bench]] named {day} to its Cargo.toml"))]
+    NoBenchmarks { day: String },
+    #[error("`{command}` failed: {status}")]
+    CommandFailed { command: String, status: String },
+    #[error("{count} feature combinations failed to build")]
+    MatrixBuildsFailed { count: usize },
+    #[error("Could not parse answers.toml: {reason}")]
+    InvalidAnswers { reason: String },
+    #[error("{count} hardcoded answers disagree with answers.toml")]
+    AnswerConflicts { count: usize },
+    #[error("{rev} is not a commit in this repository")]
+    UnknownRevision { rev: String },
+    #[error("{count} files are embedded by a day but missing, so it won't build")]
+    MissingEmbeddedFiles { count: usize },
+    #[error("attempts.rs has no ATTEMPTS list to add to")]
+    NoAttemptsList,
+    #[error("{count} real inputs are embedded at compile time")]
+    #[diagnostic(help("Read them with aoc_core::puzzle_input! so a fresh clone still builds"))]
+    EmbeddedRealInputs { count: usize },
+}
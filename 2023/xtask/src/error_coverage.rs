@@ -0,0 +1,312 @@
+use std::path::Path;
+
+use crate::{error::Error, prelude::*, workspace};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Variant {
+    pub name: String,
+    pub from: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// A test produces this variant from malformed input.
+    Tested,
+    /// Constructed by the solution code but no test reaches it.
+    Untested,
+    /// Only reachable through a `#[from]` conversion, and nothing shows that happening.
+    FromOnly,
+    /// Never constructed anywhere.
+    NeverConstructed,
+}
+
+impl Status {
+    #[tracing::instrument]
+    pub fn is_reached(&self) -> bool {
+        *self == Status::Tested
+    }
+
+    #[tracing::instrument]
+    pub fn label(&self) -> &'static str {
+        match self {
+            Status::Tested => "tested",
+            Status::Untested => "untested",
+            Status::FromOnly => "from only",
+            Status::NeverConstructed => "never constructed",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DayCoverage {
+    pub day: String,
+    pub variants: Vec<(Variant, Status)>,
+}
+
+impl DayCoverage {
+    #[tracing::instrument]
+    pub fn unreached(&self) -> impl Iterator<Item = &(Variant, Status)> {
+        self.variants
+            .iter()
+            .filter(|(_, status)| !status.is_reached())
+    }
+}
+
+/// Pulls the variant names out of a day's `pub enum Error`. Only names at
+/// the top level of the enum body count, so the fields of a struct variant
+/// spread over several lines aren't taken for variants.
+#[tracing::instrument(skip(source))]
+pub fn parse_variants(source: &str) -> Option<Vec<Variant>> {
+    let mut lines = source
+        .lines()
+        .skip_while(|line| !line.contains("pub enum Error"));
+    let mut depth = nesting(lines.next()?);
+
+    let mut variants = vec![];
+
+    for line in lines {
+        let outer = depth == 1;
+        depth += nesting(line);
+
+        if depth <= 0 {
+            return Some(variants);
+        }
+
+        let line = line.trim();
+        if !outer || line.is_empty() || line.starts_with("#[") || line.starts_with("//") {
+            continue;
+        }
+
+        let name = line
+            .chars()
+            .take_while(|c| c.is_alphanumeric() || *c == '_')
+            .collect::<String>();
+
+        if name.is_empty() {
+            continue;
+        }
+
+        variants.push(Variant {
+            name,
+            from: line.contains("#[from]"),
+        });
+    }
+
+    None
+}
+
+/// How many more brackets `line` opens than closes, skipping any inside
+/// string literals like the `{line}` in an `#[error(...)]` message.
+#[tracing::instrument]
+fn nesting(line: &str) -> i32 {
+    let mut depth = 0;
+    let mut in_string = false;
+    let mut chars = line.chars();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' if in_string => {
+                chars.next();
+            }
+            '"' => in_string = !in_string,
+            '{' | '(' | '[' if !in_string => depth += 1,
+            '}' | ')' | ']' if !in_string => depth -= 1,
+            _ => {}
+        }
+    }
+
+    depth
+}
+
+/// Splits a source file into its solution code and its `#[cfg(test)]` module.
+#[tracing::instrument(skip(source))]
+pub fn split_tests(source: &str) -> (&str, &str) {
+    match source.find("#[cfg(test)]") {
+        Some(index) => source.split_at(index),
+        None => (source, ""),
+    }
+}
+
+#[tracing::instrument(skip(source))]
+pub fn mentions_variant(source: &str, name: &str) -> bool {
+    let needle = format!("Error::{}", name);
+
+    source.match_indices(&needle).any(|(index, _)| {
+        source[index + needle.len()..]
+            .chars()
+            .next()
+            .is_none_or(|c| !(c.is_alphanumeric() || c == '_'))
+    })
+}
+
+#[tracing::instrument(skip(code, tests))]
+pub fn classify(variant: &Variant, code: &[String], tests: &[String]) -> Status {
+    if tests
+        .iter()
+        .any(|source| mentions_variant(source, &variant.name))
+    {
+        return Status::Tested;
+    }
+
+    if code
+        .iter()
+        .any(|source| mentions_variant(source, &variant.name))
+    {
+        return Status::Untested;
+    }
+
+    if variant.from {
+        return Status::FromOnly;
+    }
+
+    Status::NeverConstructed
+}
+
+#[tracing::instrument]
+pub fn day_coverage(day_dir: &Path) -> Result<DayCoverage> {
+    let src = day_dir.join("src");
+    let error_path = src.join("error.rs");
+
+    let variants = parse_variants(&std::fs::read_to_string(&error_path)?).ok_or_else(|| {
+        Error::CouldNotFindErrorEnum {
+            path: error_path.display().to_string(),
+        }
+    })?;
+
+    let mut code = vec![];
+    let mut tests = vec![];
+
+    for path in workspace::rust_files(&src)? {
+        let source = std::fs::read_to_string(&path)?;
+        let (solution, test) = split_tests(&source);
+
+        if path != error_path {
+            code.push(solution.to_string());
+        }
+        tests.push(test.to_string());
+    }
+
+    let variants = variants
+        .into_iter()
+        .map(|variant| {
+            let status = classify(&variant, &code, &tests);
+            (variant, status)
+        })
+        .collect();
+
+    Ok(DayCoverage {
+        day: day_dir
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_default(),
+        variants,
+    })
+}
+
+#[tracing::instrument]
+pub fn report(root: &Path) -> Result<Vec<DayCoverage>> {
+    workspace::day_dirs(root)?
+        .iter()
+        .filter(|dir| dir.join("src").join("error.rs").exists())
+        .map(|dir| day_coverage(dir))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    const ERROR_SOURCE: &str = "use miette::Diagnostic;
+#[derive(Error, Diagnostic, Debug)]
+pub enum Error {
+    #[error(transparent)]
+    #[diagnostic(code(aoc::io_error))]
+    IoError(#[from] std::io::Error),
+    #[error(\"Missing distance {0}\")]
+    MissingDistance(usize),
+    #[error(\"Cannot find numbers for line {line}\")]
+    CannotFindNumbers { line: usize },
+    #[error(\"No min value\")]
+    NoMinValue,
+    #[error(\"Missing line {prefix}\")]
+    MissingLine {
+        prefix: String,
+        #[source_code]
+        input: String,
+        #[label(\"here\")]
+        span: (usize, usize),
+    },
+    #[error(\"Galaxy {id} of {count}\")]
+    NoSuchGalaxy { id: u16, count: usize },
+}";
+
+    #[test]
+    fn it_should_parse_variants() -> miette::Result<()> {
+        let variants = parse_variants(ERROR_SOURCE).unwrap_or_default();
+        let names = variants.iter().map(|v| v.name.as_str()).collect::<Vec<_>>();
+        assert_eq!(
+            vec![
+                "IoError",
+                "MissingDistance",
+                "CannotFindNumbers",
+                "NoMinValue",
+                "MissingLine",
+                "NoSuchGalaxy"
+            ],
+            names
+        );
+        assert_eq!(
+            vec![true, false, false, false, false, false],
+            variants.iter().map(|v| v.from).collect::<Vec<_>>()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_not_match_variant_prefixes() -> miette::Result<()> {
+        assert!(mentions_variant("Err(Error::NoMinValue)", "NoMinValue"));
+        assert!(!mentions_variant("Err(Error::NoMinValues)", "NoMinValue"));
+        assert!(mentions_variant(
+            "Error::CannotFindNumbers { line: 0 }",
+            "CannotFindNumbers"
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_classify_variants() -> miette::Result<()> {
+        let variants = parse_variants(ERROR_SOURCE).unwrap_or_default();
+        let code = vec![
+            "return Err(Error::MissingDistance(i));".to_string(),
+            "Error::NoMinValue".to_string(),
+        ];
+        let tests = vec!["matches!(e, Error::NoMinValue)".to_string()];
+
+        let statuses = variants
+            .iter()
+            .map(|v| classify(v, &code, &tests))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            vec![
+                Status::FromOnly,
+                Status::Untested,
+                Status::NeverConstructed,
+                Status::Tested,
+                Status::NeverConstructed,
+                Status::NeverConstructed
+            ],
+            statuses
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_split_off_test_module() -> miette::Result<()> {
+        let (code, tests) = split_tests("fn a() {}\n#[cfg(test)]\nmod tests {}");
+        assert_eq!("fn a() {}\n", code);
+        assert_eq!("#[cfg(test)]\nmod tests {}", tests);
+        Ok(())
+    }
+}
@@ -0,0 +1,310 @@
+use std::{
+    collections::BTreeMap,
+    path::{Component, Path, PathBuf},
+};
+
+use crate::{error::Error, prelude::*, workspace};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Load {
+    /// Embedded with `include_str!`, so the crate doesn't build without it.
+    Embedded,
+    /// Read with `puzzle_input!` when something runs, so it can be fetched later.
+    Runtime,
+}
+
+impl Load {
+    #[tracing::instrument]
+    pub fn label(&self) -> &'static str {
+        match self {
+            Load::Embedded => "embedded",
+            Load::Runtime => "runtime",
+        }
+    }
+}
+
+/// A file a day asks for, relative to the day's crate where it's inside it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Expected {
+    pub file: PathBuf,
+    pub load: Load,
+    pub exists: bool,
+    /// Where it's asked for, like `src/part1.rs:12`.
+    pub wanted_by: Vec<String>,
+}
+
+impl Expected {
+    /// Real inputs are the `inputN.txt` files, unlike the worked examples.
+    #[tracing::instrument]
+    pub fn is_real_input(&self) -> bool {
+        self.file
+            .file_name()
+            .and_then(|name| name.to_str())
+            .is_some_and(|name| name.starts_with("input"))
+    }
+
+    #[tracing::instrument]
+    pub fn label(&self) -> &'static str {
+        match (self.load, self.exists) {
+            (Load::Embedded, false) => "missing, won't build",
+            (Load::Runtime, false) => "missing, fetch it",
+            (Load::Embedded, true) if self.is_real_input() => "embeds real input",
+            _ => "ok",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DayInputs {
+    pub day: String,
+    pub expected: Vec<Expected>,
+}
+
+impl DayInputs {
+    /// Embedded files that aren't there, so the crate can't build.
+    #[tracing::instrument]
+    pub fn unbuildable(&self) -> impl Iterator<Item = &Expected> {
+        self.expected
+            .iter()
+            .filter(|e| e.load == Load::Embedded && !e.exists)
+    }
+
+    /// Real inputs embedded at compile time, which a fresh clone may not have.
+    #[tracing::instrument]
+    pub fn embedded_real_inputs(&self) -> impl Iterator<Item = &Expected> {
+        self.expected
+            .iter()
+            .filter(|e| e.load == Load::Embedded && e.is_real_input())
+    }
+
+    /// Real inputs read at runtime that haven't been fetched yet.
+    #[tracing::instrument]
+    pub fn unfetched(&self) -> impl Iterator<Item = &Expected> {
+        self.expected
+            .iter()
+            .filter(|e| e.load == Load::Runtime && !e.exists)
+    }
+}
+
+/// `(line, path)` for each `name!("path"` in `source`, skipping comments.
+#[tracing::instrument(skip(source))]
+pub fn macro_paths(source: &str, name: &str) -> Vec<(usize, String)> {
+    let call = format!("{}!(\"", name);
+
+    source
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim_start().starts_with("//"))
+        .flat_map(|(i, line)| {
+            line.match_indices(&call)
+                .filter_map(|(index, _)| {
+                    let rest = &line[index + call.len()..];
+                    Some((i + 1, rest[..rest.find('"')?].to_string()))
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+/// `path` with its `.` and `..` resolved without touching the filesystem.
+#[tracing::instrument]
+fn normalize(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir if normalized.file_name().is_some() => {
+                normalized.pop();
+            }
+            component => normalized.push(component),
+        }
+    }
+
+    normalized
+}
+
+/// The example files `[package.metadata.aoc]` lists, which the runner embeds.
+#[tracing::instrument]
+pub fn example_files(manifest: &Path) -> Result<Vec<String>> {
+    let source = std::fs::read_to_string(manifest)?;
+    let manifest_table = source
+        .parse::<toml::Table>()
+        .map_err(|e| Error::InvalidManifest {
+            path: manifest.display().to_string(),
+            reason: e.to_string(),
+        })?;
+
+    Ok(manifest_table
+        .get("package")
+        .and_then(|package| package.get("metadata"))
+        .and_then(|metadata| metadata.get("aoc"))
+        .and_then(|aoc| aoc.get("examples"))
+        .and_then(|examples| examples.as_array())
+        .into_iter()
+        .flatten()
+        .filter_map(|example| example.get("file")?.as_str())
+        .map(|file| file.to_string())
+        .collect())
+}
+
+/// Every file the day at `dir` embeds or reads, in path order.
+#[tracing::instrument]
+pub fn day_inputs(dir: &Path) -> Result<DayInputs> {
+    let mut wanted: BTreeMap<PathBuf, (Load, Vec<String>)> = BTreeMap::new();
+    let mut want = |path: PathBuf, load: Load, by: String| {
+        let entry = wanted.entry(normalize(&path)).or_insert((load, vec![]));
+        entry.0 = entry.0.min(load);
+        entry.1.push(by);
+    };
+
+    for file in example_files(&dir.join("Cargo.toml"))? {
+        want(dir.join(file), Load::Embedded, "Cargo.toml".to_string());
+    }
+
+    for source_file in workspace::rust_files(dir)? {
+        let source = std::fs::read_to_string(&source_file)?;
+        let relative = source_file.strip_prefix(dir).unwrap_or(&source_file);
+        let by = |line| format!("{}:{}", relative.display(), line);
+        let beside = source_file.parent().unwrap_or(dir);
+
+        for (line, path) in macro_paths(&source, "include_str") {
+            want(beside.join(path), Load::Embedded, by(line));
+        }
+        for (line, path) in macro_paths(&source, "puzzle_input") {
+            want(dir.join(path), Load::Runtime, by(line));
+        }
+    }
+
+    let dir = normalize(dir);
+    let expected = wanted
+        .into_iter()
+        .map(|(path, (load, wanted_by))| Expected {
+            exists: path.is_file(),
+            file: path.strip_prefix(&dir).unwrap_or(&path).to_path_buf(),
+            load,
+            wanted_by,
+        })
+        .collect();
+
+    Ok(DayInputs {
+        day: dir
+            .file_name()
+            .and_then(|name| name.to_str())
+            .unwrap_or_default()
+            .to_string(),
+        expected,
+    })
+}
+
+#[tracing::instrument]
+pub fn report(root: &Path) -> Result<Vec<DayInputs>> {
+    workspace::day_dirs(root)?
+        .iter()
+        .map(|dir| day_inputs(dir))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn it_should_find_macro_paths_outside_comments() -> miette::Result<()> {
+        let source = r#"let input = include_str!("../example1.txt");
+    // let input = include_str!("../input2.txt");
+    let file = aoc_core::puzzle_input!("input1.txt")?;
+    let both = (include_str!("a.txt"), include_str!("b.txt"));"#;
+
+        assert_eq!(
+            vec![
+                (1, "../example1.txt".to_string()),
+                (4, "a.txt".to_string()),
+                (4, "b.txt".to_string()),
+            ],
+            macro_paths(source, "include_str")
+        );
+        assert_eq!(
+            vec![(3, "input1.txt".to_string())],
+            macro_paths(source, "puzzle_input")
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_resolve_parent_directories() -> miette::Result<()> {
+        assert_eq!(
+            PathBuf::from("/repo/day-01/input1.txt"),
+            normalize(Path::new("/repo/day-01/src/bin/../../input1.txt"))
+        );
+        assert_eq!(
+            PathBuf::from("/repo/answers.toml"),
+            normalize(Path::new("/repo/day-01/./../answers.toml"))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_audit_a_day() -> miette::Result<()> {
+        let dir = std::env::temp_dir().join(format!("xtask-inputs-{}/day-99", std::process::id()));
+        std::fs::create_dir_all(dir.join("src/bin")).map_err(Error::from)?;
+        std::fs::write(
+            dir.join("Cargo.toml"),
+            "[package]\nname = \"day-99\"\n\n[package.metadata.aoc]\nexamples = [\n    { part = 1, file = \"example1.txt\", expected = \"1\" },\n]\n",
+        )
+        .map_err(Error::from)?;
+        std::fs::write(dir.join("example1.txt"), "1").map_err(Error::from)?;
+        std::fs::write(
+            dir.join("src/part1.rs"),
+            "const EXAMPLE: &str = include_str!(\"../example2.txt\");\nconst INPUT: &str = include_str!(\"../input2.txt\");\n",
+        )
+        .map_err(Error::from)?;
+        std::fs::write(
+            dir.join("src/bin/part1.rs"),
+            "let file = aoc_core::puzzle_input!(\"input1.txt\")?;\n",
+        )
+        .map_err(Error::from)?;
+
+        let inputs = day_inputs(&dir)?;
+        let labels = inputs
+            .expected
+            .iter()
+            .map(|e| (e.file.display().to_string(), e.label()))
+            .collect::<Vec<_>>();
+
+        assert_eq!("day-99", inputs.day);
+        assert_eq!(
+            vec![
+                ("example1.txt".to_string(), "ok"),
+                ("example2.txt".to_string(), "missing, won't build"),
+                ("input1.txt".to_string(), "missing, fetch it"),
+                ("input2.txt".to_string(), "missing, won't build"),
+            ],
+            labels
+        );
+        assert_eq!(2, inputs.unbuildable().count());
+        assert_eq!(1, inputs.embedded_real_inputs().count());
+        assert_eq!(1, inputs.unfetched().count());
+        assert_eq!(vec!["src/part1.rs:1"], inputs.expected[1].wanted_by);
+
+        std::fs::remove_dir_all(dir.join("..")).map_err(Error::from)?;
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_find_nothing_missing_in_this_workspace() -> miette::Result<()> {
+        let report = report(&workspace::root())?;
+
+        assert!(!report.is_empty());
+        assert_eq!(0, report.iter().flat_map(|day| day.unbuildable()).count());
+        assert_eq!(
+            0,
+            report
+                .iter()
+                .flat_map(|day| day.embedded_real_inputs())
+                .count()
+        );
+        Ok(())
+    }
+}
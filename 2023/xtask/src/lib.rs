@@ -0,0 +1,13 @@
+pub mod error;
+pub mod prelude;
+
+pub mod attempts;
+pub mod bench_summary;
+pub mod bootstrap_answers;
+pub mod debug_lint;
+pub mod error_coverage;
+pub mod input_audit;
+pub mod matrix;
+pub mod nightly_lint;
+pub mod shortcut;
+pub mod workspace;
@@ -0,0 +1,413 @@
+use clap::{Parser, Subcommand};
+use xtask::{
+    attempts, bench_summary, bootstrap_answers, debug_lint, error::Error, error_coverage,
+    input_audit, matrix, nightly_lint, shortcut, workspace,
+};
+
+#[derive(Parser, Debug)]
+#[command(about = "Workspace maintenance tasks")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// List error variants that no test can produce
+    ErrorCoverage {
+        /// Exit with an error if any variant is unreached
+        #[arg(long)]
+        deny: bool,
+    },
+    /// List structs whose derived Debug formats whole containers inside tracing spans
+    DebugLint {
+        /// Exit with an error if anything is flagged
+        #[arg(long)]
+        deny: bool,
+    },
+    /// List crate attributes that turn on nightly-only features unconditionally
+    NightlyLint {
+        /// Exit with an error if anything is flagged
+        #[arg(long)]
+        deny: bool,
+    },
+    /// Build every day under each combination of the features it declares
+    Matrix {
+        /// Only build this day
+        #[arg(long)]
+        day: Option<u8>,
+    },
+    /// List the files each day embeds or reads at runtime, and which are missing
+    AuditInputs {
+        /// Also exit with an error if a day embeds a real input
+        #[arg(long)]
+        deny: bool,
+    },
+    /// Summarise how benchmark timings changed since a revision, to paste
+    /// into a commit message
+    BenchSummary {
+        /// The revision to compare `benchmarks/` against, like `HEAD~3` or `main`
+        #[arg(long)]
+        since: String,
+        /// Changes within this percentage are shown as noise
+        #[arg(long, default_value_t = bench_summary::DEFAULT_TOLERANCE)]
+        tolerance: f64,
+    },
+    /// Move answers hardcoded in real-input tests into answers.toml and point
+    /// the tests at it
+    BootstrapAnswers {
+        /// Report what would change without writing anything
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// Add a stub to each day's attempts changelog for variant modules it
+    /// doesn't list yet, starting the changelog where there isn't one
+    ScaffoldAttempts {
+        /// Only scaffold this day
+        #[arg(long)]
+        day: Option<u8>,
+        /// Date the stubs this day, as YYYY-MM-DD, rather than today
+        #[arg(long)]
+        date: Option<String>,
+        /// Report what would be added without writing anything
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// Run one part of a day, as `cargo day 2023 11 2`
+    Day {
+        year: u16,
+        day: u8,
+        /// A part number, or a binary's name like part2_opt
+        part: String,
+        /// Features of the day to build with, comma separated
+        #[arg(long, value_delimiter = ',')]
+        features: Vec<String>,
+        /// Build without optimisations
+        #[arg(long)]
+        debug: bool,
+        /// Passed on to the solution, after `--`
+        #[arg(last = true)]
+        args: Vec<String>,
+    },
+    /// Benchmark a day, as `cargo bench-day 2023 5`
+    BenchDay {
+        year: u16,
+        day: u8,
+        /// Only run benchmarks whose names contain this, like part2
+        filter: Option<String>,
+        /// Features of the day to build with, comma separated
+        #[arg(long, value_delimiter = ',')]
+        features: Vec<String>,
+        /// Passed on to divan, after `--`
+        #[arg(last = true)]
+        args: Vec<String>,
+    },
+}
+
+#[tracing::instrument]
+fn error_coverage(deny: bool) -> miette::Result<()> {
+    let report = error_coverage::report(&workspace::root())?;
+
+    let mut unreached = 0;
+
+    for day in &report {
+        println!("{}", day.day);
+        for (variant, status) in &day.variants {
+            println!("  {:<18} {}", status.label(), variant.name);
+        }
+        unreached += day.unreached().count();
+    }
+
+    let total = report.iter().map(|day| day.variants.len()).sum::<usize>();
+    println!();
+    println!("{} of {} error variants unreached", unreached, total);
+
+    if deny && unreached > 0 {
+        return Err(Error::UnreachedErrorVariants { count: unreached }.into());
+    }
+
+    Ok(())
+}
+
+#[tracing::instrument]
+fn debug_lint(deny: bool) -> miette::Result<()> {
+    let findings = debug_lint::report(&workspace::root())?;
+
+    for finding in &findings {
+        println!(
+            "{}: {} derives Debug over {} and is recorded by {}",
+            finding.file.display(),
+            finding.name,
+            finding.containers.join(", "),
+            finding.recorded_by
+        );
+    }
+
+    println!();
+    println!(
+        "{} structs format containers in tracing spans; implement a Debug that prints sizes, or skip them in instrument",
+        findings.len()
+    );
+
+    if deny && !findings.is_empty() {
+        return Err(Error::LargeDebugInSpans {
+            count: findings.len(),
+        }
+        .into());
+    }
+
+    Ok(())
+}
+
+#[tracing::instrument]
+fn nightly_lint(deny: bool) -> miette::Result<()> {
+    let findings = nightly_lint::report(&workspace::root())?;
+
+    for finding in &findings {
+        println!(
+            "{}:{}: enables {}",
+            finding.file.display(),
+            finding.line,
+            finding.features.join(", ")
+        );
+    }
+
+    println!();
+    println!(
+        "{} nightly feature attributes; gate them with #![cfg_attr(feature = \"nightly\", feature(..))] and add a stable fallback",
+        findings.len()
+    );
+
+    if deny && !findings.is_empty() {
+        return Err(Error::NightlyFeatures {
+            count: findings.len(),
+        }
+        .into());
+    }
+
+    Ok(())
+}
+
+#[tracing::instrument]
+fn matrix(day: Option<u8>) -> miette::Result<()> {
+    let builds = matrix::run(&workspace::root(), day)?;
+
+    print!("{}", matrix::grid(&builds));
+
+    let failed = builds.iter().filter(|b| !b.passed()).collect::<Vec<_>>();
+
+    if !failed.is_empty() {
+        println!();
+        for build in &failed {
+            println!(
+                "{} [{}]: {}",
+                build.day,
+                matrix::label(&build.features),
+                build.error.as_deref().unwrap_or_default()
+            );
+        }
+    }
+
+    println!();
+    println!("{} of {} builds failed", failed.len(), builds.len());
+
+    if !failed.is_empty() {
+        return Err(Error::MatrixBuildsFailed {
+            count: failed.len(),
+        }
+        .into());
+    }
+
+    Ok(())
+}
+
+#[tracing::instrument]
+fn audit_inputs(deny: bool) -> miette::Result<()> {
+    let report = input_audit::report(&workspace::root())?;
+
+    for day in &report {
+        println!("{}", day.day);
+        for expected in &day.expected {
+            println!(
+                "  {:<20} {:<8} {} ({})",
+                expected.label(),
+                expected.load.label(),
+                expected.file.display(),
+                expected.wanted_by.join(", ")
+            );
+        }
+    }
+
+    let unbuildable = report.iter().flat_map(|day| day.unbuildable()).count();
+    let embedded = report
+        .iter()
+        .flat_map(|day| day.embedded_real_inputs())
+        .count();
+    let unfetched = report.iter().flat_map(|day| day.unfetched()).count();
+
+    println!();
+    println!(
+        "{} embedded files missing, {} real inputs embedded, {} real inputs still to fetch",
+        unbuildable, embedded, unfetched
+    );
+
+    if unbuildable > 0 {
+        return Err(Error::MissingEmbeddedFiles { count: unbuildable }.into());
+    }
+    if deny && embedded > 0 {
+        return Err(Error::EmbeddedRealInputs { count: embedded }.into());
+    }
+
+    Ok(())
+}
+
+#[tracing::instrument]
+fn bench_summary(since: &str, tolerance: f64) -> miette::Result<()> {
+    let changes = bench_summary::since(&workspace::root(), since)?;
+
+    print!("{}", bench_summary::render(since, &changes, tolerance));
+
+    Ok(())
+}
+
+#[tracing::instrument]
+fn bootstrap_answers(dry_run: bool) -> miette::Result<()> {
+    let root = workspace::root();
+    let results = bootstrap_answers::run(&root, dry_run)?;
+
+    for (hardcoded, outcome) in &results {
+        println!(
+            "{}:{}: {} day {} part {} = {}, {}",
+            hardcoded
+                .file
+                .strip_prefix(&root)
+                .unwrap_or(&hardcoded.file)
+                .display(),
+            hardcoded.line,
+            bootstrap_answers::YEAR,
+            hardcoded.day,
+            hardcoded.part,
+            hardcoded.answer,
+            outcome.label()
+        );
+    }
+
+    let conflicts = results
+        .iter()
+        .filter(|(_, outcome)| matches!(outcome, bootstrap_answers::Outcome::Conflict { .. }))
+        .count();
+
+    println!();
+    if dry_run {
+        println!("{} hardcoded answers found, nothing written", results.len());
+    } else {
+        println!("{} tests now read answers.toml", results.len() - conflicts);
+    }
+
+    if conflicts > 0 {
+        return Err(Error::AnswerConflicts { count: conflicts }.into());
+    }
+
+    Ok(())
+}
+
+#[tracing::instrument]
+fn scaffold_attempts(day: Option<u8>, date: Option<String>, dry_run: bool) -> miette::Result<()> {
+    let date = date.unwrap_or_else(attempts::today);
+    let scaffolded = attempts::scaffold(&workspace::root(), day, &date, dry_run)?;
+
+    for day in &scaffolded {
+        let note = match day.created {
+            true => " (new attempts.rs)",
+            false => "",
+        };
+        println!("{}{}: {}", day.day, note, day.names.join(", "));
+    }
+
+    let count = scaffolded.iter().map(|day| day.names.len()).sum::<usize>();
+    println!();
+    if dry_run {
+        println!("{} attempts to add, nothing written", count);
+    } else {
+        println!("{} attempts added, dated {}", count, date);
+    }
+
+    Ok(())
+}
+
+#[tracing::instrument]
+fn run_day(
+    year: u16,
+    day: u8,
+    part: &str,
+    features: &[String],
+    dev_profile: bool,
+    args: &[String],
+) -> miette::Result<()> {
+    let root = workspace::root();
+    let target = shortcut::Target::find(&root, year, day)?;
+    let binary = target.binary(part)?;
+    target.check_features(features)?;
+
+    let release = !dev_profile;
+    shortcut::run(shortcut::run_command(
+        &root, &target, &binary, features, release, args,
+    ))?;
+
+    Ok(())
+}
+
+#[tracing::instrument]
+fn bench_day(
+    year: u16,
+    day: u8,
+    filter: Option<&str>,
+    features: &[String],
+    args: &[String],
+) -> miette::Result<()> {
+    let root = workspace::root();
+    let target = shortcut::Target::find(&root, year, day)?;
+    if !target.has_benchmarks()? {
+        return Err(Error::NoBenchmarks {
+            day: target.package,
+        }
+        .into());
+    }
+    target.check_features(features)?;
+
+    shortcut::run(shortcut::bench_command(
+        &root, &target, filter, features, args,
+    ))?;
+
+    Ok(())
+}
+
+fn main() -> miette::Result<()> {
+    let cli = Cli::parse();
+
+    match cli.command {
+        Command::ErrorCoverage { deny } => error_coverage(deny),
+        Command::DebugLint { deny } => debug_lint(deny),
+        Command::NightlyLint { deny } => nightly_lint(deny),
+        Command::Matrix { day } => matrix(day),
+        Command::AuditInputs { deny } => audit_inputs(deny),
+        Command::BenchSummary { since, tolerance } => bench_summary(&since, tolerance),
+        Command::BootstrapAnswers { dry_run } => bootstrap_answers(dry_run),
+        Command::ScaffoldAttempts { day, date, dry_run } => scaffold_attempts(day, date, dry_run),
+        Command::Day {
+            year,
+            day,
+            part,
+            features,
+            debug,
+            args,
+        } => run_day(year, day, &part, &features, debug, &args),
+        Command::BenchDay {
+            year,
+            day,
+            filter,
+            features,
+            args,
+        } => bench_day(year, day, filter.as_deref(), &features, &args),
+    }
+}
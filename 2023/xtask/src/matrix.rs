@@ -0,0 +1,234 @@
+use std::{path::Path, process::Command};
+
+use aoc_ui::Table;
+
+use crate::{error::Error, prelude::*, workspace};
+
+/// Features that change which code a day compiles. Anything else a day
+/// declares (like `dhat-heap`) is left out of the matrix.
+pub const MATRIX_FEATURES: [&str; 3] = ["parallel", "checked-math", "viz"];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Build {
+    pub day: String,
+    pub features: Vec<String>,
+    /// First compiler error line, if the build failed.
+    pub error: Option<String>,
+}
+
+impl Build {
+    #[tracing::instrument]
+    pub fn passed(&self) -> bool {
+        self.error.is_none()
+    }
+}
+
+/// The matrix features a day's `Cargo.toml` declares, in `MATRIX_FEATURES` order.
+#[tracing::instrument]
+pub fn declared_features(manifest: &Path) -> Result<Vec<String>> {
+    let manifest_table = workspace::manifest(manifest)?;
+
+    let declared = manifest_table
+        .get("features")
+        .and_then(|features| features.as_table());
+
+    Ok(MATRIX_FEATURES
+        .iter()
+        .filter(|feature| declared.is_some_and(|declared| declared.contains_key(**feature)))
+        .map(|feature| feature.to_string())
+        .collect())
+}
+
+/// Every subset of `features`, starting with none of them.
+#[tracing::instrument]
+pub fn combinations(features: &[String]) -> Vec<Vec<String>> {
+    (0..1usize << features.len())
+        .map(|mask| {
+            features
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| mask & (1 << i) != 0)
+                .map(|(_, feature)| feature.clone())
+                .collect()
+        })
+        .collect()
+}
+
+#[tracing::instrument]
+pub fn label(features: &[String]) -> String {
+    if features.is_empty() {
+        "none".to_string()
+    } else {
+        features.join("+")
+    }
+}
+
+#[tracing::instrument]
+fn build(root: &Path, day: &str, features: &[String]) -> Result<Build> {
+    let cargo = std::env::var("CARGO").unwrap_or_else(|_| "cargo".to_string());
+
+    let mut command = Command::new(cargo);
+    command
+        .current_dir(root)
+        .args(["build", "--quiet", "--all-targets", "--package", day])
+        .arg("--no-default-features");
+
+    if !features.is_empty() {
+        command.args(["--features", &features.join(",")]);
+    }
+
+    let output = command.output()?;
+
+    let error = (!output.status.success()).then(|| {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        stderr
+            .lines()
+            .find(|line| line.starts_with("error"))
+            .unwrap_or("build failed")
+            .to_string()
+    });
+
+    Ok(Build {
+        day: day.to_string(),
+        features: features.to_vec(),
+        error,
+    })
+}
+
+/// Builds every day (or just `only`) under each combination of the matrix
+/// features it declares.
+#[tracing::instrument]
+pub fn run(root: &Path, only: Option<u8>) -> Result<Vec<Build>> {
+    let mut dirs = workspace::day_dirs(root)?;
+
+    if let Some(day) = only {
+        let name = format!("day-{:02}", day);
+        dirs.retain(|dir| dir.file_name().is_some_and(|n| n == name.as_str()));
+
+        if dirs.is_empty() {
+            return Err(Error::NoSuchDay { day: name });
+        }
+    }
+
+    let mut builds = vec![];
+
+    for dir in dirs {
+        let day = dir
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_default();
+
+        for features in combinations(&declared_features(&dir.join("Cargo.toml"))?) {
+            builds.push(build(root, &day, &features)?);
+        }
+    }
+
+    Ok(builds)
+}
+
+/// One row per day and one column per combination of `MATRIX_FEATURES`.
+/// Combinations a day doesn't declare show as `-`.
+#[tracing::instrument(skip(builds))]
+pub fn grid(builds: &[Build]) -> String {
+    let all = MATRIX_FEATURES.map(|f| f.to_string());
+    let columns = combinations(&all);
+    let labels = columns.iter().map(|c| label(c)).collect::<Vec<_>>();
+
+    let mut headers = vec!["day"];
+    headers.extend(labels.iter().map(|l| l.as_str()));
+    let mut table = Table::new(&headers);
+
+    let mut days = builds.iter().map(|b| b.day.as_str()).collect::<Vec<_>>();
+    days.dedup();
+
+    for day in days {
+        let mut row = vec![day.to_string()];
+
+        for column in &columns {
+            let cell = match builds
+                .iter()
+                .find(|b| b.day == day && b.features == *column)
+            {
+                Some(build) if build.passed() => "ok",
+                Some(_) => "FAIL",
+                None => "-",
+            };
+            row.push(cell.to_string());
+        }
+
+        table.row(row);
+    }
+
+    table.render()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn strings(values: &[&str]) -> Vec<String> {
+        values.iter().map(|v| v.to_string()).collect()
+    }
+
+    #[test]
+    fn it_should_list_every_combination() -> miette::Result<()> {
+        assert_eq!(
+            vec![
+                vec![],
+                strings(&["parallel"]),
+                strings(&["viz"]),
+                strings(&["parallel", "viz"]),
+            ],
+            combinations(&strings(&["parallel", "viz"]))
+        );
+        assert_eq!(vec![Vec::<String>::new()], combinations(&[]));
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_only_read_matrix_features() -> miette::Result<()> {
+        let dir = std::env::temp_dir().join(format!("xtask-matrix-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).map_err(Error::from)?;
+        let manifest = dir.join("Cargo.toml");
+        std::fs::write(
+            &manifest,
+            "[package]\nname = \"day-99\"\n\n[features]\ndhat-heap = []\nviz = []\nparallel = [\"dep:rayon\"]\n",
+        )
+        .map_err(Error::from)?;
+
+        assert_eq!(strings(&["parallel", "viz"]), declared_features(&manifest)?);
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_draw_grid() -> miette::Result<()> {
+        let builds = vec![
+            Build {
+                day: "day-01".to_string(),
+                features: vec![],
+                error: None,
+            },
+            Build {
+                day: "day-02".to_string(),
+                features: vec![],
+                error: None,
+            },
+            Build {
+                day: "day-02".to_string(),
+                features: strings(&["viz"]),
+                error: Some("error[E0425]: cannot find value `canvas`".to_string()),
+            },
+        ];
+
+        let grid = grid(&builds);
+        let lines = grid.lines().collect::<Vec<_>>();
+
+        assert_eq!(3, lines.len());
+        assert!(lines[0].starts_with("day     none  parallel  checked-math"));
+        assert_eq!("day-01  ok    -", &lines[1][..15]);
+        assert!(lines[2].contains("FAIL"));
+        assert_eq!(1, lines[2].matches("ok").count());
+        Ok(())
+    }
+}
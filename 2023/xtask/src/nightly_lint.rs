@@ -0,0 +1,94 @@
+use std::path::{Path, PathBuf};
+
+use crate::{prelude::*, workspace};
+
+/// An unconditional `#![feature(..)]`, which makes the whole workspace need a
+/// nightly toolchain. Gating it as `#![cfg_attr(feature = "nightly", feature(..))]`
+/// with a stable fallback keeps stable builds working and isn't reported.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Finding {
+    pub file: PathBuf,
+    pub line: usize,
+    pub features: Vec<String>,
+}
+
+/// Line numbers and feature names of every ungated `#![feature(..)]` in `source`.
+#[tracing::instrument(skip(source))]
+pub fn nightly_features(source: &str) -> Vec<(usize, Vec<String>)> {
+    source
+        .lines()
+        .enumerate()
+        .filter_map(|(i, line)| {
+            let features = line
+                .trim()
+                .strip_prefix("#![feature(")?
+                .split_once(")]")?
+                .0
+                .split(',')
+                .map(|feature| feature.trim().to_string())
+                .filter(|feature| !feature.is_empty())
+                .collect();
+
+            Some((i + 1, features))
+        })
+        .collect()
+}
+
+#[tracing::instrument]
+pub fn report(root: &Path) -> Result<Vec<Finding>> {
+    let mut findings = vec![];
+
+    for member in workspace::member_dirs(root)? {
+        for path in workspace::rust_files(&member)? {
+            let source = std::fs::read_to_string(&path)?;
+            let file = path.strip_prefix(root).unwrap_or(&path);
+
+            findings.extend(
+                nightly_features(&source)
+                    .into_iter()
+                    .map(|(line, features)| Finding {
+                        file: file.to_path_buf(),
+                        line,
+                        features,
+                    }),
+            );
+        }
+    }
+
+    Ok(findings)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn it_should_find_ungated_features() -> miette::Result<()> {
+        let source = "#![feature(iter_map_windows, let_chains)]\n\npub mod part1;\n";
+
+        assert_eq!(
+            vec![(
+                1,
+                vec!["iter_map_windows".to_string(), "let_chains".to_string()]
+            )],
+            nightly_features(source)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_allow_features_gated_on_nightly() -> miette::Result<()> {
+        let source =
+            "#![cfg_attr(feature = \"nightly\", feature(iter_map_windows))]\n\npub mod part1;\n";
+
+        assert!(nightly_features(source).is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_find_nothing_in_workspace() -> miette::Result<()> {
+        assert_eq!(Vec::<Finding>::new(), report(&workspace::root())?);
+        Ok(())
+    }
+}
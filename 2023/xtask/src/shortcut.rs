@@ -0,0 +1,294 @@
+//! What the `cargo day` and `cargo bench-day` aliases run: a year, day and
+//! part checked against the workspace, then turned into the long
+//! `cargo run --package day-XX --bin partN` it stands for.
+
+use std::{
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+use crate::{bootstrap_answers::YEAR, error::Error, prelude::*, workspace};
+
+/// A day crate picked by year and day number.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Target {
+    pub package: String,
+    pub dir: PathBuf,
+}
+
+impl Target {
+    /// `day` of `year`, if this workspace has it.
+    #[tracing::instrument]
+    pub fn find(root: &Path, year: u16, day: u8) -> Result<Self> {
+        if year != YEAR {
+            return Err(Error::UnsupportedYear {
+                year,
+                supported: YEAR,
+            });
+        }
+
+        let package = format!("day-{:02}", day);
+        let dir = root.join(&package);
+        if !dir.join("Cargo.toml").is_file() {
+            return Err(Error::NoSuchDay { day: package });
+        }
+
+        Ok(Self { package, dir })
+    }
+
+    /// The day's binaries, like `part1` and `part2_opt`, in name order.
+    #[tracing::instrument]
+    pub fn binaries(&self) -> Result<Vec<String>> {
+        let mut binaries = workspace::rust_files(&self.dir.join("src").join("bin"))?
+            .iter()
+            .filter_map(|path| path.file_stem())
+            .map(|stem| stem.to_string_lossy().to_string())
+            .collect::<Vec<_>>();
+
+        binaries.sort();
+
+        Ok(binaries)
+    }
+
+    /// The binary `part` names: a bare number like `2` means `part2`,
+    /// anything else has to be a binary's own name.
+    #[tracing::instrument]
+    pub fn binary(&self, part: &str) -> Result<String> {
+        let name = match part.parse::<u8>() {
+            Ok(number) => format!("part{}", number),
+            Err(_) => part.to_string(),
+        };
+
+        let binaries = self.binaries()?;
+        if !binaries.contains(&name) {
+            return Err(Error::NoSuchBinary {
+                day: self.package.clone(),
+                part: part.to_string(),
+                available: binaries.join(", "),
+            });
+        }
+
+        Ok(name)
+    }
+
+    /// Fails on the first of `features` the day doesn't declare, naming the
+    /// ones it does.
+    #[tracing::instrument]
+    pub fn check_features(&self, features: &[String]) -> Result<()> {
+        let manifest = workspace::manifest(&self.dir.join("Cargo.toml"))?;
+        let declared = manifest
+            .get("features")
+            .and_then(|features| features.as_table())
+            .map(|table| table.keys().cloned().collect::<Vec<_>>())
+            .unwrap_or_default();
+
+        match features.iter().find(|feature| !declared.contains(feature)) {
+            Some(feature) => Err(Error::UnknownFeature {
+                day: self.package.clone(),
+                feature: feature.clone(),
+                declared: match declared.is_empty() {
+                    true => "none".to_string(),
+                    false => declared.join(", "),
+                },
+            }),
+            None => Ok(()),
+        }
+    }
+
+    /// Whether the day has a `[[bench]]` target named after it, which is
+    /// what `cargo bench-day` runs.
+    #[tracing::instrument]
+    pub fn has_benchmarks(&self) -> Result<bool> {
+        let manifest = workspace::manifest(&self.dir.join("Cargo.toml"))?;
+
+        Ok(manifest
+            .get("bench")
+            .and_then(|benches| benches.as_array())
+            .is_some_and(|benches| {
+                benches.iter().any(|bench| {
+                    bench.get("name").and_then(|name| name.as_str()) == Some(self.package.as_str())
+                })
+            }))
+    }
+}
+
+#[tracing::instrument]
+fn cargo(root: &Path, subcommand: &str, package: &str, features: &[String]) -> Command {
+    let cargo = std::env::var("CARGO").unwrap_or_else(|_| "cargo".to_string());
+
+    let mut command = Command::new(cargo);
+    command
+        .current_dir(root)
+        .args([subcommand, "--package", package]);
+
+    if !features.is_empty() {
+        command.args(["--features", &features.join(",")]);
+    }
+
+    command
+}
+
+/// `cargo run` for one binary, with `args` passed on to the solution.
+#[tracing::instrument]
+pub fn run_command(
+    root: &Path,
+    target: &Target,
+    binary: &str,
+    features: &[String],
+    release: bool,
+    args: &[String],
+) -> Command {
+    let mut command = cargo(root, "run", &target.package, features);
+    command.args(["--bin", binary]);
+
+    if release {
+        command.arg("--release");
+    }
+    if !args.is_empty() {
+        command.arg("--").args(args);
+    }
+
+    command
+}
+
+/// `cargo bench` for the day's benchmarks, only those matching `filter`
+/// if there is one, with `args` passed on to divan.
+#[tracing::instrument]
+pub fn bench_command(
+    root: &Path,
+    target: &Target,
+    filter: Option<&str>,
+    features: &[String],
+    args: &[String],
+) -> Command {
+    let mut command = cargo(root, "bench", &target.package, features);
+    command.args(["--bench", &target.package]);
+
+    if filter.is_some() || !args.is_empty() {
+        command.arg("--").args(filter).args(args);
+    }
+
+    command
+}
+
+/// Runs `command` with its output going straight to the terminal.
+#[tracing::instrument]
+pub fn run(mut command: Command) -> Result<()> {
+    let status = command.status()?;
+
+    if !status.success() {
+        return Err(Error::CommandFailed {
+            command: describe(&command),
+            status: status.to_string(),
+        });
+    }
+
+    Ok(())
+}
+
+/// `command` as it would be typed, for error messages.
+#[tracing::instrument]
+pub fn describe(command: &Command) -> String {
+    std::iter::once(command.get_program())
+        .chain(command.get_args())
+        .map(|part| part.to_string_lossy())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn strings(values: &[&str]) -> Vec<String> {
+        values.iter().map(|v| v.to_string()).collect()
+    }
+
+    /// A workspace of its own for test `name`, with one day that has
+    /// `part1`, `part2` and `part2_opt` binaries, a `viz` feature and benchmarks.
+    fn workspace(name: &str) -> miette::Result<PathBuf> {
+        let root =
+            std::env::temp_dir().join(format!("xtask-shortcut-{}-{}", name, std::process::id()));
+        let bin = root.join("day-07").join("src").join("bin");
+        std::fs::create_dir_all(&bin).map_err(Error::from)?;
+        for binary in ["part1", "part2", "part2_opt"] {
+            std::fs::write(bin.join(format!("{}.rs", binary)), "fn main() {}\n")
+                .map_err(Error::from)?;
+        }
+        std::fs::write(
+            root.join("day-07").join("Cargo.toml"),
+            "[package]\nname = \"day-07\"\n\n[[bench]]\nname = \"day-07\"\n\n[features]\nviz = []\n",
+        )
+        .map_err(Error::from)?;
+        Ok(root)
+    }
+
+    #[test]
+    fn it_should_find_days_and_their_binaries() -> miette::Result<()> {
+        let root = workspace("find")?;
+        let target = Target::find(&root, 2023, 7)?;
+
+        assert_eq!("day-07", target.package);
+        assert_eq!("part2", target.binary("2")?);
+        assert_eq!("part2_opt", target.binary("part2_opt")?);
+        assert!(target.has_benchmarks()?);
+
+        let Err(Error::NoSuchBinary { available, .. }) = target.binary("3") else {
+            panic!("expected day 7 to have no part 3");
+        };
+        assert_eq!("part1, part2, part2_opt", available);
+
+        assert!(matches!(
+            Target::find(&root, 2023, 10),
+            Err(Error::NoSuchDay { .. })
+        ));
+        assert!(matches!(
+            Target::find(&root, 2015, 7),
+            Err(Error::UnsupportedYear { year: 2015, .. })
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_only_accept_declared_features() -> miette::Result<()> {
+        let target = Target::find(&workspace("features")?, 2023, 7)?;
+
+        target.check_features(&strings(&["viz"]))?;
+
+        let Err(Error::UnknownFeature {
+            feature, declared, ..
+        }) = target.check_features(&strings(&["viz", "parallel"]))
+        else {
+            panic!("expected parallel to be rejected");
+        };
+        assert_eq!("parallel", feature);
+        assert_eq!("viz", declared);
+        Ok(())
+    }
+
+    #[test]
+    fn it_should_spell_out_the_cargo_commands() -> miette::Result<()> {
+        let root = workspace("commands")?;
+        let target = Target::find(&root, 2023, 7)?;
+
+        let run = run_command(
+            &root,
+            &target,
+            "part2",
+            &strings(&["viz"]),
+            true,
+            &strings(&["--input", "example2.txt"]),
+        );
+        assert!(describe(&run).ends_with(
+            " run --package day-07 --features viz --bin part2 --release -- --input example2.txt"
+        ));
+
+        let bench = bench_command(&root, &target, Some("part1"), &[], &[]);
+        assert!(describe(&bench).ends_with(" bench --package day-07 --bench day-07 -- part1"));
+
+        let all = bench_command(&root, &target, None, &[], &[]);
+        assert!(describe(&all).ends_with(" bench --package day-07 --bench day-07"));
+        Ok(())
+    }
+}
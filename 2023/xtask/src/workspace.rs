@@ -0,0 +1,72 @@
+use std::path::{Path, PathBuf};
+
+use crate::{error::Error, prelude::*};
+
+#[tracing::instrument]
+pub fn root() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("..")
+}
+
+/// A `Cargo.toml` parsed as a table.
+#[tracing::instrument]
+pub fn manifest(path: &Path) -> Result<toml::Table> {
+    std::fs::read_to_string(path)?
+        .parse::<toml::Table>()
+        .map_err(|e| Error::InvalidManifest {
+            path: path.display().to_string(),
+            reason: e.to_string(),
+        })
+}
+
+/// Every `day-XX` crate in the workspace, in day order.
+#[tracing::instrument]
+pub fn day_dirs(root: &Path) -> Result<Vec<PathBuf>> {
+    let mut dirs = std::fs::read_dir(root)?
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| path.is_dir())
+        .filter(|path| {
+            path.file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| name.starts_with("day-"))
+        })
+        .collect::<Vec<_>>();
+
+    dirs.sort();
+
+    Ok(dirs)
+}
+
+/// Every workspace member: the directories beside `xtask` with a `Cargo.toml`,
+/// in path order.
+#[tracing::instrument]
+pub fn member_dirs(root: &Path) -> Result<Vec<PathBuf>> {
+    let mut dirs = std::fs::read_dir(root)?
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| path.join("Cargo.toml").is_file())
+        .collect::<Vec<_>>();
+
+    dirs.sort();
+
+    Ok(dirs)
+}
+
+/// All `.rs` files under `dir`, recursively, in path order.
+#[tracing::instrument]
+pub fn rust_files(dir: &Path) -> Result<Vec<PathBuf>> {
+    let mut files = vec![];
+
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            files.extend(rust_files(&path)?);
+        } else if path.extension().is_some_and(|ext| ext == "rs") {
+            files.push(path);
+        }
+    }
+
+    files.sort();
+
+    Ok(files)
+}